@@ -0,0 +1,80 @@
+#!/usr/bin/env node
+const fs = require('fs');
+const path = require('path');
+const { Obs, ObsObjectKind } = require('../index');
+
+const usage = `Usage: obs-schema <source|encoder|output> <id> [options]
+
+Generate a JSON schema and a TypeScript interface for the settings
+of a source, encoder or output type.
+
+Options:
+  --obs-path <path>  The obs installation directory. Defaults to the obs on your PATH.
+  --libobs <path>    The path to libobs. Defaults to the library search path.
+  --name <name>      The name of the generated interface.
+  --out <dir>        Write <id>.schema.json and <id>.d.ts to this directory
+                     instead of printing them.
+  --locale <locale>  The locale used for descriptions. Defaults to en-US.`;
+
+function parseArgs(argv) {
+    const args = { positional: [] };
+    for (let i = 0; i < argv.length; i++) {
+        if (argv[i].startsWith('--')) {
+            args[argv[i].substring(2)] = argv[++i];
+        } else {
+            args.positional.push(argv[i]);
+        }
+    }
+
+    return args;
+}
+
+/**
+ * The directory containing the obs executable of an installation,
+ * the installation directory itself if it has none.
+ */
+function binPath(obsPath) {
+    const bin = process.platform === 'darwin'
+        ? path.join(obsPath, 'Contents', 'MacOS')
+        : path.join(obsPath, 'bin', '64bit');
+
+    return fs.existsSync(bin) ? bin : obsPath;
+}
+
+const kinds = {
+    source: ObsObjectKind.Source,
+    encoder: ObsObjectKind.Encoder,
+    output: ObsObjectKind.Output,
+};
+
+async function main() {
+    const args = parseArgs(process.argv.slice(2));
+    const [kind, id] = args.positional;
+    if (!(kind in kinds) || !id) {
+        console.error(usage);
+        process.exit(1);
+    }
+
+    const obsPath = args['obs-path'] || await Obs.findObs(false);
+    process.chdir(args['obs-path'] ? binPath(obsPath) : await Obs.findObs(true));
+
+    const obs = await Obs.newInstance(args.locale || 'en-US', args.libobs);
+    await obs.loadModules(await obs.getAllModules(obsPath));
+
+    const schema = obs.getTypeSchemaSync(kinds[kind], id, args.name);
+    if (args.out) {
+        fs.mkdirSync(args.out, { recursive: true });
+        fs.writeFileSync(path.join(args.out, `${id}.schema.json`),
+            JSON.stringify(JSON.parse(schema.jsonSchema), null, 2));
+        fs.writeFileSync(path.join(args.out, `${id}.d.ts`), schema.typeDefinition);
+    } else {
+        console.log(JSON.stringify(JSON.parse(schema.jsonSchema), null, 2));
+        console.log();
+        console.log(schema.typeDefinition);
+    }
+}
+
+main().catch(e => {
+    console.error(e.message || e);
+    process.exit(1);
+});
@@ -0,0 +1,5021 @@
+pub const LIBOBS_API_MAJOR_VER: u32 = 30;
+pub const LIBOBS_API_MINOR_VER: u32 = 2;
+pub const LIBOBS_API_PATCH_VER: u32 = 0;
+pub const MAX_AV_PLANES: u32 = 8;
+pub const MAX_AUDIO_MIXES: u32 = 6;
+pub const MAX_AUDIO_CHANNELS: u32 = 8;
+pub const OBS_VIDEO_SUCCESS: u32 = 0;
+pub const OBS_VIDEO_FAIL: i32 = -1;
+pub const OBS_VIDEO_NOT_SUPPORTED: i32 = -2;
+pub const OBS_VIDEO_INVALID_PARAM: i32 = -3;
+pub const OBS_VIDEO_CURRENTLY_ACTIVE: i32 = -4;
+pub const OBS_VIDEO_MODULE_NOT_FOUND: i32 = -5;
+pub const MODULE_SUCCESS: u32 = 0;
+pub const MODULE_ERROR: i32 = -1;
+pub const MODULE_FILE_NOT_FOUND: i32 = -2;
+pub const MODULE_MISSING_EXPORTS: i32 = -3;
+pub const MODULE_INCOMPATIBLE_VER: i32 = -4;
+pub const MODULE_HARDCODED_SKIP: i32 = -5;
+pub const OBS_SOURCE_VIDEO: u32 = 1;
+pub const OBS_SOURCE_AUDIO: u32 = 2;
+pub const OBS_SOURCE_ASYNC: u32 = 4;
+pub const OBS_SOURCE_ASYNC_VIDEO: u32 = 5;
+pub const OBS_SOURCE_CUSTOM_DRAW: u32 = 8;
+pub const OBS_SOURCE_INTERACTION: u32 = 32;
+pub const OBS_SOURCE_COMPOSITE: u32 = 64;
+pub const OBS_SOURCE_DO_NOT_DUPLICATE: u32 = 128;
+pub const OBS_SOURCE_DEPRECATED: u32 = 256;
+pub const OBS_SOURCE_DO_NOT_SELF_MONITOR: u32 = 512;
+pub const OBS_SOURCE_CAP_DISABLED: u32 = 1024;
+pub const OBS_SOURCE_CAP_OBSOLETE: u32 = 1024;
+pub const OBS_SOURCE_MONITOR_BY_DEFAULT: u32 = 2048;
+pub const OBS_SOURCE_SUBMIX: u32 = 4096;
+pub const OBS_SOURCE_CONTROLLABLE_MEDIA: u32 = 8192;
+pub const OBS_SOURCE_CEA_708: u32 = 16384;
+pub const OBS_SOURCE_SRGB: u32 = 32768;
+pub const OBS_SOURCE_CAP_DONT_SHOW_PROPERTIES: u32 = 65536;
+pub const OBS_ENCODER_CAP_DEPRECATED: u32 = 1;
+pub const OBS_ENCODER_CAP_PASS_TEXTURE: u32 = 2;
+pub const OBS_ENCODER_CAP_DYN_BITRATE: u32 = 4;
+pub const OBS_ENCODER_CAP_INTERNAL: u32 = 8;
+pub const OBS_ENCODER_CAP_ROI: u32 = 16;
+pub const OBS_OUTPUT_VIDEO: u32 = 1;
+pub const OBS_OUTPUT_AUDIO: u32 = 2;
+pub const OBS_OUTPUT_AV: u32 = 3;
+pub const OBS_OUTPUT_ENCODED: u32 = 4;
+pub const OBS_OUTPUT_SERVICE: u32 = 8;
+pub const OBS_OUTPUT_MULTI_TRACK: u32 = 16;
+pub const OBS_OUTPUT_CAN_PAUSE: u32 = 32;
+pub const OBS_OUTPUT_SUCCESS: u32 = 0;
+pub const OBS_OUTPUT_BAD_PATH: i32 = -1;
+pub const OBS_OUTPUT_CONNECT_FAILED: i32 = -2;
+pub const OBS_OUTPUT_INVALID_STREAM: i32 = -3;
+pub const OBS_OUTPUT_ERROR: i32 = -4;
+pub const OBS_OUTPUT_DISCONNECTED: i32 = -5;
+pub const OBS_OUTPUT_UNSUPPORTED: i32 = -6;
+pub const OBS_OUTPUT_NO_SPACE: i32 = -7;
+pub const OBS_OUTPUT_ENCODE_ERROR: i32 = -8;
+pub const LOG_ERROR: _bindgen_ty_1 = 100;
+pub const LOG_WARNING: _bindgen_ty_1 = 200;
+pub const LOG_INFO: _bindgen_ty_1 = 300;
+pub const LOG_DEBUG: _bindgen_ty_1 = 400;
+pub type _bindgen_ty_1 = ::std::os::raw::c_int;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct darray {
+    pub array: *mut ::std::os::raw::c_void,
+    pub num: usize,
+    pub capacity: usize,
+}
+#[test]
+fn bindgen_test_layout_darray() {
+    const UNINIT: ::std::mem::MaybeUninit<darray> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::< darray > (), 24usize, concat!("Size of: ",
+        stringify!(darray))
+    );
+    assert_eq!(
+        ::std::mem::align_of::< darray > (), 8usize, concat!("Alignment of ",
+        stringify!(darray))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).array) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(darray), "::", stringify!(array))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).num) as usize - ptr as usize }, 8usize,
+        concat!("Offset of field: ", stringify!(darray), "::", stringify!(num))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).capacity) as usize - ptr as usize },
+        16usize, concat!("Offset of field: ", stringify!(darray), "::",
+        stringify!(capacity))
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct video_output {
+    _unused: [u8; 0],
+}
+pub type video_t = video_output;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct audio_output {
+    _unused: [u8; 0],
+}
+pub type audio_t = audio_output;
+pub const video_format_VIDEO_FORMAT_NONE: video_format = 0;
+pub const video_format_VIDEO_FORMAT_I420: video_format = 1;
+pub const video_format_VIDEO_FORMAT_NV12: video_format = 2;
+pub const video_format_VIDEO_FORMAT_YVYU: video_format = 3;
+pub const video_format_VIDEO_FORMAT_YUY2: video_format = 4;
+pub const video_format_VIDEO_FORMAT_UYVY: video_format = 5;
+pub const video_format_VIDEO_FORMAT_RGBA: video_format = 6;
+pub const video_format_VIDEO_FORMAT_BGRA: video_format = 7;
+pub const video_format_VIDEO_FORMAT_BGRX: video_format = 8;
+pub const video_format_VIDEO_FORMAT_Y800: video_format = 9;
+pub const video_format_VIDEO_FORMAT_I444: video_format = 10;
+pub const video_format_VIDEO_FORMAT_BGR3: video_format = 11;
+pub const video_format_VIDEO_FORMAT_I422: video_format = 12;
+pub const video_format_VIDEO_FORMAT_I40A: video_format = 13;
+pub const video_format_VIDEO_FORMAT_I42A: video_format = 14;
+pub const video_format_VIDEO_FORMAT_YUVA: video_format = 15;
+pub const video_format_VIDEO_FORMAT_AYUV: video_format = 16;
+pub const video_format_VIDEO_FORMAT_I010: video_format = 17;
+pub const video_format_VIDEO_FORMAT_P010: video_format = 18;
+pub const video_format_VIDEO_FORMAT_I210: video_format = 19;
+pub const video_format_VIDEO_FORMAT_I412: video_format = 20;
+pub const video_format_VIDEO_FORMAT_YA2L: video_format = 21;
+pub const video_format_VIDEO_FORMAT_P216: video_format = 22;
+pub const video_format_VIDEO_FORMAT_P416: video_format = 23;
+pub const video_format_VIDEO_FORMAT_V210: video_format = 24;
+pub const video_format_VIDEO_FORMAT_R10L: video_format = 25;
+pub type video_format = ::std::os::raw::c_int;
+pub const video_colorspace_VIDEO_CS_DEFAULT: video_colorspace = 0;
+pub const video_colorspace_VIDEO_CS_601: video_colorspace = 1;
+pub const video_colorspace_VIDEO_CS_709: video_colorspace = 2;
+pub const video_colorspace_VIDEO_CS_SRGB: video_colorspace = 3;
+pub const video_colorspace_VIDEO_CS_2100_PQ: video_colorspace = 4;
+pub const video_colorspace_VIDEO_CS_2100_HLG: video_colorspace = 5;
+pub type video_colorspace = ::std::os::raw::c_int;
+pub const video_range_type_VIDEO_RANGE_DEFAULT: video_range_type = 0;
+pub const video_range_type_VIDEO_RANGE_PARTIAL: video_range_type = 1;
+pub const video_range_type_VIDEO_RANGE_FULL: video_range_type = 2;
+pub type video_range_type = ::std::os::raw::c_int;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct video_data {
+    pub data: [*mut u8; 8usize],
+    pub linesize: [u32; 8usize],
+    pub timestamp: u64,
+}
+#[test]
+fn bindgen_test_layout_video_data() {
+    const UNINIT: ::std::mem::MaybeUninit<video_data> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::< video_data > (), 104usize, concat!("Size of: ",
+        stringify!(video_data))
+    );
+    assert_eq!(
+        ::std::mem::align_of::< video_data > (), 8usize, concat!("Alignment of ",
+        stringify!(video_data))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).data) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(video_data), "::", stringify!(data))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).linesize) as usize - ptr as usize },
+        64usize, concat!("Offset of field: ", stringify!(video_data), "::",
+        stringify!(linesize))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).timestamp) as usize - ptr as usize },
+        96usize, concat!("Offset of field: ", stringify!(video_data), "::",
+        stringify!(timestamp))
+    );
+}
+pub const audio_format_AUDIO_FORMAT_UNKNOWN: audio_format = 0;
+pub const audio_format_AUDIO_FORMAT_U8BIT: audio_format = 1;
+pub const audio_format_AUDIO_FORMAT_16BIT: audio_format = 2;
+pub const audio_format_AUDIO_FORMAT_32BIT: audio_format = 3;
+pub const audio_format_AUDIO_FORMAT_FLOAT: audio_format = 4;
+pub const audio_format_AUDIO_FORMAT_U8BIT_PLANAR: audio_format = 5;
+pub const audio_format_AUDIO_FORMAT_16BIT_PLANAR: audio_format = 6;
+pub const audio_format_AUDIO_FORMAT_32BIT_PLANAR: audio_format = 7;
+pub const audio_format_AUDIO_FORMAT_FLOAT_PLANAR: audio_format = 8;
+pub type audio_format = ::std::os::raw::c_int;
+pub const speaker_layout_SPEAKERS_UNKNOWN: speaker_layout = 0;
+pub const speaker_layout_SPEAKERS_MONO: speaker_layout = 1;
+pub const speaker_layout_SPEAKERS_STEREO: speaker_layout = 2;
+pub const speaker_layout_SPEAKERS_2POINT1: speaker_layout = 3;
+pub const speaker_layout_SPEAKERS_4POINT0: speaker_layout = 4;
+pub const speaker_layout_SPEAKERS_4POINT1: speaker_layout = 5;
+pub const speaker_layout_SPEAKERS_5POINT1: speaker_layout = 6;
+pub const speaker_layout_SPEAKERS_7POINT1: speaker_layout = 8;
+pub type speaker_layout = ::std::os::raw::c_int;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct audio_data {
+    pub data: [*mut u8; 8usize],
+    pub frames: u32,
+    pub timestamp: u64,
+}
+#[test]
+fn bindgen_test_layout_audio_data() {
+    const UNINIT: ::std::mem::MaybeUninit<audio_data> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::< audio_data > (), 80usize, concat!("Size of: ",
+        stringify!(audio_data))
+    );
+    assert_eq!(
+        ::std::mem::align_of::< audio_data > (), 8usize, concat!("Alignment of ",
+        stringify!(audio_data))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).data) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(audio_data), "::", stringify!(data))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).frames) as usize - ptr as usize }, 64usize,
+        concat!("Offset of field: ", stringify!(audio_data), "::", stringify!(frames))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).timestamp) as usize - ptr as usize },
+        72usize, concat!("Offset of field: ", stringify!(audio_data), "::",
+        stringify!(timestamp))
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct calldata {
+    pub stack: *mut u8,
+    pub size: usize,
+    pub capacity: usize,
+    pub fixed: bool,
+}
+#[test]
+fn bindgen_test_layout_calldata() {
+    const UNINIT: ::std::mem::MaybeUninit<calldata> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::< calldata > (), 32usize, concat!("Size of: ",
+        stringify!(calldata))
+    );
+    assert_eq!(
+        ::std::mem::align_of::< calldata > (), 8usize, concat!("Alignment of ",
+        stringify!(calldata))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).stack) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(calldata), "::", stringify!(stack))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).size) as usize - ptr as usize }, 8usize,
+        concat!("Offset of field: ", stringify!(calldata), "::", stringify!(size))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).capacity) as usize - ptr as usize },
+        16usize, concat!("Offset of field: ", stringify!(calldata), "::",
+        stringify!(capacity))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).fixed) as usize - ptr as usize }, 24usize,
+        concat!("Offset of field: ", stringify!(calldata), "::", stringify!(fixed))
+    );
+}
+pub type calldata_t = calldata;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct signal_handler {
+    _unused: [u8; 0],
+}
+pub type signal_handler_t = signal_handler;
+pub type signal_callback_t = Option<
+    unsafe extern "C" fn(arg1: *mut ::std::os::raw::c_void, arg2: *mut calldata_t),
+>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct profiler_name_store {
+    _unused: [u8; 0],
+}
+pub type profiler_name_store_t = profiler_name_store;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct profiler_snapshot {
+    _unused: [u8; 0],
+}
+pub type profiler_snapshot_t = profiler_snapshot;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct profiler_snapshot_entry {
+    _unused: [u8; 0],
+}
+pub type profiler_snapshot_entry_t = profiler_snapshot_entry;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct profiler_time_entry {
+    pub time_delta: u64,
+    pub count: u64,
+}
+#[test]
+fn bindgen_test_layout_profiler_time_entry() {
+    const UNINIT: ::std::mem::MaybeUninit<profiler_time_entry> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::< profiler_time_entry > (), 16usize, concat!("Size of: ",
+        stringify!(profiler_time_entry))
+    );
+    assert_eq!(
+        ::std::mem::align_of::< profiler_time_entry > (), 8usize,
+        concat!("Alignment of ", stringify!(profiler_time_entry))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).time_delta) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ", stringify!(profiler_time_entry), "::",
+        stringify!(time_delta))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).count) as usize - ptr as usize }, 8usize,
+        concat!("Offset of field: ", stringify!(profiler_time_entry), "::",
+        stringify!(count))
+    );
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union profiler_time_entries_t {
+    pub da: darray,
+    pub __bindgen_anon_1: profiler_time_entries_t__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct profiler_time_entries_t__bindgen_ty_1 {
+    pub array: *mut profiler_time_entry,
+    pub num: usize,
+    pub capacity: usize,
+}
+pub type profiler_entry_enum_func = Option<
+    unsafe extern "C" fn(
+        context: *mut ::std::os::raw::c_void,
+        entry: *mut profiler_snapshot_entry_t,
+    ) -> bool,
+>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct gs_effect {
+    _unused: [u8; 0],
+}
+pub type gs_effect_t = gs_effect;
+pub const gs_color_space_GS_CS_SRGB: gs_color_space = 0;
+pub const gs_color_space_GS_CS_SRGB_16F: gs_color_space = 1;
+pub const gs_color_space_GS_CS_709_EXTENDED: gs_color_space = 2;
+pub const gs_color_space_GS_CS_709_SCRGB: gs_color_space = 3;
+pub type gs_color_space = ::std::os::raw::c_int;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct obs_source {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct obs_output {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct obs_encoder {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct obs_service {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct obs_module {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct obs_data {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct obs_data_item {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct obs_properties {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct obs_property {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct obs_missing_files {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct obs_mouse_event {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct obs_key_event {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct obs_source_audio_mix {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct audio_output_data {
+    _unused: [u8; 0],
+}
+pub type obs_source_t = obs_source;
+pub type obs_output_t = obs_output;
+pub type obs_encoder_t = obs_encoder;
+pub type obs_service_t = obs_service;
+pub type obs_module_t = obs_module;
+pub type obs_data_t = obs_data;
+pub type obs_data_item_t = obs_data_item;
+pub type obs_properties_t = obs_properties;
+pub type obs_property_t = obs_property;
+pub type obs_missing_files_t = obs_missing_files;
+pub const obs_scale_type_OBS_SCALE_DISABLE: obs_scale_type = 0;
+pub const obs_scale_type_OBS_SCALE_POINT: obs_scale_type = 1;
+pub const obs_scale_type_OBS_SCALE_BICUBIC: obs_scale_type = 2;
+pub const obs_scale_type_OBS_SCALE_BILINEAR: obs_scale_type = 3;
+pub const obs_scale_type_OBS_SCALE_LANCZOS: obs_scale_type = 4;
+pub const obs_scale_type_OBS_SCALE_AREA: obs_scale_type = 5;
+pub type obs_scale_type = ::std::os::raw::c_int;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct obs_video_info {
+    pub graphics_module: *const ::std::os::raw::c_char,
+    pub fps_num: u32,
+    pub fps_den: u32,
+    pub base_width: u32,
+    pub base_height: u32,
+    pub output_width: u32,
+    pub output_height: u32,
+    pub output_format: video_format,
+    pub adapter: u32,
+    pub gpu_conversion: bool,
+    pub colorspace: video_colorspace,
+    pub range: video_range_type,
+    pub scale_type: obs_scale_type,
+}
+#[test]
+fn bindgen_test_layout_obs_video_info() {
+    const UNINIT: ::std::mem::MaybeUninit<obs_video_info> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::< obs_video_info > (), 56usize, concat!("Size of: ",
+        stringify!(obs_video_info))
+    );
+    assert_eq!(
+        ::std::mem::align_of::< obs_video_info > (), 8usize, concat!("Alignment of ",
+        stringify!(obs_video_info))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).graphics_module) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ", stringify!(obs_video_info), "::",
+        stringify!(graphics_module))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).fps_num) as usize - ptr as usize }, 8usize,
+        concat!("Offset of field: ", stringify!(obs_video_info), "::",
+        stringify!(fps_num))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).fps_den) as usize - ptr as usize },
+        12usize, concat!("Offset of field: ", stringify!(obs_video_info), "::",
+        stringify!(fps_den))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).base_width) as usize - ptr as usize },
+        16usize, concat!("Offset of field: ", stringify!(obs_video_info), "::",
+        stringify!(base_width))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).base_height) as usize - ptr as usize },
+        20usize, concat!("Offset of field: ", stringify!(obs_video_info), "::",
+        stringify!(base_height))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).output_width) as usize - ptr as usize },
+        24usize, concat!("Offset of field: ", stringify!(obs_video_info), "::",
+        stringify!(output_width))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).output_height) as usize - ptr as usize },
+        28usize, concat!("Offset of field: ", stringify!(obs_video_info), "::",
+        stringify!(output_height))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).output_format) as usize - ptr as usize },
+        32usize, concat!("Offset of field: ", stringify!(obs_video_info), "::",
+        stringify!(output_format))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).adapter) as usize - ptr as usize },
+        36usize, concat!("Offset of field: ", stringify!(obs_video_info), "::",
+        stringify!(adapter))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).gpu_conversion) as usize - ptr as usize },
+        40usize, concat!("Offset of field: ", stringify!(obs_video_info), "::",
+        stringify!(gpu_conversion))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).colorspace) as usize - ptr as usize },
+        44usize, concat!("Offset of field: ", stringify!(obs_video_info), "::",
+        stringify!(colorspace))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).range) as usize - ptr as usize }, 48usize,
+        concat!("Offset of field: ", stringify!(obs_video_info), "::", stringify!(range))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).scale_type) as usize - ptr as usize },
+        52usize, concat!("Offset of field: ", stringify!(obs_video_info), "::",
+        stringify!(scale_type))
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct obs_audio_info {
+    pub samples_per_sec: u32,
+    pub speakers: speaker_layout,
+}
+#[test]
+fn bindgen_test_layout_obs_audio_info() {
+    const UNINIT: ::std::mem::MaybeUninit<obs_audio_info> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::< obs_audio_info > (), 8usize, concat!("Size of: ",
+        stringify!(obs_audio_info))
+    );
+    assert_eq!(
+        ::std::mem::align_of::< obs_audio_info > (), 4usize, concat!("Alignment of ",
+        stringify!(obs_audio_info))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).samples_per_sec) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ", stringify!(obs_audio_info), "::",
+        stringify!(samples_per_sec))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).speakers) as usize - ptr as usize },
+        4usize, concat!("Offset of field: ", stringify!(obs_audio_info), "::",
+        stringify!(speakers))
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct obs_audio_info2 {
+    pub samples_per_sec: u32,
+    pub speakers: speaker_layout,
+    pub max_buffering_ms: u32,
+    pub fixed_buffering: bool,
+}
+#[test]
+fn bindgen_test_layout_obs_audio_info2() {
+    const UNINIT: ::std::mem::MaybeUninit<obs_audio_info2> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::< obs_audio_info2 > (), 16usize, concat!("Size of: ",
+        stringify!(obs_audio_info2))
+    );
+    assert_eq!(
+        ::std::mem::align_of::< obs_audio_info2 > (), 4usize, concat!("Alignment of ",
+        stringify!(obs_audio_info2))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).samples_per_sec) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ", stringify!(obs_audio_info2), "::",
+        stringify!(samples_per_sec))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).speakers) as usize - ptr as usize },
+        4usize, concat!("Offset of field: ", stringify!(obs_audio_info2), "::",
+        stringify!(speakers))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).max_buffering_ms) as usize - ptr as usize
+        }, 8usize, concat!("Offset of field: ", stringify!(obs_audio_info2), "::",
+        stringify!(max_buffering_ms))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).fixed_buffering) as usize - ptr as usize },
+        12usize, concat!("Offset of field: ", stringify!(obs_audio_info2), "::",
+        stringify!(fixed_buffering))
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct obs_audio_data {
+    pub data: [*mut u8; 8usize],
+    pub frames: u32,
+    pub timestamp: u64,
+}
+#[test]
+fn bindgen_test_layout_obs_audio_data() {
+    const UNINIT: ::std::mem::MaybeUninit<obs_audio_data> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::< obs_audio_data > (), 80usize, concat!("Size of: ",
+        stringify!(obs_audio_data))
+    );
+    assert_eq!(
+        ::std::mem::align_of::< obs_audio_data > (), 8usize, concat!("Alignment of ",
+        stringify!(obs_audio_data))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).data) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(obs_audio_data), "::", stringify!(data))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).frames) as usize - ptr as usize }, 64usize,
+        concat!("Offset of field: ", stringify!(obs_audio_data), "::",
+        stringify!(frames))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).timestamp) as usize - ptr as usize },
+        72usize, concat!("Offset of field: ", stringify!(obs_audio_data), "::",
+        stringify!(timestamp))
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct obs_source_audio {
+    pub data: [*const u8; 8usize],
+    pub frames: u32,
+    pub speakers: speaker_layout,
+    pub format: audio_format,
+    pub samples_per_sec: u32,
+    pub timestamp: u64,
+}
+#[test]
+fn bindgen_test_layout_obs_source_audio() {
+    const UNINIT: ::std::mem::MaybeUninit<obs_source_audio> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::< obs_source_audio > (), 88usize, concat!("Size of: ",
+        stringify!(obs_source_audio))
+    );
+    assert_eq!(
+        ::std::mem::align_of::< obs_source_audio > (), 8usize, concat!("Alignment of ",
+        stringify!(obs_source_audio))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).data) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(obs_source_audio), "::",
+        stringify!(data))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).frames) as usize - ptr as usize }, 64usize,
+        concat!("Offset of field: ", stringify!(obs_source_audio), "::",
+        stringify!(frames))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).speakers) as usize - ptr as usize },
+        68usize, concat!("Offset of field: ", stringify!(obs_source_audio), "::",
+        stringify!(speakers))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).format) as usize - ptr as usize }, 72usize,
+        concat!("Offset of field: ", stringify!(obs_source_audio), "::",
+        stringify!(format))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).samples_per_sec) as usize - ptr as usize },
+        76usize, concat!("Offset of field: ", stringify!(obs_source_audio), "::",
+        stringify!(samples_per_sec))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).timestamp) as usize - ptr as usize },
+        80usize, concat!("Offset of field: ", stringify!(obs_source_audio), "::",
+        stringify!(timestamp))
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct obs_source_frame {
+    pub data: [*mut u8; 8usize],
+    pub linesize: [u32; 8usize],
+    pub width: u32,
+    pub height: u32,
+    pub timestamp: u64,
+    pub format: video_format,
+    pub color_matrix: [f32; 16usize],
+    pub full_range: bool,
+    pub max_luminance: u16,
+    pub color_range_min: [f32; 3usize],
+    pub color_range_max: [f32; 3usize],
+    pub flip: bool,
+    pub flags: u8,
+    pub trc: u8,
+    pub refs: ::std::os::raw::c_long,
+    pub prev_frame: bool,
+}
+#[test]
+fn bindgen_test_layout_obs_source_frame() {
+    const UNINIT: ::std::mem::MaybeUninit<obs_source_frame> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::< obs_source_frame > (), 232usize, concat!("Size of: ",
+        stringify!(obs_source_frame))
+    );
+    assert_eq!(
+        ::std::mem::align_of::< obs_source_frame > (), 8usize, concat!("Alignment of ",
+        stringify!(obs_source_frame))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).data) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(obs_source_frame), "::",
+        stringify!(data))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).linesize) as usize - ptr as usize },
+        64usize, concat!("Offset of field: ", stringify!(obs_source_frame), "::",
+        stringify!(linesize))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).width) as usize - ptr as usize }, 96usize,
+        concat!("Offset of field: ", stringify!(obs_source_frame), "::",
+        stringify!(width))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).height) as usize - ptr as usize },
+        100usize, concat!("Offset of field: ", stringify!(obs_source_frame), "::",
+        stringify!(height))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).timestamp) as usize - ptr as usize },
+        104usize, concat!("Offset of field: ", stringify!(obs_source_frame), "::",
+        stringify!(timestamp))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).format) as usize - ptr as usize },
+        112usize, concat!("Offset of field: ", stringify!(obs_source_frame), "::",
+        stringify!(format))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).color_matrix) as usize - ptr as usize },
+        116usize, concat!("Offset of field: ", stringify!(obs_source_frame), "::",
+        stringify!(color_matrix))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).full_range) as usize - ptr as usize },
+        180usize, concat!("Offset of field: ", stringify!(obs_source_frame), "::",
+        stringify!(full_range))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).max_luminance) as usize - ptr as usize },
+        182usize, concat!("Offset of field: ", stringify!(obs_source_frame), "::",
+        stringify!(max_luminance))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).color_range_min) as usize - ptr as usize },
+        184usize, concat!("Offset of field: ", stringify!(obs_source_frame), "::",
+        stringify!(color_range_min))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).color_range_max) as usize - ptr as usize },
+        196usize, concat!("Offset of field: ", stringify!(obs_source_frame), "::",
+        stringify!(color_range_max))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).flip) as usize - ptr as usize }, 208usize,
+        concat!("Offset of field: ", stringify!(obs_source_frame), "::",
+        stringify!(flip))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).flags) as usize - ptr as usize }, 209usize,
+        concat!("Offset of field: ", stringify!(obs_source_frame), "::",
+        stringify!(flags))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).trc) as usize - ptr as usize }, 210usize,
+        concat!("Offset of field: ", stringify!(obs_source_frame), "::", stringify!(trc))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).refs) as usize - ptr as usize }, 216usize,
+        concat!("Offset of field: ", stringify!(obs_source_frame), "::",
+        stringify!(refs))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).prev_frame) as usize - ptr as usize },
+        224usize, concat!("Offset of field: ", stringify!(obs_source_frame), "::",
+        stringify!(prev_frame))
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct obs_module_info {
+    pub bin_path: *const ::std::os::raw::c_char,
+    pub data_path: *const ::std::os::raw::c_char,
+}
+#[test]
+fn bindgen_test_layout_obs_module_info() {
+    const UNINIT: ::std::mem::MaybeUninit<obs_module_info> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::< obs_module_info > (), 16usize, concat!("Size of: ",
+        stringify!(obs_module_info))
+    );
+    assert_eq!(
+        ::std::mem::align_of::< obs_module_info > (), 8usize, concat!("Alignment of ",
+        stringify!(obs_module_info))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).bin_path) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ", stringify!(obs_module_info), "::",
+        stringify!(bin_path))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).data_path) as usize - ptr as usize },
+        8usize, concat!("Offset of field: ", stringify!(obs_module_info), "::",
+        stringify!(data_path))
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct obs_module_info2 {
+    pub bin_path: *const ::std::os::raw::c_char,
+    pub data_path: *const ::std::os::raw::c_char,
+    pub name: *const ::std::os::raw::c_char,
+}
+#[test]
+fn bindgen_test_layout_obs_module_info2() {
+    const UNINIT: ::std::mem::MaybeUninit<obs_module_info2> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::< obs_module_info2 > (), 24usize, concat!("Size of: ",
+        stringify!(obs_module_info2))
+    );
+    assert_eq!(
+        ::std::mem::align_of::< obs_module_info2 > (), 8usize, concat!("Alignment of ",
+        stringify!(obs_module_info2))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).bin_path) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ", stringify!(obs_module_info2), "::",
+        stringify!(bin_path))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).data_path) as usize - ptr as usize },
+        8usize, concat!("Offset of field: ", stringify!(obs_module_info2), "::",
+        stringify!(data_path))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).name) as usize - ptr as usize }, 16usize,
+        concat!("Offset of field: ", stringify!(obs_module_info2), "::",
+        stringify!(name))
+    );
+}
+pub type obs_find_module_callback_t = Option<
+    unsafe extern "C" fn(
+        param: *mut ::std::os::raw::c_void,
+        info: *const obs_module_info,
+    ),
+>;
+pub type obs_find_module_callback2_t = Option<
+    unsafe extern "C" fn(
+        param: *mut ::std::os::raw::c_void,
+        info: *const obs_module_info2,
+    ),
+>;
+pub type obs_enum_module_callback_t = Option<
+    unsafe extern "C" fn(param: *mut ::std::os::raw::c_void, module: *mut obs_module_t),
+>;
+pub const obs_data_type_OBS_DATA_NULL: obs_data_type = 0;
+pub const obs_data_type_OBS_DATA_STRING: obs_data_type = 1;
+pub const obs_data_type_OBS_DATA_NUMBER: obs_data_type = 2;
+pub const obs_data_type_OBS_DATA_BOOLEAN: obs_data_type = 3;
+pub const obs_data_type_OBS_DATA_OBJECT: obs_data_type = 4;
+pub const obs_data_type_OBS_DATA_ARRAY: obs_data_type = 5;
+pub type obs_data_type = ::std::os::raw::c_int;
+pub const obs_data_number_type_OBS_DATA_NUM_INVALID: obs_data_number_type = 0;
+pub const obs_data_number_type_OBS_DATA_NUM_INT: obs_data_number_type = 1;
+pub const obs_data_number_type_OBS_DATA_NUM_DOUBLE: obs_data_number_type = 2;
+pub type obs_data_number_type = ::std::os::raw::c_int;
+pub const obs_property_type_OBS_PROPERTY_INVALID: obs_property_type = 0;
+pub const obs_property_type_OBS_PROPERTY_BOOL: obs_property_type = 1;
+pub const obs_property_type_OBS_PROPERTY_INT: obs_property_type = 2;
+pub const obs_property_type_OBS_PROPERTY_FLOAT: obs_property_type = 3;
+pub const obs_property_type_OBS_PROPERTY_TEXT: obs_property_type = 4;
+pub const obs_property_type_OBS_PROPERTY_PATH: obs_property_type = 5;
+pub const obs_property_type_OBS_PROPERTY_LIST: obs_property_type = 6;
+pub const obs_property_type_OBS_PROPERTY_COLOR: obs_property_type = 7;
+pub const obs_property_type_OBS_PROPERTY_BUTTON: obs_property_type = 8;
+pub const obs_property_type_OBS_PROPERTY_FONT: obs_property_type = 9;
+pub const obs_property_type_OBS_PROPERTY_EDITABLE_LIST: obs_property_type = 10;
+pub const obs_property_type_OBS_PROPERTY_FRAME_RATE: obs_property_type = 11;
+pub const obs_property_type_OBS_PROPERTY_GROUP: obs_property_type = 12;
+pub const obs_property_type_OBS_PROPERTY_COLOR_ALPHA: obs_property_type = 13;
+pub type obs_property_type = ::std::os::raw::c_int;
+pub const obs_combo_format_OBS_COMBO_FORMAT_INVALID: obs_combo_format = 0;
+pub const obs_combo_format_OBS_COMBO_FORMAT_INT: obs_combo_format = 1;
+pub const obs_combo_format_OBS_COMBO_FORMAT_FLOAT: obs_combo_format = 2;
+pub const obs_combo_format_OBS_COMBO_FORMAT_STRING: obs_combo_format = 3;
+pub const obs_combo_format_OBS_COMBO_FORMAT_BOOL: obs_combo_format = 4;
+pub type obs_combo_format = ::std::os::raw::c_int;
+pub const obs_combo_type_OBS_COMBO_TYPE_INVALID: obs_combo_type = 0;
+pub const obs_combo_type_OBS_COMBO_TYPE_EDITABLE: obs_combo_type = 1;
+pub const obs_combo_type_OBS_COMBO_TYPE_LIST: obs_combo_type = 2;
+pub const obs_combo_type_OBS_COMBO_TYPE_RADIO: obs_combo_type = 3;
+pub type obs_combo_type = ::std::os::raw::c_int;
+pub const obs_path_type_OBS_PATH_FILE: obs_path_type = 0;
+pub const obs_path_type_OBS_PATH_FILE_SAVE: obs_path_type = 1;
+pub const obs_path_type_OBS_PATH_DIRECTORY: obs_path_type = 2;
+pub type obs_path_type = ::std::os::raw::c_int;
+pub const obs_text_type_OBS_TEXT_DEFAULT: obs_text_type = 0;
+pub const obs_text_type_OBS_TEXT_PASSWORD: obs_text_type = 1;
+pub const obs_text_type_OBS_TEXT_MULTILINE: obs_text_type = 2;
+pub const obs_text_type_OBS_TEXT_INFO: obs_text_type = 3;
+pub type obs_text_type = ::std::os::raw::c_int;
+pub const obs_number_type_OBS_NUMBER_SCROLLER: obs_number_type = 0;
+pub const obs_number_type_OBS_NUMBER_SLIDER: obs_number_type = 1;
+pub type obs_number_type = ::std::os::raw::c_int;
+pub const obs_group_type_OBS_COMBO_INVALID: obs_group_type = 0;
+pub const obs_group_type_OBS_GROUP_NORMAL: obs_group_type = 1;
+pub const obs_group_type_OBS_GROUP_CHECKABLE: obs_group_type = 2;
+pub type obs_group_type = ::std::os::raw::c_int;
+pub const obs_source_type_OBS_SOURCE_TYPE_INPUT: obs_source_type = 0;
+pub const obs_source_type_OBS_SOURCE_TYPE_FILTER: obs_source_type = 1;
+pub const obs_source_type_OBS_SOURCE_TYPE_TRANSITION: obs_source_type = 2;
+pub const obs_source_type_OBS_SOURCE_TYPE_SCENE: obs_source_type = 3;
+pub type obs_source_type = ::std::os::raw::c_int;
+pub const obs_media_state_OBS_MEDIA_STATE_NONE: obs_media_state = 0;
+pub const obs_media_state_OBS_MEDIA_STATE_PLAYING: obs_media_state = 1;
+pub const obs_media_state_OBS_MEDIA_STATE_OPENING: obs_media_state = 2;
+pub const obs_media_state_OBS_MEDIA_STATE_BUFFERING: obs_media_state = 3;
+pub const obs_media_state_OBS_MEDIA_STATE_PAUSED: obs_media_state = 4;
+pub const obs_media_state_OBS_MEDIA_STATE_STOPPED: obs_media_state = 5;
+pub const obs_media_state_OBS_MEDIA_STATE_ENDED: obs_media_state = 6;
+pub const obs_media_state_OBS_MEDIA_STATE_ERROR: obs_media_state = 7;
+pub type obs_media_state = ::std::os::raw::c_int;
+pub const obs_icon_type_OBS_ICON_TYPE_UNKNOWN: obs_icon_type = 0;
+pub const obs_icon_type_OBS_ICON_TYPE_IMAGE: obs_icon_type = 1;
+pub const obs_icon_type_OBS_ICON_TYPE_COLOR: obs_icon_type = 2;
+pub const obs_icon_type_OBS_ICON_TYPE_SLIDESHOW: obs_icon_type = 3;
+pub const obs_icon_type_OBS_ICON_TYPE_AUDIO_INPUT: obs_icon_type = 4;
+pub const obs_icon_type_OBS_ICON_TYPE_AUDIO_OUTPUT: obs_icon_type = 5;
+pub const obs_icon_type_OBS_ICON_TYPE_DESKTOP_CAPTURE: obs_icon_type = 6;
+pub const obs_icon_type_OBS_ICON_TYPE_WINDOW_CAPTURE: obs_icon_type = 7;
+pub const obs_icon_type_OBS_ICON_TYPE_GAME_CAPTURE: obs_icon_type = 8;
+pub const obs_icon_type_OBS_ICON_TYPE_CAMERA: obs_icon_type = 9;
+pub const obs_icon_type_OBS_ICON_TYPE_TEXT: obs_icon_type = 10;
+pub const obs_icon_type_OBS_ICON_TYPE_MEDIA: obs_icon_type = 11;
+pub const obs_icon_type_OBS_ICON_TYPE_BROWSER: obs_icon_type = 12;
+pub const obs_icon_type_OBS_ICON_TYPE_CUSTOM: obs_icon_type = 13;
+pub const obs_icon_type_OBS_ICON_TYPE_PROCESS_AUDIO_OUTPUT: obs_icon_type = 14;
+pub type obs_icon_type = ::std::os::raw::c_int;
+pub type obs_source_enum_proc_t = Option<
+    unsafe extern "C" fn(
+        parent: *mut obs_source_t,
+        child: *mut obs_source_t,
+        param: *mut ::std::os::raw::c_void,
+    ),
+>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct obs_source_info {
+    pub id: *const ::std::os::raw::c_char,
+    pub type_: obs_source_type,
+    pub output_flags: u32,
+    pub get_name: Option<
+        unsafe extern "C" fn(
+            type_data: *mut ::std::os::raw::c_void,
+        ) -> *const ::std::os::raw::c_char,
+    >,
+    pub create: Option<
+        unsafe extern "C" fn(
+            settings: *mut obs_data_t,
+            source: *mut obs_source_t,
+        ) -> *mut ::std::os::raw::c_void,
+    >,
+    pub destroy: Option<unsafe extern "C" fn(data: *mut ::std::os::raw::c_void)>,
+    pub get_width: Option<
+        unsafe extern "C" fn(data: *mut ::std::os::raw::c_void) -> u32,
+    >,
+    pub get_height: Option<
+        unsafe extern "C" fn(data: *mut ::std::os::raw::c_void) -> u32,
+    >,
+    pub get_defaults: Option<unsafe extern "C" fn(settings: *mut obs_data_t)>,
+    pub get_properties: Option<
+        unsafe extern "C" fn(data: *mut ::std::os::raw::c_void) -> *mut obs_properties_t,
+    >,
+    pub update: Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            settings: *mut obs_data_t,
+        ),
+    >,
+    pub activate: Option<unsafe extern "C" fn(data: *mut ::std::os::raw::c_void)>,
+    pub deactivate: Option<unsafe extern "C" fn(data: *mut ::std::os::raw::c_void)>,
+    pub show: Option<unsafe extern "C" fn(data: *mut ::std::os::raw::c_void)>,
+    pub hide: Option<unsafe extern "C" fn(data: *mut ::std::os::raw::c_void)>,
+    pub video_tick: Option<
+        unsafe extern "C" fn(data: *mut ::std::os::raw::c_void, seconds: f32),
+    >,
+    pub video_render: Option<
+        unsafe extern "C" fn(data: *mut ::std::os::raw::c_void, effect: *mut gs_effect_t),
+    >,
+    pub filter_video: Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            frame: *mut obs_source_frame,
+        ) -> *mut obs_source_frame,
+    >,
+    pub filter_audio: Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            audio: *mut obs_audio_data,
+        ) -> *mut obs_audio_data,
+    >,
+    pub enum_active_sources: Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            enum_callback: obs_source_enum_proc_t,
+            param: *mut ::std::os::raw::c_void,
+        ),
+    >,
+    pub save: Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            settings: *mut obs_data_t,
+        ),
+    >,
+    pub load: Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            settings: *mut obs_data_t,
+        ),
+    >,
+    pub mouse_click: Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            event: *const obs_mouse_event,
+            type_: i32,
+            mouse_up: bool,
+            click_count: u32,
+        ),
+    >,
+    pub mouse_move: Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            event: *const obs_mouse_event,
+            mouse_leave: bool,
+        ),
+    >,
+    pub mouse_wheel: Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            event: *const obs_mouse_event,
+            x_delta: ::std::os::raw::c_int,
+            y_delta: ::std::os::raw::c_int,
+        ),
+    >,
+    pub focus: Option<
+        unsafe extern "C" fn(data: *mut ::std::os::raw::c_void, focus: bool),
+    >,
+    pub key_click: Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            event: *const obs_key_event,
+            key_up: bool,
+        ),
+    >,
+    pub filter_remove: Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            source: *mut obs_source_t,
+        ),
+    >,
+    pub type_data: *mut ::std::os::raw::c_void,
+    pub free_type_data: Option<
+        unsafe extern "C" fn(type_data: *mut ::std::os::raw::c_void),
+    >,
+    pub audio_render: Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            ts_out: *mut u64,
+            audio_output: *mut obs_source_audio_mix,
+            mixers: u32,
+            channels: usize,
+            sample_rate: usize,
+        ) -> bool,
+    >,
+    pub enum_all_sources: Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            enum_callback: obs_source_enum_proc_t,
+            param: *mut ::std::os::raw::c_void,
+        ),
+    >,
+    pub transition_start: Option<
+        unsafe extern "C" fn(data: *mut ::std::os::raw::c_void),
+    >,
+    pub transition_stop: Option<unsafe extern "C" fn(data: *mut ::std::os::raw::c_void)>,
+    pub get_defaults2: Option<
+        unsafe extern "C" fn(
+            type_data: *mut ::std::os::raw::c_void,
+            settings: *mut obs_data_t,
+        ),
+    >,
+    pub get_properties2: Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            type_data: *mut ::std::os::raw::c_void,
+        ) -> *mut obs_properties_t,
+    >,
+    pub audio_mix: Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            ts_out: *mut u64,
+            audio_output: *mut audio_output_data,
+            channels: usize,
+            sample_rate: usize,
+        ) -> bool,
+    >,
+    pub icon_type: obs_icon_type,
+    pub media_play_pause: Option<
+        unsafe extern "C" fn(data: *mut ::std::os::raw::c_void, pause: bool),
+    >,
+    pub media_restart: Option<unsafe extern "C" fn(data: *mut ::std::os::raw::c_void)>,
+    pub media_stop: Option<unsafe extern "C" fn(data: *mut ::std::os::raw::c_void)>,
+    pub media_next: Option<unsafe extern "C" fn(data: *mut ::std::os::raw::c_void)>,
+    pub media_previous: Option<unsafe extern "C" fn(data: *mut ::std::os::raw::c_void)>,
+    pub media_get_duration: Option<
+        unsafe extern "C" fn(data: *mut ::std::os::raw::c_void) -> i64,
+    >,
+    pub media_get_time: Option<
+        unsafe extern "C" fn(data: *mut ::std::os::raw::c_void) -> i64,
+    >,
+    pub media_set_time: Option<
+        unsafe extern "C" fn(data: *mut ::std::os::raw::c_void, miliseconds: i64),
+    >,
+    pub media_get_state: Option<
+        unsafe extern "C" fn(data: *mut ::std::os::raw::c_void) -> obs_media_state,
+    >,
+    pub version: u32,
+    pub unversioned_id: *const ::std::os::raw::c_char,
+    pub missing_files: Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+        ) -> *mut obs_missing_files_t,
+    >,
+    pub video_get_color_space: Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            count: usize,
+            preferred_spaces: *const gs_color_space,
+        ) -> gs_color_space,
+    >,
+    pub filter_add: Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            source: *mut obs_source_t,
+        ),
+    >,
+}
+#[test]
+fn bindgen_test_layout_obs_source_info() {
+    const UNINIT: ::std::mem::MaybeUninit<obs_source_info> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::< obs_source_info > (), 408usize, concat!("Size of: ",
+        stringify!(obs_source_info))
+    );
+    assert_eq!(
+        ::std::mem::align_of::< obs_source_info > (), 8usize, concat!("Alignment of ",
+        stringify!(obs_source_info))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).id) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(obs_source_info), "::", stringify!(id))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).type_) as usize - ptr as usize }, 8usize,
+        concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(type_))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).output_flags) as usize - ptr as usize },
+        12usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(output_flags))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).get_name) as usize - ptr as usize },
+        16usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(get_name))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).create) as usize - ptr as usize }, 24usize,
+        concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(create))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).destroy) as usize - ptr as usize },
+        32usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(destroy))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).get_width) as usize - ptr as usize },
+        40usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(get_width))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).get_height) as usize - ptr as usize },
+        48usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(get_height))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).get_defaults) as usize - ptr as usize },
+        56usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(get_defaults))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).get_properties) as usize - ptr as usize },
+        64usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(get_properties))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).update) as usize - ptr as usize }, 72usize,
+        concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(update))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).activate) as usize - ptr as usize },
+        80usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(activate))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).deactivate) as usize - ptr as usize },
+        88usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(deactivate))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).show) as usize - ptr as usize }, 96usize,
+        concat!("Offset of field: ", stringify!(obs_source_info), "::", stringify!(show))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).hide) as usize - ptr as usize }, 104usize,
+        concat!("Offset of field: ", stringify!(obs_source_info), "::", stringify!(hide))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).video_tick) as usize - ptr as usize },
+        112usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(video_tick))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).video_render) as usize - ptr as usize },
+        120usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(video_render))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).filter_video) as usize - ptr as usize },
+        128usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(filter_video))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).filter_audio) as usize - ptr as usize },
+        136usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(filter_audio))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).enum_active_sources) as usize - ptr as
+        usize }, 144usize, concat!("Offset of field: ", stringify!(obs_source_info),
+        "::", stringify!(enum_active_sources))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).save) as usize - ptr as usize }, 152usize,
+        concat!("Offset of field: ", stringify!(obs_source_info), "::", stringify!(save))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).load) as usize - ptr as usize }, 160usize,
+        concat!("Offset of field: ", stringify!(obs_source_info), "::", stringify!(load))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).mouse_click) as usize - ptr as usize },
+        168usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(mouse_click))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).mouse_move) as usize - ptr as usize },
+        176usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(mouse_move))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).mouse_wheel) as usize - ptr as usize },
+        184usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(mouse_wheel))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).focus) as usize - ptr as usize }, 192usize,
+        concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(focus))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).key_click) as usize - ptr as usize },
+        200usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(key_click))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).filter_remove) as usize - ptr as usize },
+        208usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(filter_remove))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).type_data) as usize - ptr as usize },
+        216usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(type_data))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).free_type_data) as usize - ptr as usize },
+        224usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(free_type_data))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).audio_render) as usize - ptr as usize },
+        232usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(audio_render))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).enum_all_sources) as usize - ptr as usize
+        }, 240usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(enum_all_sources))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).transition_start) as usize - ptr as usize
+        }, 248usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(transition_start))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).transition_stop) as usize - ptr as usize },
+        256usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(transition_stop))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).get_defaults2) as usize - ptr as usize },
+        264usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(get_defaults2))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).get_properties2) as usize - ptr as usize },
+        272usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(get_properties2))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).audio_mix) as usize - ptr as usize },
+        280usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(audio_mix))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).icon_type) as usize - ptr as usize },
+        288usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(icon_type))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).media_play_pause) as usize - ptr as usize
+        }, 296usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(media_play_pause))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).media_restart) as usize - ptr as usize },
+        304usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(media_restart))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).media_stop) as usize - ptr as usize },
+        312usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(media_stop))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).media_next) as usize - ptr as usize },
+        320usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(media_next))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).media_previous) as usize - ptr as usize },
+        328usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(media_previous))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).media_get_duration) as usize - ptr as usize
+        }, 336usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(media_get_duration))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).media_get_time) as usize - ptr as usize },
+        344usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(media_get_time))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).media_set_time) as usize - ptr as usize },
+        352usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(media_set_time))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).media_get_state) as usize - ptr as usize },
+        360usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(media_get_state))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).version) as usize - ptr as usize },
+        368usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(version))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).unversioned_id) as usize - ptr as usize },
+        376usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(unversioned_id))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).missing_files) as usize - ptr as usize },
+        384usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(missing_files))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).video_get_color_space) as usize - ptr as
+        usize }, 392usize, concat!("Offset of field: ", stringify!(obs_source_info),
+        "::", stringify!(video_get_color_space))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).filter_add) as usize - ptr as usize },
+        400usize, concat!("Offset of field: ", stringify!(obs_source_info), "::",
+        stringify!(filter_add))
+    );
+}
+pub const obs_encoder_type_OBS_ENCODER_AUDIO: obs_encoder_type = 0;
+pub const obs_encoder_type_OBS_ENCODER_VIDEO: obs_encoder_type = 1;
+pub type obs_encoder_type = ::std::os::raw::c_int;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct encoder_packet {
+    pub data: *mut u8,
+    pub size: usize,
+    pub pts: i64,
+    pub dts: i64,
+    pub timebase_num: i32,
+    pub timebase_den: i32,
+    pub type_: obs_encoder_type,
+    pub keyframe: bool,
+    pub dts_usec: i64,
+    pub sys_dts_usec: i64,
+    pub priority: ::std::os::raw::c_int,
+    pub drop_priority: ::std::os::raw::c_int,
+    pub track_idx: usize,
+    pub encoder: *mut obs_encoder_t,
+}
+#[test]
+fn bindgen_test_layout_encoder_packet() {
+    const UNINIT: ::std::mem::MaybeUninit<encoder_packet> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::< encoder_packet > (), 88usize, concat!("Size of: ",
+        stringify!(encoder_packet))
+    );
+    assert_eq!(
+        ::std::mem::align_of::< encoder_packet > (), 8usize, concat!("Alignment of ",
+        stringify!(encoder_packet))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).data) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(encoder_packet), "::", stringify!(data))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).size) as usize - ptr as usize }, 8usize,
+        concat!("Offset of field: ", stringify!(encoder_packet), "::", stringify!(size))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).pts) as usize - ptr as usize }, 16usize,
+        concat!("Offset of field: ", stringify!(encoder_packet), "::", stringify!(pts))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).dts) as usize - ptr as usize }, 24usize,
+        concat!("Offset of field: ", stringify!(encoder_packet), "::", stringify!(dts))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).timebase_num) as usize - ptr as usize },
+        32usize, concat!("Offset of field: ", stringify!(encoder_packet), "::",
+        stringify!(timebase_num))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).timebase_den) as usize - ptr as usize },
+        36usize, concat!("Offset of field: ", stringify!(encoder_packet), "::",
+        stringify!(timebase_den))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).type_) as usize - ptr as usize }, 40usize,
+        concat!("Offset of field: ", stringify!(encoder_packet), "::", stringify!(type_))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).keyframe) as usize - ptr as usize },
+        44usize, concat!("Offset of field: ", stringify!(encoder_packet), "::",
+        stringify!(keyframe))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).dts_usec) as usize - ptr as usize },
+        48usize, concat!("Offset of field: ", stringify!(encoder_packet), "::",
+        stringify!(dts_usec))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).sys_dts_usec) as usize - ptr as usize },
+        56usize, concat!("Offset of field: ", stringify!(encoder_packet), "::",
+        stringify!(sys_dts_usec))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).priority) as usize - ptr as usize },
+        64usize, concat!("Offset of field: ", stringify!(encoder_packet), "::",
+        stringify!(priority))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).drop_priority) as usize - ptr as usize },
+        68usize, concat!("Offset of field: ", stringify!(encoder_packet), "::",
+        stringify!(drop_priority))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).track_idx) as usize - ptr as usize },
+        72usize, concat!("Offset of field: ", stringify!(encoder_packet), "::",
+        stringify!(track_idx))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).encoder) as usize - ptr as usize },
+        80usize, concat!("Offset of field: ", stringify!(encoder_packet), "::",
+        stringify!(encoder))
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct obs_output_info {
+    pub id: *const ::std::os::raw::c_char,
+    pub flags: u32,
+    pub get_name: Option<
+        unsafe extern "C" fn(
+            type_data: *mut ::std::os::raw::c_void,
+        ) -> *const ::std::os::raw::c_char,
+    >,
+    pub create: Option<
+        unsafe extern "C" fn(
+            settings: *mut obs_data_t,
+            output: *mut obs_output_t,
+        ) -> *mut ::std::os::raw::c_void,
+    >,
+    pub destroy: Option<unsafe extern "C" fn(data: *mut ::std::os::raw::c_void)>,
+    pub start: Option<unsafe extern "C" fn(data: *mut ::std::os::raw::c_void) -> bool>,
+    pub stop: Option<unsafe extern "C" fn(data: *mut ::std::os::raw::c_void, ts: u64)>,
+    pub raw_video: Option<
+        unsafe extern "C" fn(data: *mut ::std::os::raw::c_void, frame: *mut video_data),
+    >,
+    pub raw_audio: Option<
+        unsafe extern "C" fn(data: *mut ::std::os::raw::c_void, frames: *mut audio_data),
+    >,
+    pub encoded_packet: Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            packet: *mut encoder_packet,
+        ),
+    >,
+    pub update: Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            settings: *mut obs_data_t,
+        ),
+    >,
+    pub get_defaults: Option<unsafe extern "C" fn(settings: *mut obs_data_t)>,
+    pub get_properties: Option<
+        unsafe extern "C" fn(data: *mut ::std::os::raw::c_void) -> *mut obs_properties_t,
+    >,
+    pub unused1: Option<unsafe extern "C" fn(data: *mut ::std::os::raw::c_void)>,
+    pub get_total_bytes: Option<
+        unsafe extern "C" fn(data: *mut ::std::os::raw::c_void) -> u64,
+    >,
+    pub get_dropped_frames: Option<
+        unsafe extern "C" fn(data: *mut ::std::os::raw::c_void) -> ::std::os::raw::c_int,
+    >,
+    pub type_data: *mut ::std::os::raw::c_void,
+    pub free_type_data: Option<
+        unsafe extern "C" fn(type_data: *mut ::std::os::raw::c_void),
+    >,
+    pub get_congestion: Option<
+        unsafe extern "C" fn(data: *mut ::std::os::raw::c_void) -> f32,
+    >,
+    pub get_connect_time_ms: Option<
+        unsafe extern "C" fn(data: *mut ::std::os::raw::c_void) -> ::std::os::raw::c_int,
+    >,
+    pub encoded_video_codecs: *const ::std::os::raw::c_char,
+    pub encoded_audio_codecs: *const ::std::os::raw::c_char,
+    pub raw_audio2: Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            idx: usize,
+            frames: *mut audio_data,
+        ),
+    >,
+    pub protocols: *const ::std::os::raw::c_char,
+}
+#[test]
+fn bindgen_test_layout_obs_output_info() {
+    const UNINIT: ::std::mem::MaybeUninit<obs_output_info> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::< obs_output_info > (), 192usize, concat!("Size of: ",
+        stringify!(obs_output_info))
+    );
+    assert_eq!(
+        ::std::mem::align_of::< obs_output_info > (), 8usize, concat!("Alignment of ",
+        stringify!(obs_output_info))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).id) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(obs_output_info), "::", stringify!(id))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).flags) as usize - ptr as usize }, 8usize,
+        concat!("Offset of field: ", stringify!(obs_output_info), "::",
+        stringify!(flags))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).get_name) as usize - ptr as usize },
+        16usize, concat!("Offset of field: ", stringify!(obs_output_info), "::",
+        stringify!(get_name))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).create) as usize - ptr as usize }, 24usize,
+        concat!("Offset of field: ", stringify!(obs_output_info), "::",
+        stringify!(create))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).destroy) as usize - ptr as usize },
+        32usize, concat!("Offset of field: ", stringify!(obs_output_info), "::",
+        stringify!(destroy))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).start) as usize - ptr as usize }, 40usize,
+        concat!("Offset of field: ", stringify!(obs_output_info), "::",
+        stringify!(start))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).stop) as usize - ptr as usize }, 48usize,
+        concat!("Offset of field: ", stringify!(obs_output_info), "::", stringify!(stop))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).raw_video) as usize - ptr as usize },
+        56usize, concat!("Offset of field: ", stringify!(obs_output_info), "::",
+        stringify!(raw_video))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).raw_audio) as usize - ptr as usize },
+        64usize, concat!("Offset of field: ", stringify!(obs_output_info), "::",
+        stringify!(raw_audio))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).encoded_packet) as usize - ptr as usize },
+        72usize, concat!("Offset of field: ", stringify!(obs_output_info), "::",
+        stringify!(encoded_packet))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).update) as usize - ptr as usize }, 80usize,
+        concat!("Offset of field: ", stringify!(obs_output_info), "::",
+        stringify!(update))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).get_defaults) as usize - ptr as usize },
+        88usize, concat!("Offset of field: ", stringify!(obs_output_info), "::",
+        stringify!(get_defaults))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).get_properties) as usize - ptr as usize },
+        96usize, concat!("Offset of field: ", stringify!(obs_output_info), "::",
+        stringify!(get_properties))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).unused1) as usize - ptr as usize },
+        104usize, concat!("Offset of field: ", stringify!(obs_output_info), "::",
+        stringify!(unused1))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).get_total_bytes) as usize - ptr as usize },
+        112usize, concat!("Offset of field: ", stringify!(obs_output_info), "::",
+        stringify!(get_total_bytes))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).get_dropped_frames) as usize - ptr as usize
+        }, 120usize, concat!("Offset of field: ", stringify!(obs_output_info), "::",
+        stringify!(get_dropped_frames))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).type_data) as usize - ptr as usize },
+        128usize, concat!("Offset of field: ", stringify!(obs_output_info), "::",
+        stringify!(type_data))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).free_type_data) as usize - ptr as usize },
+        136usize, concat!("Offset of field: ", stringify!(obs_output_info), "::",
+        stringify!(free_type_data))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).get_congestion) as usize - ptr as usize },
+        144usize, concat!("Offset of field: ", stringify!(obs_output_info), "::",
+        stringify!(get_congestion))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).get_connect_time_ms) as usize - ptr as
+        usize }, 152usize, concat!("Offset of field: ", stringify!(obs_output_info),
+        "::", stringify!(get_connect_time_ms))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).encoded_video_codecs) as usize - ptr as
+        usize }, 160usize, concat!("Offset of field: ", stringify!(obs_output_info),
+        "::", stringify!(encoded_video_codecs))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).encoded_audio_codecs) as usize - ptr as
+        usize }, 168usize, concat!("Offset of field: ", stringify!(obs_output_info),
+        "::", stringify!(encoded_audio_codecs))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).raw_audio2) as usize - ptr as usize },
+        176usize, concat!("Offset of field: ", stringify!(obs_output_info), "::",
+        stringify!(raw_audio2))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((* ptr).protocols) as usize - ptr as usize },
+        184usize, concat!("Offset of field: ", stringify!(obs_output_info), "::",
+        stringify!(protocols))
+    );
+}
+/// The functions exported by the loaded libobs.
+/// A function is `None` if the loaded libobs does not export it.
+pub struct Functions {
+    pub blog: Option<
+        unsafe extern "C" fn(::std::os::raw::c_int, *const ::std::os::raw::c_char, ...),
+    >,
+    pub bfree: Option<unsafe extern "C" fn(*mut ::std::os::raw::c_void)>,
+    pub video_format_get_parameters: Option<
+        unsafe extern "C" fn(
+            video_colorspace,
+            video_range_type,
+            *mut f32,
+            *mut f32,
+            *mut f32,
+        ) -> bool,
+    >,
+    pub audio_output_get_channels: Option<unsafe extern "C" fn(*const audio_t) -> usize>,
+    pub calldata_get_data: Option<
+        unsafe extern "C" fn(
+            *const calldata_t,
+            *const ::std::os::raw::c_char,
+            *mut ::std::os::raw::c_void,
+            usize,
+        ) -> bool,
+    >,
+    pub calldata_get_string: Option<
+        unsafe extern "C" fn(
+            *const calldata_t,
+            *const ::std::os::raw::c_char,
+            *mut *const ::std::os::raw::c_char,
+        ) -> bool,
+    >,
+    pub signal_handler_connect: Option<
+        unsafe extern "C" fn(
+            *mut signal_handler_t,
+            *const ::std::os::raw::c_char,
+            signal_callback_t,
+            *mut ::std::os::raw::c_void,
+        ),
+    >,
+    pub signal_handler_disconnect: Option<
+        unsafe extern "C" fn(
+            *mut signal_handler_t,
+            *const ::std::os::raw::c_char,
+            signal_callback_t,
+            *mut ::std::os::raw::c_void,
+        ),
+    >,
+    pub profiler_start: Option<unsafe extern "C" fn()>,
+    pub profiler_stop: Option<unsafe extern "C" fn()>,
+    pub profiler_free: Option<unsafe extern "C" fn()>,
+    pub profiler_name_store_create: Option<
+        unsafe extern "C" fn() -> *mut profiler_name_store_t,
+    >,
+    pub profiler_name_store_free: Option<
+        unsafe extern "C" fn(*mut profiler_name_store_t),
+    >,
+    pub profile_snapshot_create: Option<
+        unsafe extern "C" fn() -> *mut profiler_snapshot_t,
+    >,
+    pub profile_snapshot_free: Option<unsafe extern "C" fn(*mut profiler_snapshot_t)>,
+    pub profiler_snapshot_dump_csv: Option<
+        unsafe extern "C" fn(
+            *const profiler_snapshot_t,
+            *const ::std::os::raw::c_char,
+        ) -> bool,
+    >,
+    pub profiler_snapshot_dump_csv_gz: Option<
+        unsafe extern "C" fn(
+            *const profiler_snapshot_t,
+            *const ::std::os::raw::c_char,
+        ) -> bool,
+    >,
+    pub profiler_snapshot_enumerate_roots: Option<
+        unsafe extern "C" fn(
+            *mut profiler_snapshot_t,
+            profiler_entry_enum_func,
+            *mut ::std::os::raw::c_void,
+        ),
+    >,
+    pub profiler_snapshot_enumerate_children: Option<
+        unsafe extern "C" fn(
+            *mut profiler_snapshot_entry_t,
+            profiler_entry_enum_func,
+            *mut ::std::os::raw::c_void,
+        ),
+    >,
+    pub profiler_snapshot_entry_name: Option<
+        unsafe extern "C" fn(
+            *mut profiler_snapshot_entry_t,
+        ) -> *const ::std::os::raw::c_char,
+    >,
+    pub profiler_snapshot_entry_times: Option<
+        unsafe extern "C" fn(
+            *mut profiler_snapshot_entry_t,
+        ) -> *mut profiler_time_entries_t,
+    >,
+    pub profiler_snapshot_entry_min_time: Option<
+        unsafe extern "C" fn(*mut profiler_snapshot_entry_t) -> u64,
+    >,
+    pub profiler_snapshot_entry_max_time: Option<
+        unsafe extern "C" fn(*mut profiler_snapshot_entry_t) -> u64,
+    >,
+    pub profiler_snapshot_entry_overall_count: Option<
+        unsafe extern "C" fn(*mut profiler_snapshot_entry_t) -> u64,
+    >,
+    pub profiler_snapshot_entry_times_between_calls: Option<
+        unsafe extern "C" fn(
+            *mut profiler_snapshot_entry_t,
+        ) -> *mut profiler_time_entries_t,
+    >,
+    pub profiler_snapshot_entry_expected_time_between_calls: Option<
+        unsafe extern "C" fn(*mut profiler_snapshot_entry_t) -> u64,
+    >,
+    pub profiler_snapshot_entry_min_time_between_calls: Option<
+        unsafe extern "C" fn(*mut profiler_snapshot_entry_t) -> u64,
+    >,
+    pub profiler_snapshot_entry_max_time_between_calls: Option<
+        unsafe extern "C" fn(*mut profiler_snapshot_entry_t) -> u64,
+    >,
+    pub obs_startup: Option<
+        unsafe extern "C" fn(
+            *const ::std::os::raw::c_char,
+            *const ::std::os::raw::c_char,
+            *mut profiler_name_store_t,
+        ) -> bool,
+    >,
+    pub obs_shutdown: Option<unsafe extern "C" fn()>,
+    pub obs_get_version: Option<unsafe extern "C" fn() -> u32>,
+    pub obs_get_version_string: Option<
+        unsafe extern "C" fn() -> *const ::std::os::raw::c_char,
+    >,
+    pub obs_set_locale: Option<unsafe extern "C" fn(*const ::std::os::raw::c_char)>,
+    pub obs_get_locale: Option<unsafe extern "C" fn() -> *const ::std::os::raw::c_char>,
+    pub obs_reset_video: Option<
+        unsafe extern "C" fn(*mut obs_video_info) -> ::std::os::raw::c_int,
+    >,
+    pub obs_reset_audio: Option<unsafe extern "C" fn(*const obs_audio_info) -> bool>,
+    pub obs_reset_audio2: Option<unsafe extern "C" fn(*const obs_audio_info2) -> bool>,
+    pub obs_get_video_info: Option<unsafe extern "C" fn(*mut obs_video_info) -> bool>,
+    pub obs_get_audio_info: Option<unsafe extern "C" fn(*mut obs_audio_info) -> bool>,
+    pub obs_get_audio_info2: Option<unsafe extern "C" fn(*mut obs_audio_info2) -> bool>,
+    pub obs_video_active: Option<unsafe extern "C" fn() -> bool>,
+    pub obs_get_video: Option<unsafe extern "C" fn() -> *mut video_t>,
+    pub obs_get_audio: Option<unsafe extern "C" fn() -> *mut audio_t>,
+    pub obs_get_video_frame_time: Option<unsafe extern "C" fn() -> u64>,
+    pub obs_audio_monitoring_available: Option<unsafe extern "C" fn() -> bool>,
+    pub obs_set_audio_monitoring_device: Option<
+        unsafe extern "C" fn(
+            *const ::std::os::raw::c_char,
+            *const ::std::os::raw::c_char,
+        ) -> bool,
+    >,
+    pub obs_set_output_source: Option<unsafe extern "C" fn(u32, *mut obs_source_t)>,
+    pub obs_enum_source_types: Option<
+        unsafe extern "C" fn(usize, *mut *const ::std::os::raw::c_char) -> bool,
+    >,
+    pub obs_enum_output_types: Option<
+        unsafe extern "C" fn(usize, *mut *const ::std::os::raw::c_char) -> bool,
+    >,
+    pub obs_enum_encoder_types: Option<
+        unsafe extern "C" fn(usize, *mut *const ::std::os::raw::c_char) -> bool,
+    >,
+    pub obs_enum_service_types: Option<
+        unsafe extern "C" fn(usize, *mut *const ::std::os::raw::c_char) -> bool,
+    >,
+    pub obs_enum_outputs: Option<
+        unsafe extern "C" fn(
+            Option<
+                unsafe extern "C" fn(
+                    arg1: *mut ::std::os::raw::c_void,
+                    arg2: *mut obs_output_t,
+                ) -> bool,
+            >,
+            *mut ::std::os::raw::c_void,
+        ),
+    >,
+    pub obs_open_module: Option<
+        unsafe extern "C" fn(
+            *mut *mut obs_module_t,
+            *const ::std::os::raw::c_char,
+            *const ::std::os::raw::c_char,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub obs_init_module: Option<unsafe extern "C" fn(*mut obs_module_t) -> bool>,
+    pub obs_get_module_file_name: Option<
+        unsafe extern "C" fn(*mut obs_module_t) -> *const ::std::os::raw::c_char,
+    >,
+    pub obs_get_module_name: Option<
+        unsafe extern "C" fn(*mut obs_module_t) -> *const ::std::os::raw::c_char,
+    >,
+    pub obs_get_module_author: Option<
+        unsafe extern "C" fn(*mut obs_module_t) -> *const ::std::os::raw::c_char,
+    >,
+    pub obs_get_module_description: Option<
+        unsafe extern "C" fn(*mut obs_module_t) -> *const ::std::os::raw::c_char,
+    >,
+    pub obs_get_module_binary_path: Option<
+        unsafe extern "C" fn(*mut obs_module_t) -> *const ::std::os::raw::c_char,
+    >,
+    pub obs_get_module_data_path: Option<
+        unsafe extern "C" fn(*mut obs_module_t) -> *const ::std::os::raw::c_char,
+    >,
+    pub obs_add_module_path: Option<
+        unsafe extern "C" fn(
+            *const ::std::os::raw::c_char,
+            *const ::std::os::raw::c_char,
+        ),
+    >,
+    pub obs_add_data_path: Option<unsafe extern "C" fn(*const ::std::os::raw::c_char)>,
+    pub obs_post_load_modules: Option<unsafe extern "C" fn()>,
+    pub obs_add_disabled_module: Option<
+        unsafe extern "C" fn(*const ::std::os::raw::c_char),
+    >,
+    pub obs_add_safe_module: Option<unsafe extern "C" fn(*const ::std::os::raw::c_char)>,
+    pub obs_find_modules: Option<
+        unsafe extern "C" fn(obs_find_module_callback_t, *mut ::std::os::raw::c_void),
+    >,
+    pub obs_find_modules2: Option<
+        unsafe extern "C" fn(obs_find_module_callback2_t, *mut ::std::os::raw::c_void),
+    >,
+    pub obs_enum_modules: Option<
+        unsafe extern "C" fn(obs_enum_module_callback_t, *mut ::std::os::raw::c_void),
+    >,
+    pub obs_module_get_config_path: Option<
+        unsafe extern "C" fn(
+            *mut obs_module_t,
+            *const ::std::os::raw::c_char,
+        ) -> *mut ::std::os::raw::c_char,
+    >,
+    pub obs_data_create: Option<unsafe extern "C" fn() -> *mut obs_data_t>,
+    pub obs_data_create_from_json: Option<
+        unsafe extern "C" fn(*const ::std::os::raw::c_char) -> *mut obs_data_t,
+    >,
+    pub obs_data_addref: Option<unsafe extern "C" fn(*mut obs_data_t)>,
+    pub obs_data_release: Option<unsafe extern "C" fn(*mut obs_data_t)>,
+    pub obs_data_get_json: Option<
+        unsafe extern "C" fn(*mut obs_data_t) -> *const ::std::os::raw::c_char,
+    >,
+    pub obs_data_set_string: Option<
+        unsafe extern "C" fn(
+            *mut obs_data_t,
+            *const ::std::os::raw::c_char,
+            *const ::std::os::raw::c_char,
+        ),
+    >,
+    pub obs_data_set_int: Option<
+        unsafe extern "C" fn(
+            *mut obs_data_t,
+            *const ::std::os::raw::c_char,
+            ::std::os::raw::c_longlong,
+        ),
+    >,
+    pub obs_data_set_double: Option<
+        unsafe extern "C" fn(*mut obs_data_t, *const ::std::os::raw::c_char, f64),
+    >,
+    pub obs_data_set_bool: Option<
+        unsafe extern "C" fn(*mut obs_data_t, *const ::std::os::raw::c_char, bool),
+    >,
+    pub obs_data_set_default_string: Option<
+        unsafe extern "C" fn(
+            *mut obs_data_t,
+            *const ::std::os::raw::c_char,
+            *const ::std::os::raw::c_char,
+        ),
+    >,
+    pub obs_data_set_default_int: Option<
+        unsafe extern "C" fn(
+            *mut obs_data_t,
+            *const ::std::os::raw::c_char,
+            ::std::os::raw::c_longlong,
+        ),
+    >,
+    pub obs_data_set_default_double: Option<
+        unsafe extern "C" fn(*mut obs_data_t, *const ::std::os::raw::c_char, f64),
+    >,
+    pub obs_data_set_default_bool: Option<
+        unsafe extern "C" fn(*mut obs_data_t, *const ::std::os::raw::c_char, bool),
+    >,
+    pub obs_data_get_string: Option<
+        unsafe extern "C" fn(
+            *mut obs_data_t,
+            *const ::std::os::raw::c_char,
+        ) -> *const ::std::os::raw::c_char,
+    >,
+    pub obs_data_get_int: Option<
+        unsafe extern "C" fn(
+            *mut obs_data_t,
+            *const ::std::os::raw::c_char,
+        ) -> ::std::os::raw::c_longlong,
+    >,
+    pub obs_data_get_double: Option<
+        unsafe extern "C" fn(*mut obs_data_t, *const ::std::os::raw::c_char) -> f64,
+    >,
+    pub obs_data_get_bool: Option<
+        unsafe extern "C" fn(*mut obs_data_t, *const ::std::os::raw::c_char) -> bool,
+    >,
+    pub obs_data_get_default_string: Option<
+        unsafe extern "C" fn(
+            *mut obs_data_t,
+            *const ::std::os::raw::c_char,
+        ) -> *const ::std::os::raw::c_char,
+    >,
+    pub obs_data_get_default_int: Option<
+        unsafe extern "C" fn(
+            *mut obs_data_t,
+            *const ::std::os::raw::c_char,
+        ) -> ::std::os::raw::c_longlong,
+    >,
+    pub obs_data_get_default_double: Option<
+        unsafe extern "C" fn(*mut obs_data_t, *const ::std::os::raw::c_char) -> f64,
+    >,
+    pub obs_data_get_default_bool: Option<
+        unsafe extern "C" fn(*mut obs_data_t, *const ::std::os::raw::c_char) -> bool,
+    >,
+    pub obs_data_has_user_value: Option<
+        unsafe extern "C" fn(*mut obs_data_t, *const ::std::os::raw::c_char) -> bool,
+    >,
+    pub obs_data_has_default_value: Option<
+        unsafe extern "C" fn(*mut obs_data_t, *const ::std::os::raw::c_char) -> bool,
+    >,
+    pub obs_data_first: Option<
+        unsafe extern "C" fn(*mut obs_data_t) -> *mut obs_data_item_t,
+    >,
+    pub obs_data_item_next: Option<
+        unsafe extern "C" fn(*mut *mut obs_data_item_t) -> bool,
+    >,
+    pub obs_data_item_gettype: Option<
+        unsafe extern "C" fn(*mut obs_data_item_t) -> obs_data_type,
+    >,
+    pub obs_data_item_numtype: Option<
+        unsafe extern "C" fn(*mut obs_data_item_t) -> obs_data_number_type,
+    >,
+    pub obs_data_item_get_name: Option<
+        unsafe extern "C" fn(*mut obs_data_item_t) -> *const ::std::os::raw::c_char,
+    >,
+    pub obs_data_item_has_user_value: Option<
+        unsafe extern "C" fn(*mut obs_data_item_t) -> bool,
+    >,
+    pub obs_data_item_get_string: Option<
+        unsafe extern "C" fn(*mut obs_data_item_t) -> *const ::std::os::raw::c_char,
+    >,
+    pub obs_data_item_get_int: Option<
+        unsafe extern "C" fn(*mut obs_data_item_t) -> ::std::os::raw::c_longlong,
+    >,
+    pub obs_data_item_get_double: Option<
+        unsafe extern "C" fn(*mut obs_data_item_t) -> f64,
+    >,
+    pub obs_data_item_get_bool: Option<
+        unsafe extern "C" fn(*mut obs_data_item_t) -> bool,
+    >,
+    pub obs_properties_create: Option<unsafe extern "C" fn() -> *mut obs_properties_t>,
+    pub obs_properties_destroy: Option<unsafe extern "C" fn(*mut obs_properties_t)>,
+    pub obs_properties_first: Option<
+        unsafe extern "C" fn(*mut obs_properties_t) -> *mut obs_property_t,
+    >,
+    pub obs_properties_get: Option<
+        unsafe extern "C" fn(
+            *mut obs_properties_t,
+            *const ::std::os::raw::c_char,
+        ) -> *mut obs_property_t,
+    >,
+    pub obs_properties_add_bool: Option<
+        unsafe extern "C" fn(
+            *mut obs_properties_t,
+            *const ::std::os::raw::c_char,
+            *const ::std::os::raw::c_char,
+        ) -> *mut obs_property_t,
+    >,
+    pub obs_properties_add_int: Option<
+        unsafe extern "C" fn(
+            *mut obs_properties_t,
+            *const ::std::os::raw::c_char,
+            *const ::std::os::raw::c_char,
+            ::std::os::raw::c_int,
+            ::std::os::raw::c_int,
+            ::std::os::raw::c_int,
+        ) -> *mut obs_property_t,
+    >,
+    pub obs_properties_add_float: Option<
+        unsafe extern "C" fn(
+            *mut obs_properties_t,
+            *const ::std::os::raw::c_char,
+            *const ::std::os::raw::c_char,
+            f64,
+            f64,
+            f64,
+        ) -> *mut obs_property_t,
+    >,
+    pub obs_properties_add_int_slider: Option<
+        unsafe extern "C" fn(
+            *mut obs_properties_t,
+            *const ::std::os::raw::c_char,
+            *const ::std::os::raw::c_char,
+            ::std::os::raw::c_int,
+            ::std::os::raw::c_int,
+            ::std::os::raw::c_int,
+        ) -> *mut obs_property_t,
+    >,
+    pub obs_properties_add_text: Option<
+        unsafe extern "C" fn(
+            *mut obs_properties_t,
+            *const ::std::os::raw::c_char,
+            *const ::std::os::raw::c_char,
+            obs_text_type,
+        ) -> *mut obs_property_t,
+    >,
+    pub obs_properties_add_path: Option<
+        unsafe extern "C" fn(
+            *mut obs_properties_t,
+            *const ::std::os::raw::c_char,
+            *const ::std::os::raw::c_char,
+            obs_path_type,
+            *const ::std::os::raw::c_char,
+            *const ::std::os::raw::c_char,
+        ) -> *mut obs_property_t,
+    >,
+    pub obs_properties_add_list: Option<
+        unsafe extern "C" fn(
+            *mut obs_properties_t,
+            *const ::std::os::raw::c_char,
+            *const ::std::os::raw::c_char,
+            obs_combo_type,
+            obs_combo_format,
+        ) -> *mut obs_property_t,
+    >,
+    pub obs_properties_add_color: Option<
+        unsafe extern "C" fn(
+            *mut obs_properties_t,
+            *const ::std::os::raw::c_char,
+            *const ::std::os::raw::c_char,
+        ) -> *mut obs_property_t,
+    >,
+    pub obs_properties_add_group: Option<
+        unsafe extern "C" fn(
+            *mut obs_properties_t,
+            *const ::std::os::raw::c_char,
+            *const ::std::os::raw::c_char,
+            obs_group_type,
+            *mut obs_properties_t,
+        ) -> *mut obs_property_t,
+    >,
+    pub obs_property_next: Option<
+        unsafe extern "C" fn(*mut *mut obs_property_t) -> bool,
+    >,
+    pub obs_property_name: Option<
+        unsafe extern "C" fn(*mut obs_property_t) -> *const ::std::os::raw::c_char,
+    >,
+    pub obs_property_description: Option<
+        unsafe extern "C" fn(*mut obs_property_t) -> *const ::std::os::raw::c_char,
+    >,
+    pub obs_property_long_description: Option<
+        unsafe extern "C" fn(*mut obs_property_t) -> *const ::std::os::raw::c_char,
+    >,
+    pub obs_property_get_type: Option<
+        unsafe extern "C" fn(*mut obs_property_t) -> obs_property_type,
+    >,
+    pub obs_property_int_min: Option<
+        unsafe extern "C" fn(*mut obs_property_t) -> ::std::os::raw::c_int,
+    >,
+    pub obs_property_int_max: Option<
+        unsafe extern "C" fn(*mut obs_property_t) -> ::std::os::raw::c_int,
+    >,
+    pub obs_property_int_step: Option<
+        unsafe extern "C" fn(*mut obs_property_t) -> ::std::os::raw::c_int,
+    >,
+    pub obs_property_float_min: Option<unsafe extern "C" fn(*mut obs_property_t) -> f64>,
+    pub obs_property_float_max: Option<unsafe extern "C" fn(*mut obs_property_t) -> f64>,
+    pub obs_property_float_step: Option<
+        unsafe extern "C" fn(*mut obs_property_t) -> f64,
+    >,
+    pub obs_property_list_type: Option<
+        unsafe extern "C" fn(*mut obs_property_t) -> obs_combo_type,
+    >,
+    pub obs_property_list_format: Option<
+        unsafe extern "C" fn(*mut obs_property_t) -> obs_combo_format,
+    >,
+    pub obs_property_list_add_string: Option<
+        unsafe extern "C" fn(
+            *mut obs_property_t,
+            *const ::std::os::raw::c_char,
+            *const ::std::os::raw::c_char,
+        ) -> usize,
+    >,
+    pub obs_property_list_add_int: Option<
+        unsafe extern "C" fn(
+            *mut obs_property_t,
+            *const ::std::os::raw::c_char,
+            ::std::os::raw::c_longlong,
+        ) -> usize,
+    >,
+    pub obs_property_list_item_count: Option<
+        unsafe extern "C" fn(*mut obs_property_t) -> usize,
+    >,
+    pub obs_property_list_item_name: Option<
+        unsafe extern "C" fn(*mut obs_property_t, usize) -> *const ::std::os::raw::c_char,
+    >,
+    pub obs_property_list_item_string: Option<
+        unsafe extern "C" fn(*mut obs_property_t, usize) -> *const ::std::os::raw::c_char,
+    >,
+    pub obs_property_list_item_int: Option<
+        unsafe extern "C" fn(*mut obs_property_t, usize) -> ::std::os::raw::c_longlong,
+    >,
+    pub obs_property_list_item_float: Option<
+        unsafe extern "C" fn(*mut obs_property_t, usize) -> f64,
+    >,
+    pub obs_property_group_type: Option<
+        unsafe extern "C" fn(*mut obs_property_t) -> obs_group_type,
+    >,
+    pub obs_property_group_content: Option<
+        unsafe extern "C" fn(*mut obs_property_t) -> *mut obs_properties_t,
+    >,
+    pub obs_register_source_s: Option<
+        unsafe extern "C" fn(*const obs_source_info, usize),
+    >,
+    pub obs_source_create: Option<
+        unsafe extern "C" fn(
+            *const ::std::os::raw::c_char,
+            *const ::std::os::raw::c_char,
+            *mut obs_data_t,
+            *mut obs_data_t,
+        ) -> *mut obs_source_t,
+    >,
+    pub obs_source_release: Option<unsafe extern "C" fn(*mut obs_source_t)>,
+    pub obs_source_get_id: Option<
+        unsafe extern "C" fn(*const obs_source_t) -> *const ::std::os::raw::c_char,
+    >,
+    pub obs_source_get_display_name: Option<
+        unsafe extern "C" fn(
+            *const ::std::os::raw::c_char,
+        ) -> *const ::std::os::raw::c_char,
+    >,
+    pub obs_source_get_output_flags: Option<
+        unsafe extern "C" fn(*const obs_source_t) -> u32,
+    >,
+    pub obs_source_get_settings: Option<
+        unsafe extern "C" fn(*const obs_source_t) -> *mut obs_data_t,
+    >,
+    pub obs_source_get_signal_handler: Option<
+        unsafe extern "C" fn(*const obs_source_t) -> *mut signal_handler_t,
+    >,
+    pub obs_source_update: Option<
+        unsafe extern "C" fn(*mut obs_source_t, *mut obs_data_t),
+    >,
+    pub obs_source_properties: Option<
+        unsafe extern "C" fn(*const obs_source_t) -> *mut obs_properties_t,
+    >,
+    pub obs_get_source_properties: Option<
+        unsafe extern "C" fn(*const ::std::os::raw::c_char) -> *mut obs_properties_t,
+    >,
+    pub obs_get_source_defaults: Option<
+        unsafe extern "C" fn(*const ::std::os::raw::c_char) -> *mut obs_data_t,
+    >,
+    pub obs_source_output_video: Option<
+        unsafe extern "C" fn(*mut obs_source_t, *const obs_source_frame),
+    >,
+    pub obs_source_output_audio: Option<
+        unsafe extern "C" fn(*mut obs_source_t, *const obs_source_audio),
+    >,
+    pub obs_source_skip_video_filter: Option<unsafe extern "C" fn(*mut obs_source_t)>,
+    pub obs_source_media_play_pause: Option<
+        unsafe extern "C" fn(*mut obs_source_t, bool),
+    >,
+    pub obs_source_media_restart: Option<unsafe extern "C" fn(*mut obs_source_t)>,
+    pub obs_source_media_stop: Option<unsafe extern "C" fn(*mut obs_source_t)>,
+    pub obs_source_media_next: Option<unsafe extern "C" fn(*mut obs_source_t)>,
+    pub obs_source_media_previous: Option<unsafe extern "C" fn(*mut obs_source_t)>,
+    pub obs_source_media_get_duration: Option<
+        unsafe extern "C" fn(*mut obs_source_t) -> i64,
+    >,
+    pub obs_source_media_get_time: Option<
+        unsafe extern "C" fn(*mut obs_source_t) -> i64,
+    >,
+    pub obs_source_media_set_time: Option<unsafe extern "C" fn(*mut obs_source_t, i64)>,
+    pub obs_source_media_get_state: Option<
+        unsafe extern "C" fn(*mut obs_source_t) -> obs_media_state,
+    >,
+    pub obs_video_encoder_create: Option<
+        unsafe extern "C" fn(
+            *const ::std::os::raw::c_char,
+            *const ::std::os::raw::c_char,
+            *mut obs_data_t,
+            *mut obs_data_t,
+        ) -> *mut obs_encoder_t,
+    >,
+    pub obs_audio_encoder_create: Option<
+        unsafe extern "C" fn(
+            *const ::std::os::raw::c_char,
+            *const ::std::os::raw::c_char,
+            *mut obs_data_t,
+            usize,
+            *mut obs_data_t,
+        ) -> *mut obs_encoder_t,
+    >,
+    pub obs_encoder_release: Option<unsafe extern "C" fn(*mut obs_encoder_t)>,
+    pub obs_encoder_get_settings: Option<
+        unsafe extern "C" fn(*const obs_encoder_t) -> *mut obs_data_t,
+    >,
+    pub obs_encoder_update: Option<
+        unsafe extern "C" fn(*mut obs_encoder_t, *mut obs_data_t),
+    >,
+    pub obs_encoder_get_codec: Option<
+        unsafe extern "C" fn(*const obs_encoder_t) -> *const ::std::os::raw::c_char,
+    >,
+    pub obs_encoder_get_type: Option<
+        unsafe extern "C" fn(*const obs_encoder_t) -> obs_encoder_type,
+    >,
+    pub obs_encoder_get_display_name: Option<
+        unsafe extern "C" fn(
+            *const ::std::os::raw::c_char,
+        ) -> *const ::std::os::raw::c_char,
+    >,
+    pub obs_encoder_defaults: Option<
+        unsafe extern "C" fn(*const ::std::os::raw::c_char) -> *mut obs_data_t,
+    >,
+    pub obs_encoder_properties: Option<
+        unsafe extern "C" fn(*const obs_encoder_t) -> *mut obs_properties_t,
+    >,
+    pub obs_get_encoder_properties: Option<
+        unsafe extern "C" fn(*const ::std::os::raw::c_char) -> *mut obs_properties_t,
+    >,
+    pub obs_get_encoder_codec: Option<
+        unsafe extern "C" fn(
+            *const ::std::os::raw::c_char,
+        ) -> *const ::std::os::raw::c_char,
+    >,
+    pub obs_get_encoder_type: Option<
+        unsafe extern "C" fn(*const ::std::os::raw::c_char) -> obs_encoder_type,
+    >,
+    pub obs_get_encoder_caps: Option<
+        unsafe extern "C" fn(*const ::std::os::raw::c_char) -> u32,
+    >,
+    pub obs_encoder_set_video: Option<
+        unsafe extern "C" fn(*mut obs_encoder_t, *mut video_t),
+    >,
+    pub obs_encoder_set_audio: Option<
+        unsafe extern "C" fn(*mut obs_encoder_t, *mut audio_t),
+    >,
+    pub obs_encoder_get_extra_data: Option<
+        unsafe extern "C" fn(*const obs_encoder_t, *mut *mut u8, *mut usize) -> bool,
+    >,
+    pub obs_register_output_s: Option<
+        unsafe extern "C" fn(*const obs_output_info, usize),
+    >,
+    pub obs_output_create: Option<
+        unsafe extern "C" fn(
+            *const ::std::os::raw::c_char,
+            *const ::std::os::raw::c_char,
+            *mut obs_data_t,
+            *mut obs_data_t,
+        ) -> *mut obs_output_t,
+    >,
+    pub obs_output_release: Option<unsafe extern "C" fn(*mut obs_output_t)>,
+    pub obs_output_get_ref: Option<
+        unsafe extern "C" fn(*mut obs_output_t) -> *mut obs_output_t,
+    >,
+    pub obs_output_get_name: Option<
+        unsafe extern "C" fn(*const obs_output_t) -> *const ::std::os::raw::c_char,
+    >,
+    pub obs_output_get_id: Option<
+        unsafe extern "C" fn(*const obs_output_t) -> *const ::std::os::raw::c_char,
+    >,
+    pub obs_output_get_display_name: Option<
+        unsafe extern "C" fn(
+            *const ::std::os::raw::c_char,
+        ) -> *const ::std::os::raw::c_char,
+    >,
+    pub obs_output_start: Option<unsafe extern "C" fn(*mut obs_output_t) -> bool>,
+    pub obs_output_stop: Option<unsafe extern "C" fn(*mut obs_output_t)>,
+    pub obs_output_force_stop: Option<unsafe extern "C" fn(*mut obs_output_t)>,
+    pub obs_output_active: Option<unsafe extern "C" fn(*const obs_output_t) -> bool>,
+    pub obs_output_reconnecting: Option<unsafe extern "C" fn(*const obs_output_t) -> bool>,
+    pub obs_output_can_pause: Option<unsafe extern "C" fn(*const obs_output_t) -> bool>,
+    pub obs_output_pause: Option<unsafe extern "C" fn(*mut obs_output_t, bool) -> bool>,
+    pub obs_output_paused: Option<unsafe extern "C" fn(*const obs_output_t) -> bool>,
+    pub obs_output_defaults: Option<
+        unsafe extern "C" fn(*const ::std::os::raw::c_char) -> *mut obs_data_t,
+    >,
+    pub obs_get_output_properties: Option<
+        unsafe extern "C" fn(*const ::std::os::raw::c_char) -> *mut obs_properties_t,
+    >,
+    pub obs_output_properties: Option<
+        unsafe extern "C" fn(*const obs_output_t) -> *mut obs_properties_t,
+    >,
+    pub obs_output_update: Option<
+        unsafe extern "C" fn(*mut obs_output_t, *mut obs_data_t),
+    >,
+    pub obs_output_get_settings: Option<
+        unsafe extern "C" fn(*const obs_output_t) -> *mut obs_data_t,
+    >,
+    pub obs_output_get_signal_handler: Option<
+        unsafe extern "C" fn(*const obs_output_t) -> *mut signal_handler_t,
+    >,
+    pub obs_output_set_last_error: Option<
+        unsafe extern "C" fn(*mut obs_output_t, *const ::std::os::raw::c_char),
+    >,
+    pub obs_output_get_last_error: Option<
+        unsafe extern "C" fn(*mut obs_output_t) -> *const ::std::os::raw::c_char,
+    >,
+    pub obs_output_set_media: Option<
+        unsafe extern "C" fn(*mut obs_output_t, *mut video_t, *mut audio_t),
+    >,
+    pub obs_output_set_video_encoder: Option<
+        unsafe extern "C" fn(*mut obs_output_t, *mut obs_encoder_t),
+    >,
+    pub obs_output_set_audio_encoder: Option<
+        unsafe extern "C" fn(*mut obs_output_t, *mut obs_encoder_t, usize),
+    >,
+    pub obs_output_get_video_encoder: Option<
+        unsafe extern "C" fn(*const obs_output_t) -> *mut obs_encoder_t,
+    >,
+    pub obs_output_get_audio_encoder: Option<
+        unsafe extern "C" fn(*const obs_output_t, usize) -> *mut obs_encoder_t,
+    >,
+    pub obs_get_output_flags: Option<
+        unsafe extern "C" fn(*const ::std::os::raw::c_char) -> u32,
+    >,
+    pub obs_get_output_supported_video_codecs: Option<
+        unsafe extern "C" fn(
+            *const ::std::os::raw::c_char,
+        ) -> *const ::std::os::raw::c_char,
+    >,
+    pub obs_get_output_supported_audio_codecs: Option<
+        unsafe extern "C" fn(
+            *const ::std::os::raw::c_char,
+        ) -> *const ::std::os::raw::c_char,
+    >,
+    pub obs_output_can_begin_data_capture: Option<
+        unsafe extern "C" fn(*const obs_output_t, u32) -> bool,
+    >,
+    pub obs_output_initialize_encoders: Option<
+        unsafe extern "C" fn(*mut obs_output_t, u32) -> bool,
+    >,
+    pub obs_output_begin_data_capture: Option<
+        unsafe extern "C" fn(*mut obs_output_t, u32) -> bool,
+    >,
+    pub obs_output_end_data_capture: Option<unsafe extern "C" fn(*mut obs_output_t)>,
+    pub obs_output_signal_stop: Option<
+        unsafe extern "C" fn(*mut obs_output_t, ::std::os::raw::c_int),
+    >,
+    pub obs_output_get_total_bytes: Option<
+        unsafe extern "C" fn(*const obs_output_t) -> u64,
+    >,
+    pub obs_output_get_frames_dropped: Option<
+        unsafe extern "C" fn(*const obs_output_t) -> ::std::os::raw::c_int,
+    >,
+    pub obs_output_get_total_frames: Option<
+        unsafe extern "C" fn(*const obs_output_t) -> ::std::os::raw::c_int,
+    >,
+}
+impl Functions {
+    unsafe fn load(library: &libloading::Library) -> Self {
+        Self {
+            blog: library.get(b"blog\0").ok().map(|symbol| *symbol),
+            bfree: library.get(b"bfree\0").ok().map(|symbol| *symbol),
+            video_format_get_parameters: library
+                .get(b"video_format_get_parameters\0")
+                .ok()
+                .map(|symbol| *symbol),
+            audio_output_get_channels: library
+                .get(b"audio_output_get_channels\0")
+                .ok()
+                .map(|symbol| *symbol),
+            calldata_get_data: library
+                .get(b"calldata_get_data\0")
+                .ok()
+                .map(|symbol| *symbol),
+            calldata_get_string: library
+                .get(b"calldata_get_string\0")
+                .ok()
+                .map(|symbol| *symbol),
+            signal_handler_connect: library
+                .get(b"signal_handler_connect\0")
+                .ok()
+                .map(|symbol| *symbol),
+            signal_handler_disconnect: library
+                .get(b"signal_handler_disconnect\0")
+                .ok()
+                .map(|symbol| *symbol),
+            profiler_start: library.get(b"profiler_start\0").ok().map(|symbol| *symbol),
+            profiler_stop: library.get(b"profiler_stop\0").ok().map(|symbol| *symbol),
+            profiler_free: library.get(b"profiler_free\0").ok().map(|symbol| *symbol),
+            profiler_name_store_create: library
+                .get(b"profiler_name_store_create\0")
+                .ok()
+                .map(|symbol| *symbol),
+            profiler_name_store_free: library
+                .get(b"profiler_name_store_free\0")
+                .ok()
+                .map(|symbol| *symbol),
+            profile_snapshot_create: library
+                .get(b"profile_snapshot_create\0")
+                .ok()
+                .map(|symbol| *symbol),
+            profile_snapshot_free: library
+                .get(b"profile_snapshot_free\0")
+                .ok()
+                .map(|symbol| *symbol),
+            profiler_snapshot_dump_csv: library
+                .get(b"profiler_snapshot_dump_csv\0")
+                .ok()
+                .map(|symbol| *symbol),
+            profiler_snapshot_dump_csv_gz: library
+                .get(b"profiler_snapshot_dump_csv_gz\0")
+                .ok()
+                .map(|symbol| *symbol),
+            profiler_snapshot_enumerate_roots: library
+                .get(b"profiler_snapshot_enumerate_roots\0")
+                .ok()
+                .map(|symbol| *symbol),
+            profiler_snapshot_enumerate_children: library
+                .get(b"profiler_snapshot_enumerate_children\0")
+                .ok()
+                .map(|symbol| *symbol),
+            profiler_snapshot_entry_name: library
+                .get(b"profiler_snapshot_entry_name\0")
+                .ok()
+                .map(|symbol| *symbol),
+            profiler_snapshot_entry_times: library
+                .get(b"profiler_snapshot_entry_times\0")
+                .ok()
+                .map(|symbol| *symbol),
+            profiler_snapshot_entry_min_time: library
+                .get(b"profiler_snapshot_entry_min_time\0")
+                .ok()
+                .map(|symbol| *symbol),
+            profiler_snapshot_entry_max_time: library
+                .get(b"profiler_snapshot_entry_max_time\0")
+                .ok()
+                .map(|symbol| *symbol),
+            profiler_snapshot_entry_overall_count: library
+                .get(b"profiler_snapshot_entry_overall_count\0")
+                .ok()
+                .map(|symbol| *symbol),
+            profiler_snapshot_entry_times_between_calls: library
+                .get(b"profiler_snapshot_entry_times_between_calls\0")
+                .ok()
+                .map(|symbol| *symbol),
+            profiler_snapshot_entry_expected_time_between_calls: library
+                .get(b"profiler_snapshot_entry_expected_time_between_calls\0")
+                .ok()
+                .map(|symbol| *symbol),
+            profiler_snapshot_entry_min_time_between_calls: library
+                .get(b"profiler_snapshot_entry_min_time_between_calls\0")
+                .ok()
+                .map(|symbol| *symbol),
+            profiler_snapshot_entry_max_time_between_calls: library
+                .get(b"profiler_snapshot_entry_max_time_between_calls\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_startup: library.get(b"obs_startup\0").ok().map(|symbol| *symbol),
+            obs_shutdown: library.get(b"obs_shutdown\0").ok().map(|symbol| *symbol),
+            obs_get_version: library
+                .get(b"obs_get_version\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_get_version_string: library
+                .get(b"obs_get_version_string\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_set_locale: library.get(b"obs_set_locale\0").ok().map(|symbol| *symbol),
+            obs_get_locale: library.get(b"obs_get_locale\0").ok().map(|symbol| *symbol),
+            obs_reset_video: library
+                .get(b"obs_reset_video\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_reset_audio: library
+                .get(b"obs_reset_audio\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_reset_audio2: library
+                .get(b"obs_reset_audio2\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_get_video_info: library
+                .get(b"obs_get_video_info\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_get_audio_info: library
+                .get(b"obs_get_audio_info\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_get_audio_info2: library
+                .get(b"obs_get_audio_info2\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_video_active: library
+                .get(b"obs_video_active\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_get_video: library.get(b"obs_get_video\0").ok().map(|symbol| *symbol),
+            obs_get_audio: library.get(b"obs_get_audio\0").ok().map(|symbol| *symbol),
+            obs_get_video_frame_time: library
+                .get(b"obs_get_video_frame_time\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_audio_monitoring_available: library
+                .get(b"obs_audio_monitoring_available\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_set_audio_monitoring_device: library
+                .get(b"obs_set_audio_monitoring_device\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_set_output_source: library
+                .get(b"obs_set_output_source\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_enum_source_types: library
+                .get(b"obs_enum_source_types\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_enum_output_types: library
+                .get(b"obs_enum_output_types\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_enum_encoder_types: library
+                .get(b"obs_enum_encoder_types\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_enum_service_types: library
+                .get(b"obs_enum_service_types\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_enum_outputs: library
+                .get(b"obs_enum_outputs\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_open_module: library
+                .get(b"obs_open_module\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_init_module: library
+                .get(b"obs_init_module\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_get_module_file_name: library
+                .get(b"obs_get_module_file_name\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_get_module_name: library
+                .get(b"obs_get_module_name\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_get_module_author: library
+                .get(b"obs_get_module_author\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_get_module_description: library
+                .get(b"obs_get_module_description\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_get_module_binary_path: library
+                .get(b"obs_get_module_binary_path\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_get_module_data_path: library
+                .get(b"obs_get_module_data_path\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_add_module_path: library
+                .get(b"obs_add_module_path\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_add_data_path: library
+                .get(b"obs_add_data_path\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_post_load_modules: library
+                .get(b"obs_post_load_modules\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_add_disabled_module: library
+                .get(b"obs_add_disabled_module\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_add_safe_module: library
+                .get(b"obs_add_safe_module\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_find_modules: library
+                .get(b"obs_find_modules\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_find_modules2: library
+                .get(b"obs_find_modules2\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_enum_modules: library
+                .get(b"obs_enum_modules\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_module_get_config_path: library
+                .get(b"obs_module_get_config_path\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_data_create: library
+                .get(b"obs_data_create\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_data_create_from_json: library
+                .get(b"obs_data_create_from_json\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_data_addref: library
+                .get(b"obs_data_addref\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_data_release: library
+                .get(b"obs_data_release\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_data_get_json: library
+                .get(b"obs_data_get_json\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_data_set_string: library
+                .get(b"obs_data_set_string\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_data_set_int: library
+                .get(b"obs_data_set_int\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_data_set_double: library
+                .get(b"obs_data_set_double\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_data_set_bool: library
+                .get(b"obs_data_set_bool\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_data_set_default_string: library
+                .get(b"obs_data_set_default_string\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_data_set_default_int: library
+                .get(b"obs_data_set_default_int\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_data_set_default_double: library
+                .get(b"obs_data_set_default_double\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_data_set_default_bool: library
+                .get(b"obs_data_set_default_bool\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_data_get_string: library
+                .get(b"obs_data_get_string\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_data_get_int: library
+                .get(b"obs_data_get_int\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_data_get_double: library
+                .get(b"obs_data_get_double\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_data_get_bool: library
+                .get(b"obs_data_get_bool\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_data_get_default_string: library
+                .get(b"obs_data_get_default_string\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_data_get_default_int: library
+                .get(b"obs_data_get_default_int\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_data_get_default_double: library
+                .get(b"obs_data_get_default_double\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_data_get_default_bool: library
+                .get(b"obs_data_get_default_bool\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_data_has_user_value: library
+                .get(b"obs_data_has_user_value\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_data_has_default_value: library
+                .get(b"obs_data_has_default_value\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_data_first: library.get(b"obs_data_first\0").ok().map(|symbol| *symbol),
+            obs_data_item_next: library
+                .get(b"obs_data_item_next\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_data_item_gettype: library
+                .get(b"obs_data_item_gettype\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_data_item_numtype: library
+                .get(b"obs_data_item_numtype\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_data_item_get_name: library
+                .get(b"obs_data_item_get_name\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_data_item_has_user_value: library
+                .get(b"obs_data_item_has_user_value\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_data_item_get_string: library
+                .get(b"obs_data_item_get_string\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_data_item_get_int: library
+                .get(b"obs_data_item_get_int\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_data_item_get_double: library
+                .get(b"obs_data_item_get_double\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_data_item_get_bool: library
+                .get(b"obs_data_item_get_bool\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_properties_create: library
+                .get(b"obs_properties_create\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_properties_destroy: library
+                .get(b"obs_properties_destroy\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_properties_first: library
+                .get(b"obs_properties_first\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_properties_get: library
+                .get(b"obs_properties_get\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_properties_add_bool: library
+                .get(b"obs_properties_add_bool\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_properties_add_int: library
+                .get(b"obs_properties_add_int\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_properties_add_float: library
+                .get(b"obs_properties_add_float\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_properties_add_int_slider: library
+                .get(b"obs_properties_add_int_slider\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_properties_add_text: library
+                .get(b"obs_properties_add_text\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_properties_add_path: library
+                .get(b"obs_properties_add_path\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_properties_add_list: library
+                .get(b"obs_properties_add_list\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_properties_add_color: library
+                .get(b"obs_properties_add_color\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_properties_add_group: library
+                .get(b"obs_properties_add_group\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_property_next: library
+                .get(b"obs_property_next\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_property_name: library
+                .get(b"obs_property_name\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_property_description: library
+                .get(b"obs_property_description\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_property_long_description: library
+                .get(b"obs_property_long_description\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_property_get_type: library
+                .get(b"obs_property_get_type\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_property_int_min: library
+                .get(b"obs_property_int_min\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_property_int_max: library
+                .get(b"obs_property_int_max\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_property_int_step: library
+                .get(b"obs_property_int_step\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_property_float_min: library
+                .get(b"obs_property_float_min\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_property_float_max: library
+                .get(b"obs_property_float_max\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_property_float_step: library
+                .get(b"obs_property_float_step\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_property_list_type: library
+                .get(b"obs_property_list_type\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_property_list_format: library
+                .get(b"obs_property_list_format\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_property_list_add_string: library
+                .get(b"obs_property_list_add_string\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_property_list_add_int: library
+                .get(b"obs_property_list_add_int\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_property_list_item_count: library
+                .get(b"obs_property_list_item_count\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_property_list_item_name: library
+                .get(b"obs_property_list_item_name\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_property_list_item_string: library
+                .get(b"obs_property_list_item_string\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_property_list_item_int: library
+                .get(b"obs_property_list_item_int\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_property_list_item_float: library
+                .get(b"obs_property_list_item_float\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_property_group_type: library
+                .get(b"obs_property_group_type\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_property_group_content: library
+                .get(b"obs_property_group_content\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_register_source_s: library
+                .get(b"obs_register_source_s\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_source_create: library
+                .get(b"obs_source_create\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_source_release: library
+                .get(b"obs_source_release\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_source_get_id: library
+                .get(b"obs_source_get_id\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_source_get_display_name: library
+                .get(b"obs_source_get_display_name\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_source_get_output_flags: library
+                .get(b"obs_source_get_output_flags\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_source_get_settings: library
+                .get(b"obs_source_get_settings\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_source_get_signal_handler: library
+                .get(b"obs_source_get_signal_handler\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_source_update: library
+                .get(b"obs_source_update\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_source_properties: library
+                .get(b"obs_source_properties\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_get_source_properties: library
+                .get(b"obs_get_source_properties\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_get_source_defaults: library
+                .get(b"obs_get_source_defaults\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_source_output_video: library
+                .get(b"obs_source_output_video\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_source_output_audio: library
+                .get(b"obs_source_output_audio\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_source_skip_video_filter: library
+                .get(b"obs_source_skip_video_filter\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_source_media_play_pause: library
+                .get(b"obs_source_media_play_pause\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_source_media_restart: library
+                .get(b"obs_source_media_restart\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_source_media_stop: library
+                .get(b"obs_source_media_stop\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_source_media_next: library
+                .get(b"obs_source_media_next\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_source_media_previous: library
+                .get(b"obs_source_media_previous\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_source_media_get_duration: library
+                .get(b"obs_source_media_get_duration\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_source_media_get_time: library
+                .get(b"obs_source_media_get_time\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_source_media_set_time: library
+                .get(b"obs_source_media_set_time\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_source_media_get_state: library
+                .get(b"obs_source_media_get_state\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_video_encoder_create: library
+                .get(b"obs_video_encoder_create\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_audio_encoder_create: library
+                .get(b"obs_audio_encoder_create\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_encoder_release: library
+                .get(b"obs_encoder_release\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_encoder_get_settings: library
+                .get(b"obs_encoder_get_settings\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_encoder_update: library
+                .get(b"obs_encoder_update\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_encoder_get_codec: library
+                .get(b"obs_encoder_get_codec\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_encoder_get_type: library
+                .get(b"obs_encoder_get_type\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_encoder_get_display_name: library
+                .get(b"obs_encoder_get_display_name\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_encoder_defaults: library
+                .get(b"obs_encoder_defaults\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_encoder_properties: library
+                .get(b"obs_encoder_properties\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_get_encoder_properties: library
+                .get(b"obs_get_encoder_properties\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_get_encoder_codec: library
+                .get(b"obs_get_encoder_codec\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_get_encoder_type: library
+                .get(b"obs_get_encoder_type\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_get_encoder_caps: library
+                .get(b"obs_get_encoder_caps\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_encoder_set_video: library
+                .get(b"obs_encoder_set_video\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_encoder_set_audio: library
+                .get(b"obs_encoder_set_audio\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_encoder_get_extra_data: library
+                .get(b"obs_encoder_get_extra_data\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_register_output_s: library
+                .get(b"obs_register_output_s\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_output_create: library
+                .get(b"obs_output_create\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_output_release: library
+                .get(b"obs_output_release\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_output_get_ref: library
+                .get(b"obs_output_get_ref\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_output_get_name: library
+                .get(b"obs_output_get_name\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_output_get_id: library
+                .get(b"obs_output_get_id\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_output_get_display_name: library
+                .get(b"obs_output_get_display_name\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_output_start: library
+                .get(b"obs_output_start\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_output_stop: library
+                .get(b"obs_output_stop\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_output_force_stop: library
+                .get(b"obs_output_force_stop\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_output_active: library
+                .get(b"obs_output_active\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_output_reconnecting: library
+                .get(b"obs_output_reconnecting\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_output_can_pause: library
+                .get(b"obs_output_can_pause\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_output_pause: library
+                .get(b"obs_output_pause\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_output_paused: library
+                .get(b"obs_output_paused\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_output_defaults: library
+                .get(b"obs_output_defaults\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_get_output_properties: library
+                .get(b"obs_get_output_properties\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_output_properties: library
+                .get(b"obs_output_properties\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_output_update: library
+                .get(b"obs_output_update\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_output_get_settings: library
+                .get(b"obs_output_get_settings\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_output_get_signal_handler: library
+                .get(b"obs_output_get_signal_handler\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_output_set_last_error: library
+                .get(b"obs_output_set_last_error\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_output_get_last_error: library
+                .get(b"obs_output_get_last_error\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_output_set_media: library
+                .get(b"obs_output_set_media\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_output_set_video_encoder: library
+                .get(b"obs_output_set_video_encoder\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_output_set_audio_encoder: library
+                .get(b"obs_output_set_audio_encoder\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_output_get_video_encoder: library
+                .get(b"obs_output_get_video_encoder\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_output_get_audio_encoder: library
+                .get(b"obs_output_get_audio_encoder\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_get_output_flags: library
+                .get(b"obs_get_output_flags\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_get_output_supported_video_codecs: library
+                .get(b"obs_get_output_supported_video_codecs\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_get_output_supported_audio_codecs: library
+                .get(b"obs_get_output_supported_audio_codecs\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_output_can_begin_data_capture: library
+                .get(b"obs_output_can_begin_data_capture\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_output_initialize_encoders: library
+                .get(b"obs_output_initialize_encoders\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_output_begin_data_capture: library
+                .get(b"obs_output_begin_data_capture\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_output_end_data_capture: library
+                .get(b"obs_output_end_data_capture\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_output_signal_stop: library
+                .get(b"obs_output_signal_stop\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_output_get_total_bytes: library
+                .get(b"obs_output_get_total_bytes\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_output_get_frames_dropped: library
+                .get(b"obs_output_get_frames_dropped\0")
+                .ok()
+                .map(|symbol| *symbol),
+            obs_output_get_total_frames: library
+                .get(b"obs_output_get_total_frames\0")
+                .ok()
+                .map(|symbol| *symbol),
+        }
+    }
+}
+#[inline]
+pub unsafe fn bfree(ptr: *mut ::std::os::raw::c_void) {
+    (functions().bfree.expect("libobs does not export bfree"))(ptr)
+}
+#[inline]
+pub unsafe fn video_format_get_parameters(
+    color_space: video_colorspace,
+    range: video_range_type,
+    matrix: *mut f32,
+    min_range: *mut f32,
+    max_range: *mut f32,
+) -> bool {
+    (functions()
+        .video_format_get_parameters
+        .expect(
+            "libobs does not export video_format_get_parameters",
+        ))(color_space, range, matrix, min_range, max_range)
+}
+#[inline]
+pub unsafe fn audio_output_get_channels(audio: *const audio_t) -> usize {
+    (functions()
+        .audio_output_get_channels
+        .expect("libobs does not export audio_output_get_channels"))(audio)
+}
+#[inline]
+pub unsafe fn calldata_get_data(
+    data: *const calldata_t,
+    name: *const ::std::os::raw::c_char,
+    out: *mut ::std::os::raw::c_void,
+    size: usize,
+) -> bool {
+    (functions()
+        .calldata_get_data
+        .expect("libobs does not export calldata_get_data"))(data, name, out, size)
+}
+#[inline]
+pub unsafe fn calldata_get_string(
+    data: *const calldata_t,
+    name: *const ::std::os::raw::c_char,
+    str: *mut *const ::std::os::raw::c_char,
+) -> bool {
+    (functions()
+        .calldata_get_string
+        .expect("libobs does not export calldata_get_string"))(data, name, str)
+}
+#[inline]
+pub unsafe fn signal_handler_connect(
+    handler: *mut signal_handler_t,
+    signal: *const ::std::os::raw::c_char,
+    callback: signal_callback_t,
+    data: *mut ::std::os::raw::c_void,
+) {
+    (functions()
+        .signal_handler_connect
+        .expect(
+            "libobs does not export signal_handler_connect",
+        ))(handler, signal, callback, data)
+}
+#[inline]
+pub unsafe fn signal_handler_disconnect(
+    handler: *mut signal_handler_t,
+    signal: *const ::std::os::raw::c_char,
+    callback: signal_callback_t,
+    data: *mut ::std::os::raw::c_void,
+) {
+    (functions()
+        .signal_handler_disconnect
+        .expect(
+            "libobs does not export signal_handler_disconnect",
+        ))(handler, signal, callback, data)
+}
+#[inline]
+pub unsafe fn profiler_start() {
+    (functions().profiler_start.expect("libobs does not export profiler_start"))()
+}
+#[inline]
+pub unsafe fn profiler_stop() {
+    (functions().profiler_stop.expect("libobs does not export profiler_stop"))()
+}
+#[inline]
+pub unsafe fn profiler_free() {
+    (functions().profiler_free.expect("libobs does not export profiler_free"))()
+}
+#[inline]
+pub unsafe fn profiler_name_store_create() -> *mut profiler_name_store_t {
+    (functions()
+        .profiler_name_store_create
+        .expect("libobs does not export profiler_name_store_create"))()
+}
+#[inline]
+pub unsafe fn profiler_name_store_free(store: *mut profiler_name_store_t) {
+    (functions()
+        .profiler_name_store_free
+        .expect("libobs does not export profiler_name_store_free"))(store)
+}
+#[inline]
+pub unsafe fn profile_snapshot_create() -> *mut profiler_snapshot_t {
+    (functions()
+        .profile_snapshot_create
+        .expect("libobs does not export profile_snapshot_create"))()
+}
+#[inline]
+pub unsafe fn profile_snapshot_free(snap: *mut profiler_snapshot_t) {
+    (functions()
+        .profile_snapshot_free
+        .expect("libobs does not export profile_snapshot_free"))(snap)
+}
+#[inline]
+pub unsafe fn profiler_snapshot_dump_csv(
+    snap: *const profiler_snapshot_t,
+    filename: *const ::std::os::raw::c_char,
+) -> bool {
+    (functions()
+        .profiler_snapshot_dump_csv
+        .expect("libobs does not export profiler_snapshot_dump_csv"))(snap, filename)
+}
+#[inline]
+pub unsafe fn profiler_snapshot_dump_csv_gz(
+    snap: *const profiler_snapshot_t,
+    filename: *const ::std::os::raw::c_char,
+) -> bool {
+    (functions()
+        .profiler_snapshot_dump_csv_gz
+        .expect("libobs does not export profiler_snapshot_dump_csv_gz"))(snap, filename)
+}
+#[inline]
+pub unsafe fn profiler_snapshot_enumerate_roots(
+    snap: *mut profiler_snapshot_t,
+    func: profiler_entry_enum_func,
+    context: *mut ::std::os::raw::c_void,
+) {
+    (functions()
+        .profiler_snapshot_enumerate_roots
+        .expect(
+            "libobs does not export profiler_snapshot_enumerate_roots",
+        ))(snap, func, context)
+}
+#[inline]
+pub unsafe fn profiler_snapshot_enumerate_children(
+    entry: *mut profiler_snapshot_entry_t,
+    func: profiler_entry_enum_func,
+    context: *mut ::std::os::raw::c_void,
+) {
+    (functions()
+        .profiler_snapshot_enumerate_children
+        .expect(
+            "libobs does not export profiler_snapshot_enumerate_children",
+        ))(entry, func, context)
+}
+#[inline]
+pub unsafe fn profiler_snapshot_entry_name(
+    entry: *mut profiler_snapshot_entry_t,
+) -> *const ::std::os::raw::c_char {
+    (functions()
+        .profiler_snapshot_entry_name
+        .expect("libobs does not export profiler_snapshot_entry_name"))(entry)
+}
+#[inline]
+pub unsafe fn profiler_snapshot_entry_times(
+    entry: *mut profiler_snapshot_entry_t,
+) -> *mut profiler_time_entries_t {
+    (functions()
+        .profiler_snapshot_entry_times
+        .expect("libobs does not export profiler_snapshot_entry_times"))(entry)
+}
+#[inline]
+pub unsafe fn profiler_snapshot_entry_min_time(
+    entry: *mut profiler_snapshot_entry_t,
+) -> u64 {
+    (functions()
+        .profiler_snapshot_entry_min_time
+        .expect("libobs does not export profiler_snapshot_entry_min_time"))(entry)
+}
+#[inline]
+pub unsafe fn profiler_snapshot_entry_max_time(
+    entry: *mut profiler_snapshot_entry_t,
+) -> u64 {
+    (functions()
+        .profiler_snapshot_entry_max_time
+        .expect("libobs does not export profiler_snapshot_entry_max_time"))(entry)
+}
+#[inline]
+pub unsafe fn profiler_snapshot_entry_overall_count(
+    entry: *mut profiler_snapshot_entry_t,
+) -> u64 {
+    (functions()
+        .profiler_snapshot_entry_overall_count
+        .expect("libobs does not export profiler_snapshot_entry_overall_count"))(entry)
+}
+#[inline]
+pub unsafe fn profiler_snapshot_entry_times_between_calls(
+    entry: *mut profiler_snapshot_entry_t,
+) -> *mut profiler_time_entries_t {
+    (functions()
+        .profiler_snapshot_entry_times_between_calls
+        .expect(
+            "libobs does not export profiler_snapshot_entry_times_between_calls",
+        ))(entry)
+}
+#[inline]
+pub unsafe fn profiler_snapshot_entry_expected_time_between_calls(
+    entry: *mut profiler_snapshot_entry_t,
+) -> u64 {
+    (functions()
+        .profiler_snapshot_entry_expected_time_between_calls
+        .expect(
+            "libobs does not export profiler_snapshot_entry_expected_time_between_calls",
+        ))(entry)
+}
+#[inline]
+pub unsafe fn profiler_snapshot_entry_min_time_between_calls(
+    entry: *mut profiler_snapshot_entry_t,
+) -> u64 {
+    (functions()
+        .profiler_snapshot_entry_min_time_between_calls
+        .expect(
+            "libobs does not export profiler_snapshot_entry_min_time_between_calls",
+        ))(entry)
+}
+#[inline]
+pub unsafe fn profiler_snapshot_entry_max_time_between_calls(
+    entry: *mut profiler_snapshot_entry_t,
+) -> u64 {
+    (functions()
+        .profiler_snapshot_entry_max_time_between_calls
+        .expect(
+            "libobs does not export profiler_snapshot_entry_max_time_between_calls",
+        ))(entry)
+}
+#[inline]
+pub unsafe fn obs_startup(
+    locale: *const ::std::os::raw::c_char,
+    module_config_path: *const ::std::os::raw::c_char,
+    store: *mut profiler_name_store_t,
+) -> bool {
+    (functions()
+        .obs_startup
+        .expect("libobs does not export obs_startup"))(locale, module_config_path, store)
+}
+#[inline]
+pub unsafe fn obs_shutdown() {
+    (functions().obs_shutdown.expect("libobs does not export obs_shutdown"))()
+}
+#[inline]
+pub unsafe fn obs_get_version() -> u32 {
+    (functions().obs_get_version.expect("libobs does not export obs_get_version"))()
+}
+#[inline]
+pub unsafe fn obs_get_version_string() -> *const ::std::os::raw::c_char {
+    (functions()
+        .obs_get_version_string
+        .expect("libobs does not export obs_get_version_string"))()
+}
+#[inline]
+pub unsafe fn obs_set_locale(locale: *const ::std::os::raw::c_char) {
+    (functions().obs_set_locale.expect("libobs does not export obs_set_locale"))(locale)
+}
+#[inline]
+pub unsafe fn obs_get_locale() -> *const ::std::os::raw::c_char {
+    (functions().obs_get_locale.expect("libobs does not export obs_get_locale"))()
+}
+#[inline]
+pub unsafe fn obs_reset_video(ovi: *mut obs_video_info) -> ::std::os::raw::c_int {
+    (functions().obs_reset_video.expect("libobs does not export obs_reset_video"))(ovi)
+}
+#[inline]
+pub unsafe fn obs_reset_audio(oai: *const obs_audio_info) -> bool {
+    (functions().obs_reset_audio.expect("libobs does not export obs_reset_audio"))(oai)
+}
+#[inline]
+pub unsafe fn obs_reset_audio2(oai: *const obs_audio_info2) -> bool {
+    (functions().obs_reset_audio2.expect("libobs does not export obs_reset_audio2"))(oai)
+}
+#[inline]
+pub unsafe fn obs_get_video_info(ovi: *mut obs_video_info) -> bool {
+    (functions()
+        .obs_get_video_info
+        .expect("libobs does not export obs_get_video_info"))(ovi)
+}
+#[inline]
+pub unsafe fn obs_get_audio_info(oai: *mut obs_audio_info) -> bool {
+    (functions()
+        .obs_get_audio_info
+        .expect("libobs does not export obs_get_audio_info"))(oai)
+}
+#[inline]
+pub unsafe fn obs_get_audio_info2(oai2: *mut obs_audio_info2) -> bool {
+    (functions()
+        .obs_get_audio_info2
+        .expect("libobs does not export obs_get_audio_info2"))(oai2)
+}
+#[inline]
+pub unsafe fn obs_video_active() -> bool {
+    (functions().obs_video_active.expect("libobs does not export obs_video_active"))()
+}
+#[inline]
+pub unsafe fn obs_get_video() -> *mut video_t {
+    (functions().obs_get_video.expect("libobs does not export obs_get_video"))()
+}
+#[inline]
+pub unsafe fn obs_get_audio() -> *mut audio_t {
+    (functions().obs_get_audio.expect("libobs does not export obs_get_audio"))()
+}
+#[inline]
+pub unsafe fn obs_get_video_frame_time() -> u64 {
+    (functions()
+        .obs_get_video_frame_time
+        .expect("libobs does not export obs_get_video_frame_time"))()
+}
+#[inline]
+pub unsafe fn obs_audio_monitoring_available() -> bool {
+    (functions()
+        .obs_audio_monitoring_available
+        .expect("libobs does not export obs_audio_monitoring_available"))()
+}
+#[inline]
+pub unsafe fn obs_set_audio_monitoring_device(
+    name: *const ::std::os::raw::c_char,
+    id: *const ::std::os::raw::c_char,
+) -> bool {
+    (functions()
+        .obs_set_audio_monitoring_device
+        .expect("libobs does not export obs_set_audio_monitoring_device"))(name, id)
+}
+#[inline]
+pub unsafe fn obs_set_output_source(channel: u32, source: *mut obs_source_t) {
+    (functions()
+        .obs_set_output_source
+        .expect("libobs does not export obs_set_output_source"))(channel, source)
+}
+#[inline]
+pub unsafe fn obs_enum_source_types(
+    idx: usize,
+    id: *mut *const ::std::os::raw::c_char,
+) -> bool {
+    (functions()
+        .obs_enum_source_types
+        .expect("libobs does not export obs_enum_source_types"))(idx, id)
+}
+#[inline]
+pub unsafe fn obs_enum_output_types(
+    idx: usize,
+    id: *mut *const ::std::os::raw::c_char,
+) -> bool {
+    (functions()
+        .obs_enum_output_types
+        .expect("libobs does not export obs_enum_output_types"))(idx, id)
+}
+#[inline]
+pub unsafe fn obs_enum_encoder_types(
+    idx: usize,
+    id: *mut *const ::std::os::raw::c_char,
+) -> bool {
+    (functions()
+        .obs_enum_encoder_types
+        .expect("libobs does not export obs_enum_encoder_types"))(idx, id)
+}
+#[inline]
+pub unsafe fn obs_enum_service_types(
+    idx: usize,
+    id: *mut *const ::std::os::raw::c_char,
+) -> bool {
+    (functions()
+        .obs_enum_service_types
+        .expect("libobs does not export obs_enum_service_types"))(idx, id)
+}
+#[inline]
+pub unsafe fn obs_enum_outputs(
+    enum_proc: Option<
+        unsafe extern "C" fn(
+            arg1: *mut ::std::os::raw::c_void,
+            arg2: *mut obs_output_t,
+        ) -> bool,
+    >,
+    param: *mut ::std::os::raw::c_void,
+) {
+    (functions()
+        .obs_enum_outputs
+        .expect("libobs does not export obs_enum_outputs"))(enum_proc, param)
+}
+#[inline]
+pub unsafe fn obs_open_module(
+    module: *mut *mut obs_module_t,
+    path: *const ::std::os::raw::c_char,
+    data_path: *const ::std::os::raw::c_char,
+) -> ::std::os::raw::c_int {
+    (functions()
+        .obs_open_module
+        .expect("libobs does not export obs_open_module"))(module, path, data_path)
+}
+#[inline]
+pub unsafe fn obs_init_module(module: *mut obs_module_t) -> bool {
+    (functions()
+        .obs_init_module
+        .expect("libobs does not export obs_init_module"))(module)
+}
+#[inline]
+pub unsafe fn obs_get_module_file_name(
+    module: *mut obs_module_t,
+) -> *const ::std::os::raw::c_char {
+    (functions()
+        .obs_get_module_file_name
+        .expect("libobs does not export obs_get_module_file_name"))(module)
+}
+#[inline]
+pub unsafe fn obs_get_module_name(
+    module: *mut obs_module_t,
+) -> *const ::std::os::raw::c_char {
+    (functions()
+        .obs_get_module_name
+        .expect("libobs does not export obs_get_module_name"))(module)
+}
+#[inline]
+pub unsafe fn obs_get_module_author(
+    module: *mut obs_module_t,
+) -> *const ::std::os::raw::c_char {
+    (functions()
+        .obs_get_module_author
+        .expect("libobs does not export obs_get_module_author"))(module)
+}
+#[inline]
+pub unsafe fn obs_get_module_description(
+    module: *mut obs_module_t,
+) -> *const ::std::os::raw::c_char {
+    (functions()
+        .obs_get_module_description
+        .expect("libobs does not export obs_get_module_description"))(module)
+}
+#[inline]
+pub unsafe fn obs_get_module_binary_path(
+    module: *mut obs_module_t,
+) -> *const ::std::os::raw::c_char {
+    (functions()
+        .obs_get_module_binary_path
+        .expect("libobs does not export obs_get_module_binary_path"))(module)
+}
+#[inline]
+pub unsafe fn obs_get_module_data_path(
+    module: *mut obs_module_t,
+) -> *const ::std::os::raw::c_char {
+    (functions()
+        .obs_get_module_data_path
+        .expect("libobs does not export obs_get_module_data_path"))(module)
+}
+#[inline]
+pub unsafe fn obs_add_module_path(
+    bin: *const ::std::os::raw::c_char,
+    data: *const ::std::os::raw::c_char,
+) {
+    (functions()
+        .obs_add_module_path
+        .expect("libobs does not export obs_add_module_path"))(bin, data)
+}
+#[inline]
+pub unsafe fn obs_add_data_path(path: *const ::std::os::raw::c_char) {
+    (functions()
+        .obs_add_data_path
+        .expect("libobs does not export obs_add_data_path"))(path)
+}
+#[inline]
+pub unsafe fn obs_post_load_modules() {
+    (functions()
+        .obs_post_load_modules
+        .expect("libobs does not export obs_post_load_modules"))()
+}
+#[inline]
+pub unsafe fn obs_add_disabled_module(name: *const ::std::os::raw::c_char) {
+    (functions()
+        .obs_add_disabled_module
+        .expect("libobs does not export obs_add_disabled_module"))(name)
+}
+#[inline]
+pub unsafe fn obs_add_safe_module(name: *const ::std::os::raw::c_char) {
+    (functions()
+        .obs_add_safe_module
+        .expect("libobs does not export obs_add_safe_module"))(name)
+}
+#[inline]
+pub unsafe fn obs_find_modules(
+    callback: obs_find_module_callback_t,
+    param: *mut ::std::os::raw::c_void,
+) {
+    (functions()
+        .obs_find_modules
+        .expect("libobs does not export obs_find_modules"))(callback, param)
+}
+#[inline]
+pub unsafe fn obs_find_modules2(
+    callback: obs_find_module_callback2_t,
+    param: *mut ::std::os::raw::c_void,
+) {
+    (functions()
+        .obs_find_modules2
+        .expect("libobs does not export obs_find_modules2"))(callback, param)
+}
+#[inline]
+pub unsafe fn obs_enum_modules(
+    callback: obs_enum_module_callback_t,
+    param: *mut ::std::os::raw::c_void,
+) {
+    (functions()
+        .obs_enum_modules
+        .expect("libobs does not export obs_enum_modules"))(callback, param)
+}
+#[inline]
+pub unsafe fn obs_module_get_config_path(
+    module: *mut obs_module_t,
+    file: *const ::std::os::raw::c_char,
+) -> *mut ::std::os::raw::c_char {
+    (functions()
+        .obs_module_get_config_path
+        .expect("libobs does not export obs_module_get_config_path"))(module, file)
+}
+#[inline]
+pub unsafe fn obs_data_create() -> *mut obs_data_t {
+    (functions().obs_data_create.expect("libobs does not export obs_data_create"))()
+}
+#[inline]
+pub unsafe fn obs_data_create_from_json(
+    json_string: *const ::std::os::raw::c_char,
+) -> *mut obs_data_t {
+    (functions()
+        .obs_data_create_from_json
+        .expect("libobs does not export obs_data_create_from_json"))(json_string)
+}
+#[inline]
+pub unsafe fn obs_data_addref(data: *mut obs_data_t) {
+    (functions().obs_data_addref.expect("libobs does not export obs_data_addref"))(data)
+}
+#[inline]
+pub unsafe fn obs_data_release(data: *mut obs_data_t) {
+    (functions()
+        .obs_data_release
+        .expect("libobs does not export obs_data_release"))(data)
+}
+#[inline]
+pub unsafe fn obs_data_get_json(data: *mut obs_data_t) -> *const ::std::os::raw::c_char {
+    (functions()
+        .obs_data_get_json
+        .expect("libobs does not export obs_data_get_json"))(data)
+}
+#[inline]
+pub unsafe fn obs_data_set_string(
+    data: *mut obs_data_t,
+    name: *const ::std::os::raw::c_char,
+    val: *const ::std::os::raw::c_char,
+) {
+    (functions()
+        .obs_data_set_string
+        .expect("libobs does not export obs_data_set_string"))(data, name, val)
+}
+#[inline]
+pub unsafe fn obs_data_set_int(
+    data: *mut obs_data_t,
+    name: *const ::std::os::raw::c_char,
+    val: ::std::os::raw::c_longlong,
+) {
+    (functions()
+        .obs_data_set_int
+        .expect("libobs does not export obs_data_set_int"))(data, name, val)
+}
+#[inline]
+pub unsafe fn obs_data_set_double(
+    data: *mut obs_data_t,
+    name: *const ::std::os::raw::c_char,
+    val: f64,
+) {
+    (functions()
+        .obs_data_set_double
+        .expect("libobs does not export obs_data_set_double"))(data, name, val)
+}
+#[inline]
+pub unsafe fn obs_data_set_bool(
+    data: *mut obs_data_t,
+    name: *const ::std::os::raw::c_char,
+    val: bool,
+) {
+    (functions()
+        .obs_data_set_bool
+        .expect("libobs does not export obs_data_set_bool"))(data, name, val)
+}
+#[inline]
+pub unsafe fn obs_data_set_default_string(
+    data: *mut obs_data_t,
+    name: *const ::std::os::raw::c_char,
+    val: *const ::std::os::raw::c_char,
+) {
+    (functions()
+        .obs_data_set_default_string
+        .expect("libobs does not export obs_data_set_default_string"))(data, name, val)
+}
+#[inline]
+pub unsafe fn obs_data_set_default_int(
+    data: *mut obs_data_t,
+    name: *const ::std::os::raw::c_char,
+    val: ::std::os::raw::c_longlong,
+) {
+    (functions()
+        .obs_data_set_default_int
+        .expect("libobs does not export obs_data_set_default_int"))(data, name, val)
+}
+#[inline]
+pub unsafe fn obs_data_set_default_double(
+    data: *mut obs_data_t,
+    name: *const ::std::os::raw::c_char,
+    val: f64,
+) {
+    (functions()
+        .obs_data_set_default_double
+        .expect("libobs does not export obs_data_set_default_double"))(data, name, val)
+}
+#[inline]
+pub unsafe fn obs_data_set_default_bool(
+    data: *mut obs_data_t,
+    name: *const ::std::os::raw::c_char,
+    val: bool,
+) {
+    (functions()
+        .obs_data_set_default_bool
+        .expect("libobs does not export obs_data_set_default_bool"))(data, name, val)
+}
+#[inline]
+pub unsafe fn obs_data_get_string(
+    data: *mut obs_data_t,
+    name: *const ::std::os::raw::c_char,
+) -> *const ::std::os::raw::c_char {
+    (functions()
+        .obs_data_get_string
+        .expect("libobs does not export obs_data_get_string"))(data, name)
+}
+#[inline]
+pub unsafe fn obs_data_get_int(
+    data: *mut obs_data_t,
+    name: *const ::std::os::raw::c_char,
+) -> ::std::os::raw::c_longlong {
+    (functions()
+        .obs_data_get_int
+        .expect("libobs does not export obs_data_get_int"))(data, name)
+}
+#[inline]
+pub unsafe fn obs_data_get_double(
+    data: *mut obs_data_t,
+    name: *const ::std::os::raw::c_char,
+) -> f64 {
+    (functions()
+        .obs_data_get_double
+        .expect("libobs does not export obs_data_get_double"))(data, name)
+}
+#[inline]
+pub unsafe fn obs_data_get_bool(
+    data: *mut obs_data_t,
+    name: *const ::std::os::raw::c_char,
+) -> bool {
+    (functions()
+        .obs_data_get_bool
+        .expect("libobs does not export obs_data_get_bool"))(data, name)
+}
+#[inline]
+pub unsafe fn obs_data_get_default_string(
+    data: *mut obs_data_t,
+    name: *const ::std::os::raw::c_char,
+) -> *const ::std::os::raw::c_char {
+    (functions()
+        .obs_data_get_default_string
+        .expect("libobs does not export obs_data_get_default_string"))(data, name)
+}
+#[inline]
+pub unsafe fn obs_data_get_default_int(
+    data: *mut obs_data_t,
+    name: *const ::std::os::raw::c_char,
+) -> ::std::os::raw::c_longlong {
+    (functions()
+        .obs_data_get_default_int
+        .expect("libobs does not export obs_data_get_default_int"))(data, name)
+}
+#[inline]
+pub unsafe fn obs_data_get_default_double(
+    data: *mut obs_data_t,
+    name: *const ::std::os::raw::c_char,
+) -> f64 {
+    (functions()
+        .obs_data_get_default_double
+        .expect("libobs does not export obs_data_get_default_double"))(data, name)
+}
+#[inline]
+pub unsafe fn obs_data_get_default_bool(
+    data: *mut obs_data_t,
+    name: *const ::std::os::raw::c_char,
+) -> bool {
+    (functions()
+        .obs_data_get_default_bool
+        .expect("libobs does not export obs_data_get_default_bool"))(data, name)
+}
+#[inline]
+pub unsafe fn obs_data_has_user_value(
+    data: *mut obs_data_t,
+    name: *const ::std::os::raw::c_char,
+) -> bool {
+    (functions()
+        .obs_data_has_user_value
+        .expect("libobs does not export obs_data_has_user_value"))(data, name)
+}
+#[inline]
+pub unsafe fn obs_data_has_default_value(
+    data: *mut obs_data_t,
+    name: *const ::std::os::raw::c_char,
+) -> bool {
+    (functions()
+        .obs_data_has_default_value
+        .expect("libobs does not export obs_data_has_default_value"))(data, name)
+}
+#[inline]
+pub unsafe fn obs_data_first(data: *mut obs_data_t) -> *mut obs_data_item_t {
+    (functions().obs_data_first.expect("libobs does not export obs_data_first"))(data)
+}
+#[inline]
+pub unsafe fn obs_data_item_next(item: *mut *mut obs_data_item_t) -> bool {
+    (functions()
+        .obs_data_item_next
+        .expect("libobs does not export obs_data_item_next"))(item)
+}
+#[inline]
+pub unsafe fn obs_data_item_gettype(item: *mut obs_data_item_t) -> obs_data_type {
+    (functions()
+        .obs_data_item_gettype
+        .expect("libobs does not export obs_data_item_gettype"))(item)
+}
+#[inline]
+pub unsafe fn obs_data_item_numtype(item: *mut obs_data_item_t) -> obs_data_number_type {
+    (functions()
+        .obs_data_item_numtype
+        .expect("libobs does not export obs_data_item_numtype"))(item)
+}
+#[inline]
+pub unsafe fn obs_data_item_get_name(
+    item: *mut obs_data_item_t,
+) -> *const ::std::os::raw::c_char {
+    (functions()
+        .obs_data_item_get_name
+        .expect("libobs does not export obs_data_item_get_name"))(item)
+}
+#[inline]
+pub unsafe fn obs_data_item_has_user_value(data: *mut obs_data_item_t) -> bool {
+    (functions()
+        .obs_data_item_has_user_value
+        .expect("libobs does not export obs_data_item_has_user_value"))(data)
+}
+#[inline]
+pub unsafe fn obs_data_item_get_string(
+    item: *mut obs_data_item_t,
+) -> *const ::std::os::raw::c_char {
+    (functions()
+        .obs_data_item_get_string
+        .expect("libobs does not export obs_data_item_get_string"))(item)
+}
+#[inline]
+pub unsafe fn obs_data_item_get_int(
+    item: *mut obs_data_item_t,
+) -> ::std::os::raw::c_longlong {
+    (functions()
+        .obs_data_item_get_int
+        .expect("libobs does not export obs_data_item_get_int"))(item)
+}
+#[inline]
+pub unsafe fn obs_data_item_get_double(item: *mut obs_data_item_t) -> f64 {
+    (functions()
+        .obs_data_item_get_double
+        .expect("libobs does not export obs_data_item_get_double"))(item)
+}
+#[inline]
+pub unsafe fn obs_data_item_get_bool(item: *mut obs_data_item_t) -> bool {
+    (functions()
+        .obs_data_item_get_bool
+        .expect("libobs does not export obs_data_item_get_bool"))(item)
+}
+#[inline]
+pub unsafe fn obs_properties_create() -> *mut obs_properties_t {
+    (functions()
+        .obs_properties_create
+        .expect("libobs does not export obs_properties_create"))()
+}
+#[inline]
+pub unsafe fn obs_properties_destroy(props: *mut obs_properties_t) {
+    (functions()
+        .obs_properties_destroy
+        .expect("libobs does not export obs_properties_destroy"))(props)
+}
+#[inline]
+pub unsafe fn obs_properties_first(props: *mut obs_properties_t) -> *mut obs_property_t {
+    (functions()
+        .obs_properties_first
+        .expect("libobs does not export obs_properties_first"))(props)
+}
+#[inline]
+pub unsafe fn obs_properties_get(
+    props: *mut obs_properties_t,
+    property: *const ::std::os::raw::c_char,
+) -> *mut obs_property_t {
+    (functions()
+        .obs_properties_get
+        .expect("libobs does not export obs_properties_get"))(props, property)
+}
+#[inline]
+pub unsafe fn obs_properties_add_bool(
+    props: *mut obs_properties_t,
+    name: *const ::std::os::raw::c_char,
+    description: *const ::std::os::raw::c_char,
+) -> *mut obs_property_t {
+    (functions()
+        .obs_properties_add_bool
+        .expect(
+            "libobs does not export obs_properties_add_bool",
+        ))(props, name, description)
+}
+#[inline]
+pub unsafe fn obs_properties_add_int(
+    props: *mut obs_properties_t,
+    name: *const ::std::os::raw::c_char,
+    description: *const ::std::os::raw::c_char,
+    min: ::std::os::raw::c_int,
+    max: ::std::os::raw::c_int,
+    step: ::std::os::raw::c_int,
+) -> *mut obs_property_t {
+    (functions()
+        .obs_properties_add_int
+        .expect(
+            "libobs does not export obs_properties_add_int",
+        ))(props, name, description, min, max, step)
+}
+#[inline]
+pub unsafe fn obs_properties_add_float(
+    props: *mut obs_properties_t,
+    name: *const ::std::os::raw::c_char,
+    description: *const ::std::os::raw::c_char,
+    min: f64,
+    max: f64,
+    step: f64,
+) -> *mut obs_property_t {
+    (functions()
+        .obs_properties_add_float
+        .expect(
+            "libobs does not export obs_properties_add_float",
+        ))(props, name, description, min, max, step)
+}
+#[inline]
+pub unsafe fn obs_properties_add_int_slider(
+    props: *mut obs_properties_t,
+    name: *const ::std::os::raw::c_char,
+    description: *const ::std::os::raw::c_char,
+    min: ::std::os::raw::c_int,
+    max: ::std::os::raw::c_int,
+    step: ::std::os::raw::c_int,
+) -> *mut obs_property_t {
+    (functions()
+        .obs_properties_add_int_slider
+        .expect(
+            "libobs does not export obs_properties_add_int_slider",
+        ))(props, name, description, min, max, step)
+}
+#[inline]
+pub unsafe fn obs_properties_add_text(
+    props: *mut obs_properties_t,
+    name: *const ::std::os::raw::c_char,
+    description: *const ::std::os::raw::c_char,
+    type_: obs_text_type,
+) -> *mut obs_property_t {
+    (functions()
+        .obs_properties_add_text
+        .expect(
+            "libobs does not export obs_properties_add_text",
+        ))(props, name, description, type_)
+}
+#[inline]
+pub unsafe fn obs_properties_add_path(
+    props: *mut obs_properties_t,
+    name: *const ::std::os::raw::c_char,
+    description: *const ::std::os::raw::c_char,
+    type_: obs_path_type,
+    filter: *const ::std::os::raw::c_char,
+    default_path: *const ::std::os::raw::c_char,
+) -> *mut obs_property_t {
+    (functions()
+        .obs_properties_add_path
+        .expect(
+            "libobs does not export obs_properties_add_path",
+        ))(props, name, description, type_, filter, default_path)
+}
+#[inline]
+pub unsafe fn obs_properties_add_list(
+    props: *mut obs_properties_t,
+    name: *const ::std::os::raw::c_char,
+    description: *const ::std::os::raw::c_char,
+    type_: obs_combo_type,
+    format: obs_combo_format,
+) -> *mut obs_property_t {
+    (functions()
+        .obs_properties_add_list
+        .expect(
+            "libobs does not export obs_properties_add_list",
+        ))(props, name, description, type_, format)
+}
+#[inline]
+pub unsafe fn obs_properties_add_color(
+    props: *mut obs_properties_t,
+    name: *const ::std::os::raw::c_char,
+    description: *const ::std::os::raw::c_char,
+) -> *mut obs_property_t {
+    (functions()
+        .obs_properties_add_color
+        .expect(
+            "libobs does not export obs_properties_add_color",
+        ))(props, name, description)
+}
+#[inline]
+pub unsafe fn obs_properties_add_group(
+    props: *mut obs_properties_t,
+    name: *const ::std::os::raw::c_char,
+    description: *const ::std::os::raw::c_char,
+    type_: obs_group_type,
+    group: *mut obs_properties_t,
+) -> *mut obs_property_t {
+    (functions()
+        .obs_properties_add_group
+        .expect(
+            "libobs does not export obs_properties_add_group",
+        ))(props, name, description, type_, group)
+}
+#[inline]
+pub unsafe fn obs_property_next(p: *mut *mut obs_property_t) -> bool {
+    (functions().obs_property_next.expect("libobs does not export obs_property_next"))(p)
+}
+#[inline]
+pub unsafe fn obs_property_name(
+    p: *mut obs_property_t,
+) -> *const ::std::os::raw::c_char {
+    (functions().obs_property_name.expect("libobs does not export obs_property_name"))(p)
+}
+#[inline]
+pub unsafe fn obs_property_description(
+    p: *mut obs_property_t,
+) -> *const ::std::os::raw::c_char {
+    (functions()
+        .obs_property_description
+        .expect("libobs does not export obs_property_description"))(p)
+}
+#[inline]
+pub unsafe fn obs_property_long_description(
+    p: *mut obs_property_t,
+) -> *const ::std::os::raw::c_char {
+    (functions()
+        .obs_property_long_description
+        .expect("libobs does not export obs_property_long_description"))(p)
+}
+#[inline]
+pub unsafe fn obs_property_get_type(p: *mut obs_property_t) -> obs_property_type {
+    (functions()
+        .obs_property_get_type
+        .expect("libobs does not export obs_property_get_type"))(p)
+}
+#[inline]
+pub unsafe fn obs_property_int_min(p: *mut obs_property_t) -> ::std::os::raw::c_int {
+    (functions()
+        .obs_property_int_min
+        .expect("libobs does not export obs_property_int_min"))(p)
+}
+#[inline]
+pub unsafe fn obs_property_int_max(p: *mut obs_property_t) -> ::std::os::raw::c_int {
+    (functions()
+        .obs_property_int_max
+        .expect("libobs does not export obs_property_int_max"))(p)
+}
+#[inline]
+pub unsafe fn obs_property_int_step(p: *mut obs_property_t) -> ::std::os::raw::c_int {
+    (functions()
+        .obs_property_int_step
+        .expect("libobs does not export obs_property_int_step"))(p)
+}
+#[inline]
+pub unsafe fn obs_property_float_min(p: *mut obs_property_t) -> f64 {
+    (functions()
+        .obs_property_float_min
+        .expect("libobs does not export obs_property_float_min"))(p)
+}
+#[inline]
+pub unsafe fn obs_property_float_max(p: *mut obs_property_t) -> f64 {
+    (functions()
+        .obs_property_float_max
+        .expect("libobs does not export obs_property_float_max"))(p)
+}
+#[inline]
+pub unsafe fn obs_property_float_step(p: *mut obs_property_t) -> f64 {
+    (functions()
+        .obs_property_float_step
+        .expect("libobs does not export obs_property_float_step"))(p)
+}
+#[inline]
+pub unsafe fn obs_property_list_type(p: *mut obs_property_t) -> obs_combo_type {
+    (functions()
+        .obs_property_list_type
+        .expect("libobs does not export obs_property_list_type"))(p)
+}
+#[inline]
+pub unsafe fn obs_property_list_format(p: *mut obs_property_t) -> obs_combo_format {
+    (functions()
+        .obs_property_list_format
+        .expect("libobs does not export obs_property_list_format"))(p)
+}
+#[inline]
+pub unsafe fn obs_property_list_add_string(
+    p: *mut obs_property_t,
+    name: *const ::std::os::raw::c_char,
+    val: *const ::std::os::raw::c_char,
+) -> usize {
+    (functions()
+        .obs_property_list_add_string
+        .expect("libobs does not export obs_property_list_add_string"))(p, name, val)
+}
+#[inline]
+pub unsafe fn obs_property_list_add_int(
+    p: *mut obs_property_t,
+    name: *const ::std::os::raw::c_char,
+    val: ::std::os::raw::c_longlong,
+) -> usize {
+    (functions()
+        .obs_property_list_add_int
+        .expect("libobs does not export obs_property_list_add_int"))(p, name, val)
+}
+#[inline]
+pub unsafe fn obs_property_list_item_count(p: *mut obs_property_t) -> usize {
+    (functions()
+        .obs_property_list_item_count
+        .expect("libobs does not export obs_property_list_item_count"))(p)
+}
+#[inline]
+pub unsafe fn obs_property_list_item_name(
+    p: *mut obs_property_t,
+    idx: usize,
+) -> *const ::std::os::raw::c_char {
+    (functions()
+        .obs_property_list_item_name
+        .expect("libobs does not export obs_property_list_item_name"))(p, idx)
+}
+#[inline]
+pub unsafe fn obs_property_list_item_string(
+    p: *mut obs_property_t,
+    idx: usize,
+) -> *const ::std::os::raw::c_char {
+    (functions()
+        .obs_property_list_item_string
+        .expect("libobs does not export obs_property_list_item_string"))(p, idx)
+}
+#[inline]
+pub unsafe fn obs_property_list_item_int(
+    p: *mut obs_property_t,
+    idx: usize,
+) -> ::std::os::raw::c_longlong {
+    (functions()
+        .obs_property_list_item_int
+        .expect("libobs does not export obs_property_list_item_int"))(p, idx)
+}
+#[inline]
+pub unsafe fn obs_property_list_item_float(p: *mut obs_property_t, idx: usize) -> f64 {
+    (functions()
+        .obs_property_list_item_float
+        .expect("libobs does not export obs_property_list_item_float"))(p, idx)
+}
+#[inline]
+pub unsafe fn obs_property_group_type(p: *mut obs_property_t) -> obs_group_type {
+    (functions()
+        .obs_property_group_type
+        .expect("libobs does not export obs_property_group_type"))(p)
+}
+#[inline]
+pub unsafe fn obs_property_group_content(
+    p: *mut obs_property_t,
+) -> *mut obs_properties_t {
+    (functions()
+        .obs_property_group_content
+        .expect("libobs does not export obs_property_group_content"))(p)
+}
+#[inline]
+pub unsafe fn obs_register_source_s(info: *const obs_source_info, size: usize) {
+    (functions()
+        .obs_register_source_s
+        .expect("libobs does not export obs_register_source_s"))(info, size)
+}
+#[inline]
+pub unsafe fn obs_source_create(
+    id: *const ::std::os::raw::c_char,
+    name: *const ::std::os::raw::c_char,
+    settings: *mut obs_data_t,
+    hotkey_data: *mut obs_data_t,
+) -> *mut obs_source_t {
+    (functions()
+        .obs_source_create
+        .expect(
+            "libobs does not export obs_source_create",
+        ))(id, name, settings, hotkey_data)
+}
+#[inline]
+pub unsafe fn obs_source_release(source: *mut obs_source_t) {
+    (functions()
+        .obs_source_release
+        .expect("libobs does not export obs_source_release"))(source)
+}
+#[inline]
+pub unsafe fn obs_source_get_id(
+    source: *const obs_source_t,
+) -> *const ::std::os::raw::c_char {
+    (functions()
+        .obs_source_get_id
+        .expect("libobs does not export obs_source_get_id"))(source)
+}
+#[inline]
+pub unsafe fn obs_source_get_display_name(
+    id: *const ::std::os::raw::c_char,
+) -> *const ::std::os::raw::c_char {
+    (functions()
+        .obs_source_get_display_name
+        .expect("libobs does not export obs_source_get_display_name"))(id)
+}
+#[inline]
+pub unsafe fn obs_source_get_output_flags(source: *const obs_source_t) -> u32 {
+    (functions()
+        .obs_source_get_output_flags
+        .expect("libobs does not export obs_source_get_output_flags"))(source)
+}
+#[inline]
+pub unsafe fn obs_source_get_settings(source: *const obs_source_t) -> *mut obs_data_t {
+    (functions()
+        .obs_source_get_settings
+        .expect("libobs does not export obs_source_get_settings"))(source)
+}
+#[inline]
+pub unsafe fn obs_source_get_signal_handler(
+    source: *const obs_source_t,
+) -> *mut signal_handler_t {
+    (functions()
+        .obs_source_get_signal_handler
+        .expect("libobs does not export obs_source_get_signal_handler"))(source)
+}
+#[inline]
+pub unsafe fn obs_source_update(source: *mut obs_source_t, settings: *mut obs_data_t) {
+    (functions()
+        .obs_source_update
+        .expect("libobs does not export obs_source_update"))(source, settings)
+}
+#[inline]
+pub unsafe fn obs_source_properties(
+    source: *const obs_source_t,
+) -> *mut obs_properties_t {
+    (functions()
+        .obs_source_properties
+        .expect("libobs does not export obs_source_properties"))(source)
+}
+#[inline]
+pub unsafe fn obs_get_source_properties(
+    id: *const ::std::os::raw::c_char,
+) -> *mut obs_properties_t {
+    (functions()
+        .obs_get_source_properties
+        .expect("libobs does not export obs_get_source_properties"))(id)
+}
+#[inline]
+pub unsafe fn obs_get_source_defaults(
+    id: *const ::std::os::raw::c_char,
+) -> *mut obs_data_t {
+    (functions()
+        .obs_get_source_defaults
+        .expect("libobs does not export obs_get_source_defaults"))(id)
+}
+#[inline]
+pub unsafe fn obs_source_output_video(
+    source: *mut obs_source_t,
+    frame: *const obs_source_frame,
+) {
+    (functions()
+        .obs_source_output_video
+        .expect("libobs does not export obs_source_output_video"))(source, frame)
+}
+#[inline]
+pub unsafe fn obs_source_output_audio(
+    source: *mut obs_source_t,
+    audio: *const obs_source_audio,
+) {
+    (functions()
+        .obs_source_output_audio
+        .expect("libobs does not export obs_source_output_audio"))(source, audio)
+}
+#[inline]
+pub unsafe fn obs_source_skip_video_filter(filter: *mut obs_source_t) {
+    (functions()
+        .obs_source_skip_video_filter
+        .expect("libobs does not export obs_source_skip_video_filter"))(filter)
+}
+#[inline]
+pub unsafe fn obs_source_media_play_pause(source: *mut obs_source_t, pause: bool) {
+    (functions()
+        .obs_source_media_play_pause
+        .expect("libobs does not export obs_source_media_play_pause"))(source, pause)
+}
+#[inline]
+pub unsafe fn obs_source_media_restart(source: *mut obs_source_t) {
+    (functions()
+        .obs_source_media_restart
+        .expect("libobs does not export obs_source_media_restart"))(source)
+}
+#[inline]
+pub unsafe fn obs_source_media_stop(source: *mut obs_source_t) {
+    (functions()
+        .obs_source_media_stop
+        .expect("libobs does not export obs_source_media_stop"))(source)
+}
+#[inline]
+pub unsafe fn obs_source_media_next(source: *mut obs_source_t) {
+    (functions()
+        .obs_source_media_next
+        .expect("libobs does not export obs_source_media_next"))(source)
+}
+#[inline]
+pub unsafe fn obs_source_media_previous(source: *mut obs_source_t) {
+    (functions()
+        .obs_source_media_previous
+        .expect("libobs does not export obs_source_media_previous"))(source)
+}
+#[inline]
+pub unsafe fn obs_source_media_get_duration(source: *mut obs_source_t) -> i64 {
+    (functions()
+        .obs_source_media_get_duration
+        .expect("libobs does not export obs_source_media_get_duration"))(source)
+}
+#[inline]
+pub unsafe fn obs_source_media_get_time(source: *mut obs_source_t) -> i64 {
+    (functions()
+        .obs_source_media_get_time
+        .expect("libobs does not export obs_source_media_get_time"))(source)
+}
+#[inline]
+pub unsafe fn obs_source_media_set_time(source: *mut obs_source_t, ms: i64) {
+    (functions()
+        .obs_source_media_set_time
+        .expect("libobs does not export obs_source_media_set_time"))(source, ms)
+}
+#[inline]
+pub unsafe fn obs_source_media_get_state(source: *mut obs_source_t) -> obs_media_state {
+    (functions()
+        .obs_source_media_get_state
+        .expect("libobs does not export obs_source_media_get_state"))(source)
+}
+#[inline]
+pub unsafe fn obs_video_encoder_create(
+    id: *const ::std::os::raw::c_char,
+    name: *const ::std::os::raw::c_char,
+    settings: *mut obs_data_t,
+    hotkey_data: *mut obs_data_t,
+) -> *mut obs_encoder_t {
+    (functions()
+        .obs_video_encoder_create
+        .expect(
+            "libobs does not export obs_video_encoder_create",
+        ))(id, name, settings, hotkey_data)
+}
+#[inline]
+pub unsafe fn obs_audio_encoder_create(
+    id: *const ::std::os::raw::c_char,
+    name: *const ::std::os::raw::c_char,
+    settings: *mut obs_data_t,
+    mixer_idx: usize,
+    hotkey_data: *mut obs_data_t,
+) -> *mut obs_encoder_t {
+    (functions()
+        .obs_audio_encoder_create
+        .expect(
+            "libobs does not export obs_audio_encoder_create",
+        ))(id, name, settings, mixer_idx, hotkey_data)
+}
+#[inline]
+pub unsafe fn obs_encoder_release(encoder: *mut obs_encoder_t) {
+    (functions()
+        .obs_encoder_release
+        .expect("libobs does not export obs_encoder_release"))(encoder)
+}
+#[inline]
+pub unsafe fn obs_encoder_get_settings(
+    encoder: *const obs_encoder_t,
+) -> *mut obs_data_t {
+    (functions()
+        .obs_encoder_get_settings
+        .expect("libobs does not export obs_encoder_get_settings"))(encoder)
+}
+#[inline]
+pub unsafe fn obs_encoder_update(
+    encoder: *mut obs_encoder_t,
+    settings: *mut obs_data_t,
+) {
+    (functions()
+        .obs_encoder_update
+        .expect("libobs does not export obs_encoder_update"))(encoder, settings)
+}
+#[inline]
+pub unsafe fn obs_encoder_get_codec(
+    encoder: *const obs_encoder_t,
+) -> *const ::std::os::raw::c_char {
+    (functions()
+        .obs_encoder_get_codec
+        .expect("libobs does not export obs_encoder_get_codec"))(encoder)
+}
+#[inline]
+pub unsafe fn obs_encoder_get_type(encoder: *const obs_encoder_t) -> obs_encoder_type {
+    (functions()
+        .obs_encoder_get_type
+        .expect("libobs does not export obs_encoder_get_type"))(encoder)
+}
+#[inline]
+pub unsafe fn obs_encoder_get_display_name(
+    id: *const ::std::os::raw::c_char,
+) -> *const ::std::os::raw::c_char {
+    (functions()
+        .obs_encoder_get_display_name
+        .expect("libobs does not export obs_encoder_get_display_name"))(id)
+}
+#[inline]
+pub unsafe fn obs_encoder_defaults(
+    id: *const ::std::os::raw::c_char,
+) -> *mut obs_data_t {
+    (functions()
+        .obs_encoder_defaults
+        .expect("libobs does not export obs_encoder_defaults"))(id)
+}
+#[inline]
+pub unsafe fn obs_encoder_properties(
+    encoder: *const obs_encoder_t,
+) -> *mut obs_properties_t {
+    (functions()
+        .obs_encoder_properties
+        .expect("libobs does not export obs_encoder_properties"))(encoder)
+}
+#[inline]
+pub unsafe fn obs_get_encoder_properties(
+    id: *const ::std::os::raw::c_char,
+) -> *mut obs_properties_t {
+    (functions()
+        .obs_get_encoder_properties
+        .expect("libobs does not export obs_get_encoder_properties"))(id)
+}
+#[inline]
+pub unsafe fn obs_get_encoder_codec(
+    id: *const ::std::os::raw::c_char,
+) -> *const ::std::os::raw::c_char {
+    (functions()
+        .obs_get_encoder_codec
+        .expect("libobs does not export obs_get_encoder_codec"))(id)
+}
+#[inline]
+pub unsafe fn obs_get_encoder_type(
+    id: *const ::std::os::raw::c_char,
+) -> obs_encoder_type {
+    (functions()
+        .obs_get_encoder_type
+        .expect("libobs does not export obs_get_encoder_type"))(id)
+}
+#[inline]
+pub unsafe fn obs_get_encoder_caps(encoder_id: *const ::std::os::raw::c_char) -> u32 {
+    (functions()
+        .obs_get_encoder_caps
+        .expect("libobs does not export obs_get_encoder_caps"))(encoder_id)
+}
+#[inline]
+pub unsafe fn obs_encoder_set_video(encoder: *mut obs_encoder_t, video: *mut video_t) {
+    (functions()
+        .obs_encoder_set_video
+        .expect("libobs does not export obs_encoder_set_video"))(encoder, video)
+}
+#[inline]
+pub unsafe fn obs_encoder_set_audio(encoder: *mut obs_encoder_t, audio: *mut audio_t) {
+    (functions()
+        .obs_encoder_set_audio
+        .expect("libobs does not export obs_encoder_set_audio"))(encoder, audio)
+}
+#[inline]
+pub unsafe fn obs_encoder_get_extra_data(
+    encoder: *const obs_encoder_t,
+    extra_data: *mut *mut u8,
+    size: *mut usize,
+) -> bool {
+    (functions()
+        .obs_encoder_get_extra_data
+        .expect(
+            "libobs does not export obs_encoder_get_extra_data",
+        ))(encoder, extra_data, size)
+}
+#[inline]
+pub unsafe fn obs_register_output_s(info: *const obs_output_info, size: usize) {
+    (functions()
+        .obs_register_output_s
+        .expect("libobs does not export obs_register_output_s"))(info, size)
+}
+#[inline]
+pub unsafe fn obs_output_create(
+    id: *const ::std::os::raw::c_char,
+    name: *const ::std::os::raw::c_char,
+    settings: *mut obs_data_t,
+    hotkey_data: *mut obs_data_t,
+) -> *mut obs_output_t {
+    (functions()
+        .obs_output_create
+        .expect(
+            "libobs does not export obs_output_create",
+        ))(id, name, settings, hotkey_data)
+}
+#[inline]
+pub unsafe fn obs_output_release(output: *mut obs_output_t) {
+    (functions()
+        .obs_output_release
+        .expect("libobs does not export obs_output_release"))(output)
+}
+#[inline]
+pub unsafe fn obs_output_get_ref(output: *mut obs_output_t) -> *mut obs_output_t {
+    (functions()
+        .obs_output_get_ref
+        .expect("libobs does not export obs_output_get_ref"))(output)
+}
+#[inline]
+pub unsafe fn obs_output_get_name(
+    output: *const obs_output_t,
+) -> *const ::std::os::raw::c_char {
+    (functions()
+        .obs_output_get_name
+        .expect("libobs does not export obs_output_get_name"))(output)
+}
+#[inline]
+pub unsafe fn obs_output_get_id(
+    output: *const obs_output_t,
+) -> *const ::std::os::raw::c_char {
+    (functions()
+        .obs_output_get_id
+        .expect("libobs does not export obs_output_get_id"))(output)
+}
+#[inline]
+pub unsafe fn obs_output_get_display_name(
+    id: *const ::std::os::raw::c_char,
+) -> *const ::std::os::raw::c_char {
+    (functions()
+        .obs_output_get_display_name
+        .expect("libobs does not export obs_output_get_display_name"))(id)
+}
+#[inline]
+pub unsafe fn obs_output_start(output: *mut obs_output_t) -> bool {
+    (functions()
+        .obs_output_start
+        .expect("libobs does not export obs_output_start"))(output)
+}
+#[inline]
+pub unsafe fn obs_output_stop(output: *mut obs_output_t) {
+    (functions()
+        .obs_output_stop
+        .expect("libobs does not export obs_output_stop"))(output)
+}
+#[inline]
+pub unsafe fn obs_output_force_stop(output: *mut obs_output_t) {
+    (functions()
+        .obs_output_force_stop
+        .expect("libobs does not export obs_output_force_stop"))(output)
+}
+#[inline]
+pub unsafe fn obs_output_active(output: *const obs_output_t) -> bool {
+    (functions()
+        .obs_output_active
+        .expect("libobs does not export obs_output_active"))(output)
+}
+#[inline]
+pub unsafe fn obs_output_reconnecting(output: *const obs_output_t) -> bool {
+    (functions()
+        .obs_output_reconnecting
+        .expect("libobs does not export obs_output_reconnecting"))(output)
+}
+#[inline]
+pub unsafe fn obs_output_can_pause(output: *const obs_output_t) -> bool {
+    (functions()
+        .obs_output_can_pause
+        .expect("libobs does not export obs_output_can_pause"))(output)
+}
+#[inline]
+pub unsafe fn obs_output_pause(output: *mut obs_output_t, pause: bool) -> bool {
+    (functions()
+        .obs_output_pause
+        .expect("libobs does not export obs_output_pause"))(output, pause)
+}
+#[inline]
+pub unsafe fn obs_output_paused(output: *const obs_output_t) -> bool {
+    (functions()
+        .obs_output_paused
+        .expect("libobs does not export obs_output_paused"))(output)
+}
+#[inline]
+pub unsafe fn obs_output_defaults(id: *const ::std::os::raw::c_char) -> *mut obs_data_t {
+    (functions()
+        .obs_output_defaults
+        .expect("libobs does not export obs_output_defaults"))(id)
+}
+#[inline]
+pub unsafe fn obs_get_output_properties(
+    id: *const ::std::os::raw::c_char,
+) -> *mut obs_properties_t {
+    (functions()
+        .obs_get_output_properties
+        .expect("libobs does not export obs_get_output_properties"))(id)
+}
+#[inline]
+pub unsafe fn obs_output_properties(
+    output: *const obs_output_t,
+) -> *mut obs_properties_t {
+    (functions()
+        .obs_output_properties
+        .expect("libobs does not export obs_output_properties"))(output)
+}
+#[inline]
+pub unsafe fn obs_output_update(output: *mut obs_output_t, settings: *mut obs_data_t) {
+    (functions()
+        .obs_output_update
+        .expect("libobs does not export obs_output_update"))(output, settings)
+}
+#[inline]
+pub unsafe fn obs_output_get_settings(output: *const obs_output_t) -> *mut obs_data_t {
+    (functions()
+        .obs_output_get_settings
+        .expect("libobs does not export obs_output_get_settings"))(output)
+}
+#[inline]
+pub unsafe fn obs_output_get_signal_handler(
+    output: *const obs_output_t,
+) -> *mut signal_handler_t {
+    (functions()
+        .obs_output_get_signal_handler
+        .expect("libobs does not export obs_output_get_signal_handler"))(output)
+}
+#[inline]
+pub unsafe fn obs_output_set_last_error(
+    output: *mut obs_output_t,
+    message: *const ::std::os::raw::c_char,
+) {
+    (functions()
+        .obs_output_set_last_error
+        .expect("libobs does not export obs_output_set_last_error"))(output, message)
+}
+#[inline]
+pub unsafe fn obs_output_get_last_error(
+    output: *mut obs_output_t,
+) -> *const ::std::os::raw::c_char {
+    (functions()
+        .obs_output_get_last_error
+        .expect("libobs does not export obs_output_get_last_error"))(output)
+}
+#[inline]
+pub unsafe fn obs_output_set_media(
+    output: *mut obs_output_t,
+    video: *mut video_t,
+    audio: *mut audio_t,
+) {
+    (functions()
+        .obs_output_set_media
+        .expect("libobs does not export obs_output_set_media"))(output, video, audio)
+}
+#[inline]
+pub unsafe fn obs_output_set_video_encoder(
+    output: *mut obs_output_t,
+    encoder: *mut obs_encoder_t,
+) {
+    (functions()
+        .obs_output_set_video_encoder
+        .expect("libobs does not export obs_output_set_video_encoder"))(output, encoder)
+}
+#[inline]
+pub unsafe fn obs_output_set_audio_encoder(
+    output: *mut obs_output_t,
+    encoder: *mut obs_encoder_t,
+    idx: usize,
+) {
+    (functions()
+        .obs_output_set_audio_encoder
+        .expect(
+            "libobs does not export obs_output_set_audio_encoder",
+        ))(output, encoder, idx)
+}
+#[inline]
+pub unsafe fn obs_output_get_video_encoder(
+    output: *const obs_output_t,
+) -> *mut obs_encoder_t {
+    (functions()
+        .obs_output_get_video_encoder
+        .expect("libobs does not export obs_output_get_video_encoder"))(output)
+}
+#[inline]
+pub unsafe fn obs_output_get_audio_encoder(
+    output: *const obs_output_t,
+    idx: usize,
+) -> *mut obs_encoder_t {
+    (functions()
+        .obs_output_get_audio_encoder
+        .expect("libobs does not export obs_output_get_audio_encoder"))(output, idx)
+}
+#[inline]
+pub unsafe fn obs_get_output_flags(id: *const ::std::os::raw::c_char) -> u32 {
+    (functions()
+        .obs_get_output_flags
+        .expect("libobs does not export obs_get_output_flags"))(id)
+}
+#[inline]
+pub unsafe fn obs_get_output_supported_video_codecs(
+    id: *const ::std::os::raw::c_char,
+) -> *const ::std::os::raw::c_char {
+    (functions()
+        .obs_get_output_supported_video_codecs
+        .expect("libobs does not export obs_get_output_supported_video_codecs"))(id)
+}
+#[inline]
+pub unsafe fn obs_get_output_supported_audio_codecs(
+    id: *const ::std::os::raw::c_char,
+) -> *const ::std::os::raw::c_char {
+    (functions()
+        .obs_get_output_supported_audio_codecs
+        .expect("libobs does not export obs_get_output_supported_audio_codecs"))(id)
+}
+#[inline]
+pub unsafe fn obs_output_can_begin_data_capture(
+    output: *const obs_output_t,
+    flags: u32,
+) -> bool {
+    (functions()
+        .obs_output_can_begin_data_capture
+        .expect(
+            "libobs does not export obs_output_can_begin_data_capture",
+        ))(output, flags)
+}
+#[inline]
+pub unsafe fn obs_output_initialize_encoders(
+    output: *mut obs_output_t,
+    flags: u32,
+) -> bool {
+    (functions()
+        .obs_output_initialize_encoders
+        .expect("libobs does not export obs_output_initialize_encoders"))(output, flags)
+}
+#[inline]
+pub unsafe fn obs_output_begin_data_capture(
+    output: *mut obs_output_t,
+    flags: u32,
+) -> bool {
+    (functions()
+        .obs_output_begin_data_capture
+        .expect("libobs does not export obs_output_begin_data_capture"))(output, flags)
+}
+#[inline]
+pub unsafe fn obs_output_end_data_capture(output: *mut obs_output_t) {
+    (functions()
+        .obs_output_end_data_capture
+        .expect("libobs does not export obs_output_end_data_capture"))(output)
+}
+#[inline]
+pub unsafe fn obs_output_signal_stop(
+    output: *mut obs_output_t,
+    code: ::std::os::raw::c_int,
+) {
+    (functions()
+        .obs_output_signal_stop
+        .expect("libobs does not export obs_output_signal_stop"))(output, code)
+}
+#[inline]
+pub unsafe fn obs_output_get_total_bytes(output: *const obs_output_t) -> u64 {
+    (functions()
+        .obs_output_get_total_bytes
+        .expect("libobs does not export obs_output_get_total_bytes"))(output)
+}
+#[inline]
+pub unsafe fn obs_output_get_frames_dropped(
+    output: *const obs_output_t,
+) -> ::std::os::raw::c_int {
+    (functions()
+        .obs_output_get_frames_dropped
+        .expect("libobs does not export obs_output_get_frames_dropped"))(output)
+}
+#[inline]
+pub unsafe fn obs_output_get_total_frames(
+    output: *const obs_output_t,
+) -> ::std::os::raw::c_int {
+    (functions()
+        .obs_output_get_total_frames
+        .expect("libobs does not export obs_output_get_total_frames"))(output)
+}
@@ -1,32 +1,184 @@
 extern crate napi_build;
 
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+use std::collections::HashSet;
 use std::env;
+use std::fs;
 use std::path::{Path, PathBuf};
+use syn::{FnArg, ForeignItem, Item, Pat, Type};
+
+/// The pre-generated bindings shipped with the crate.
+const BINDINGS_PATH: &str = "bindings/libobs.rs";
 
 fn main() {
-    napi_build::setup();
+    if env::var("CARGO_FEATURE_NAPI").is_ok() {
+        napi_build::setup();
+    }
+
+    println!("cargo:rerun-if-changed={}", BINDINGS_PATH);
+    println!("cargo:rerun-if-env-changed=LIBOBS_INCLUDE_DIR");
+    println!("cargo:rerun-if-env-changed=LIBOBS_UPDATE_BINDINGS");
+
+    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap()).join("bindings.rs");
+
+    // libobs is loaded at runtime, the headers are only
+    // required in order to re-generate the bindings
+    match env::var("LIBOBS_INCLUDE_DIR") {
+        Ok(obs_include_dir) => {
+            let bindings = generate_bindings(&obs_include_dir);
+            fs::write(&out_path, &bindings).expect("Couldn't write bindings!");
+
+            if env::var("LIBOBS_UPDATE_BINDINGS").is_ok() {
+                fs::write(BINDINGS_PATH, &bindings).expect("Couldn't update the bindings!");
+            }
+        }
+        Err(_) => {
+            if !Path::new(BINDINGS_PATH).exists() {
+                panic!(
+                    "No pre-generated bindings found at {}, set LIBOBS_INCLUDE_DIR to generate them",
+                    BINDINGS_PATH
+                );
+            }
+
+            fs::copy(BINDINGS_PATH, &out_path).expect("Couldn't copy bindings!");
+        }
+    }
+}
 
-    let obs_include_dir = env::var("LIBOBS_INCLUDE_DIR").expect("LIBOBS_INCLUDE_DIR is not set");
-    let obs_lib_dir = env::var("LIBOBS_LIBRARY_DIR").expect("LIBOBS_LIBRARY_DIR is not set");
-    let obs_header = Path::new(&obs_include_dir)
+fn generate_bindings(obs_include_dir: &str) -> String {
+    let obs_header = Path::new(obs_include_dir)
         .join("obs.h")
         .to_string_lossy()
         .to_string();
 
-    println!("cargo:rustc-link-search={}", obs_lib_dir);
-    // Link against obs.(lib|so|dylib)
-    println!("cargo:rustc-link-lib=obs");
     println!("cargo:rerun-if-changed={}", obs_header);
 
     let bindings = bindgen::Builder::default()
         .header(obs_header)
         .parse_callbacks(Box::new(bindgen::CargoCallbacks))
+        // The bindings are shipped for every platform, `c_ulong` is only 32 bits on windows
+        .size_t_is_usize(true)
         .generate()
         .expect("Unable to generate bindings");
 
-    // Write the bindings to the $OUT_DIR/bindings.rs file.
-    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
-    bindings
-        .write_to_file(out_path.join("bindings.rs"))
-        .expect("Couldn't write bindings!");
+    make_dynamic(&bindings.to_string())
+}
+
+/// Turn the extern functions of the generated bindings into symbols
+/// which are loaded from libobs at runtime, keeping a free function
+/// with the original signature for each of them.
+fn make_dynamic(bindings: &str) -> String {
+    let file = syn::parse_file(bindings).expect("Unable to parse the bindings");
+
+    let mut items = vec![];
+    let mut fields = vec![];
+    let mut loaders = vec![];
+    let mut wrappers = vec![];
+
+    for item in file.items {
+        let foreign = match item {
+            Item::ForeignMod(foreign) => foreign,
+            item => {
+                items.push(item);
+                continue;
+            }
+        };
+
+        for item in foreign.items {
+            let func = match item {
+                ForeignItem::Fn(func) => func,
+                // Exported variables are not used
+                _ => continue,
+            };
+
+            let docs = func.attrs.iter().filter(|attr| attr.path.is_ident("doc"));
+            let name = &func.sig.ident;
+            let symbol = Literal::byte_string(format!("{}\0", name).as_bytes());
+            let output = &func.sig.output;
+            let variadic = &func.sig.variadic;
+            let inputs = &func.sig.inputs;
+            let types = inputs.iter().map(|arg| match arg {
+                FnArg::Typed(arg) => &arg.ty,
+                FnArg::Receiver(_) => unreachable!(),
+            });
+
+            fields.push(quote! {
+                pub #name: Option<unsafe extern "C" fn(#(#types,)* #variadic) #output>,
+            });
+            loaders.push(quote! {
+                #name: library.get(#symbol).ok().map(|symbol| *symbol),
+            });
+
+            // Variadic functions can only be called through the function pointer
+            if variadic.is_some() {
+                continue;
+            }
+
+            let args = inputs.iter().enumerate().map(|(i, arg)| match arg {
+                FnArg::Typed(arg) => match &*arg.pat {
+                    Pat::Ident(ident) => ident.ident.clone(),
+                    _ => format_ident!("arg{}", i),
+                },
+                FnArg::Receiver(_) => unreachable!(),
+            });
+            let message = format!("libobs does not export {}", name);
+
+            wrappers.push(quote! {
+                #(#docs)*
+                #[inline]
+                pub unsafe fn #name(#inputs) #output {
+                    (functions().#name.expect(#message))(#(#args),*)
+                }
+            });
+        }
+    }
+
+    normalize_enums(&mut items);
+
+    let tokens: TokenStream = quote! {
+        #(#items)*
+
+        /// The functions exported by the loaded libobs.
+        /// A function is `None` if the loaded libobs does not export it.
+        pub struct Functions {
+            #(#fields)*
+        }
+
+        impl Functions {
+            unsafe fn load(library: &libloading::Library) -> Self {
+                Self {
+                    #(#loaders)*
+                }
+            }
+        }
+
+        #(#wrappers)*
+    };
+
+    prettyplease::unparse(&syn::parse2(tokens).expect("Unable to parse the dynamic bindings"))
+}
+
+/// Declare all enums as `c_int`, like msvc does. Other compilers use `c_uint`
+/// for enums without negative values, which would make the bindings platform dependent.
+fn normalize_enums(items: &mut [Item]) {
+    // Enum constants are declared using the type of their enum
+    let enums = items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Const(constant) => match &*constant.ty {
+                Type::Path(path) => path.path.get_ident().map(|ident| ident.to_string()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect::<HashSet<_>>();
+
+    for item in items {
+        if let Item::Type(alias) = item {
+            if enums.contains(&alias.ident.to_string()) {
+                *alias.ty = syn::parse_quote!(::std::os::raw::c_int);
+            }
+        }
+    }
 }
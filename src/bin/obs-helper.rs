@@ -0,0 +1,5 @@
+//! Runs libobs on behalf of another process, see `libobs::helper`.
+
+fn main() {
+    std::process::exit(libobs::helper::main(std::env::args().skip(1).collect()));
+}
@@ -1,7 +1,11 @@
-#![feature(core_c_str)]
-#![feature(alloc_c_string)]
 #![deny(clippy::all)]
 
+//! Rust bindings for libobs.
+//!
+//! The api is usable from plain rust, the node.js bindings
+//! are built on top of it if the `napi` feature is enabled.
+
+#[cfg(feature = "napi")]
 #[macro_use]
 extern crate napi_derive;
 
@@ -10,4 +14,8 @@ extern crate strum_macros;
 extern crate alloc;
 extern crate core;
 
+#[cfg(feature = "napi")]
+mod node;
 mod obs;
+
+pub use crate::obs::*;
@@ -0,0 +1,44 @@
+use crate::obs::custom::encoded_packet::Packet;
+use crate::obs::data::obs_encoder_type::EncoderKind;
+use napi::bindgen_prelude::Buffer;
+
+/// An encoded video or audio packet.
+#[napi(object)]
+pub struct EncodedPacket {
+    /// The encoded data.
+    pub data: Buffer,
+    /// The presentation timestamp in `timebaseNum / timebaseDen` units.
+    pub pts: i64,
+    /// The decode timestamp in `timebaseNum / timebaseDen` units.
+    pub dts: i64,
+    pub timebase_num: i32,
+    pub timebase_den: i32,
+    /// Whether this is a video or an audio packet.
+    pub kind: EncoderKind,
+    /// Whether this packet is a keyframe.
+    pub keyframe: bool,
+    /// The index of the audio track this packet belongs to.
+    pub track_idx: u32,
+    /// The codec of the packet, e.g. `h264` or `aac`.
+    pub codec: Option<String>,
+    /// The codec extra data, e.g. the SPS/PPS of h264 or the AudioSpecificConfig of aac.
+    /// Only set on keyframes and audio packets.
+    pub extra_data: Option<Buffer>,
+}
+
+impl From<Packet> for EncodedPacket {
+    fn from(packet: Packet) -> Self {
+        Self {
+            data: packet.data.into(),
+            pts: packet.pts,
+            dts: packet.dts,
+            timebase_num: packet.timebase_num,
+            timebase_den: packet.timebase_den,
+            kind: packet.kind,
+            keyframe: packet.keyframe,
+            track_idx: packet.track_idx,
+            codec: packet.codec,
+            extra_data: packet.extra_data.map(|d| d.into()),
+        }
+    }
+}
@@ -0,0 +1,15 @@
+//! The node.js bindings, thin wrappers around the rust api.
+
+mod encoded_packet;
+mod napi_error;
+mod node_util;
+mod obs;
+mod obs_encoder;
+mod obs_encoder_type;
+mod obs_output;
+mod obs_output_type;
+mod obs_properties;
+mod obs_settings;
+mod obs_source;
+mod obs_worker;
+mod remote_obs;
@@ -1,7 +1,7 @@
+use crate::obs::util::obs_error::ObsError;
 use crate::obs::util::types::ResultType;
-use std::error::Error;
 
-pub fn to_napi_error(err: Box<dyn Error>) -> napi::Error {
+pub fn to_napi_error(err: ObsError) -> napi::Error {
     napi::Error::new(napi::Status::GenericFailure, err.to_string())
 }
 
@@ -9,10 +9,6 @@ pub fn to_napi_error_str(err: &str) -> napi::Error {
     napi::Error::new(napi::Status::GenericFailure, err.to_string())
 }
 
-pub fn to_napi_error_string(err: String) -> napi::Error {
-    napi::Error::new(napi::Status::GenericFailure, err)
-}
-
 pub trait MapToNapiError<T> {
     fn map_napi_err(self) -> napi::Result<T>;
 }
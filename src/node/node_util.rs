@@ -7,8 +7,8 @@ pub fn is_integer(env: &Env, value: &JsNumber) -> napi::Result<bool> {
         .coerce_to_object()?;
     let is_integer: JsFunction = number.get_named_property("isInteger")?;
 
-    Ok(is_integer
+    is_integer
         .call(None, &[value])?
         .coerce_to_bool()?
-        .get_value()?)
+        .get_value()
 }
@@ -0,0 +1,802 @@
+use crate::node::napi_error::{to_napi_error_str, MapToNapiError};
+use crate::node::obs_encoder::{ObsAudioEncoder, ObsVideoEncoder};
+use crate::node::obs_encoder_type::ObsEncoderType;
+use crate::node::obs_output::ObsOutput;
+use crate::node::obs_output_type::ObsOutputType;
+use crate::node::obs_settings::ObsSettings;
+use crate::node::obs_source::ObsSource;
+use crate::node::obs_worker::ObsWorker;
+use crate::obs::data::loaded_obs_module::LoadedObsModule;
+use crate::obs::data::obs_module::ObsModule;
+use crate::obs::data::profiler_snapshot::ProfilerEntry;
+use crate::obs::helper::probe::{ModuleProbeResult, ProbeOptions};
+use crate::obs::objects::module_load_options::ModuleLoadOptions;
+use crate::obs::objects::module_load_result::ModuleLoadResult;
+use crate::obs::objects::obs_version::{Capability, ObsVersion};
+use crate::obs::objects::reconfigure_options::{ReconfigureData, ReconfigureOptions};
+use crate::obs::objects::reset_audio_data::ResetAudioData;
+use crate::obs::objects::reset_video_data::ResetVideoData;
+use crate::obs::objects::startup_options::StartupOptions;
+use crate::obs::objects::type_schema::{ObsObjectKind, TypeSchema};
+use crate::obs::obs::Obs as InnerObs;
+use crate::obs::util::types::ResultType;
+use napi::{Env, JsObject};
+
+/// The main obs class.
+/// You can only have one instance of this class active at a time.
+///
+/// # Example
+/// ```ts
+/// const obs = await Obs.newInstance('en-US');
+///
+/// // Get all modules which may be loaded
+/// const modules = await obs.getAllModules('/path/to/your/obs/installation');
+/// // Load the modules
+/// await obs.loadModules(modules);
+///
+/// // Reset the audio and video
+/// await obs.resetAudio({
+///    fixedBuffering: false,
+///    speakers: obs.SpeakerLayout.Stereo,
+///    maxBufferingMs: 1000,
+///    samplesPerSec: 48000,
+/// });
+///
+/// await obs.resetVideo({
+///    adapter: 0,
+///    baseHeight: 1440,
+///    baseWidth: 2560,
+///    outputHeight: 1440,
+///    outputWidth: 2560,
+///    scaleType: obs.ScaleType.Bicubic,
+///    colorspace: obs.VideoColorSpace.CS709,
+///    fpsDen: 1,
+///    fpsNum: 60,
+///    gpuConversion: true,
+///    range: obs.VideoRange.Partial,
+///    graphicsModule: obs.GraphicsModule.D3D11,
+///    outputFormat: obs.VideoFormat.NV12,
+/// });
+///
+/// // Create a video encoder
+/// const videoEncoder = await obs.createVideoEncoder('nvenc', 'jim_nvenc', new ObsSettings({
+///     rate_control: 'CQP',
+///     cqp: 23,
+///     preset: 'medium',
+///     profile: 'high',
+/// ));
+///
+/// // Create an audio encoder
+/// const audioEncoder = await obs.createAudioEncoder('aac', 'jim_aac', new ObsSettings({
+///    bitrate: 128,
+///    rate_control: 'CBR',
+/// }));
+///
+/// // Create a new video source
+/// const videoSource = await obs.createSource('screen_capture', 'monitor_capture', 0, new ObsSettings({
+///    capture_cursor: true,
+///    monitor: 0,
+///    method: 2,
+/// }));
+///
+/// // Create a new audio source
+/// await obs.createSource('audio_capture', 'wasapi_output_capture', 1);
+///
+/// // Create a new flv output
+/// const out = await obs.createOutput('output', 'flv_output', new ObsSettings({
+///    path: '/path/to/your/output.flv',
+/// }));
+///
+/// // Start the output
+/// out.start(videoEncoder, audioEncoder);
+///
+/// // Wait for 10 seconds
+/// await new Promise(resolve => setTimeout(resolve, 10000));
+///
+/// // Stop the output
+/// out.stop();
+/// ```
+#[napi]
+pub struct Obs {
+    worker: ObsWorker,
+}
+
+#[napi]
+impl Obs {
+    /// Create a new OBS instance.
+    ///
+    /// @param locale - the locale to use, e.g. `en-US`
+    /// @param libobsPath - the path to libobs or the directory containing it.
+    ///                     Searches the library search path if not set.
+    /// @param options - the module config path, whether to enable the profiler
+    ///                  and additional data paths
+    #[napi(constructor)]
+    pub fn new(
+        locale: String,
+        libobs_path: Option<String>,
+        options: Option<StartupOptions>,
+    ) -> napi::Result<Self> {
+        let options = options.unwrap_or_default();
+        let worker = ObsWorker::start_sync(move || {
+            InnerObs::with_options(&locale, libobs_path.as_deref(), &options)
+        })?;
+
+        Ok(Self { worker })
+    }
+
+    /// Create a new OBS instance.
+    /// Async version.
+    #[napi(js_name = "newInstance", ts_return_type = "Promise<Obs>")]
+    pub fn new_obs_instance(
+        env: Env,
+        locale: String,
+        libobs_path: Option<String>,
+        options: Option<StartupOptions>,
+    ) -> napi::Result<JsObject> {
+        let options = options.unwrap_or_default();
+        let worker = ObsWorker::start(move || {
+            InnerObs::with_options(&locale, libobs_path.as_deref(), &options)
+        });
+
+        env.spawn_future(async move {
+            Ok(Self {
+                worker: worker.await?,
+            })
+        })
+    }
+
+    /// The current locale, e.g. `en-US`.
+    #[napi(getter)]
+    pub fn get_locale(&self) -> napi::Result<String> {
+        self.worker.run_sync(|obs| obs.get_locale())
+    }
+
+    /// Change the locale of obs and all loaded modules.
+    /// Properties retrieved after the change use the new locale.
+    #[napi]
+    pub fn set_locale_sync(&self, locale: String) -> napi::Result<()> {
+        self.worker.run_sync(move |obs| obs.set_locale(&locale))
+    }
+
+    /// Change the locale of obs and all loaded modules.
+    /// Async version of `setLocaleSync`.
+    #[napi(ts_return_type = "Promise<void>")]
+    pub fn set_locale(&self, env: Env, locale: String) -> napi::Result<JsObject> {
+        env.spawn_future(self.worker.run(move |obs| obs.set_locale(&locale)))
+    }
+
+    /// Take a snapshot of the data collected by the profiler.
+    /// Returns the root entries, e.g. the video and graphics threads.
+    /// All times are in microseconds.
+    /// Throws an error if obs was not started with the profiler enabled.
+    #[napi]
+    pub fn get_profiler_snapshot_sync(&self) -> napi::Result<Vec<ProfilerEntry>> {
+        self.worker.run_sync(|obs| obs.get_profiler_snapshot())
+    }
+
+    /// Take a snapshot of the data collected by the profiler.
+    /// Async version of `getProfilerSnapshotSync`.
+    #[napi(ts_return_type = "Promise<Array<ProfilerEntry>>")]
+    pub fn get_profiler_snapshot(&self, env: Env) -> napi::Result<JsObject> {
+        env.spawn_future(self.worker.run(|obs| obs.get_profiler_snapshot()))
+    }
+
+    /// Write a snapshot of the data collected by the profiler to a CSV file,
+    /// which is compressed using gzip if the path ends with `.gz`.
+    /// Throws an error if obs was not started with the profiler enabled.
+    #[napi]
+    pub fn dump_profiler_snapshot_sync(&self, path: String) -> napi::Result<()> {
+        self.worker
+            .run_sync(move |obs| obs.dump_profiler_snapshot(&path))
+    }
+
+    /// Write a snapshot of the data collected by the profiler to a CSV file.
+    /// Async version of `dumpProfilerSnapshotSync`.
+    #[napi(ts_return_type = "Promise<void>")]
+    pub fn dump_profiler_snapshot(&self, env: Env, path: String) -> napi::Result<JsObject> {
+        env.spawn_future(
+            self.worker
+                .run(move |obs| obs.dump_profiler_snapshot(&path)),
+        )
+    }
+
+    /// Get all modules which may be loaded.
+    /// This may include 'modules' which are not in fact modules.
+    #[napi]
+    pub fn get_all_modules_sync(&self, obs_path: Option<String>) -> napi::Result<Vec<ObsModule>> {
+        self.worker
+            .run_sync(move |obs| obs.get_all_modules(obs_path.as_deref()))
+    }
+
+    /// Get all modules which may be loaded.
+    /// Async version of `getAllModulesSync`.
+    #[napi(ts_return_type = "Promise<Array<ObsModule>>")]
+    pub fn get_all_modules(&self, env: Env, obs_path: Option<String>) -> napi::Result<JsObject> {
+        env.spawn_future(
+            self.worker
+                .run(move |obs| obs.get_all_modules(obs_path.as_deref())),
+        )
+    }
+
+    /// Load modules.
+    /// Returns the result of loading each module.
+    ///
+    /// @param modules - the modules to load
+    /// @param options - allow and deny lists, disabled and safe modules,
+    ///                  whether to run the post load step and whether to
+    ///                  throw an error if a module fails to load
+    #[napi]
+    pub fn load_modules_sync(
+        &self,
+        modules: Vec<ObsModule>,
+        options: Option<ModuleLoadOptions>,
+    ) -> napi::Result<Vec<ModuleLoadResult>> {
+        let options = options.unwrap_or_default();
+        self.worker
+            .run_sync(move |obs| obs.load_modules(modules, &options))
+    }
+
+    /// Load modules.
+    /// Async version of `loadModulesSync`.
+    #[napi(ts_return_type = "Promise<Array<ModuleLoadResult>>")]
+    pub fn load_modules(
+        &self,
+        env: Env,
+        modules: Vec<ObsModule>,
+        options: Option<ModuleLoadOptions>,
+    ) -> napi::Result<JsObject> {
+        let options = options.unwrap_or_default();
+        env.spawn_future(
+            self.worker
+                .run(move |obs| obs.load_modules(modules, &options)),
+        )
+    }
+
+    /// Test-load modules, each in its own short-lived helper process,
+    /// to find modules which crash, hang or fail to load
+    /// before loading them into this process.
+    /// Requires the `obs-helper` binary, see `ProbeOptions.helperPath`.
+    ///
+    /// # Example
+    /// ```ts
+    /// const results = Obs.probeModulesSync(modules, { timeout: 5000 });
+    /// const safe = results.filter(r => r.status === ModuleProbeStatus.Loaded).map(r => r.module);
+    /// await obs.loadModules(safe);
+    /// ```
+    #[napi]
+    pub fn probe_modules_sync(
+        modules: Vec<ObsModule>,
+        options: Option<ProbeOptions>,
+    ) -> napi::Result<Vec<ModuleProbeResult>> {
+        InnerObs::probe_modules(&modules, &options.unwrap_or_default()).map_napi_err()
+    }
+
+    /// Test-load modules, each in its own short-lived helper process.
+    /// Async version of `probeModulesSync`.
+    #[napi]
+    pub async fn probe_modules(
+        modules: Vec<ObsModule>,
+        options: Option<ProbeOptions>,
+    ) -> napi::Result<Vec<ModuleProbeResult>> {
+        tokio::task::spawn_blocking(move || Self::probe_modules_sync(modules, options))
+            .await
+            .map_err(|e| to_napi_error_str(&e.to_string()))?
+    }
+
+    #[napi(ts_return_type = "Promise<boolean>")]
+    pub fn init_audio_monitoring(
+        &self,
+        env: Env,
+        device_name: String,
+        device_id: String,
+    ) -> napi::Result<JsObject> {
+        env.spawn_future(
+            self.worker
+                .run(move |obs| obs.init_audio_monitoring(&device_name, &device_id)),
+        )
+    }
+
+    /// Reset the video data.
+    #[napi]
+    pub fn reset_video_sync(&self, data: ResetVideoData) -> napi::Result<()> {
+        self.worker.run_sync(move |obs| obs.reset_video(&data))
+    }
+
+    /// Reset the video data.
+    /// Async version of `resetVideoSync`.
+    #[napi(ts_return_type = "Promise<void>")]
+    pub fn reset_video(&self, env: Env, data: ResetVideoData) -> napi::Result<JsObject> {
+        env.spawn_future(self.worker.run(move |obs| obs.reset_video(&data)))
+    }
+
+    /// Reset the audio data.
+    #[napi]
+    pub fn reset_audio_sync(&self, data: ResetAudioData) -> napi::Result<()> {
+        self.worker.run_sync(move |obs| obs.reset_audio(&data))
+    }
+
+    /// Reset the audio data.
+    /// Async version of `resetAudioSync`.
+    #[napi(ts_return_type = "Promise<void>")]
+    pub fn reset_audio(&self, env: Env, data: ResetAudioData) -> napi::Result<JsObject> {
+        env.spawn_future(self.worker.run(move |obs| obs.reset_audio(&data)))
+    }
+
+    /// Reset the video and/or audio while outputs may be running.
+    /// Active outputs are stopped gracefully before resetting and
+    /// are started again afterwards if `restartOutputs` is set.
+    #[napi]
+    pub fn reconfigure_sync(
+        &self,
+        data: ReconfigureData,
+        options: Option<ReconfigureOptions>,
+    ) -> napi::Result<()> {
+        let options = options.unwrap_or_default();
+        self.worker
+            .run_sync(move |obs| obs.reconfigure(&data, &options))
+    }
+
+    /// Reset the video and/or audio while outputs may be running.
+    /// Async version of `reconfigureSync`.
+    #[napi(ts_return_type = "Promise<void>")]
+    pub fn reconfigure(
+        &self,
+        env: Env,
+        data: ReconfigureData,
+        options: Option<ReconfigureOptions>,
+    ) -> napi::Result<JsObject> {
+        let options = options.unwrap_or_default();
+        env.spawn_future(self.worker.run(move |obs| obs.reconfigure(&data, &options)))
+    }
+
+    /// Get the video data obs is currently running with.
+    /// Throws an error if the video has not been reset yet.
+    #[napi]
+    pub fn get_video_info_sync(&self) -> napi::Result<ResetVideoData> {
+        self.worker.run_sync(|obs| obs.get_video_info())
+    }
+
+    /// Get the video data obs is currently running with.
+    /// Async version of `getVideoInfoSync`.
+    #[napi(ts_return_type = "Promise<ResetVideoData>")]
+    pub fn get_video_info(&self, env: Env) -> napi::Result<JsObject> {
+        env.spawn_future(self.worker.run(|obs| obs.get_video_info()))
+    }
+
+    /// Get the audio data obs is currently running with.
+    /// Throws an error if the audio has not been reset yet.
+    #[napi]
+    pub fn get_audio_info_sync(&self) -> napi::Result<ResetAudioData> {
+        self.worker.run_sync(|obs| obs.get_audio_info())
+    }
+
+    /// Get the audio data obs is currently running with.
+    /// Async version of `getAudioInfoSync`.
+    #[napi(ts_return_type = "Promise<ResetAudioData>")]
+    pub fn get_audio_info(&self, env: Env) -> napi::Result<JsObject> {
+        env.spawn_future(self.worker.run(|obs| obs.get_audio_info()))
+    }
+
+    /// Whether video is active, i.e. whether any output is using it.
+    /// The video can't be reset while it is active.
+    #[napi(getter)]
+    pub fn video_active(&self) -> napi::Result<bool> {
+        self.worker.run_sync(|obs| Ok(obs.video_active()))
+    }
+
+    /// The version of the loaded libobs.
+    #[napi(getter)]
+    pub fn version(&self) -> napi::Result<ObsVersion> {
+        self.worker.run_sync(|obs| Ok(obs.version()))
+    }
+
+    /// Check if the loaded libobs supports a capability.
+    /// Methods requiring an unsupported capability throw an error
+    /// or fall back to an older api.
+    #[napi]
+    pub fn supports(&self, capability: Capability) -> napi::Result<bool> {
+        self.worker
+            .run_sync(move |obs| Ok(obs.supports(capability)))
+    }
+
+    /// Get the results of all modules loaded using `loadModules`.
+    #[napi(getter)]
+    pub fn module_results(&self) -> napi::Result<Vec<ModuleLoadResult>> {
+        self.worker
+            .run_sync(|obs| Ok(obs.module_results().to_vec()))
+    }
+
+    /// Get a list of all loaded modules.
+    /// The registered types are only known for modules loaded using `loadModules`.
+    #[napi(getter)]
+    pub fn get_loaded_modules(&self) -> napi::Result<Vec<LoadedObsModule>> {
+        self.worker.run_sync(|obs| obs.get_loaded_modules())
+    }
+
+    /// Find the loaded module which registered the type with the given id.
+    /// Only modules loaded using `loadModules` are considered.
+    ///
+    /// # Example
+    /// ```ts
+    /// const module = obs.findTypeModule('jim_nvenc');
+    /// console.log(module?.fileName);
+    /// ```
+    #[napi]
+    pub fn find_type_module(&self, id: String) -> napi::Result<Option<LoadedObsModule>> {
+        self.worker.run_sync(move |obs| obs.find_type_module(&id))
+    }
+
+    /// List all encoder types.
+    /// This list includes video and audio encoders.
+    #[napi]
+    pub fn list_encoder_types_sync(&self) -> napi::Result<Vec<String>> {
+        self.worker.run_sync(|obs| Ok(obs.list_encoder_types()))
+    }
+
+    /// List all encoder types.
+    /// Async version of `listEncoderTypesSync`.
+    #[napi(ts_return_type = "Promise<Array<string>>")]
+    pub fn list_encoder_types(&self, env: Env) -> napi::Result<JsObject> {
+        env.spawn_future(self.worker.run(|obs| Ok(obs.list_encoder_types())))
+    }
+
+    /// Get information about an encoder type without creating an encoder.
+    /// Throws an error if the encoder type does not exist.
+    #[napi]
+    pub fn get_encoder_type_sync(&self, id: String) -> napi::Result<ObsEncoderType> {
+        self.worker
+            .run_sync(move |obs| obs.get_encoder_type(&id).map(ObsEncoderType))
+    }
+
+    /// Get information about an encoder type without creating an encoder.
+    /// Async version of `getEncoderTypeSync`.
+    #[napi(ts_return_type = "Promise<ObsEncoderType>")]
+    pub fn get_encoder_type(&self, env: Env, id: String) -> napi::Result<JsObject> {
+        env.spawn_future(
+            self.worker
+                .run(move |obs| obs.get_encoder_type(&id).map(ObsEncoderType)),
+        )
+    }
+
+    /// List information about all encoder types.
+    /// This list includes video and audio encoders.
+    #[napi]
+    pub fn list_encoder_type_details_sync(&self) -> napi::Result<Vec<ObsEncoderType>> {
+        self.worker.run_sync(list_encoder_type_details)
+    }
+
+    /// List information about all encoder types.
+    /// Async version of `listEncoderTypeDetailsSync`.
+    #[napi(ts_return_type = "Promise<Array<ObsEncoderType>>")]
+    pub fn list_encoder_type_details(&self, env: Env) -> napi::Result<JsObject> {
+        env.spawn_future(self.worker.run(list_encoder_type_details))
+    }
+
+    /// List all output types.
+    #[napi]
+    pub fn list_output_types_sync(&self) -> napi::Result<Vec<String>> {
+        self.worker.run_sync(|obs| Ok(obs.list_output_types()))
+    }
+
+    /// List all output types.
+    /// Async version of `listOutputTypesSync`.
+    #[napi(ts_return_type = "Promise<Array<string>>")]
+    pub fn list_output_types(&self, env: Env) -> napi::Result<JsObject> {
+        env.spawn_future(self.worker.run(|obs| Ok(obs.list_output_types())))
+    }
+
+    /// Get information about an output type without creating an output.
+    /// Throws an error if the output type does not exist.
+    #[napi]
+    pub fn get_output_type_sync(&self, id: String) -> napi::Result<ObsOutputType> {
+        self.worker
+            .run_sync(move |obs| obs.get_output_type(&id).map(ObsOutputType))
+    }
+
+    /// Get information about an output type without creating an output.
+    /// Async version of `getOutputTypeSync`.
+    #[napi(ts_return_type = "Promise<ObsOutputType>")]
+    pub fn get_output_type(&self, env: Env, id: String) -> napi::Result<JsObject> {
+        env.spawn_future(
+            self.worker
+                .run(move |obs| obs.get_output_type(&id).map(ObsOutputType)),
+        )
+    }
+
+    /// List information about all output types.
+    #[napi]
+    pub fn list_output_type_details_sync(&self) -> napi::Result<Vec<ObsOutputType>> {
+        self.worker.run_sync(list_output_type_details)
+    }
+
+    /// List information about all output types.
+    /// Async version of `listOutputTypeDetailsSync`.
+    #[napi(ts_return_type = "Promise<Array<ObsOutputType>>")]
+    pub fn list_output_type_details(&self, env: Env) -> napi::Result<JsObject> {
+        env.spawn_future(self.worker.run(list_output_type_details))
+    }
+
+    #[napi]
+    pub fn list_source_types_sync(&self) -> napi::Result<Vec<String>> {
+        self.worker.run_sync(|obs| Ok(obs.list_source_types()))
+    }
+
+    #[napi(ts_return_type = "Promise<Array<string>>")]
+    pub fn list_source_types(&self, env: Env) -> napi::Result<JsObject> {
+        env.spawn_future(self.worker.run(|obs| Ok(obs.list_source_types())))
+    }
+
+    /// Generate a JSON schema and a TypeScript interface
+    /// for the settings of a source, encoder or output type.
+    ///
+    /// @param kind - the kind of the type
+    /// @param id - the id of the type, e.g. `ffmpeg_source`
+    /// @param name - the name of the generated interface. Defaults to the id in PascalCase.
+    #[napi]
+    pub fn get_type_schema_sync(
+        &self,
+        kind: ObsObjectKind,
+        id: String,
+        name: Option<String>,
+    ) -> napi::Result<TypeSchema> {
+        self.worker
+            .run_sync(move |obs| obs.get_type_schema(kind, &id, name.as_deref()))
+    }
+
+    /// Generate a JSON schema and a TypeScript interface
+    /// for the settings of a source, encoder or output type.
+    /// Async version of `getTypeSchemaSync`.
+    #[napi(ts_return_type = "Promise<TypeSchema>")]
+    pub fn get_type_schema(
+        &self,
+        env: Env,
+        kind: ObsObjectKind,
+        id: String,
+        name: Option<String>,
+    ) -> napi::Result<JsObject> {
+        env.spawn_future(
+            self.worker
+                .run(move |obs| obs.get_type_schema(kind, &id, name.as_deref())),
+        )
+    }
+
+    #[napi]
+    pub fn create_video_encoder_sync(
+        &self,
+        name: String,
+        id: String,
+        settings: Option<&ObsSettings>,
+    ) -> napi::Result<ObsVideoEncoder> {
+        let settings = settings.map(|s| s.0.clone());
+        self.worker.run_sync(move |obs| {
+            obs.create_video_encoder(&name, &id, settings.as_ref())
+                .map(ObsVideoEncoder)
+        })
+    }
+
+    #[napi(ts_return_type = "Promise<ObsVideoEncoder>")]
+    pub fn create_video_encoder(
+        &self,
+        env: Env,
+        name: String,
+        id: String,
+        settings: Option<&ObsSettings>,
+    ) -> napi::Result<JsObject> {
+        let settings = settings.map(|s| s.0.clone());
+        env.spawn_future(self.worker.run(move |obs| {
+            obs.create_video_encoder(&name, &id, settings.as_ref())
+                .map(ObsVideoEncoder)
+        }))
+    }
+
+    #[napi]
+    pub fn create_audio_encoder_sync(
+        &self,
+        name: String,
+        id: String,
+        settings: Option<&ObsSettings>,
+    ) -> napi::Result<ObsAudioEncoder> {
+        let settings = settings.map(|s| s.0.clone());
+        self.worker.run_sync(move |obs| {
+            obs.create_audio_encoder(&name, &id, settings.as_ref())
+                .map(ObsAudioEncoder)
+        })
+    }
+
+    #[napi(ts_return_type = "Promise<ObsAudioEncoder>")]
+    pub fn create_audio_encoder(
+        &self,
+        env: Env,
+        name: String,
+        id: String,
+        settings: Option<&ObsSettings>,
+    ) -> napi::Result<JsObject> {
+        let settings = settings.map(|s| s.0.clone());
+        env.spawn_future(self.worker.run(move |obs| {
+            obs.create_audio_encoder(&name, &id, settings.as_ref())
+                .map(ObsAudioEncoder)
+        }))
+    }
+
+    #[napi]
+    pub fn create_output_sync(
+        &self,
+        name: String,
+        id: String,
+        settings: Option<&ObsSettings>,
+    ) -> napi::Result<ObsOutput> {
+        let settings = settings.map(|s| s.0.clone());
+        self.worker.run_sync(move |obs| {
+            obs.create_output(&name, &id, settings.as_ref())
+                .map(ObsOutput)
+        })
+    }
+
+    #[napi(ts_return_type = "Promise<ObsOutput>")]
+    pub fn create_output(
+        &self,
+        env: Env,
+        name: String,
+        id: String,
+        settings: Option<&ObsSettings>,
+    ) -> napi::Result<JsObject> {
+        let settings = settings.map(|s| s.0.clone());
+        env.spawn_future(self.worker.run(move |obs| {
+            obs.create_output(&name, &id, settings.as_ref())
+                .map(ObsOutput)
+        }))
+    }
+
+    #[napi]
+    pub fn create_source_sync(
+        &self,
+        name: String,
+        id: String,
+        channel: u32,
+        settings: Option<&ObsSettings>,
+    ) -> napi::Result<ObsSource> {
+        let settings = settings.map(|s| s.0.clone());
+        self.worker.run_sync(move |obs| {
+            obs.create_source(&name, &id, channel, settings.as_ref())
+                .map(ObsSource)
+        })
+    }
+
+    #[napi(ts_return_type = "Promise<ObsSource>")]
+    pub fn create_source(
+        &self,
+        env: Env,
+        name: String,
+        id: String,
+        channel: u32,
+        settings: Option<&ObsSettings>,
+    ) -> napi::Result<JsObject> {
+        let settings = settings.map(|s| s.0.clone());
+        env.spawn_future(self.worker.run(move |obs| {
+            obs.create_source(&name, &id, channel, settings.as_ref())
+                .map(ObsSource)
+        }))
+    }
+
+    /// Register a video source type whose frames are pushed from javascript.
+    /// Create sources of this type using `createSource` and push frames
+    /// to them using `ObsSource.pushVideoFrame`.
+    ///
+    /// # Example
+    /// ```ts
+    /// obs.registerVideoSourceType('node_video', 'Node.js video');
+    /// const source = await obs.createSource('graphics', 'node_video', 0);
+    ///
+    /// source.pushVideoFrame(canvas.toBuffer('raw'), {
+    ///    format: VideoFormat.BGRA,
+    ///    width: canvas.width,
+    ///    height: canvas.height,
+    /// });
+    /// ```
+    ///
+    /// @param id - the id of the source type
+    /// @param displayName - the name of the source type shown to users
+    #[napi]
+    pub fn register_video_source_type(&self, id: String, display_name: String) -> napi::Result<()> {
+        self.worker
+            .run_sync(move |obs| obs.register_video_source_type(&id, &display_name))
+    }
+
+    /// Register an audio source type whose samples are pushed from javascript.
+    /// Create sources of this type using `createSource` and push audio
+    /// to them using `ObsSource.pushAudio`.
+    ///
+    /// # Example
+    /// ```ts
+    /// obs.registerAudioSourceType('node_audio', 'Node.js audio');
+    /// const source = await obs.createSource('tts', 'node_audio', 1);
+    ///
+    /// source.pushAudio(pcm, {
+    ///    format: AudioFormat.Bit16,
+    ///    speakers: SpeakerLayout.Mono,
+    ///    sampleRate: 24000,
+    /// });
+    /// ```
+    ///
+    /// @param id - the id of the source type
+    /// @param displayName - the name of the source type shown to users
+    #[napi]
+    pub fn register_audio_source_type(&self, id: String, display_name: String) -> napi::Result<()> {
+        self.worker
+            .run_sync(move |obs| obs.register_audio_source_type(&id, &display_name))
+    }
+
+    /// Register an output type whose encoded packets are passed to javascript.
+    /// Create outputs of this type using `createOutput` and receive
+    /// their packets using `ObsOutput.onPacket`.
+    ///
+    /// # Example
+    /// ```ts
+    /// obs.registerOutputType('node_output', 'Node.js output');
+    /// const output = await obs.createOutput('muxer', 'node_output');
+    ///
+    /// output.onPacket(packet => {
+    ///    console.log(packet.kind, packet.pts, packet.data.length);
+    /// });
+    ///
+    /// output.start(videoEncoder, audioEncoder);
+    /// ```
+    ///
+    /// @param id - the id of the output type
+    /// @param displayName - the name of the output type shown to users
+    #[napi]
+    pub fn register_output_type(&self, id: String, display_name: String) -> napi::Result<()> {
+        self.worker
+            .run_sync(move |obs| obs.register_output_type(&id, &display_name))
+    }
+
+    /// Register an output type muxing the encoded video and audio into
+    /// an MPEG transport stream, which is passed to javascript.
+    /// Supports h264 and hevc video and aac audio.
+    /// Create outputs of this type using `createOutput` and receive
+    /// the stream using `ObsOutput.onData` or `createOutputStream`
+    /// from `libobs/stream`.
+    ///
+    /// # Example
+    /// ```ts
+    /// import { createOutputStream } from 'libobs/stream';
+    ///
+    /// obs.registerMpegTsOutputType('node_mpegts', 'Node.js MPEG-TS');
+    /// const output = await obs.createOutput('ts', 'node_mpegts');
+    ///
+    /// createOutputStream(output).pipe(fs.createWriteStream('recording.ts'));
+    /// output.start(videoEncoder, audioEncoder);
+    /// ```
+    ///
+    /// @param id - the id of the output type
+    /// @param displayName - the name of the output type shown to users
+    #[napi(js_name = "registerMpegTsOutputType")]
+    pub fn register_mpegts_output_type(
+        &self,
+        id: String,
+        display_name: String,
+    ) -> napi::Result<()> {
+        self.worker
+            .run_sync(move |obs| obs.register_mpegts_output_type(&id, &display_name))
+    }
+
+    #[napi]
+    pub fn find_obs_sync(bin_path: Option<bool>) -> napi::Result<String> {
+        InnerObs::find_obs(bin_path.unwrap_or(false)).map_napi_err()
+    }
+
+    #[napi]
+    pub async fn find_obs(bin_path: Option<bool>) -> napi::Result<String> {
+        Self::find_obs_sync(bin_path)
+    }
+}
+
+fn list_encoder_type_details(obs: &mut InnerObs) -> ResultType<Vec<ObsEncoderType>> {
+    obs.list_encoder_type_details()
+        .map(|types| types.into_iter().map(ObsEncoderType).collect())
+}
+
+fn list_output_type_details(obs: &mut InnerObs) -> ResultType<Vec<ObsOutputType>> {
+    obs.list_output_type_details()
+        .map(|types| types.into_iter().map(ObsOutputType).collect())
+}
@@ -0,0 +1,110 @@
+use crate::node::napi_error::MapToNapiError;
+use crate::node::obs_properties::ObsProperties;
+use crate::node::obs_settings::ObsSettings;
+use crate::obs::data::settings_validation::SettingsIssue;
+use crate::obs::io::obs_encoder::ObsEncoder as InnerObsEncoder;
+
+/// An obs video encoder.
+///
+/// # Example
+/// ```ts
+/// const encoder = await obs.createVideoEncoder('nvenc', 'jim_nvenc', new ObsSettings({
+///    rate_control: 'CQP',
+///    cqp: 23,
+///    preset: 'medium',
+///    profile: 'high',
+/// }));
+#[napi]
+pub struct ObsVideoEncoder(pub(crate) InnerObsEncoder);
+
+#[napi]
+impl ObsVideoEncoder {
+    /// Get the settings of this encoder.
+    #[napi]
+    pub fn get_settings(&self) -> napi::Result<ObsSettings> {
+        self.0.get_settings().map(ObsSettings).map_napi_err()
+    }
+
+    /// Update the settings of this encoder.
+    ///
+    /// @param settings - the settings to apply
+    /// @param strict - whether to validate the settings first and throw
+    ///                 an error if they are invalid. Defaults to `false`.
+    #[napi]
+    pub fn update_settings(
+        &self,
+        settings: &ObsSettings,
+        strict: Option<bool>,
+    ) -> napi::Result<()> {
+        self.0
+            .update_settings(&settings.0, strict.unwrap_or(false))
+            .map_napi_err()
+    }
+
+    /// Validate settings against the properties of this encoder.
+    /// Returns an empty list if the settings are valid.
+    #[napi]
+    pub fn validate(&self, settings: &ObsSettings) -> napi::Result<Vec<SettingsIssue>> {
+        self.0.validate(&settings.0).map_napi_err()
+    }
+
+    /// Get the properties of this encoder.
+    #[napi]
+    pub fn get_properties(&self) -> napi::Result<ObsProperties> {
+        self.0.get_properties().map(ObsProperties).map_napi_err()
+    }
+}
+
+unsafe impl Send for ObsVideoEncoder {}
+
+/// An obs audio encoder.
+///
+/// # Example
+/// ```ts
+/// const audioEncoder = await obs.createAudioEncoder('aac', 'ffmpeg_aac', new ObsSettings({
+///    bitrate: 128,
+///    rate_control: 'CBR',
+/// }));
+/// ```
+#[napi]
+pub struct ObsAudioEncoder(pub(crate) InnerObsEncoder);
+
+#[napi]
+impl ObsAudioEncoder {
+    /// Get the settings of this encoder.
+    #[napi]
+    pub fn get_settings(&self) -> napi::Result<ObsSettings> {
+        self.0.get_settings().map(ObsSettings).map_napi_err()
+    }
+
+    /// Update the settings of this encoder.
+    ///
+    /// @param settings - the settings to apply
+    /// @param strict - whether to validate the settings first and throw
+    ///                 an error if they are invalid. Defaults to `false`.
+    #[napi]
+    pub fn update_settings(
+        &self,
+        settings: &ObsSettings,
+        strict: Option<bool>,
+    ) -> napi::Result<()> {
+        self.0
+            .update_settings(&settings.0, strict.unwrap_or(false))
+            .map_napi_err()
+    }
+
+    /// Validate settings against the properties of this encoder.
+    /// Returns an empty list if the settings are valid.
+    #[napi]
+    pub fn validate(&self, settings: &ObsSettings) -> napi::Result<Vec<SettingsIssue>> {
+        self.0.validate(&settings.0).map_napi_err()
+    }
+
+    /// Get the properties of this encoder.
+    #[napi]
+    pub fn get_properties(&self) -> napi::Result<ObsProperties> {
+        self.0.get_properties().map(ObsProperties).map_napi_err()
+    }
+}
+
+unsafe impl Send for ObsAudioEncoder {}
@@ -0,0 +1,65 @@
+use crate::node::napi_error::MapToNapiError;
+use crate::node::obs_properties::ObsProperties;
+use crate::node::obs_settings::ObsSettings;
+use crate::obs::data::obs_encoder_type::{
+    EncoderCaps, EncoderKind, ObsEncoderType as InnerObsEncoderType,
+};
+
+/// Information about a registered encoder type.
+/// No encoder is created to retrieve this information.
+///
+/// # Example
+/// ```ts
+/// const nvenc = await obs.getEncoderType('jim_nvenc');
+/// console.log(nvenc.codec, nvenc.caps.deprecated);
+///
+/// const defaults = nvenc.getDefaults();
+/// const properties = nvenc.getProperties();
+/// ```
+#[napi]
+pub struct ObsEncoderType(pub(crate) InnerObsEncoderType);
+
+#[napi]
+impl ObsEncoderType {
+    /// The id of the encoder type.
+    #[napi(getter)]
+    pub fn get_id(&self) -> String {
+        self.0.get_id().to_string()
+    }
+
+    /// The codec this encoder produces, e.g. `h264` or `aac`.
+    #[napi(getter)]
+    pub fn get_codec(&self) -> napi::Result<Option<String>> {
+        self.0.get_codec().map_napi_err()
+    }
+
+    /// Whether this is an audio or a video encoder.
+    #[napi(getter, js_name = "type")]
+    pub fn get_encoder_kind(&self) -> napi::Result<EncoderKind> {
+        self.0.get_encoder_kind().map_napi_err()
+    }
+
+    /// The capabilities of this encoder type.
+    #[napi(getter)]
+    pub fn get_caps(&self) -> napi::Result<EncoderCaps> {
+        self.0.get_caps().map_napi_err()
+    }
+
+    /// The localized display name of this encoder type.
+    #[napi(getter)]
+    pub fn get_display_name(&self) -> napi::Result<Option<String>> {
+        self.0.get_display_name().map_napi_err()
+    }
+
+    /// Get the default settings of this encoder type.
+    #[napi]
+    pub fn get_defaults(&self) -> napi::Result<ObsSettings> {
+        self.0.get_defaults().map(ObsSettings).map_napi_err()
+    }
+
+    /// Get the properties of this encoder type.
+    #[napi]
+    pub fn get_properties(&self) -> napi::Result<ObsProperties> {
+        self.0.get_properties().map(ObsProperties).map_napi_err()
+    }
+}
@@ -0,0 +1,375 @@
+use crate::node::encoded_packet::EncodedPacket;
+use crate::node::napi_error::{to_napi_error, to_napi_error_str, MapToNapiError};
+use crate::node::obs_encoder::{ObsAudioEncoder, ObsVideoEncoder};
+use crate::node::obs_properties::ObsProperties;
+use crate::node::obs_settings::ObsSettings;
+use crate::obs::custom::encoded_packet::Packet;
+use crate::obs::data::settings_validation::SettingsIssue;
+use crate::obs::io::obs_output::ObsOutput as InnerObsOutput;
+use crate::obs::objects::output_state::OutputState;
+use crate::obs::util::obs_error::ObsError;
+use crate::obs::util::types::ResultType;
+use futures::channel::oneshot;
+use futures::future::{self, BoxFuture, FutureExt};
+use napi::bindgen_prelude::Buffer;
+use napi::threadsafe_function::{
+    ErrorStrategy, ThreadSafeCallContext, ThreadsafeFunction, ThreadsafeFunctionCallMode,
+};
+use napi::{Env, JsFunction, JsObject};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// An obs output.
+#[napi]
+pub struct ObsOutput(pub(crate) InnerObsOutput);
+
+#[napi]
+impl ObsOutput {
+    /// Start the output.
+    /// This will start the output and start sending data to the output.
+    /// At least one encoder must be set before starting the output.
+    /// Throws an error if the output is not stopped.
+    #[napi]
+    pub fn start(
+        &self,
+        video_encoder: Option<&ObsVideoEncoder>,
+        audio_encoder: Option<&ObsAudioEncoder>,
+    ) -> napi::Result<()> {
+        self.0
+            .start(video_encoder.map(|e| &e.0), audio_encoder.map(|e| &e.0))
+            .map_napi_err()
+    }
+
+    /// Stop the output.
+    /// This will stop the output and stop sending data to the output.
+    /// The output is in the `Stopping` state until it has actually stopped.
+    /// Throws an error if the output is not running.
+    #[napi]
+    pub fn stop(&self) -> napi::Result<()> {
+        self.0.stop().map_napi_err()
+    }
+
+    /// Start the output and wait until it has actually started.
+    /// Rejects if the output stopped before it started, e.g. because
+    /// the connection to the server failed. The error contains the stop code
+    /// and the last error reported by the output.
+    ///
+    /// Timing out or aborting only rejects the promise, the output is not stopped.
+    ///
+    /// @param videoEncoder - the video encoder to use
+    /// @param audioEncoder - the audio encoder to use
+    /// @param options - `timeout` in milliseconds and a `signal` to abort waiting
+    #[napi(
+        ts_args_type = "videoEncoder?: ObsVideoEncoder | undefined | null, audioEncoder?: ObsAudioEncoder | undefined | null, options?: { timeout?: number, signal?: AbortSignal }",
+        ts_return_type = "Promise<void>"
+    )]
+    pub fn start_async(
+        &self,
+        env: Env,
+        video_encoder: Option<&ObsVideoEncoder>,
+        audio_encoder: Option<&ObsAudioEncoder>,
+        options: Option<JsObject>,
+    ) -> napi::Result<JsObject> {
+        let options = WaitOptions::from_js(&env, options)?;
+        let (sender, receiver) = oneshot::channel();
+        let res = self.0.start_with_callback(
+            video_encoder.map(|e| &e.0),
+            audio_encoder.map(|e| &e.0),
+            move |res| {
+                let _ = sender.send(res);
+            },
+        );
+
+        env.spawn_future(async move {
+            res.map_napi_err()?;
+            options
+                .wait(receiver, "Timed out waiting for the output to start")
+                .await
+        })
+    }
+
+    /// Stop the output and wait until it has actually stopped.
+    /// Outputs writing to a file finalize the file before they stop,
+    /// so the file is only complete once the returned promise resolves.
+    ///
+    /// Timing out or aborting only rejects the promise, the output still stops.
+    ///
+    /// @param options - `timeout` in milliseconds and a `signal` to abort waiting
+    #[napi(
+        ts_args_type = "options?: { timeout?: number, signal?: AbortSignal }",
+        ts_return_type = "Promise<void>"
+    )]
+    pub fn stop_async(&self, env: Env, options: Option<JsObject>) -> napi::Result<JsObject> {
+        let options = WaitOptions::from_js(&env, options)?;
+        let (sender, receiver) = oneshot::channel();
+        let res = self.0.stop_with_callback(move |res| {
+            let _ = sender.send(res);
+        });
+
+        env.spawn_future(async move {
+            res.map_napi_err()?;
+            options
+                .wait(receiver, "Timed out waiting for the output to stop")
+                .await
+        })
+    }
+
+    /// Force stop the output.
+    /// This will force stop the output and stop sending data to the output.
+    /// Throws an error if the output is not running.
+    #[napi]
+    pub fn force_stop(&self) -> napi::Result<()> {
+        self.0.force_stop().map_napi_err()
+    }
+
+    /// Get the output properties.
+    #[napi]
+    pub fn get_properties(&self) -> napi::Result<ObsProperties> {
+        self.0.get_properties().map(ObsProperties).map_napi_err()
+    }
+
+    /// Get if the output is paused.
+    #[napi(getter)]
+    pub fn get_paused(&self) -> bool {
+        self.0.get_paused()
+    }
+
+    /// Check if the output can be paused.
+    #[napi(getter)]
+    pub fn can_pause(&self) -> bool {
+        self.0.can_pause()
+    }
+
+    /// Check if the output is active.
+    #[napi(getter)]
+    pub fn get_active(&self) -> bool {
+        self.0.get_active()
+    }
+
+    /// The state of the output.
+    /// The state is kept in sync with libobs, so it also changes
+    /// if libobs stops the output by itself, e.g. on a disconnect.
+    #[napi(getter)]
+    pub fn get_state(&self) -> OutputState {
+        self.0.get_state()
+    }
+
+    /// Listen for state changes of this output.
+    /// The callback is called with the new state.
+    /// The listener does not keep the process alive.
+    #[napi(ts_args_type = "callback: (state: OutputState) => void")]
+    pub fn on_state_change(&self, env: Env, callback: JsFunction) -> napi::Result<()> {
+        let mut tsfn: ThreadsafeFunction<OutputState, ErrorStrategy::Fatal> = callback
+            .create_threadsafe_function(0, |ctx: ThreadSafeCallContext<OutputState>| {
+                Ok(vec![ctx.value])
+            })?;
+        tsfn.unref(&env)?;
+
+        self.0.on_state_change(move |state| {
+            tsfn.call(state, ThreadsafeFunctionCallMode::NonBlocking);
+        });
+
+        Ok(())
+    }
+
+    /// Get the output name.
+    #[napi(getter)]
+    pub fn get_name(&self) -> String {
+        self.0.get_name()
+    }
+
+    /// Pause the output.
+    /// Throws an error if the output is not running.
+    #[napi]
+    pub fn pause(&self) -> napi::Result<()> {
+        self.0.pause().map_napi_err()
+    }
+
+    /// Resume the output.
+    /// Throws an error if the output is not paused.
+    #[napi]
+    pub fn resume(&self) -> napi::Result<()> {
+        self.0.resume().map_napi_err()
+    }
+
+    /// Set the output settings.
+    #[napi(setter)]
+    pub fn set_settings(&self, settings: &ObsSettings) {
+        self.0.set_settings(&settings.0)
+    }
+
+    /// Update the output settings.
+    ///
+    /// @param settings - the settings to apply
+    /// @param strict - whether to validate the settings first and throw
+    ///                 an error if they are invalid. Defaults to `false`.
+    #[napi]
+    pub fn update_settings(
+        &self,
+        settings: &ObsSettings,
+        strict: Option<bool>,
+    ) -> napi::Result<()> {
+        self.0
+            .update_settings(&settings.0, strict.unwrap_or(false))
+            .map_napi_err()
+    }
+
+    /// Validate settings against the properties of this output.
+    /// Returns an empty list if the settings are valid.
+    #[napi]
+    pub fn validate(&self, settings: &ObsSettings) -> napi::Result<Vec<SettingsIssue>> {
+        self.0.validate(&settings.0).map_napi_err()
+    }
+
+    /// Receive the encoded packets of this output.
+    /// The output must be of a type registered using `Obs.registerOutputType`.
+    /// Replaces any previously set packet listener.
+    /// The listener does not keep the process alive.
+    #[napi(ts_args_type = "callback: (packet: EncodedPacket) => void")]
+    pub fn on_packet(&self, env: Env, callback: JsFunction) -> napi::Result<()> {
+        let mut tsfn: ThreadsafeFunction<Packet, ErrorStrategy::Fatal> = callback
+            .create_threadsafe_function(0, |ctx: ThreadSafeCallContext<Packet>| {
+                Ok(vec![EncodedPacket::from(ctx.value)])
+            })?;
+        tsfn.unref(&env)?;
+
+        self.0
+            .on_packet(move |packet| {
+                tsfn.call(packet, ThreadsafeFunctionCallMode::NonBlocking);
+            })
+            .map_napi_err()
+    }
+
+    /// Receive the muxed MPEG-TS data of this output.
+    /// The output must be of a type registered using `Obs.registerMpegTsOutputType`.
+    /// The callback is called with `null` once the output has stopped.
+    /// Replaces any previously set data listener.
+    /// The listener does not keep the process alive.
+    #[napi(ts_args_type = "callback: (chunk: Buffer | null) => void")]
+    pub fn on_data(&self, env: Env, callback: JsFunction) -> napi::Result<()> {
+        let mut tsfn: ThreadsafeFunction<Option<Vec<u8>>, ErrorStrategy::Fatal> = callback
+            .create_threadsafe_function(0, |ctx: ThreadSafeCallContext<Option<Vec<u8>>>| {
+                Ok(vec![ctx.value.map(Buffer::from)])
+            })?;
+        tsfn.unref(&env)?;
+
+        self.0
+            .on_data(move |chunk| {
+                tsfn.call(chunk, ThreadsafeFunctionCallMode::NonBlocking);
+            })
+            .map_napi_err()
+    }
+
+    /// Get the output settings.
+    #[napi(getter)]
+    pub fn get_settings(&self) -> napi::Result<ObsSettings> {
+        self.0.get_settings().map(ObsSettings).map_napi_err()
+    }
+}
+
+unsafe impl Send for ObsOutput {}
+
+/// The options for waiting for an output to start or stop.
+pub(crate) struct WaitOptions {
+    timeout: Option<Duration>,
+    aborted: Option<oneshot::Receiver<()>>,
+}
+
+impl WaitOptions {
+    pub(crate) fn from_js(env: &Env, options: Option<JsObject>) -> napi::Result<Self> {
+        let options = match options {
+            Some(options) => options,
+            None => {
+                return Ok(Self {
+                    timeout: None,
+                    aborted: None,
+                })
+            }
+        };
+
+        let timeout = options
+            .get::<_, u32>("timeout")?
+            .map(|timeout| Duration::from_millis(timeout as _));
+        let aborted = match options.get::<_, JsObject>("signal")? {
+            Some(signal) => Some(on_abort(env, &signal)?),
+            None => None,
+        };
+
+        Ok(Self { timeout, aborted })
+    }
+
+    /// Wait for `result`, the timeout or the abort signal, whichever comes first.
+    pub(crate) async fn wait(
+        self,
+        result: oneshot::Receiver<ResultType<()>>,
+        timeout_message: &'static str,
+    ) -> napi::Result<()> {
+        let mut futures: Vec<BoxFuture<'static, napi::Result<()>>> = vec![async move {
+            match result.await {
+                Ok(res) => res.map_napi_err(),
+                Err(_) => Err(to_napi_error_str("The output was destroyed")),
+            }
+        }
+        .boxed()];
+
+        if let Some(timeout) = self.timeout {
+            futures.push(
+                async move {
+                    tokio::time::sleep(timeout).await;
+                    Err(to_napi_error(ObsError::Timeout(timeout_message)))
+                }
+                .boxed(),
+            );
+        }
+
+        if let Some(aborted) = self.aborted {
+            futures.push(
+                async move {
+                    // The sender is dropped without sending if the signal is collected
+                    if aborted.await.is_err() {
+                        future::pending::<()>().await;
+                    }
+
+                    Err(to_napi_error_str("The operation was aborted"))
+                }
+                .boxed(),
+            );
+        }
+
+        future::select_all(futures).await.0
+    }
+}
+
+/// Listen for the `abort` event of an `AbortSignal`.
+/// The returned receiver completes immediately if the signal has already been aborted.
+fn on_abort(env: &Env, signal: &JsObject) -> napi::Result<oneshot::Receiver<()>> {
+    let (sender, receiver) = oneshot::channel();
+    if signal.get::<_, bool>("aborted")?.unwrap_or(false) {
+        let _ = sender.send(());
+        return Ok(receiver);
+    }
+
+    let sender = Mutex::new(Some(sender));
+    let listener = env.create_function_from_closure("onAbort", move |_| {
+        if let Some(sender) = sender.lock().unwrap().take() {
+            let _ = sender.send(());
+        }
+
+        Ok(())
+    })?;
+
+    let mut listener_options = env.create_object()?;
+    listener_options.set("once", true)?;
+
+    let add_event_listener: JsFunction = signal.get_named_property("addEventListener")?;
+    add_event_listener.call(
+        Some(signal),
+        &[
+            env.create_string("abort")?.into_unknown(),
+            listener.into_unknown(),
+            listener_options.into_unknown(),
+        ],
+    )?;
+
+    Ok(receiver)
+}
@@ -0,0 +1,62 @@
+use crate::node::napi_error::MapToNapiError;
+use crate::node::obs_properties::ObsProperties;
+use crate::node::obs_settings::ObsSettings;
+use crate::obs::data::obs_output_type::{ObsOutputType as InnerObsOutputType, OutputFlags};
+
+/// Information about a registered output type.
+/// No output is created to retrieve this information.
+///
+/// # Example
+/// ```ts
+/// const flv = await obs.getOutputType('flv_output');
+/// console.log(flv.flags.encoded, flv.supportedVideoCodecs);
+/// ```
+#[napi]
+pub struct ObsOutputType(pub(crate) InnerObsOutputType);
+
+#[napi]
+impl ObsOutputType {
+    /// The id of the output type.
+    #[napi(getter)]
+    pub fn get_id(&self) -> String {
+        self.0.get_id().to_string()
+    }
+
+    /// The flags of this output type.
+    #[napi(getter)]
+    pub fn get_flags(&self) -> napi::Result<OutputFlags> {
+        self.0.get_flags().map_napi_err()
+    }
+
+    /// The video codecs supported by this output type.
+    /// Empty if the output type does not restrict the video codecs.
+    #[napi(getter)]
+    pub fn get_supported_video_codecs(&self) -> napi::Result<Vec<String>> {
+        self.0.get_supported_video_codecs().map_napi_err()
+    }
+
+    /// The audio codecs supported by this output type.
+    /// Empty if the output type does not restrict the audio codecs.
+    #[napi(getter)]
+    pub fn get_supported_audio_codecs(&self) -> napi::Result<Vec<String>> {
+        self.0.get_supported_audio_codecs().map_napi_err()
+    }
+
+    /// The localized display name of this output type.
+    #[napi(getter)]
+    pub fn get_display_name(&self) -> napi::Result<Option<String>> {
+        self.0.get_display_name().map_napi_err()
+    }
+
+    /// Get the default settings of this output type.
+    #[napi]
+    pub fn get_defaults(&self) -> napi::Result<ObsSettings> {
+        self.0.get_defaults().map(ObsSettings).map_napi_err()
+    }
+
+    /// Get the properties of this output type.
+    #[napi]
+    pub fn get_properties(&self) -> napi::Result<ObsProperties> {
+        self.0.get_properties().map(ObsProperties).map_napi_err()
+    }
+}
@@ -0,0 +1,63 @@
+use crate::node::napi_error::MapToNapiError;
+use crate::node::obs_settings::ObsSettings;
+use crate::obs::data::obs_properties::ObsProperties as InnerObsProperties;
+use crate::obs::data::obs_property::ObsProperty;
+use crate::obs::data::settings_validation::SettingsIssue;
+
+/// An obs properties object.
+#[napi]
+pub struct ObsProperties(pub(crate) InnerObsProperties);
+
+#[napi]
+impl ObsProperties {
+    /// Get a property by its name.
+    #[napi]
+    pub fn get_property(&self, name: String) -> Option<ObsProperty> {
+        self.0.get_property(&name)
+    }
+
+    /// Get a list of all properties stored in this object.
+    #[napi]
+    pub fn list_properties(&self) -> Vec<ObsProperty> {
+        self.0.list_properties()
+    }
+
+    /// Generate a JSON schema describing the settings these properties edit.
+    ///
+    /// @param title - the title of the schema
+    /// @param defaults - the default settings to include in the schema
+    #[napi]
+    pub fn to_json_schema(
+        &self,
+        title: Option<String>,
+        defaults: Option<&ObsSettings>,
+    ) -> napi::Result<String> {
+        self.0
+            .to_json_schema(title.as_deref(), defaults.map(|d| &d.0))
+            .map_napi_err()
+    }
+
+    /// Generate a TypeScript interface describing the settings these properties edit.
+    ///
+    /// @param name - the name of the interface
+    /// @param defaults - the default settings to document in the interface
+    #[napi]
+    pub fn to_type_definition(
+        &self,
+        name: String,
+        defaults: Option<&ObsSettings>,
+    ) -> napi::Result<String> {
+        self.0
+            .to_type_definition(&name, defaults.map(|d| &d.0))
+            .map_napi_err()
+    }
+
+    /// Validate settings against these properties.
+    /// Checks for unknown keys, type mismatches, numbers out of range
+    /// and values which are not part of a list property.
+    /// Returns an empty list if the settings are valid.
+    #[napi]
+    pub fn validate(&self, settings: &ObsSettings) -> napi::Result<Vec<SettingsIssue>> {
+        self.0.validate(&settings.0).map_napi_err()
+    }
+}
@@ -0,0 +1,129 @@
+use crate::node::napi_error::{to_napi_error_str, MapToNapiError};
+use crate::node::node_util::is_integer;
+use crate::obs::data::obs_settings::ObsSettings as InnerObsSettings;
+use napi::{Env, JsObject, JsString, JsUnknown, ValueType};
+
+/// A wrapper around obs settings.
+///
+/// # Examples
+/// ```ts
+/// const settings = new ObsSettings({
+///     rate_control: 'CQP',
+///     cqp: 23,
+///     preset: 'medium',
+///     profile: 'high',
+/// });
+/// ```
+/// or
+/// ```ts
+/// const settings = new ObsSettings()
+///   .setString('rate_control', 'CQP')
+///   .setInt('cqp', 23)
+///   .setString('preset', 'medium')
+///   .setString('profile', 'high');
+/// ```
+#[napi]
+pub struct ObsSettings(pub(crate) InnerObsSettings);
+
+#[napi]
+impl ObsSettings {
+    /// Create a new settings object.
+    /// You can either define the settings in the constructor or use the `set_*` methods.
+    ///
+    /// @param data - The settings to use.
+    #[napi(constructor)]
+    pub fn new(
+        env: Env,
+        #[napi(ts_arg_type = "Record<string, string | number | boolean> | null")] data: Option<
+            JsObject,
+        >,
+    ) -> napi::Result<Self> {
+        let mut res = Self(InnerObsSettings::new().map_napi_err()?);
+        if let Some(data) = data {
+            let keys = data.get_property_names()?;
+            for i in 0..keys.get_array_length()? {
+                let key: JsString = keys.get_element(i)?;
+                let key = key.into_utf16()?.as_str()?;
+
+                let value: JsUnknown = data.get_named_property(&key)?;
+                match value.get_type()? {
+                    ValueType::String => {
+                        let value: JsString = value.coerce_to_string()?;
+                        let value = value.into_utf16()?.as_str()?;
+                        res.set_string(key, value)?;
+                    }
+                    ValueType::Number => {
+                        let value = value.coerce_to_number()?;
+
+                        if is_integer(&env, &value)? {
+                            res.set_int(key, value.try_into()?)?;
+                        } else {
+                            res.set_double(key, value.try_into()?)?;
+                        }
+                    }
+                    ValueType::Boolean => {
+                        let value = value.coerce_to_bool()?;
+                        res.set_bool(key, value.get_value()?)?;
+                    }
+                    _ => {
+                        return Err(to_napi_error_str("Unsupported value type"));
+                    }
+                }
+            }
+        }
+
+        Ok(res)
+    }
+
+    /// Set a string value.
+    #[napi]
+    pub fn set_string(&mut self, name: String, value: String) -> napi::Result<&Self> {
+        self.0.set_string(&name, &value).map_napi_err()?;
+        Ok(self)
+    }
+
+    /// Get a string value.
+    #[napi]
+    pub fn get_string(&self, name: String) -> napi::Result<String> {
+        self.0.get_string(&name).map_napi_err()
+    }
+
+    /// Set an integer value.
+    #[napi]
+    pub fn set_int(&mut self, name: String, value: i64) -> napi::Result<&Self> {
+        self.0.set_int(&name, value).map_napi_err()?;
+        Ok(self)
+    }
+
+    /// Get an integer value.
+    #[napi]
+    pub fn get_int(&self, name: String) -> napi::Result<i64> {
+        self.0.get_int(&name).map_napi_err()
+    }
+
+    /// Set a double value.
+    #[napi]
+    pub fn set_double(&mut self, name: String, value: f64) -> napi::Result<&Self> {
+        self.0.set_double(&name, value).map_napi_err()?;
+        Ok(self)
+    }
+
+    /// Get a double value.
+    #[napi]
+    pub fn get_double(&self, name: String) -> napi::Result<f64> {
+        self.0.get_double(&name).map_napi_err()
+    }
+
+    /// Set a boolean value.
+    #[napi]
+    pub fn set_bool(&mut self, name: String, value: bool) -> napi::Result<&Self> {
+        self.0.set_bool(&name, value).map_napi_err()?;
+        Ok(self)
+    }
+
+    /// Get a boolean value.
+    #[napi]
+    pub fn get_bool(&self, name: String) -> napi::Result<bool> {
+        self.0.get_bool(&name).map_napi_err()
+    }
+}
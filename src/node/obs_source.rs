@@ -0,0 +1,218 @@
+use crate::node::napi_error::MapToNapiError;
+use crate::node::obs_properties::ObsProperties;
+use crate::node::obs_settings::ObsSettings;
+use crate::obs::custom::audio_data::AudioDataInfo;
+use crate::obs::custom::video_frame::VideoFrameInfo;
+use crate::obs::data::settings_validation::SettingsIssue;
+use crate::obs::io::obs_source::ObsSource as InnerObsSource;
+use crate::obs::objects::media_state::{MediaEvent, MediaState};
+use napi::bindgen_prelude::Buffer;
+use napi::threadsafe_function::{
+    ErrorStrategy, ThreadSafeCallContext, ThreadsafeFunction, ThreadsafeFunctionCallMode,
+};
+use napi::{Env, JsFunction};
+
+/// An obs source.
+///
+/// # Example
+/// ```ts
+/// const source = await obs.createSource('My Source', 'monitor_capture', new ObsSettings({
+///    monitor: 1,
+///    capture_cursor: false,
+///    method: 2,
+/// }));
+/// ```
+///
+/// Media sources like `ffmpeg_source` or `vlc_source` can be controlled:
+/// ```ts
+/// const media = await obs.createSource('clip', 'ffmpeg_source', 0, new ObsSettings({
+///    local_file: '/path/to/clip.mp4',
+/// }));
+///
+/// const listener = media.onMediaEvent(event => {
+///    if (event === MediaEvent.Ended) {
+///        console.log('Clip ended');
+///    }
+/// });
+///
+/// media.play();
+/// media.seek(5000);
+/// media.removeMediaListener(listener);
+/// ```
+#[napi]
+pub struct ObsSource(pub(crate) InnerObsSource);
+
+#[napi]
+impl ObsSource {
+    /// Get the default settings for this source.
+    #[napi]
+    pub fn get_default_settings(&self) -> napi::Result<ObsSettings> {
+        self.0
+            .get_default_settings()
+            .map(ObsSettings)
+            .map_napi_err()
+    }
+
+    /// Get the properties for this source.
+    #[napi]
+    pub fn get_properties(&self) -> napi::Result<ObsProperties> {
+        self.0.get_properties().map(ObsProperties).map_napi_err()
+    }
+
+    /// Update the settings of the source.
+    ///
+    /// @param settings - the settings to apply
+    /// @param strict - whether to validate the settings first and throw
+    ///                 an error if they are invalid. Defaults to `false`.
+    #[napi]
+    pub fn update_settings(
+        &self,
+        settings: &ObsSettings,
+        strict: Option<bool>,
+    ) -> napi::Result<()> {
+        self.0
+            .update_settings(&settings.0, strict.unwrap_or(false))
+            .map_napi_err()
+    }
+
+    /// Validate settings against the properties of this source.
+    /// Returns an empty list if the settings are valid.
+    #[napi]
+    pub fn validate(&self, settings: &ObsSettings) -> napi::Result<Vec<SettingsIssue>> {
+        self.0.validate(&settings.0).map_napi_err()
+    }
+
+    /// Get the settings of the source.
+    #[napi(getter)]
+    pub fn get_settings(&self) -> napi::Result<ObsSettings> {
+        self.0.get_settings().map(ObsSettings).map_napi_err()
+    }
+}
+
+#[napi]
+impl ObsSource {
+    /// Play the media of this source.
+    /// Throws an error if this is not a media source.
+    #[napi]
+    pub fn play(&self) -> napi::Result<()> {
+        self.0.play().map_napi_err()
+    }
+
+    /// Pause the media of this source.
+    /// Throws an error if this is not a media source.
+    #[napi]
+    pub fn pause(&self) -> napi::Result<()> {
+        self.0.pause().map_napi_err()
+    }
+
+    /// Stop the media of this source.
+    /// Throws an error if this is not a media source.
+    #[napi]
+    pub fn stop(&self) -> napi::Result<()> {
+        self.0.stop().map_napi_err()
+    }
+
+    /// Restart the media of this source.
+    /// Throws an error if this is not a media source.
+    #[napi]
+    pub fn restart(&self) -> napi::Result<()> {
+        self.0.restart().map_napi_err()
+    }
+
+    /// Seek to a position in the media of this source.
+    /// Throws an error if this is not a media source.
+    ///
+    /// @param ms - the position to seek to in milliseconds
+    #[napi]
+    pub fn seek(&self, ms: i64) -> napi::Result<()> {
+        self.0.seek(ms).map_napi_err()
+    }
+
+    /// Skip to the next item of the media of this source.
+    /// Throws an error if this is not a media source.
+    #[napi]
+    pub fn next(&self) -> napi::Result<()> {
+        self.0.next().map_napi_err()
+    }
+
+    /// Skip to the previous item of the media of this source.
+    /// Throws an error if this is not a media source.
+    #[napi]
+    pub fn previous(&self) -> napi::Result<()> {
+        self.0.previous().map_napi_err()
+    }
+
+    /// The current position of the media in milliseconds.
+    #[napi(getter)]
+    pub fn get_time(&self) -> napi::Result<i64> {
+        self.0.get_time().map_napi_err()
+    }
+
+    /// The duration of the media in milliseconds.
+    #[napi(getter)]
+    pub fn get_duration(&self) -> napi::Result<i64> {
+        self.0.get_duration().map_napi_err()
+    }
+
+    /// The playback state of the media.
+    #[napi(getter)]
+    pub fn get_state(&self) -> napi::Result<MediaState> {
+        self.0.get_state().map_napi_err()
+    }
+
+    /// Listen for media events of this source,
+    /// e.g. when the media started, ended or was paused.
+    /// The listener does not keep the process alive.
+    /// Returns the id of the listener, which can be passed
+    /// to `removeMediaListener` to stop listening.
+    #[napi(ts_args_type = "callback: (event: MediaEvent) => void")]
+    pub fn on_media_event(&self, env: Env, callback: JsFunction) -> napi::Result<u32> {
+        let mut tsfn: ThreadsafeFunction<MediaEvent, ErrorStrategy::Fatal> = callback
+            .create_threadsafe_function(0, |ctx: ThreadSafeCallContext<MediaEvent>| {
+                Ok(vec![ctx.value])
+            })?;
+        tsfn.unref(&env)?;
+
+        self.0
+            .on_media_event(move |event| {
+                tsfn.call(event, ThreadsafeFunctionCallMode::NonBlocking);
+            })
+            .map_napi_err()
+    }
+
+    /// Remove a listener added using `onMediaEvent`.
+    /// Returns `false` if no listener with the given id exists.
+    #[napi]
+    pub fn remove_media_listener(&self, id: u32) -> bool {
+        self.0.remove_media_listener(id)
+    }
+}
+
+#[napi]
+impl ObsSource {
+    /// Push a raw video frame to this source.
+    /// The source must be of a type registered using `Obs.registerVideoSourceType`
+    /// or any other async video source type.
+    /// The frame data must be tightly packed, planes following each other.
+    ///
+    /// @param buffer - the frame data
+    /// @param info - the format, size and timestamp of the frame
+    #[napi]
+    pub fn push_video_frame(&self, buffer: Buffer, info: VideoFrameInfo) -> napi::Result<()> {
+        self.0.push_video_frame(&buffer, &info).map_napi_err()
+    }
+
+    /// Push raw audio data to this source.
+    /// The source must be of a type registered using `Obs.registerAudioSourceType`
+    /// or any other audio source type.
+    /// Planar data must contain the planes of all channels following each other.
+    ///
+    /// @param buffer - the audio data
+    /// @param info - the format, speaker layout, sample rate and timestamp of the data
+    #[napi]
+    pub fn push_audio(&self, buffer: Buffer, info: AudioDataInfo) -> napi::Result<()> {
+        self.0.push_audio(&buffer, &info).map_napi_err()
+    }
+}
+
+unsafe impl Send for ObsSource {}
@@ -0,0 +1,127 @@
+use crate::node::napi_error::{to_napi_error_str, MapToNapiError};
+use crate::obs::obs::Obs as InnerObs;
+use crate::obs::util::obs_error::ObsError;
+use crate::obs::util::types::ResultType;
+use futures::channel::oneshot;
+use futures::executor::block_on;
+use std::future::Future;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc;
+use std::thread::{self, JoinHandle};
+
+type Job = Box<dyn FnOnce(&mut InnerObs) + Send>;
+
+/// Runs all calls to the obs instance on a dedicated thread,
+/// so they don't block the node.js event loop.
+/// The obs instance is created on and owned by the worker thread.
+pub struct ObsWorker {
+    sender: Option<mpsc::Sender<Job>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl ObsWorker {
+    /// Start the worker thread and create the obs instance on it.
+    /// Resolves once the instance has been created.
+    pub fn start<F>(create: F) -> impl Future<Output = napi::Result<Self>>
+    where
+        F: FnOnce() -> ResultType<InnerObs> + Send + 'static,
+    {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let (ready_sender, ready) = oneshot::channel();
+
+        let thread = thread::Builder::new()
+            .name("obs".to_string())
+            .spawn(move || {
+                let mut obs = match catch_panic(create) {
+                    Ok(obs) => {
+                        let _ = ready_sender.send(Ok(()));
+                        obs
+                    }
+                    Err(e) => {
+                        let _ = ready_sender.send(Err(e));
+                        return;
+                    }
+                };
+
+                for job in receiver {
+                    job(&mut obs);
+                }
+            });
+
+        async move {
+            let thread = thread.map_err(|e| to_napi_error_str(&e.to_string()))?;
+            let worker = Self {
+                sender: Some(sender),
+                thread: Some(thread),
+            };
+
+            match ready.await {
+                Ok(res) => res.map(|_| worker).map_napi_err(),
+                Err(_) => Err(to_napi_error_str("The obs thread has stopped")),
+            }
+        }
+    }
+
+    /// Start the worker thread and create the obs instance on it,
+    /// blocking until the instance has been created.
+    pub fn start_sync<F>(create: F) -> napi::Result<Self>
+    where
+        F: FnOnce() -> ResultType<InnerObs> + Send + 'static,
+    {
+        block_on(Self::start(create))
+    }
+
+    /// Run a function on the worker thread.
+    /// The returned future does not borrow the worker.
+    pub fn run<T, F>(&self, f: F) -> impl Future<Output = napi::Result<T>>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut InnerObs) -> ResultType<T> + Send + 'static,
+    {
+        let (result_sender, result) = oneshot::channel();
+        let sent = match &self.sender {
+            Some(sender) => sender
+                .send(Box::new(move |obs: &mut InnerObs| {
+                    let _ = result_sender.send(catch_panic(|| f(obs)));
+                }))
+                .is_ok(),
+            None => false,
+        };
+
+        async move {
+            if !sent {
+                return Err(to_napi_error_str("The obs thread has stopped"));
+            }
+
+            match result.await {
+                Ok(res) => res.map_napi_err(),
+                Err(_) => Err(to_napi_error_str("The obs thread has stopped")),
+            }
+        }
+    }
+
+    /// Run a function on the worker thread, blocking until it has finished.
+    pub fn run_sync<T, F>(&self, f: F) -> napi::Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut InnerObs) -> ResultType<T> + Send + 'static,
+    {
+        block_on(self.run(f))
+    }
+}
+
+impl Drop for ObsWorker {
+    fn drop(&mut self) {
+        // Closing the channel stops the thread once all queued calls have finished
+        self.sender.take();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Convert a panic into an error, so a panicking call doesn't stop the worker thread.
+fn catch_panic<T, F: FnOnce() -> ResultType<T>>(f: F) -> ResultType<T> {
+    panic::catch_unwind(AssertUnwindSafe(f))
+        .unwrap_or_else(|_| Err(ObsError::Failed("The obs call panicked".to_string())))
+}
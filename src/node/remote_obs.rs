@@ -0,0 +1,808 @@
+use crate::node::napi_error::{to_napi_error_str, MapToNapiError};
+use crate::node::obs_output::WaitOptions;
+use crate::obs::data::obs_module::ObsModule;
+use crate::obs::data::settings_validation::SettingsIssue;
+use crate::obs::helper::remote::{
+    RemoteEncoder as InnerRemoteEncoder, RemoteEvent, RemoteObs as InnerRemoteObs,
+    RemoteObsOptions, RemoteOutput as InnerRemoteOutput, RemoteSource as InnerRemoteSource,
+};
+use crate::obs::objects::media_state::{MediaEvent, MediaState};
+use crate::obs::objects::module_load_options::ModuleLoadOptions;
+use crate::obs::objects::module_load_result::ModuleLoadResult;
+use crate::obs::objects::obs_version::ObsVersion;
+use crate::obs::objects::output_state::OutputState;
+use crate::obs::objects::reconfigure_options::{ReconfigureData, ReconfigureOptions};
+use crate::obs::objects::reset_audio_data::ResetAudioData;
+use crate::obs::objects::reset_video_data::ResetVideoData;
+use crate::obs::objects::type_schema::TypeSchema;
+use crate::obs::util::types::ResultType;
+use futures::channel::oneshot;
+use napi::threadsafe_function::{
+    ErrorStrategy, ThreadSafeCallContext, ThreadsafeFunction, ThreadsafeFunctionCallMode,
+};
+use napi::{Env, JsFunction, JsObject};
+use serde_json::Value;
+
+/// Run a blocking call to the helper process on the blocking thread pool.
+async fn blocking<T, F>(f: F) -> napi::Result<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> ResultType<T> + Send + 'static,
+{
+    tokio::task::spawn_blocking(f)
+        .await
+        .map_err(|e| to_napi_error_str(&e.to_string()))?
+        .map_napi_err()
+}
+
+/// An obs instance running in a helper process.
+/// A crash of libobs or a plugin only terminates the helper process,
+/// which is restarted and set up again unless disabled in the options.
+/// Requires the `obs-helper` binary, see `RemoteObsOptions.helperPath`.
+///
+/// Settings are passed as plain objects, so libobs is never loaded
+/// into the node.js process.
+///
+/// Only a subset of the methods of `Obs` and its objects is available,
+/// type details, custom types and pushing frames are missing.
+/// Properties are only available as settings schemas.
+/// See the README for the full list.
+///
+/// # Example
+/// ```ts
+/// const obs = await RemoteObs.newInstance({ restart: true });
+/// obs.onEvent(event => {
+///    if (event.kind === RemoteEventKind.Restarted) {
+///        output.start(videoEncoder, audioEncoder);
+///    }
+/// });
+///
+/// await obs.loadModules(await obs.getAllModules('/path/to/your/obs/installation'));
+/// await obs.resetAudio(audioData);
+/// await obs.resetVideo(videoData);
+///
+/// const videoEncoder = await obs.createVideoEncoder('x264', 'obs_x264');
+/// const audioEncoder = await obs.createAudioEncoder('aac', 'ffmpeg_aac');
+/// await obs.createSource('screen_capture', 'monitor_capture', 0, { monitor: 0 });
+///
+/// const output = await obs.createOutput('output', 'flv_output', {
+///    path: '/path/to/your/output.flv',
+/// });
+/// output.start(videoEncoder, audioEncoder);
+/// ```
+#[napi]
+pub struct RemoteObs(InnerRemoteObs);
+
+#[napi]
+impl RemoteObs {
+    /// Start the helper process and obs in it.
+    ///
+    /// @param options - the locale, the paths of libobs and the helper,
+    ///                  the startup options and whether to restart
+    ///                  the helper after a crash
+    #[napi(constructor)]
+    pub fn new(options: Option<RemoteObsOptions>) -> napi::Result<Self> {
+        InnerRemoteObs::new(options.unwrap_or_default())
+            .map(Self)
+            .map_napi_err()
+    }
+
+    /// Start the helper process and obs in it.
+    /// Async version.
+    #[napi(js_name = "newInstance", ts_return_type = "Promise<RemoteObs>")]
+    pub fn new_remote_instance(
+        env: Env,
+        options: Option<RemoteObsOptions>,
+    ) -> napi::Result<JsObject> {
+        let options = options.unwrap_or_default();
+        env.spawn_future(async move {
+            blocking(move || InnerRemoteObs::new(options))
+                .await
+                .map(Self)
+        })
+    }
+
+    /// Whether the helper process is running.
+    #[napi(getter)]
+    pub fn get_running(&self) -> bool {
+        self.0.is_running()
+    }
+
+    /// Listen for events of the helper process,
+    /// e.g. crashes and restarts.
+    /// The listener does not keep the process alive.
+    #[napi(ts_args_type = "callback: (event: RemoteEvent) => void")]
+    pub fn on_event(&self, env: Env, callback: JsFunction) -> napi::Result<()> {
+        let mut tsfn: ThreadsafeFunction<RemoteEvent, ErrorStrategy::Fatal> = callback
+            .create_threadsafe_function(0, |ctx: ThreadSafeCallContext<RemoteEvent>| {
+                Ok(vec![ctx.value])
+            })?;
+        tsfn.unref(&env)?;
+
+        self.0.on_event(move |event| {
+            tsfn.call(event.clone(), ThreadsafeFunctionCallMode::NonBlocking);
+        });
+
+        Ok(())
+    }
+
+    /// The current locale of obs in the helper process.
+    #[napi(getter)]
+    pub fn get_locale(&self) -> napi::Result<String> {
+        self.0.get_locale().map_napi_err()
+    }
+
+    /// Change the locale of obs and all loaded modules.
+    /// The locale is kept if the helper process is restarted.
+    #[napi]
+    pub fn set_locale_sync(&self, locale: String) -> napi::Result<()> {
+        self.0.set_locale(&locale).map_napi_err()
+    }
+
+    /// Change the locale of obs and all loaded modules.
+    /// Async version of `setLocaleSync`.
+    #[napi(ts_return_type = "Promise<void>")]
+    pub fn set_locale(&self, env: Env, locale: String) -> napi::Result<JsObject> {
+        let obs = self.0.clone();
+        env.spawn_future(blocking(move || obs.set_locale(&locale)))
+    }
+
+    /// Take a snapshot of the data collected by the profiler in the helper process.
+    /// Throws an error if obs was not started with the profiler enabled.
+    #[napi(ts_return_type = "Promise<Array<ProfilerEntry>>")]
+    pub fn get_profiler_snapshot(&self, env: Env) -> napi::Result<JsObject> {
+        let obs = self.0.clone();
+        env.spawn_future(blocking(move || obs.get_profiler_snapshot()))
+    }
+
+    /// Write a snapshot of the data collected by the profiler in the helper process
+    /// to a CSV file, which is compressed using gzip if the path ends with `.gz`.
+    #[napi(ts_return_type = "Promise<void>")]
+    pub fn dump_profiler_snapshot(&self, env: Env, path: String) -> napi::Result<JsObject> {
+        let obs = self.0.clone();
+        env.spawn_future(blocking(move || obs.dump_profiler_snapshot(&path)))
+    }
+
+    /// Get all modules which may be loaded.
+    #[napi]
+    pub fn get_all_modules_sync(&self, obs_path: Option<String>) -> napi::Result<Vec<ObsModule>> {
+        self.0.get_all_modules(obs_path.as_deref()).map_napi_err()
+    }
+
+    /// Get all modules which may be loaded.
+    /// Async version of `getAllModulesSync`.
+    #[napi(ts_return_type = "Promise<Array<ObsModule>>")]
+    pub fn get_all_modules(&self, env: Env, obs_path: Option<String>) -> napi::Result<JsObject> {
+        let obs = self.0.clone();
+        env.spawn_future(blocking(move || obs.get_all_modules(obs_path.as_deref())))
+    }
+
+    /// Load modules in the helper process.
+    /// Modules are loaded again if the helper process is restarted.
+    #[napi]
+    pub fn load_modules_sync(
+        &self,
+        modules: Vec<ObsModule>,
+        options: Option<ModuleLoadOptions>,
+    ) -> napi::Result<Vec<ModuleLoadResult>> {
+        self.0
+            .load_modules(modules, &options.unwrap_or_default())
+            .map_napi_err()
+    }
+
+    /// Load modules in the helper process.
+    /// Async version of `loadModulesSync`.
+    #[napi(ts_return_type = "Promise<Array<ModuleLoadResult>>")]
+    pub fn load_modules(
+        &self,
+        env: Env,
+        modules: Vec<ObsModule>,
+        options: Option<ModuleLoadOptions>,
+    ) -> napi::Result<JsObject> {
+        let obs = self.0.clone();
+        let options = options.unwrap_or_default();
+        env.spawn_future(blocking(move || obs.load_modules(modules, &options)))
+    }
+
+    /// Reset the video data.
+    #[napi]
+    pub fn reset_video_sync(&self, data: ResetVideoData) -> napi::Result<()> {
+        self.0.reset_video(&data).map_napi_err()
+    }
+
+    /// Reset the video data.
+    /// Async version of `resetVideoSync`.
+    #[napi(ts_return_type = "Promise<void>")]
+    pub fn reset_video(&self, env: Env, data: ResetVideoData) -> napi::Result<JsObject> {
+        let obs = self.0.clone();
+        env.spawn_future(blocking(move || obs.reset_video(&data)))
+    }
+
+    /// Reset the audio data.
+    #[napi]
+    pub fn reset_audio_sync(&self, data: ResetAudioData) -> napi::Result<()> {
+        self.0.reset_audio(&data).map_napi_err()
+    }
+
+    /// Reset the audio data.
+    /// Async version of `resetAudioSync`.
+    #[napi(ts_return_type = "Promise<void>")]
+    pub fn reset_audio(&self, env: Env, data: ResetAudioData) -> napi::Result<JsObject> {
+        let obs = self.0.clone();
+        env.spawn_future(blocking(move || obs.reset_audio(&data)))
+    }
+
+    /// Reset the video and/or audio in the helper process while outputs may be running.
+    #[napi]
+    pub fn reconfigure_sync(
+        &self,
+        data: ReconfigureData,
+        options: Option<ReconfigureOptions>,
+    ) -> napi::Result<()> {
+        self.0
+            .reconfigure(&data, &options.unwrap_or_default())
+            .map_napi_err()
+    }
+
+    /// Reset the video and/or audio in the helper process while outputs may be running.
+    /// Async version of `reconfigureSync`.
+    #[napi(ts_return_type = "Promise<void>")]
+    pub fn reconfigure(
+        &self,
+        env: Env,
+        data: ReconfigureData,
+        options: Option<ReconfigureOptions>,
+    ) -> napi::Result<JsObject> {
+        let obs = self.0.clone();
+        let options = options.unwrap_or_default();
+        env.spawn_future(blocking(move || obs.reconfigure(&data, &options)))
+    }
+
+    /// Get the video data obs is currently running with in the helper process.
+    #[napi]
+    pub fn get_video_info(&self) -> napi::Result<ResetVideoData> {
+        self.0.get_video_info().map_napi_err()
+    }
+
+    /// Get the audio data obs is currently running with in the helper process.
+    #[napi]
+    pub fn get_audio_info(&self) -> napi::Result<ResetAudioData> {
+        self.0.get_audio_info().map_napi_err()
+    }
+
+    /// Whether video is active in the helper process.
+    #[napi(getter)]
+    pub fn video_active(&self) -> napi::Result<bool> {
+        self.0.video_active().map_napi_err()
+    }
+
+    /// Get the version of libobs loaded by the helper process.
+    #[napi]
+    pub fn get_version(&self) -> napi::Result<ObsVersion> {
+        self.0.version().map_napi_err()
+    }
+
+    /// List all source types.
+    #[napi]
+    pub fn list_source_types(&self) -> napi::Result<Vec<String>> {
+        self.0.list_source_types().map_napi_err()
+    }
+
+    /// List all encoder types.
+    #[napi]
+    pub fn list_encoder_types(&self) -> napi::Result<Vec<String>> {
+        self.0.list_encoder_types().map_napi_err()
+    }
+
+    /// List all output types.
+    #[napi]
+    pub fn list_output_types(&self) -> napi::Result<Vec<String>> {
+        self.0.list_output_types().map_napi_err()
+    }
+
+    /// Create a video encoder.
+    #[napi(ts_args_type = "name: string, id: string, settings?: Record<string, any>")]
+    pub fn create_video_encoder_sync(
+        &self,
+        name: String,
+        id: String,
+        settings: Option<Value>,
+    ) -> napi::Result<RemoteObsEncoder> {
+        self.0
+            .create_video_encoder(&name, &id, settings.as_ref())
+            .map(RemoteObsEncoder)
+            .map_napi_err()
+    }
+
+    /// Create a video encoder.
+    /// Async version of `createVideoEncoderSync`.
+    #[napi(
+        ts_args_type = "name: string, id: string, settings?: Record<string, any>",
+        ts_return_type = "Promise<RemoteObsEncoder>"
+    )]
+    pub fn create_video_encoder(
+        &self,
+        env: Env,
+        name: String,
+        id: String,
+        settings: Option<Value>,
+    ) -> napi::Result<JsObject> {
+        let obs = self.0.clone();
+        env.spawn_future(blocking(move || {
+            obs.create_video_encoder(&name, &id, settings.as_ref())
+                .map(RemoteObsEncoder)
+        }))
+    }
+
+    /// Create an audio encoder.
+    #[napi(ts_args_type = "name: string, id: string, settings?: Record<string, any>")]
+    pub fn create_audio_encoder_sync(
+        &self,
+        name: String,
+        id: String,
+        settings: Option<Value>,
+    ) -> napi::Result<RemoteObsEncoder> {
+        self.0
+            .create_audio_encoder(&name, &id, settings.as_ref())
+            .map(RemoteObsEncoder)
+            .map_napi_err()
+    }
+
+    /// Create an audio encoder.
+    /// Async version of `createAudioEncoderSync`.
+    #[napi(
+        ts_args_type = "name: string, id: string, settings?: Record<string, any>",
+        ts_return_type = "Promise<RemoteObsEncoder>"
+    )]
+    pub fn create_audio_encoder(
+        &self,
+        env: Env,
+        name: String,
+        id: String,
+        settings: Option<Value>,
+    ) -> napi::Result<JsObject> {
+        let obs = self.0.clone();
+        env.spawn_future(blocking(move || {
+            obs.create_audio_encoder(&name, &id, settings.as_ref())
+                .map(RemoteObsEncoder)
+        }))
+    }
+
+    /// Create a source and set it as the source of an output channel.
+    #[napi(
+        ts_args_type = "name: string, id: string, channel: number, settings?: Record<string, any>"
+    )]
+    pub fn create_source_sync(
+        &self,
+        name: String,
+        id: String,
+        channel: u32,
+        settings: Option<Value>,
+    ) -> napi::Result<RemoteObsSource> {
+        self.0
+            .create_source(&name, &id, channel, settings.as_ref())
+            .map(RemoteObsSource)
+            .map_napi_err()
+    }
+
+    /// Create a source and set it as the source of an output channel.
+    /// Async version of `createSourceSync`.
+    #[napi(
+        ts_args_type = "name: string, id: string, channel: number, settings?: Record<string, any>",
+        ts_return_type = "Promise<RemoteObsSource>"
+    )]
+    pub fn create_source(
+        &self,
+        env: Env,
+        name: String,
+        id: String,
+        channel: u32,
+        settings: Option<Value>,
+    ) -> napi::Result<JsObject> {
+        let obs = self.0.clone();
+        env.spawn_future(blocking(move || {
+            obs.create_source(&name, &id, channel, settings.as_ref())
+                .map(RemoteObsSource)
+        }))
+    }
+
+    /// Create an output.
+    #[napi(ts_args_type = "name: string, id: string, settings?: Record<string, any>")]
+    pub fn create_output_sync(
+        &self,
+        name: String,
+        id: String,
+        settings: Option<Value>,
+    ) -> napi::Result<RemoteObsOutput> {
+        self.0
+            .create_output(&name, &id, settings.as_ref())
+            .map(RemoteObsOutput)
+            .map_napi_err()
+    }
+
+    /// Create an output.
+    /// Async version of `createOutputSync`.
+    #[napi(
+        ts_args_type = "name: string, id: string, settings?: Record<string, any>",
+        ts_return_type = "Promise<RemoteObsOutput>"
+    )]
+    pub fn create_output(
+        &self,
+        env: Env,
+        name: String,
+        id: String,
+        settings: Option<Value>,
+    ) -> napi::Result<JsObject> {
+        let obs = self.0.clone();
+        env.spawn_future(blocking(move || {
+            obs.create_output(&name, &id, settings.as_ref())
+                .map(RemoteObsOutput)
+        }))
+    }
+}
+
+/// An encoder in the helper process.
+#[napi]
+pub struct RemoteObsEncoder(InnerRemoteEncoder);
+
+#[napi]
+impl RemoteObsEncoder {
+    /// Get the settings of this encoder.
+    #[napi(ts_return_type = "Record<string, any>")]
+    pub fn get_settings(&self) -> napi::Result<Value> {
+        self.0.get_settings().map_napi_err()
+    }
+
+    /// Update the settings of this encoder.
+    ///
+    /// @param settings - the settings to apply
+    /// @param strict - whether to validate the settings first and throw
+    ///                 if they are invalid. Defaults to `false`.
+    #[napi(ts_args_type = "settings: Record<string, any>, strict?: boolean")]
+    pub fn update_settings(&self, settings: Value, strict: Option<bool>) -> napi::Result<()> {
+        self.0
+            .update_settings(&settings, strict.unwrap_or(false))
+            .map_napi_err()
+    }
+
+    /// Generate the settings schema of this encoder from its properties.
+    ///
+    /// @param name - the name of the TypeScript interface, defaults to `Settings`
+    #[napi]
+    pub fn get_schema(&self, name: Option<String>) -> napi::Result<TypeSchema> {
+        self.0.get_schema(name.as_deref()).map_napi_err()
+    }
+
+    /// Validate settings against the properties of this encoder.
+    /// Returns an empty list if the settings are valid.
+    #[napi(ts_args_type = "settings: Record<string, any>")]
+    pub fn validate(&self, settings: Value) -> napi::Result<Vec<SettingsIssue>> {
+        self.0.validate(&settings).map_napi_err()
+    }
+}
+
+/// A source in the helper process.
+#[napi]
+pub struct RemoteObsSource(InnerRemoteSource);
+
+#[napi]
+impl RemoteObsSource {
+    /// Play the media of this source.
+    /// Throws an error if this is not a media source.
+    #[napi]
+    pub fn play(&self) -> napi::Result<()> {
+        self.0.play().map_napi_err()
+    }
+
+    /// Pause the media of this source.
+    /// Throws an error if this is not a media source.
+    #[napi]
+    pub fn pause(&self) -> napi::Result<()> {
+        self.0.pause().map_napi_err()
+    }
+
+    /// Stop the media of this source.
+    /// Throws an error if this is not a media source.
+    #[napi]
+    pub fn stop(&self) -> napi::Result<()> {
+        self.0.stop().map_napi_err()
+    }
+
+    /// Restart the media of this source.
+    /// Throws an error if this is not a media source.
+    #[napi]
+    pub fn restart(&self) -> napi::Result<()> {
+        self.0.restart().map_napi_err()
+    }
+
+    /// Seek to a position in the media of this source.
+    /// Throws an error if this is not a media source.
+    ///
+    /// @param ms - the position to seek to in milliseconds
+    #[napi]
+    pub fn seek(&self, ms: i64) -> napi::Result<()> {
+        self.0.seek(ms).map_napi_err()
+    }
+
+    /// Skip to the next item of the media of this source.
+    /// Throws an error if this is not a media source.
+    #[napi]
+    pub fn next(&self) -> napi::Result<()> {
+        self.0.next().map_napi_err()
+    }
+
+    /// Skip to the previous item of the media of this source.
+    /// Throws an error if this is not a media source.
+    #[napi]
+    pub fn previous(&self) -> napi::Result<()> {
+        self.0.previous().map_napi_err()
+    }
+
+    /// The current position of the media in milliseconds.
+    #[napi(getter)]
+    pub fn get_time(&self) -> napi::Result<i64> {
+        self.0.get_time().map_napi_err()
+    }
+
+    /// The duration of the media in milliseconds.
+    #[napi(getter)]
+    pub fn get_duration(&self) -> napi::Result<i64> {
+        self.0.get_duration().map_napi_err()
+    }
+
+    /// The playback state of the media.
+    #[napi(getter)]
+    pub fn get_state(&self) -> napi::Result<MediaState> {
+        self.0.get_state().map_napi_err()
+    }
+
+    /// Listen for media events of this source,
+    /// e.g. when the media started, ended or was paused.
+    /// Listeners are kept if the helper process is restarted.
+    /// The listener does not keep the process alive.
+    /// Returns the id of the listener, which can be passed
+    /// to `removeMediaListener` to stop listening.
+    #[napi(ts_args_type = "callback: (event: MediaEvent) => void")]
+    pub fn on_media_event(&self, env: Env, callback: JsFunction) -> napi::Result<u32> {
+        let mut tsfn: ThreadsafeFunction<MediaEvent, ErrorStrategy::Fatal> = callback
+            .create_threadsafe_function(0, |ctx: ThreadSafeCallContext<MediaEvent>| {
+                Ok(vec![ctx.value])
+            })?;
+        tsfn.unref(&env)?;
+
+        Ok(self.0.on_media_event(move |event| {
+            tsfn.call(event, ThreadsafeFunctionCallMode::NonBlocking);
+        }))
+    }
+
+    /// Remove a listener added using `onMediaEvent`.
+    /// Returns `false` if no listener with the given id exists.
+    #[napi]
+    pub fn remove_media_listener(&self, id: u32) -> bool {
+        self.0.remove_media_listener(id)
+    }
+
+    /// Get the settings of this source.
+    #[napi(ts_return_type = "Record<string, any>")]
+    pub fn get_settings(&self) -> napi::Result<Value> {
+        self.0.get_settings().map_napi_err()
+    }
+
+    /// Update the settings of this source.
+    ///
+    /// @param settings - the settings to apply
+    /// @param strict - whether to validate the settings first and throw
+    ///                 if they are invalid. Defaults to `false`.
+    #[napi(ts_args_type = "settings: Record<string, any>, strict?: boolean")]
+    pub fn update_settings(&self, settings: Value, strict: Option<bool>) -> napi::Result<()> {
+        self.0
+            .update_settings(&settings, strict.unwrap_or(false))
+            .map_napi_err()
+    }
+
+    /// Generate the settings schema of this source from its properties.
+    ///
+    /// @param name - the name of the TypeScript interface, defaults to `Settings`
+    #[napi]
+    pub fn get_schema(&self, name: Option<String>) -> napi::Result<TypeSchema> {
+        self.0.get_schema(name.as_deref()).map_napi_err()
+    }
+
+    /// Validate settings against the properties of this source.
+    /// Returns an empty list if the settings are valid.
+    #[napi(ts_args_type = "settings: Record<string, any>")]
+    pub fn validate(&self, settings: Value) -> napi::Result<Vec<SettingsIssue>> {
+        self.0.validate(&settings).map_napi_err()
+    }
+}
+
+/// An output in the helper process.
+/// Outputs are stopped if the helper process crashes
+/// and must be started again once it has been restarted.
+#[napi]
+pub struct RemoteObsOutput(InnerRemoteOutput);
+
+#[napi]
+impl RemoteObsOutput {
+    /// Start the output.
+    /// At least one encoder must be set before starting the output.
+    /// Throws an error if the output is not stopped.
+    #[napi]
+    pub fn start(
+        &self,
+        video_encoder: Option<&RemoteObsEncoder>,
+        audio_encoder: Option<&RemoteObsEncoder>,
+    ) -> napi::Result<()> {
+        self.0
+            .start(video_encoder.map(|e| &e.0), audio_encoder.map(|e| &e.0))
+            .map_napi_err()
+    }
+
+    /// Stop the output.
+    /// Throws an error if the output is not running.
+    #[napi]
+    pub fn stop(&self) -> napi::Result<()> {
+        self.0.stop().map_napi_err()
+    }
+
+    /// Start the output and wait until it has actually started.
+    /// Rejects if the output stopped before it started, e.g. because
+    /// the connection to the server failed, or if the helper process crashed.
+    ///
+    /// Timing out or aborting only rejects the promise, the output is not stopped.
+    ///
+    /// @param videoEncoder - the video encoder to use
+    /// @param audioEncoder - the audio encoder to use
+    /// @param options - `timeout` in milliseconds and a `signal` to abort waiting
+    #[napi(
+        ts_args_type = "videoEncoder?: RemoteObsEncoder | undefined | null, audioEncoder?: RemoteObsEncoder | undefined | null, options?: { timeout?: number, signal?: AbortSignal }",
+        ts_return_type = "Promise<void>"
+    )]
+    pub fn start_async(
+        &self,
+        env: Env,
+        video_encoder: Option<&RemoteObsEncoder>,
+        audio_encoder: Option<&RemoteObsEncoder>,
+        options: Option<JsObject>,
+    ) -> napi::Result<JsObject> {
+        let options = WaitOptions::from_js(&env, options)?;
+        let (sender, receiver) = oneshot::channel();
+        let res = self.0.start_with_callback(
+            video_encoder.map(|e| &e.0),
+            audio_encoder.map(|e| &e.0),
+            move |res| {
+                let _ = sender.send(res);
+            },
+        );
+
+        env.spawn_future(async move {
+            res.map_napi_err()?;
+            options
+                .wait(receiver, "Timed out waiting for the output to start")
+                .await
+        })
+    }
+
+    /// Stop the output and wait until it has actually stopped.
+    /// Outputs writing to a file finalize the file before they stop,
+    /// so the file is only complete once the returned promise resolves.
+    ///
+    /// Timing out or aborting only rejects the promise, the output still stops.
+    ///
+    /// @param options - `timeout` in milliseconds and a `signal` to abort waiting
+    #[napi(
+        ts_args_type = "options?: { timeout?: number, signal?: AbortSignal }",
+        ts_return_type = "Promise<void>"
+    )]
+    pub fn stop_async(&self, env: Env, options: Option<JsObject>) -> napi::Result<JsObject> {
+        let options = WaitOptions::from_js(&env, options)?;
+        let (sender, receiver) = oneshot::channel();
+        let res = self.0.stop_with_callback(move |res| {
+            let _ = sender.send(res);
+        });
+
+        env.spawn_future(async move {
+            res.map_napi_err()?;
+            options
+                .wait(receiver, "Timed out waiting for the output to stop")
+                .await
+        })
+    }
+
+    /// Force stop the output.
+    /// Throws an error if the output is not running.
+    #[napi]
+    pub fn force_stop(&self) -> napi::Result<()> {
+        self.0.force_stop().map_napi_err()
+    }
+
+    /// Pause the output.
+    /// Throws an error if the output is not running.
+    #[napi]
+    pub fn pause(&self) -> napi::Result<()> {
+        self.0.pause().map_napi_err()
+    }
+
+    /// Resume the output.
+    /// Throws an error if the output is not paused.
+    #[napi]
+    pub fn resume(&self) -> napi::Result<()> {
+        self.0.resume().map_napi_err()
+    }
+
+    /// Get if the output is paused.
+    #[napi(getter)]
+    pub fn get_paused(&self) -> napi::Result<bool> {
+        self.0.get_paused().map_napi_err()
+    }
+
+    /// Check if the output can be paused.
+    #[napi(getter)]
+    pub fn can_pause(&self) -> napi::Result<bool> {
+        self.0.can_pause().map_napi_err()
+    }
+
+    /// Check if the output is active in the helper process.
+    #[napi(getter)]
+    pub fn get_active(&self) -> napi::Result<bool> {
+        self.0.get_active().map_napi_err()
+    }
+
+    /// The state of the output.
+    /// Changes to `Stopped` if the helper process crashes.
+    #[napi(getter)]
+    pub fn get_state(&self) -> OutputState {
+        self.0.get_state()
+    }
+
+    /// Listen for state changes of this output.
+    /// The callback is called with the new state.
+    /// The listener does not keep the process alive.
+    #[napi(ts_args_type = "callback: (state: OutputState) => void")]
+    pub fn on_state_change(&self, env: Env, callback: JsFunction) -> napi::Result<()> {
+        let mut tsfn: ThreadsafeFunction<OutputState, ErrorStrategy::Fatal> = callback
+            .create_threadsafe_function(0, |ctx: ThreadSafeCallContext<OutputState>| {
+                Ok(vec![ctx.value])
+            })?;
+        tsfn.unref(&env)?;
+
+        self.0.on_state_change(move |state| {
+            tsfn.call(state, ThreadsafeFunctionCallMode::NonBlocking);
+        });
+
+        Ok(())
+    }
+
+    /// Get the settings of this output.
+    #[napi(ts_return_type = "Record<string, any>")]
+    pub fn get_settings(&self) -> napi::Result<Value> {
+        self.0.get_settings().map_napi_err()
+    }
+
+    /// Update the settings of this output.
+    ///
+    /// @param settings - the settings to apply
+    /// @param strict - whether to validate the settings first and throw
+    ///                 if they are invalid. Defaults to `false`.
+    #[napi(ts_args_type = "settings: Record<string, any>, strict?: boolean")]
+    pub fn update_settings(&self, settings: Value, strict: Option<bool>) -> napi::Result<()> {
+        self.0
+            .update_settings(&settings, strict.unwrap_or(false))
+            .map_napi_err()
+    }
+
+    /// Generate the settings schema of this output from its properties.
+    ///
+    /// @param name - the name of the TypeScript interface, defaults to `Settings`
+    #[napi]
+    pub fn get_schema(&self, name: Option<String>) -> napi::Result<TypeSchema> {
+        self.0.get_schema(name.as_deref()).map_napi_err()
+    }
+
+    /// Validate settings against the properties of this output.
+    /// Returns an empty list if the settings are valid.
+    #[napi(ts_args_type = "settings: Record<string, any>")]
+    pub fn validate(&self, settings: Value) -> napi::Result<Vec<SettingsIssue>> {
+        self.0.validate(&settings).map_napi_err()
+    }
+}
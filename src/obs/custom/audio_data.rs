@@ -0,0 +1,164 @@
+use crate::obs::objects::reset_audio_data::SpeakerLayout;
+use crate::obs::sys;
+use crate::obs::traits::enum_value::EnumValue;
+use crate::obs::util::types::ResultType;
+use std::collections::HashMap;
+use std::mem;
+use std::sync::Mutex;
+
+/// How far the timestamp following the previous audio data may lag behind
+/// the current video frame time before it is discarded, in nanoseconds.
+const MAX_TIMESTAMP_LAG: u64 = 1_000_000_000;
+
+/// The timestamp following the last audio data output to each source, by source address.
+static NEXT_TIMESTAMPS: Mutex<Option<HashMap<usize, u64>>> = Mutex::new(None);
+
+/// The sample format of audio data.
+#[cfg_attr(feature = "napi", napi)]
+#[cfg_attr(not(feature = "napi"), derive(Clone, Copy))]
+pub enum AudioFormat {
+    U8Bit,
+    Bit16,
+    Bit32,
+    Float,
+    U8BitPlanar,
+    Bit16Planar,
+    Bit32Planar,
+    FloatPlanar,
+}
+
+impl AudioFormat {
+    fn bytes_per_sample(&self) -> usize {
+        match self {
+            AudioFormat::U8Bit | AudioFormat::U8BitPlanar => 1,
+            AudioFormat::Bit16 | AudioFormat::Bit16Planar => 2,
+            AudioFormat::Bit32
+            | AudioFormat::Bit32Planar
+            | AudioFormat::Float
+            | AudioFormat::FloatPlanar => 4,
+        }
+    }
+
+    fn is_planar(&self) -> bool {
+        matches!(
+            self,
+            AudioFormat::U8BitPlanar
+                | AudioFormat::Bit16Planar
+                | AudioFormat::Bit32Planar
+                | AudioFormat::FloatPlanar
+        )
+    }
+}
+
+impl EnumValue for AudioFormat {
+    fn value(&self) -> i32 {
+        match self {
+            AudioFormat::U8Bit => sys::audio_format_AUDIO_FORMAT_U8BIT,
+            AudioFormat::Bit16 => sys::audio_format_AUDIO_FORMAT_16BIT,
+            AudioFormat::Bit32 => sys::audio_format_AUDIO_FORMAT_32BIT,
+            AudioFormat::Float => sys::audio_format_AUDIO_FORMAT_FLOAT,
+            AudioFormat::U8BitPlanar => sys::audio_format_AUDIO_FORMAT_U8BIT_PLANAR,
+            AudioFormat::Bit16Planar => sys::audio_format_AUDIO_FORMAT_16BIT_PLANAR,
+            AudioFormat::Bit32Planar => sys::audio_format_AUDIO_FORMAT_32BIT_PLANAR,
+            AudioFormat::FloatPlanar => sys::audio_format_AUDIO_FORMAT_FLOAT_PLANAR,
+        }
+    }
+}
+
+/// The description of raw audio data pushed from JavaScript.
+#[cfg_attr(feature = "napi", napi(object))]
+pub struct AudioDataInfo {
+    /// The sample format of the audio data.
+    pub format: AudioFormat,
+    /// The speaker layout of the audio data.
+    pub speakers: SpeakerLayout,
+    /// The sample rate of the audio data, e.g. `48000`.
+    pub sample_rate: u32,
+    /// The timestamp of the first sample in nanoseconds.
+    /// Defaults to continuing the previous audio data output to the source,
+    /// or to the current video frame time if there was none
+    /// or there was a gap of more than a second since.
+    pub timestamp: Option<i64>,
+}
+
+/// Output audio data to an audio source.
+/// Planar data must contain the planes of all channels following each other.
+/// The data is copied by obs, so `data` may be freed afterwards.
+pub unsafe fn output_audio(
+    source: *mut sys::obs_source_t,
+    data: &[u8],
+    info: &AudioDataInfo,
+) -> ResultType<()> {
+    let channels = info.speakers.channels();
+    if channels == 0 {
+        return Err("The speaker layout must not be Unknown".into());
+    }
+
+    let frame_size = info.format.bytes_per_sample() * channels;
+    if !data.len().is_multiple_of(frame_size) {
+        return Err(format!(
+            "The audio buffer size must be a multiple of {} bytes",
+            frame_size
+        )
+        .into());
+    }
+
+    if info.sample_rate == 0 {
+        return Err("The sample rate must not be 0".into());
+    }
+
+    let frames = data.len() / frame_size;
+    let mut audio: sys::obs_source_audio = mem::zeroed();
+    if info.format.is_planar() {
+        let plane_size = frames * info.format.bytes_per_sample();
+        for i in 0..channels {
+            audio.data[i] = data.as_ptr().add(i * plane_size);
+        }
+    } else {
+        audio.data[0] = data.as_ptr();
+    }
+
+    audio.frames = frames as u32;
+    audio.speakers = info.speakers.value();
+    audio.format = info.format.value();
+    audio.samples_per_sec = info.sample_rate;
+    audio.timestamp = match info.timestamp {
+        Some(timestamp) => {
+            u64::try_from(timestamp).map_err(|_| "The timestamp must not be negative")?
+        }
+        None => next_timestamp(source),
+    };
+
+    let duration = frames as u64 * 1_000_000_000 / info.sample_rate as u64;
+    NEXT_TIMESTAMPS
+        .lock()
+        .unwrap()
+        .get_or_insert_with(HashMap::new)
+        .insert(source as usize, audio.timestamp + duration);
+
+    sys::obs_source_output_audio(source, &audio);
+    Ok(())
+}
+
+/// Get the timestamp of audio data following the previous data output to a source.
+unsafe fn next_timestamp(source: *mut sys::obs_source_t) -> u64 {
+    let now = sys::obs_get_video_frame_time();
+    let next = NEXT_TIMESTAMPS
+        .lock()
+        .unwrap()
+        .as_ref()
+        .and_then(|timestamps| timestamps.get(&(source as usize)).copied());
+
+    match next {
+        Some(next) if next + MAX_TIMESTAMP_LAG >= now => next,
+        _ => now,
+    }
+}
+
+/// Forget the timestamp following the last audio data output to a source,
+/// called before the source is destroyed.
+pub(crate) fn reset_timestamp(source: *mut sys::obs_source_t) {
+    if let Some(timestamps) = NEXT_TIMESTAMPS.lock().unwrap().as_mut() {
+        timestamps.remove(&(source as usize));
+    }
+}
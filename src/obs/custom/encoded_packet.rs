@@ -0,0 +1,70 @@
+use crate::obs::data::obs_encoder_type::EncoderKind;
+use crate::obs::sys;
+use std::ffi::CStr;
+use std::ptr;
+use std::slice;
+
+/// An encoded packet copied from obs.
+pub struct Packet {
+    pub data: Vec<u8>,
+    pub pts: i64,
+    pub dts: i64,
+    pub timebase_num: i32,
+    pub timebase_den: i32,
+    pub kind: EncoderKind,
+    pub keyframe: bool,
+    pub track_idx: u32,
+    pub codec: Option<String>,
+    pub extra_data: Option<Vec<u8>>,
+}
+
+impl Packet {
+    /// Copy an encoded packet.
+    /// The codec extra data is only copied for keyframes and audio packets.
+    ///
+    /// # Safety
+    /// `packet` must be a packet passed to an output by obs.
+    pub unsafe fn from_raw(packet: &sys::encoder_packet) -> Self {
+        let kind = if packet.type_ == sys::obs_encoder_type_OBS_ENCODER_AUDIO {
+            EncoderKind::Audio
+        } else {
+            EncoderKind::Video
+        };
+
+        let mut codec = None;
+        let mut extra_data = None;
+        if !packet.encoder.is_null() {
+            let c = sys::obs_encoder_get_codec(packet.encoder);
+            if !c.is_null() {
+                codec = Some(CStr::from_ptr(c).to_string_lossy().into_owned());
+            }
+
+            if packet.keyframe || matches!(kind, EncoderKind::Audio) {
+                let mut data: *mut u8 = ptr::null_mut();
+                let mut size = 0;
+                if sys::obs_encoder_get_extra_data(packet.encoder, &mut data, &mut size)
+                    && !data.is_null()
+                {
+                    extra_data = Some(slice::from_raw_parts(data, size).to_vec());
+                }
+            }
+        }
+
+        Self {
+            data: if packet.data.is_null() {
+                vec![]
+            } else {
+                slice::from_raw_parts(packet.data, packet.size).to_vec()
+            },
+            pts: packet.pts,
+            dts: packet.dts,
+            timebase_num: packet.timebase_num,
+            timebase_den: packet.timebase_den,
+            kind,
+            keyframe: packet.keyframe,
+            track_idx: packet.track_idx as u32,
+            codec,
+            extra_data,
+        }
+    }
+}
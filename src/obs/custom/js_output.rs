@@ -0,0 +1,86 @@
+use crate::obs::custom::encoded_packet::Packet;
+use crate::obs::plugin::{is_registered_as, Settings};
+use crate::obs::plugin::{register_output, OutputContext, OutputImpl, OutputTypeFlags, PacketRef};
+use crate::obs::sys;
+use crate::obs::util::types::ResultType;
+use std::ffi::CStr;
+use std::sync::Mutex;
+
+pub type PacketListener = Box<dyn Fn(Packet) + Send + Sync>;
+
+/// The packet listeners of all outputs, identified by their output pointer.
+static PACKET_LISTENERS: Mutex<Vec<(usize, PacketListener)>> = Mutex::new(Vec::new());
+
+/// An output whose encoded packets are passed to a listener.
+struct JsOutput {
+    ctx: OutputContext,
+    total_bytes: u64,
+}
+
+impl OutputImpl for JsOutput {
+    const FLAGS: OutputTypeFlags = OutputTypeFlags::AV.union(OutputTypeFlags::MULTI_TRACK);
+
+    fn create(_settings: &Settings, ctx: OutputContext) -> ResultType<Self> {
+        Ok(Self {
+            ctx,
+            total_bytes: 0,
+        })
+    }
+
+    fn start(&mut self) -> ResultType<()> {
+        self.total_bytes = 0;
+        Ok(())
+    }
+
+    fn encoded_packet(&mut self, packet: PacketRef) {
+        self.total_bytes += packet.data().len() as u64;
+
+        let output = unsafe { self.ctx.raw() } as usize;
+        let listeners = PACKET_LISTENERS.lock().unwrap();
+        if let Some((_, listener)) = listeners.iter().find(|(o, _)| *o == output) {
+            listener(packet.to_packet());
+        }
+    }
+
+    fn total_bytes(&self) -> u64 {
+        self.total_bytes
+    }
+}
+
+impl Drop for JsOutput {
+    fn drop(&mut self) {
+        remove_packet_listener(unsafe { self.ctx.raw() });
+    }
+}
+
+/// Register an output type whose encoded packets are passed to a listener.
+/// The type will be available until obs is shut down.
+pub fn register_js_output(id: String, display_name: String) -> ResultType<()> {
+    register_output::<JsOutput>(&id, &display_name)
+}
+
+/// Check whether the output is of a type registered using `register_js_output`.
+pub unsafe fn is_js_output(output: *mut sys::obs_output_t) -> bool {
+    let id = sys::obs_output_get_id(output);
+    if id.is_null() {
+        return false;
+    }
+
+    is_registered_as::<JsOutput>(&CStr::from_ptr(id).to_string_lossy())
+}
+
+/// Set the listener receiving the encoded packets of an output,
+/// replacing any previous listener.
+pub fn set_packet_listener(output: *mut sys::obs_output_t, listener: PacketListener) {
+    let mut listeners = PACKET_LISTENERS.lock().unwrap();
+    listeners.retain(|(o, _)| *o != output as usize);
+    listeners.push((output as usize, listener));
+}
+
+/// Remove the packet listener of an output.
+pub fn remove_packet_listener(output: *mut sys::obs_output_t) {
+    PACKET_LISTENERS
+        .lock()
+        .unwrap()
+        .retain(|(o, _)| *o != output as usize);
+}
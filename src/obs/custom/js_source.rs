@@ -0,0 +1,25 @@
+use crate::obs::plugin::{Settings, SourceContext, SourceFlags, SourceImpl};
+use crate::obs::util::types::ResultType;
+
+/// An async video source whose frames are pushed from javascript.
+pub struct JsVideoSource;
+
+/// An audio source whose samples are pushed from javascript.
+pub struct JsAudioSource;
+
+// The sources have no state, the data is pushed from javascript
+impl SourceImpl for JsVideoSource {
+    const FLAGS: SourceFlags = SourceFlags::ASYNC_VIDEO;
+
+    fn create(_settings: &Settings, _ctx: SourceContext) -> ResultType<Self> {
+        Ok(Self)
+    }
+}
+
+impl SourceImpl for JsAudioSource {
+    const FLAGS: SourceFlags = SourceFlags::AUDIO;
+
+    fn create(_settings: &Settings, _ctx: SourceContext) -> ResultType<Self> {
+        Ok(Self)
+    }
+}
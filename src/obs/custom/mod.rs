@@ -0,0 +1,7 @@
+pub(crate) mod audio_data;
+pub(crate) mod encoded_packet;
+pub(crate) mod js_output;
+pub(crate) mod js_source;
+pub(crate) mod mpegts;
+pub(crate) mod mpegts_output;
+pub(crate) mod video_frame;
@@ -0,0 +1,589 @@
+use crate::obs::custom::encoded_packet::Packet;
+use crate::obs::data::obs_encoder_type::EncoderKind;
+use crate::obs::util::types::ResultType;
+use std::iter;
+
+const TS_PACKET_SIZE: usize = 188;
+const PAT_PID: u16 = 0x0000;
+const PMT_PID: u16 = 0x1000;
+const VIDEO_PID: u16 = 0x0100;
+const AUDIO_PID: u16 = 0x0101;
+
+/// The offset added to all timestamps so that timestamps
+/// of b-frames and the pcr never become negative.
+const TIMESTAMP_OFFSET: i64 = 63000;
+/// The time the pcr is set before the dts of a packet.
+const PCR_DELAY: i64 = 9000;
+/// The interval tables are repeated at in audio only streams.
+const TABLE_INTERVAL: i64 = 90000;
+
+/// A video codec which can be muxed into a transport stream.
+#[derive(Clone, Copy, PartialEq)]
+pub enum VideoCodec {
+    H264,
+    Hevc,
+}
+
+impl VideoCodec {
+    pub fn from_name(codec: &str) -> ResultType<Self> {
+        match codec {
+            "h264" => Ok(VideoCodec::H264),
+            "hevc" => Ok(VideoCodec::Hevc),
+            _ => Err(format!("The video codec '{}' is not supported", codec).into()),
+        }
+    }
+
+    fn stream_type(&self) -> u8 {
+        match self {
+            VideoCodec::H264 => 0x1B,
+            VideoCodec::Hevc => 0x24,
+        }
+    }
+
+    fn access_unit_delimiter(&self) -> &'static [u8] {
+        match self {
+            VideoCodec::H264 => &[0x00, 0x00, 0x00, 0x01, 0x09, 0xF0],
+            VideoCodec::Hevc => &[0x00, 0x00, 0x00, 0x01, 0x46, 0x01, 0x50],
+        }
+    }
+
+    fn starts_with_delimiter(&self, data: &[u8]) -> bool {
+        let nal = if data.starts_with(&[0, 0, 0, 1]) {
+            data.get(4)
+        } else if data.starts_with(&[0, 0, 1]) {
+            data.get(3)
+        } else {
+            None
+        };
+
+        match (self, nal) {
+            (VideoCodec::H264, Some(nal)) => nal & 0x1F == 9,
+            (VideoCodec::Hevc, Some(nal)) => (nal >> 1) & 0x3F == 35,
+            _ => false,
+        }
+    }
+}
+
+/// A muxer writing h264/hevc video and aac audio into an MPEG transport stream.
+pub struct TsMuxer {
+    video: Option<VideoCodec>,
+    audio: bool,
+    continuity: [u8; 4],
+    base_dts: Option<i64>,
+    last_table: Option<i64>,
+    aac_config: Option<[u8; 2]>,
+}
+
+impl TsMuxer {
+    pub fn new(video: Option<VideoCodec>, audio: bool) -> Self {
+        Self {
+            video,
+            audio,
+            continuity: [0; 4],
+            base_dts: None,
+            last_table: None,
+            aac_config: None,
+        }
+    }
+
+    /// Mux a packet, appending the transport stream packets to `out`.
+    /// Packets of streams not part of this muxer are ignored.
+    pub fn write_packet(&mut self, packet: &Packet, out: &mut Vec<u8>) {
+        if packet.data.is_empty() {
+            return;
+        }
+
+        let (pid, stream_id, payload) = match packet.kind {
+            EncoderKind::Video => match self.video {
+                Some(codec) => (VIDEO_PID, 0xE0, self.video_payload(codec, packet)),
+                None => return,
+            },
+            EncoderKind::Audio if self.audio && packet.track_idx == 0 => {
+                match self.audio_payload(packet) {
+                    Some(payload) => (AUDIO_PID, 0xC0, payload),
+                    None => return,
+                }
+            }
+            EncoderKind::Audio => return,
+        };
+
+        let dts = to_90khz(packet.dts, packet.timebase_num, packet.timebase_den);
+        let pts = to_90khz(packet.pts, packet.timebase_num, packet.timebase_den);
+        let base = *self.base_dts.get_or_insert(dts);
+        let dts = dts - base + TIMESTAMP_OFFSET;
+        let pts = pts - base + TIMESTAMP_OFFSET;
+
+        let is_video = matches!(packet.kind, EncoderKind::Video);
+        let write_tables = match self.last_table {
+            None => true,
+            Some(_) if is_video => packet.keyframe,
+            Some(last) => self.video.is_none() && dts - last >= TABLE_INTERVAL,
+        };
+
+        if write_tables {
+            self.write_pat(out);
+            self.write_pmt(out);
+            self.last_table = Some(dts);
+        }
+
+        let pes = pes_packet(stream_id, pts, dts, is_video, &payload);
+        let pcr = if pid == self.pcr_pid() {
+            Some((dts - PCR_DELAY).max(0))
+        } else {
+            None
+        };
+
+        self.write_ts_packets(pid, &pes, pcr, is_video && packet.keyframe, out);
+    }
+
+    fn pcr_pid(&self) -> u16 {
+        if self.video.is_some() {
+            VIDEO_PID
+        } else {
+            AUDIO_PID
+        }
+    }
+
+    fn video_payload(&self, codec: VideoCodec, packet: &Packet) -> Vec<u8> {
+        let mut payload = Vec::with_capacity(packet.data.len() + 64);
+        if !codec.starts_with_delimiter(&packet.data) {
+            payload.extend_from_slice(codec.access_unit_delimiter());
+        }
+
+        // The parameter sets are not part of the packets, but are
+        // required in-band in front of every keyframe
+        if packet.keyframe {
+            if let Some(extra_data) = &packet.extra_data {
+                payload.extend_from_slice(extra_data);
+            }
+        }
+
+        payload.extend_from_slice(&packet.data);
+        payload
+    }
+
+    fn audio_payload(&mut self, packet: &Packet) -> Option<Vec<u8>> {
+        // Already contains an adts header
+        if packet.data.len() > 1 && packet.data[0] == 0xFF && packet.data[1] & 0xF0 == 0xF0 {
+            return Some(packet.data.clone());
+        }
+
+        if let Some(extra_data) = &packet.extra_data {
+            if extra_data.len() >= 2 {
+                self.aac_config = Some([extra_data[0], extra_data[1]]);
+            }
+        }
+
+        let config = self.aac_config?;
+        let object_type = (config[0] >> 3) as usize;
+        let frequency_index = (((config[0] & 0x07) << 1) | (config[1] >> 7)) as usize;
+        let channels = ((config[1] >> 3) & 0x0F) as usize;
+        let length = packet.data.len() + 7;
+
+        let mut payload = Vec::with_capacity(length);
+        payload.extend_from_slice(&[
+            0xFF,
+            0xF1,
+            (((object_type.max(1) - 1) << 6) | (frequency_index << 2) | (channels >> 2)) as u8,
+            (((channels & 0x03) << 6) | (length >> 11)) as u8,
+            ((length >> 3) & 0xFF) as u8,
+            (((length & 0x07) << 5) | 0x1F) as u8,
+            0xFC,
+        ]);
+        payload.extend_from_slice(&packet.data);
+
+        Some(payload)
+    }
+
+    fn write_pat(&mut self, out: &mut Vec<u8>) {
+        let section = psi_section(
+            0x00,
+            0x0001,
+            &[0x00, 0x01, 0xE0 | (PMT_PID >> 8) as u8, PMT_PID as u8],
+        );
+        self.write_psi(PAT_PID, &section, out);
+    }
+
+    fn write_pmt(&mut self, out: &mut Vec<u8>) {
+        let pcr_pid = self.pcr_pid();
+        let mut data = vec![0xE0 | (pcr_pid >> 8) as u8, pcr_pid as u8, 0xF0, 0x00];
+
+        let mut add_stream = |stream_type: u8, pid: u16| {
+            data.extend_from_slice(&[stream_type, 0xE0 | (pid >> 8) as u8, pid as u8, 0xF0, 0x00]);
+        };
+
+        if let Some(codec) = self.video {
+            add_stream(codec.stream_type(), VIDEO_PID);
+        }
+        if self.audio {
+            add_stream(0x0F, AUDIO_PID);
+        }
+
+        let section = psi_section(0x02, 0x0001, &data);
+        self.write_psi(PMT_PID, &section, out);
+    }
+
+    fn write_psi(&mut self, pid: u16, section: &[u8], out: &mut Vec<u8>) {
+        let start = out.len();
+        out.extend_from_slice(&[
+            0x47,
+            0x40 | (pid >> 8) as u8,
+            pid as u8,
+            0x10 | self.next_continuity(pid),
+            // Pointer field
+            0x00,
+        ]);
+        out.extend_from_slice(section);
+        out.resize(start + TS_PACKET_SIZE, 0xFF);
+    }
+
+    fn write_ts_packets(
+        &mut self,
+        pid: u16,
+        pes: &[u8],
+        pcr: Option<i64>,
+        random_access: bool,
+        out: &mut Vec<u8>,
+    ) {
+        let mut pos = 0;
+        let mut first = true;
+
+        while pos < pes.len() {
+            let remaining = pes.len() - pos;
+            let mut adaptation = Vec::new();
+            if first && (pcr.is_some() || random_access) {
+                let mut flags = 0;
+                if random_access {
+                    flags |= 0x40;
+                }
+                if pcr.is_some() {
+                    flags |= 0x10;
+                }
+
+                adaptation.push(flags);
+                if let Some(pcr) = pcr {
+                    adaptation.extend_from_slice(&encode_pcr(pcr));
+                }
+            }
+
+            let mut has_adaptation = !adaptation.is_empty();
+            let header_size = if has_adaptation {
+                5 + adaptation.len()
+            } else {
+                4
+            };
+
+            let mut space = TS_PACKET_SIZE - header_size;
+            if remaining < space {
+                let stuffing = space - remaining;
+                if has_adaptation {
+                    adaptation.extend(iter::repeat_n(0xFF, stuffing));
+                } else {
+                    // The adaptation field length itself takes one byte
+                    has_adaptation = true;
+                    if stuffing > 1 {
+                        adaptation.push(0x00);
+                        adaptation.extend(iter::repeat_n(0xFF, stuffing - 2));
+                    }
+                }
+
+                space = remaining;
+            }
+
+            let continuity = self.next_continuity(pid);
+            out.extend_from_slice(&[
+                0x47,
+                if first { 0x40 } else { 0x00 } | (pid >> 8) as u8 & 0x1F,
+                pid as u8,
+                if has_adaptation { 0x30 } else { 0x10 } | continuity,
+            ]);
+
+            if has_adaptation {
+                out.push(adaptation.len() as u8);
+                out.extend_from_slice(&adaptation);
+            }
+
+            out.extend_from_slice(&pes[pos..pos + space]);
+            pos += space;
+            first = false;
+        }
+    }
+
+    fn next_continuity(&mut self, pid: u16) -> u8 {
+        let index = match pid {
+            PAT_PID => 0,
+            PMT_PID => 1,
+            VIDEO_PID => 2,
+            _ => 3,
+        };
+
+        let continuity = self.continuity[index];
+        self.continuity[index] = (continuity + 1) & 0x0F;
+        continuity
+    }
+}
+
+fn to_90khz(ts: i64, timebase_num: i32, timebase_den: i32) -> i64 {
+    if timebase_den == 0 {
+        return 0;
+    }
+
+    ts * 90000 * timebase_num as i64 / timebase_den as i64
+}
+
+fn pes_packet(stream_id: u8, pts: i64, dts: i64, is_video: bool, payload: &[u8]) -> Vec<u8> {
+    let has_dts = pts != dts;
+    let header_length = if has_dts { 10 } else { 5 };
+    let length = 3 + header_length + payload.len();
+
+    let mut pes = Vec::with_capacity(9 + header_length + payload.len());
+    pes.extend_from_slice(&[0x00, 0x00, 0x01, stream_id]);
+
+    // Video packets may be larger than the maximum packet length
+    if is_video || length > u16::MAX as usize {
+        pes.extend_from_slice(&[0x00, 0x00]);
+    } else {
+        pes.extend_from_slice(&(length as u16).to_be_bytes());
+    }
+
+    pes.push(if is_video { 0x84 } else { 0x80 });
+    pes.push(if has_dts { 0xC0 } else { 0x80 });
+    pes.push(header_length as u8);
+
+    if has_dts {
+        pes.extend_from_slice(&encode_timestamp(0x03, pts));
+        pes.extend_from_slice(&encode_timestamp(0x01, dts));
+    } else {
+        pes.extend_from_slice(&encode_timestamp(0x02, pts));
+    }
+
+    pes.extend_from_slice(payload);
+    pes
+}
+
+fn encode_timestamp(prefix: u8, ts: i64) -> [u8; 5] {
+    let ts = (ts as u64) & 0x1_FFFF_FFFF;
+    [
+        (prefix << 4) | ((((ts >> 30) & 0x07) as u8) << 1) | 1,
+        (ts >> 22) as u8,
+        ((((ts >> 15) & 0x7F) as u8) << 1) | 1,
+        (ts >> 7) as u8,
+        (((ts & 0x7F) as u8) << 1) | 1,
+    ]
+}
+
+fn encode_pcr(pcr: i64) -> [u8; 6] {
+    let base = (pcr as u64) & 0x1_FFFF_FFFF;
+    [
+        (base >> 25) as u8,
+        (base >> 17) as u8,
+        (base >> 9) as u8,
+        (base >> 1) as u8,
+        (((base & 0x01) as u8) << 7) | 0x7E,
+        0x00,
+    ]
+}
+
+fn psi_section(table_id: u8, id: u16, data: &[u8]) -> Vec<u8> {
+    // id, version, section number and last section number, data and crc
+    let length = 5 + data.len() + 4;
+
+    let mut section = Vec::with_capacity(3 + length);
+    section.extend_from_slice(&[
+        table_id,
+        0xB0 | (length >> 8) as u8,
+        length as u8,
+        (id >> 8) as u8,
+        id as u8,
+        0xC1,
+        0x00,
+        0x00,
+    ]);
+    section.extend_from_slice(data);
+    section.extend_from_slice(&crc32(&section).to_be_bytes());
+
+    section
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for byte in data {
+        crc ^= (*byte as u32) << 24;
+        for _ in 0..8 {
+            crc = if crc & 0x8000_0000 != 0 {
+                (crc << 1) ^ 0x04C1_1DB7
+            } else {
+                crc << 1
+            };
+        }
+    }
+
+    crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_packets(out: &[u8]) {
+        assert_eq!(out.len() % TS_PACKET_SIZE, 0);
+        for packet in out.chunks(TS_PACKET_SIZE) {
+            assert_eq!(packet[0], 0x47);
+        }
+    }
+
+    fn decode_timestamp(data: &[u8]) -> u64 {
+        assert_eq!(data[0] & 0x01, 1);
+        assert_eq!(data[2] & 0x01, 1);
+        assert_eq!(data[4] & 0x01, 1);
+
+        ((data[0] as u64 >> 1) & 0x07) << 30
+            | (data[1] as u64) << 22
+            | (data[2] as u64 >> 1) << 15
+            | (data[3] as u64) << 7
+            | data[4] as u64 >> 1
+    }
+
+    #[test]
+    fn crc() {
+        // The check value of CRC-32/MPEG-2
+        assert_eq!(crc32(b"123456789"), 0x0376_E6E7);
+    }
+
+    #[test]
+    fn pat_section() {
+        let mut muxer = TsMuxer::new(Some(VideoCodec::H264), true);
+        let mut out = Vec::new();
+        muxer.write_pat(&mut out);
+
+        assert_packets(&out);
+        assert_eq!(&out[..5], &[0x47, 0x40, 0x00, 0x10, 0x00]);
+        // A PAT with transport stream id 1 mapping program 1 to pid 0x1000
+        assert_eq!(
+            &out[5..21],
+            &[
+                0x00, 0xB0, 0x0D, 0x00, 0x01, 0xC1, 0x00, 0x00, 0x00, 0x01, 0xF0, 0x00, 0x2A, 0xB1,
+                0x04, 0xB2
+            ]
+        );
+        assert!(out[21..].iter().all(|b| *b == 0xFF));
+    }
+
+    #[test]
+    fn exact_payload() {
+        let mut muxer = TsMuxer::new(None, true);
+        let mut out = Vec::new();
+        muxer.write_ts_packets(AUDIO_PID, &[0xAB; 184], None, false, &mut out);
+
+        assert_packets(&out);
+        assert_eq!(out.len(), TS_PACKET_SIZE);
+        assert_eq!(out[3], 0x10);
+        assert!(out[4..].iter().all(|b| *b == 0xAB));
+    }
+
+    #[test]
+    fn single_stuffing_byte() {
+        let mut muxer = TsMuxer::new(None, true);
+        let mut out = Vec::new();
+        muxer.write_ts_packets(AUDIO_PID, &[0xAB; 183], None, false, &mut out);
+
+        assert_packets(&out);
+        assert_eq!(out.len(), TS_PACKET_SIZE);
+        // An empty adaptation field only consists of its length
+        assert_eq!(out[3], 0x30);
+        assert_eq!(out[4], 0x00);
+        assert!(out[5..].iter().all(|b| *b == 0xAB));
+    }
+
+    #[test]
+    fn stuffing_in_second_packet() {
+        let mut muxer = TsMuxer::new(None, true);
+        let mut out = Vec::new();
+        muxer.write_ts_packets(AUDIO_PID, &[0xAB; 185], None, false, &mut out);
+
+        assert_packets(&out);
+        assert_eq!(out.len(), 2 * TS_PACKET_SIZE);
+
+        let (first, second) = out.split_at(TS_PACKET_SIZE);
+        assert_eq!(&first[1..4], &[0x41, 0x01, 0x10]);
+        assert_eq!(&second[1..4], &[0x01, 0x01, 0x31]);
+        assert_eq!(second[4], 182);
+        assert_eq!(second[5], 0x00);
+        assert!(second[6..187].iter().all(|b| *b == 0xFF));
+        assert_eq!(second[187], 0xAB);
+    }
+
+    #[test]
+    fn continuity_wraps() {
+        let mut muxer = TsMuxer::new(None, true);
+        let mut out = Vec::new();
+        for _ in 0..17 {
+            muxer.write_ts_packets(AUDIO_PID, &[0xAB; 184], None, false, &mut out);
+        }
+
+        let counters = out
+            .chunks(TS_PACKET_SIZE)
+            .map(|packet| packet[3] & 0x0F)
+            .collect::<Vec<_>>();
+        assert_eq!(counters[..16], (0..16).collect::<Vec<_>>()[..]);
+        assert_eq!(counters[16], 0);
+    }
+
+    #[test]
+    fn timestamps() {
+        assert_eq!(encode_timestamp(0x02, 0), [0x21, 0x00, 0x01, 0x00, 0x01]);
+        for ts in [1, 90000, 0x1_2345_6789, 0x1_FFFF_FFFF] {
+            assert_eq!(decode_timestamp(&encode_timestamp(0x02, ts)), ts as u64);
+        }
+    }
+
+    #[test]
+    fn pes_header() {
+        let pes = pes_packet(0xE0, 3003, 0, true, &[0xAB]);
+        assert_eq!(&pes[..4], &[0x00, 0x00, 0x01, 0xE0]);
+        assert_eq!(&pes[6..9], &[0x84, 0xC0, 10]);
+        assert_eq!(pes[9] >> 4, 0x03);
+        assert_eq!(decode_timestamp(&pes[9..14]), 3003);
+        assert_eq!(pes[14] >> 4, 0x01);
+        assert_eq!(decode_timestamp(&pes[14..19]), 0);
+
+        let pes = pes_packet(0xC0, 1000, 1000, false, &[0xAB]);
+        assert_eq!(&pes[4..6], &[0x00, 9]);
+        assert_eq!(&pes[6..9], &[0x80, 0x80, 5]);
+        assert_eq!(pes[9] >> 4, 0x02);
+        assert_eq!(decode_timestamp(&pes[9..14]), 1000);
+    }
+
+    #[test]
+    fn keyframe_starts_with_tables() {
+        let mut muxer = TsMuxer::new(Some(VideoCodec::H264), false);
+        let mut out = Vec::new();
+        muxer.write_packet(
+            &Packet {
+                data: vec![0x00, 0x00, 0x00, 0x01, 0x65, 0xAB],
+                pts: 0,
+                dts: 0,
+                timebase_num: 1,
+                timebase_den: 30,
+                kind: EncoderKind::Video,
+                keyframe: true,
+                track_idx: 0,
+                codec: Some("h264".to_string()),
+                extra_data: Some(vec![0x00, 0x00, 0x00, 0x01, 0x67]),
+            },
+            &mut out,
+        );
+
+        assert_packets(&out);
+        let pids = out
+            .chunks(TS_PACKET_SIZE)
+            .map(|packet| ((packet[1] as u16 & 0x1F) << 8) | packet[2] as u16)
+            .collect::<Vec<_>>();
+        assert_eq!(pids, [PAT_PID, PMT_PID, VIDEO_PID]);
+
+        // Random access and pcr flags
+        let video = &out[2 * TS_PACKET_SIZE..];
+        assert_eq!(video[3] & 0x30, 0x30);
+        assert_eq!(video[5], 0x50);
+    }
+}
@@ -0,0 +1,124 @@
+use crate::obs::custom::mpegts::{TsMuxer, VideoCodec};
+use crate::obs::plugin::{is_registered_as, Settings};
+use crate::obs::plugin::{register_output, OutputContext, OutputImpl, OutputTypeFlags, PacketRef};
+use crate::obs::sys;
+use crate::obs::util::types::ResultType;
+use std::ffi::CStr;
+use std::sync::Mutex;
+
+/// Receives chunks of the muxed stream, `None` marks the end of the stream.
+pub type DataListener = Box<dyn Fn(Option<Vec<u8>>) + Send + Sync>;
+
+/// The data listeners of all outputs, identified by their output pointer.
+static DATA_LISTENERS: Mutex<Vec<(usize, DataListener)>> = Mutex::new(Vec::new());
+
+/// An output muxing its packets into an MPEG transport stream.
+struct MpegTsOutput {
+    ctx: OutputContext,
+    total_bytes: u64,
+    muxer: Option<TsMuxer>,
+}
+
+impl MpegTsOutput {
+    fn send_data(&self, chunk: Option<Vec<u8>>) {
+        let output = unsafe { self.ctx.raw() } as usize;
+        let listeners = DATA_LISTENERS.lock().unwrap();
+        if let Some((_, listener)) = listeners.iter().find(|(o, _)| *o == output) {
+            listener(chunk);
+        }
+    }
+}
+
+impl OutputImpl for MpegTsOutput {
+    const FLAGS: OutputTypeFlags = OutputTypeFlags::AV;
+    const VIDEO_CODECS: Option<&'static str> = Some("h264;hevc");
+    const AUDIO_CODECS: Option<&'static str> = Some("aac");
+
+    fn create(_settings: &Settings, ctx: OutputContext) -> ResultType<Self> {
+        Ok(Self {
+            ctx,
+            total_bytes: 0,
+            muxer: None,
+        })
+    }
+
+    fn start(&mut self) -> ResultType<()> {
+        let video = self
+            .ctx
+            .video_codec()
+            .map(|codec| VideoCodec::from_name(&codec))
+            .transpose()?;
+
+        let audio = match self.ctx.audio_codec(0) {
+            Some(codec) if codec == "aac" => true,
+            Some(codec) => {
+                return Err(format!("The audio codec '{}' is not supported", codec).into());
+            }
+            None => false,
+        };
+
+        self.muxer = Some(TsMuxer::new(video, audio));
+        self.total_bytes = 0;
+        Ok(())
+    }
+
+    fn stop(&mut self) {
+        self.muxer = None;
+        self.send_data(None);
+    }
+
+    fn encoded_packet(&mut self, packet: PacketRef) {
+        let mut chunk = Vec::new();
+        if let Some(muxer) = self.muxer.as_mut() {
+            muxer.write_packet(&packet.to_packet(), &mut chunk);
+        }
+
+        if !chunk.is_empty() {
+            self.total_bytes += chunk.len() as u64;
+            self.send_data(Some(chunk));
+        }
+    }
+
+    fn total_bytes(&self) -> u64 {
+        self.total_bytes
+    }
+}
+
+impl Drop for MpegTsOutput {
+    fn drop(&mut self) {
+        remove_data_listener(unsafe { self.ctx.raw() });
+    }
+}
+
+/// Register an output type muxing h264/hevc video and aac audio
+/// into an MPEG transport stream which is passed to a listener.
+/// The type will be available until obs is shut down.
+pub fn register_mpegts_output(id: String, display_name: String) -> ResultType<()> {
+    register_output::<MpegTsOutput>(&id, &display_name)
+}
+
+/// Check whether the output is of a type registered using `register_mpegts_output`.
+pub unsafe fn is_mpegts_output(output: *mut sys::obs_output_t) -> bool {
+    let id = sys::obs_output_get_id(output);
+    if id.is_null() {
+        return false;
+    }
+
+    is_registered_as::<MpegTsOutput>(&CStr::from_ptr(id).to_string_lossy())
+}
+
+/// Set the listener receiving the muxed data of an output,
+/// replacing any previous listener.
+pub fn set_data_listener(output: *mut sys::obs_output_t, listener: DataListener) {
+    let mut listeners = DATA_LISTENERS.lock().unwrap();
+    listeners.retain(|(o, _)| *o != output as usize);
+    listeners.push((output as usize, listener));
+}
+
+/// Remove the data listener of an output.
+pub fn remove_data_listener(output: *mut sys::obs_output_t) {
+    DATA_LISTENERS
+        .lock()
+        .unwrap()
+        .retain(|(o, _)| *o != output as usize);
+}
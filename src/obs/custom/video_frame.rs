@@ -0,0 +1,140 @@
+use crate::obs::objects::reset_video_data::{VideoColorSpace, VideoFormat, VideoRange};
+use crate::obs::sys;
+use crate::obs::traits::enum_value::EnumValue;
+use crate::obs::util::types::ResultType;
+use std::mem;
+
+/// The description of a raw video frame pushed from JavaScript.
+#[cfg_attr(feature = "napi", napi(object))]
+pub struct VideoFrameInfo {
+    /// The pixel format of the frame data.
+    pub format: VideoFormat,
+    /// The width of the frame in pixels.
+    pub width: u32,
+    /// The height of the frame in pixels.
+    pub height: u32,
+    /// The timestamp of the frame in nanoseconds.
+    /// Defaults to the current video frame time.
+    pub timestamp: Option<i64>,
+    /// The color space of YUV frames. Defaults to `CS709`.
+    pub colorspace: Option<VideoColorSpace>,
+    /// The color range of YUV frames. Defaults to `Partial`.
+    pub range: Option<VideoRange>,
+}
+
+/// A single plane of a video frame: the offset into the frame data,
+/// the number of bytes per line and the number of lines.
+struct Plane {
+    offset: usize,
+    linesize: usize,
+    lines: usize,
+}
+
+/// Get the planes of a tightly packed frame with the given format.
+fn planes(format: &VideoFormat, width: usize, height: usize) -> ResultType<Vec<Plane>> {
+    let cw = width.div_ceil(2);
+    let ch = height.div_ceil(2);
+
+    // (bytes per line, lines) of each plane
+    let sizes: Vec<(usize, usize)> = match format {
+        VideoFormat::None => return Err("The video format must not be None".into()),
+        VideoFormat::I420 => vec![(width, height), (cw, ch), (cw, ch)],
+        VideoFormat::NV12 => vec![(width, height), (cw * 2, ch)],
+        VideoFormat::YVYU | VideoFormat::YUY2 | VideoFormat::UYVY => vec![(cw * 4, height)],
+        VideoFormat::RGBA | VideoFormat::BGRA | VideoFormat::AYUV => vec![(width * 4, height)],
+        VideoFormat::Y800 => vec![(width, height)],
+        VideoFormat::BGR3 => vec![(width * 3, height)],
+        VideoFormat::I444 => vec![(width, height); 3],
+        VideoFormat::I40A => vec![(width, height), (cw, ch), (cw, ch), (width, height)],
+        VideoFormat::I42A => vec![(width, height), (cw, height), (cw, height), (width, height)],
+        VideoFormat::YUVA => vec![(width, height); 4],
+        VideoFormat::I010 => vec![(width * 2, height), (cw * 2, ch), (cw * 2, ch)],
+        VideoFormat::P010 => vec![(width * 2, height), (cw * 4, ch)],
+        VideoFormat::I210 => vec![(width * 2, height), (cw * 2, height), (cw * 2, height)],
+        VideoFormat::I412 => vec![(width * 2, height); 3],
+        VideoFormat::YA2L => vec![(width * 2, height); 4],
+    };
+
+    let mut offset = 0;
+    Ok(sizes
+        .into_iter()
+        .map(|(linesize, lines)| {
+            let plane = Plane {
+                offset,
+                linesize,
+                lines,
+            };
+            offset += linesize * lines;
+            plane
+        })
+        .collect())
+}
+
+fn is_yuv(format: &VideoFormat) -> bool {
+    !matches!(
+        format,
+        VideoFormat::None
+            | VideoFormat::RGBA
+            | VideoFormat::BGRA
+            | VideoFormat::BGR3
+            | VideoFormat::Y800
+    )
+}
+
+/// Output a tightly packed video frame to an async video source.
+/// The frame data is copied by obs, so `data` may be freed afterwards.
+pub unsafe fn output_video(
+    source: *mut sys::obs_source_t,
+    data: &[u8],
+    info: &VideoFrameInfo,
+) -> ResultType<()> {
+    if let Some(capability) = info.format.capability() {
+        capability.require()?;
+    }
+
+    let planes = planes(&info.format, info.width as usize, info.height as usize)?;
+    if planes.len() > sys::MAX_AV_PLANES as usize {
+        return Err("The video format has too many planes".into());
+    }
+
+    let size: usize = planes.iter().map(|p| p.linesize * p.lines).sum();
+    if data.len() < size {
+        return Err(format!(
+            "The frame buffer is too small. Expected {} bytes, got {}",
+            size,
+            data.len()
+        )
+        .into());
+    }
+
+    let mut frame: sys::obs_source_frame = mem::zeroed();
+    for (i, plane) in planes.iter().enumerate() {
+        frame.data[i] = data.as_ptr().add(plane.offset) as *mut u8;
+        frame.linesize[i] = plane.linesize as u32;
+    }
+
+    frame.width = info.width;
+    frame.height = info.height;
+    frame.format = info.format.value();
+    frame.timestamp = match info.timestamp {
+        Some(timestamp) => {
+            u64::try_from(timestamp).map_err(|_| "The timestamp must not be negative")?
+        }
+        None => sys::obs_get_video_frame_time(),
+    };
+
+    if is_yuv(&info.format) {
+        let range = info.range.unwrap_or(VideoRange::Partial);
+        frame.full_range = matches!(range, VideoRange::Full);
+        sys::video_format_get_parameters(
+            info.colorspace.unwrap_or(VideoColorSpace::CS709).value(),
+            range.value(),
+            frame.color_matrix.as_mut_ptr(),
+            frame.color_range_min.as_mut_ptr(),
+            frame.color_range_max.as_mut_ptr(),
+        );
+    }
+
+    sys::obs_source_output_video(source, &frame);
+    Ok(())
+}
@@ -92,6 +92,88 @@ impl InnerObsProperty {
 
         Ok(items)
     }
+
+    pub fn int_range(&self) -> (i32, i32, i32) {
+        unsafe {
+            (
+                sys::obs_property_int_min(self.property),
+                sys::obs_property_int_max(self.property),
+                sys::obs_property_int_step(self.property),
+            )
+        }
+    }
+
+    pub fn float_range(&self) -> (f64, f64, f64) {
+        unsafe {
+            (
+                sys::obs_property_float_min(self.property),
+                sys::obs_property_float_max(self.property),
+                sys::obs_property_float_step(self.property),
+            )
+        }
+    }
+
+    pub fn get_list_format(&self) -> sys::obs_combo_format {
+        unsafe { sys::obs_property_list_format(self.property) }
+    }
+
+    pub fn is_list_editable(&self) -> bool {
+        unsafe {
+            sys::obs_property_list_type(self.property)
+                == sys::obs_combo_type_OBS_COMBO_TYPE_EDITABLE
+        }
+    }
+
+    pub fn get_list_values(&self) -> ResultType<Vec<(String, ListValue)>> {
+        let names = self.get_list_items()?;
+        let format = self.get_list_format();
+
+        Ok(names
+            .into_iter()
+            .enumerate()
+            .map(|(i, name)| {
+                let i = i as _;
+                let value = unsafe {
+                    match format {
+                        sys::obs_combo_format_OBS_COMBO_FORMAT_INT => {
+                            ListValue::Int(sys::obs_property_list_item_int(self.property, i))
+                        }
+                        sys::obs_combo_format_OBS_COMBO_FORMAT_FLOAT => {
+                            ListValue::Float(sys::obs_property_list_item_float(self.property, i))
+                        }
+                        _ => {
+                            let value = sys::obs_property_list_item_string(self.property, i);
+                            if value.is_null() {
+                                ListValue::String(String::new())
+                            } else {
+                                ListValue::String(
+                                    CStr::from_ptr(value).to_string_lossy().into_owned(),
+                                )
+                            }
+                        }
+                    }
+                };
+
+                (name, value)
+            })
+            .collect())
+    }
+
+    pub fn group_type(&self) -> sys::obs_group_type {
+        unsafe { sys::obs_property_group_type(self.property) }
+    }
+
+    pub fn group_content(&self) -> *mut sys::obs_properties_t {
+        unsafe { sys::obs_property_group_content(self.property) }
+    }
+}
+
+/// The value of a list property item.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ListValue {
+    Int(i64),
+    Float(f64),
+    String(String),
 }
 
 impl FromRaw<sys::obs_property_t> for InnerObsProperty {
@@ -1,16 +1,18 @@
+use crate::obs::data::module_types::ModuleTypes;
 use crate::obs::sys;
-use crate::obs::util::napi_error::to_napi_error_str;
-use std::ffi::CStr;
+use crate::obs::util::c_string;
+use crate::obs::util::types::ResultType;
+use libloading::Library;
 
 extern "C" fn enum_module(param: *mut std::os::raw::c_void, data: *mut sys::obs_module_t) {
-    let modules = unsafe { &mut *(param as *mut Vec<napi::Result<LoadedObsModule>>) };
+    let modules = unsafe { &mut *(param as *mut Vec<ResultType<LoadedObsModule>>) };
     let module = LoadedObsModule::new(data);
 
     modules.push(module);
 }
 
 /// A loaded OBS module.
-#[napi(object)]
+#[cfg_attr(feature = "napi", napi(object))]
 pub struct LoadedObsModule {
     pub name: Option<String>,
     pub file_name: String,
@@ -18,10 +20,20 @@ pub struct LoadedObsModule {
     pub author: Option<String>,
     pub binary_path: String,
     pub data_path: String,
+    /// The locale used by the module, which is the locale of obs.
+    pub locale: Option<String>,
+    /// The directory the module stores its configuration in,
+    /// `None` if obs was started without a module config path.
+    pub config_path: Option<String>,
+    /// The libobs api version the module was built against, e.g. `30.0.0`.
+    pub api_version: Option<String>,
+    /// The types registered by the module.
+    /// Only known for modules loaded using `loadModules`.
+    pub types: Option<ModuleTypes>,
 }
 
 impl LoadedObsModule {
-    fn new(module: *mut sys::obs_module_t) -> napi::Result<Self> {
+    fn new(module: *mut sys::obs_module_t) -> ResultType<Self> {
         let name = unsafe { sys::obs_get_module_name(module) };
         let file_name = unsafe { sys::obs_get_module_file_name(module) };
         let description = unsafe { sys::obs_get_module_description(module) };
@@ -29,29 +41,39 @@ impl LoadedObsModule {
         let binary_path = unsafe { sys::obs_get_module_binary_path(module) };
         let data_path = unsafe { sys::obs_get_module_data_path(module) };
 
-        let to_string = |ptr: *const i8| {
-            if ptr.is_null() {
-                None
-            } else {
-                Some(unsafe { CStr::from_ptr(ptr) }.to_string_lossy().to_string())
+        // All of the strings are owned by libobs.
+        let to_string = |ptr| unsafe { c_string::to_string(ptr) };
+
+        let locale = unsafe { sys::obs_get_locale() };
+        let config_path = unsafe {
+            let path = sys::obs_module_get_config_path(module, c"".as_ptr());
+            let res = to_string(path);
+            if !path.is_null() {
+                sys::bfree(path as *mut _);
             }
+
+            // Without a module config path libobs only returns "<module>/".
+            res.filter(|path| path.trim_end_matches('/').contains('/'))
         };
 
         Ok(Self {
             name: to_string(name),
-            file_name: to_string(file_name)
-                .ok_or(to_napi_error_str("Could not get the module file name"))?,
+            file_name: to_string(file_name).ok_or("Could not get the module file name")?,
             description: to_string(description),
             author: to_string(author),
-            binary_path: to_string(binary_path)
-                .ok_or(to_napi_error_str("Could not get the module binary path"))?,
-            data_path: to_string(data_path)
-                .ok_or(to_napi_error_str("Could not get the module data path"))?,
+            binary_path: to_string(binary_path).ok_or("Could not get the module binary path")?,
+            data_path: to_string(data_path).ok_or("Could not get the module data path")?,
+            locale: to_string(locale),
+            config_path,
+            api_version: to_string(binary_path)
+                .and_then(|path| module_api_version(&path))
+                .map(sys::version_to_string),
+            types: None,
         })
     }
 
-    pub fn list_loaded_modules() -> napi::Result<Vec<Self>> {
-        let mut modules = Vec::<napi::Result<Self>>::new();
+    pub fn list_loaded_modules() -> ResultType<Vec<Self>> {
+        let mut modules = Vec::<ResultType<Self>>::new();
         unsafe {
             sys::obs_enum_modules(Some(enum_module), &mut modules as *mut _ as *mut _);
         }
@@ -59,3 +81,13 @@ impl LoadedObsModule {
         modules.into_iter().collect::<_>()
     }
 }
+
+/// Get the libobs api version a module binary was built against.
+/// The module is already loaded, so this only increases its reference count.
+fn module_api_version(binary_path: &str) -> Option<u32> {
+    let library = unsafe { Library::new(binary_path) }.ok()?;
+    let module_ver =
+        unsafe { library.get::<unsafe extern "C" fn() -> u32>(b"obs_module_ver\0") }.ok()?;
+
+    Some(unsafe { module_ver() })
+}
@@ -1,8 +1,14 @@
 pub(crate) mod inner_obs_property;
 pub(crate) mod loaded_obs_module;
+pub(crate) mod module_types;
 pub(crate) mod obs_data;
+pub(crate) mod obs_encoder_type;
 pub(crate) mod obs_module;
+pub(crate) mod obs_output_type;
 pub(crate) mod obs_properties;
 pub(crate) mod obs_property;
 pub(crate) mod obs_property_type;
 pub(crate) mod obs_settings;
+pub(crate) mod profiler_snapshot;
+pub(crate) mod properties_schema;
+pub(crate) mod settings_validation;
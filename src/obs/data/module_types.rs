@@ -0,0 +1,79 @@
+use crate::obs::sys;
+use serde::{Deserialize, Serialize};
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::ptr;
+
+/// The ids of the types registered by a module.
+#[cfg_attr(feature = "napi", napi(object))]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ModuleTypes {
+    /// The ids of the source types, including filters and transitions.
+    pub sources: Vec<String>,
+    /// The ids of the encoder types.
+    pub encoders: Vec<String>,
+    /// The ids of the output types.
+    pub outputs: Vec<String>,
+    /// The ids of the service types.
+    pub services: Vec<String>,
+}
+
+impl ModuleTypes {
+    /// Get the ids of all registered types.
+    pub fn registered() -> Self {
+        unsafe {
+            Self {
+                sources: enum_types(|i, id| sys::obs_enum_source_types(i, id)),
+                encoders: enum_types(|i, id| sys::obs_enum_encoder_types(i, id)),
+                outputs: enum_types(|i, id| sys::obs_enum_output_types(i, id)),
+                services: enum_types(|i, id| sys::obs_enum_service_types(i, id)),
+            }
+        }
+    }
+
+    /// Get the types which are in this list but not in `before`.
+    pub fn added_since(&self, before: &Self) -> Self {
+        let added = |now: &[String], before: &[String]| {
+            now.iter()
+                .filter(|id| !before.contains(id))
+                .cloned()
+                .collect()
+        };
+
+        Self {
+            sources: added(&self.sources, &before.sources),
+            encoders: added(&self.encoders, &before.encoders),
+            outputs: added(&self.outputs, &before.outputs),
+            services: added(&self.services, &before.services),
+        }
+    }
+
+    /// Check if a type with the given id is in this list.
+    pub fn contains(&self, id: &str) -> bool {
+        [&self.sources, &self.encoders, &self.outputs, &self.services]
+            .iter()
+            .any(|types| types.iter().any(|type_id| type_id == id))
+    }
+}
+
+/// Collect the ids returned by one of the `obs_enum_*_types` functions.
+pub(crate) unsafe fn enum_types<F>(enum_fn: F) -> Vec<String>
+where
+    F: Fn(usize, *mut *const c_char) -> bool,
+{
+    let mut ok = true;
+    let mut i: usize = 0;
+    let mut res = vec![];
+
+    while ok {
+        let mut ptr: *const c_char = ptr::null();
+        ok = enum_fn(i, &mut ptr);
+        i += 1;
+
+        if ok && !ptr.is_null() {
+            res.push(CStr::from_ptr(ptr).to_string_lossy().to_string());
+        }
+    }
+
+    res
+}
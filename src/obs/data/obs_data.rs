@@ -39,6 +39,16 @@ impl Raw<sys::obs_data_t> for ObsData {
     }
 }
 
+impl Clone for ObsData {
+    /// Create another reference to the same data.
+    fn clone(&self) -> Self {
+        unsafe {
+            sys::obs_data_addref(self.raw());
+            Self::from_raw_unchecked(self.raw(), self._guard.clone())
+        }
+    }
+}
+
 impl Drop for ObsData {
     fn drop(&mut self) {
         unsafe {
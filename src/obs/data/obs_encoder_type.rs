@@ -0,0 +1,137 @@
+use crate::obs::data::obs_properties::ObsProperties;
+use crate::obs::data::obs_settings::ObsSettings;
+use crate::obs::sys;
+use crate::obs::traits::from_raw::FromRaw;
+use crate::obs::util::c_string::to_string;
+use crate::obs::util::obs_error::ObsError;
+use crate::obs::util::obs_guard::ObsGuard;
+use crate::obs::util::types::ResultType;
+use std::ffi::CString;
+use std::sync::Arc;
+
+/// The kind of data an encoder type encodes.
+#[cfg_attr(feature = "napi", napi)]
+#[cfg_attr(not(feature = "napi"), derive(Clone, Copy))]
+pub enum EncoderKind {
+    Audio,
+    Video,
+}
+
+impl EncoderKind {
+    pub(crate) fn from_value(value: sys::obs_encoder_type) -> ResultType<Self> {
+        match value {
+            sys::obs_encoder_type_OBS_ENCODER_AUDIO => Ok(EncoderKind::Audio),
+            sys::obs_encoder_type_OBS_ENCODER_VIDEO => Ok(EncoderKind::Video),
+            _ => Err("Unknown encoder type".into()),
+        }
+    }
+}
+
+/// The capabilities of an encoder type.
+#[cfg_attr(feature = "napi", napi(object))]
+pub struct EncoderCaps {
+    /// Whether the encoder type is deprecated.
+    pub deprecated: bool,
+    /// Whether the encoder can encode textures directly.
+    pub pass_texture: bool,
+    /// Whether the bitrate can be changed while the encoder is active.
+    pub dyn_bitrate: bool,
+    /// Whether the encoder type is internal and should not be shown to users.
+    pub internal: bool,
+}
+
+/// Information about a registered encoder type.
+/// No encoder is created to retrieve this information.
+///
+/// # Example
+/// ```ignore
+/// let nvenc = obs.get_encoder_type("jim_nvenc")?;
+/// println!("{:?} {}", nvenc.get_codec()?, nvenc.get_caps()?.deprecated);
+///
+/// let defaults = nvenc.get_defaults()?;
+/// let properties = nvenc.get_properties()?;
+/// ```
+pub struct ObsEncoderType {
+    id: String,
+    guard: Arc<ObsGuard>,
+}
+
+impl ObsEncoderType {
+    pub(crate) fn new(id: String, guard: Arc<ObsGuard>) -> ResultType<Self> {
+        let c_id = CString::new(id.as_str())?;
+
+        // Unknown encoder ids don't have a codec
+        if unsafe { sys::obs_get_encoder_codec(c_id.as_ptr()) }.is_null() {
+            Err(ObsError::UnknownType {
+                kind: "encoder",
+                id,
+            })
+        } else {
+            Ok(Self { id, guard })
+        }
+    }
+
+    /// The id of the encoder type.
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+
+    /// The codec this encoder produces, e.g. `h264` or `aac`.
+    pub fn get_codec(&self) -> ResultType<Option<String>> {
+        let id = CString::new(self.id.as_str())?;
+        Ok(unsafe { to_string(sys::obs_get_encoder_codec(id.as_ptr())) })
+    }
+
+    /// Whether this is an audio or a video encoder.
+    pub fn get_encoder_kind(&self) -> ResultType<EncoderKind> {
+        let id = CString::new(self.id.as_str())?;
+
+        EncoderKind::from_value(unsafe { sys::obs_get_encoder_type(id.as_ptr()) })
+    }
+
+    /// The capabilities of this encoder type.
+    pub fn get_caps(&self) -> ResultType<EncoderCaps> {
+        let id = CString::new(self.id.as_str())?;
+        let caps = unsafe { sys::obs_get_encoder_caps(id.as_ptr()) };
+
+        Ok(EncoderCaps {
+            deprecated: caps & sys::OBS_ENCODER_CAP_DEPRECATED != 0,
+            pass_texture: caps & sys::OBS_ENCODER_CAP_PASS_TEXTURE != 0,
+            dyn_bitrate: caps & sys::OBS_ENCODER_CAP_DYN_BITRATE != 0,
+            internal: caps & sys::OBS_ENCODER_CAP_INTERNAL != 0,
+        })
+    }
+
+    /// The localized display name of this encoder type.
+    pub fn get_display_name(&self) -> ResultType<Option<String>> {
+        let id = CString::new(self.id.as_str())?;
+        Ok(unsafe { to_string(sys::obs_encoder_get_display_name(id.as_ptr())) })
+    }
+
+    /// Get the default settings of this encoder type.
+    pub fn get_defaults(&self) -> ResultType<ObsSettings> {
+        let id = CString::new(self.id.as_str())?;
+        let settings = unsafe { sys::obs_encoder_defaults(id.as_ptr()) };
+
+        if settings.is_null() {
+            Err("Failed to get encoder defaults".into())
+        } else {
+            Ok(ObsSettings::from_raw(settings, Some(self.guard.clone())))
+        }
+    }
+
+    /// Get the properties of this encoder type.
+    pub fn get_properties(&self) -> ResultType<ObsProperties> {
+        let id = CString::new(self.id.as_str())?;
+        let properties = unsafe { sys::obs_get_encoder_properties(id.as_ptr()) };
+
+        if properties.is_null() {
+            Err("Failed to get encoder properties".into())
+        } else {
+            Ok(ObsProperties::from_raw(
+                properties,
+                Some(self.guard.clone()),
+            ))
+        }
+    }
+}
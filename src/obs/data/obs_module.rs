@@ -1,7 +1,11 @@
+use crate::obs::data::module_types::ModuleTypes;
+use crate::obs::objects::module_load_result::{ModuleLoadResult, ModuleLoadStatus};
+use crate::obs::objects::obs_version::Capability;
+use crate::obs::plugin::log_error;
 use crate::obs::sys;
-use crate::obs::util::napi_error::to_napi_error_str;
 use crate::obs::util::obs_error::{module_error_to_string, OBS_MODULE_SUCCESS};
 use crate::obs::util::types::ResultType;
+use serde::{Deserialize, Serialize};
 use std::ffi::{CStr, CString};
 use std::path::Path;
 use std::ptr;
@@ -22,13 +26,25 @@ extern "C" fn get_module(param: *mut std::ffi::c_void, info: *const sys::obs_mod
             }
         };
 
-        println!("Failed to get info for module '{}'", name);
+        log_error(format!("Failed to get info for module '{}'", name));
+    }
+}
+
+/// Used on libobs versions without `obs_find_modules2`,
+/// which do not report the module names.
+extern "C" fn get_module_legacy(param: *mut std::ffi::c_void, info: *const sys::obs_module_info) {
+    let modules = unsafe { &mut *(param as *mut Vec<ObsModule>) };
+
+    match unsafe { ObsModule::from_legacy(&*info) } {
+        Ok(module) => modules.push(module),
+        Err(e) => log_error(format!("Failed to get info for module: {}", e)),
     }
 }
 
 /// An obs module.
-#[napi(object)]
-#[derive(Debug, Clone)]
+#[cfg_attr(feature = "napi", napi(object))]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ObsModule {
     /// The name of the module.
     pub name: String,
@@ -50,25 +66,29 @@ mod separators {
 }
 
 impl ObsModule {
-    pub fn get_all_modules(obs_path: String) -> napi::Result<Vec<ObsModule>> {
+    pub fn get_all_modules(obs_path: &str) -> ResultType<Vec<ObsModule>> {
         let mut modules = Vec::new();
         unsafe {
-            let path = Path::new(obs_path.as_str());
+            let path = Path::new(obs_path);
             let bin = CString::new(
                 path.join("obs-plugins")
                     .join("64bit")
                     .to_str()
-                    .ok_or(to_napi_error_str("Invalid path"))?,
+                    .ok_or("Invalid path")?,
             )?;
             let data = CString::new(
                 path.join("data")
                     .join("obs-plugins")
                     .to_str()
-                    .ok_or(to_napi_error_str("Invalid path"))?,
+                    .ok_or("Invalid path")?,
             )?;
 
             sys::obs_add_module_path(bin.as_ptr(), data.as_ptr());
-            sys::obs_find_modules2(Some(get_module), &mut modules as *mut _ as *mut _);
+            if Capability::FindModules2.is_available() {
+                sys::obs_find_modules2(Some(get_module), &mut modules as *mut _ as *mut _);
+            } else {
+                sys::obs_find_modules(Some(get_module_legacy), &mut modules as *mut _ as *mut _);
+            }
         }
 
         Ok(modules)
@@ -94,31 +114,64 @@ impl ObsModule {
         })
     }
 
-    pub fn load(&self) -> ResultType<()> {
+    /// Create a module from the info reported by `obs_find_modules`.
+    /// The name is derived from the file name of the module binary.
+    unsafe fn from_legacy(info: &sys::obs_module_info) -> ResultType<Self> {
+        if info.bin_path.is_null() || info.data_path.is_null() {
+            return Err("Invalid module info".into());
+        }
+
+        let bin_path = CStr::from_ptr(info.bin_path)
+            .to_str()?
+            .replace(separators::SEP_TO_REPLACE, separators::PATH_SEP);
+        let data_path = CStr::from_ptr(info.data_path)
+            .to_str()?
+            .replace(separators::SEP_TO_REPLACE, separators::PATH_SEP);
+        let name = Path::new(&bin_path)
+            .file_stem()
+            .map(|name| name.to_string_lossy().to_string())
+            .ok_or("Invalid module binary path")?;
+
+        Ok(Self {
+            name,
+            bin_path,
+            data_path,
+        })
+    }
+
+    /// Open and initialize the module.
+    /// The types registered by the module while it is initialized are recorded.
+    pub fn load(&self) -> ModuleLoadResult {
+        match self.open_and_init() {
+            Ok((status, types)) => ModuleLoadResult {
+                types,
+                ..ModuleLoadResult::new(self.clone(), status)
+            },
+            Err((code, error)) => ModuleLoadResult::failed(self.clone(), code, error),
+        }
+    }
+
+    fn open_and_init(
+        &self,
+    ) -> Result<(ModuleLoadStatus, Option<ModuleTypes>), (Option<i32>, String)> {
         let mut module: *mut sys::obs_module_t = ptr::null_mut();
-        let path = CString::new(self.bin_path.as_str())?;
-        let data = CString::new(self.data_path.as_str())?;
+        let path = CString::new(self.bin_path.as_str()).map_err(|e| (None, e.to_string()))?;
+        let data = CString::new(self.data_path.as_str()).map_err(|e| (None, e.to_string()))?;
 
         let open_res = unsafe { sys::obs_open_module(&mut module, path.as_ptr(), data.as_ptr()) };
 
-        if (open_res != OBS_MODULE_SUCCESS || module.is_null())
-            && open_res != sys::MODULE_HARDCODED_SKIP
-        {
-            return Err(format!(
-                "Failed to load module '{}': {}",
-                self.name,
-                module_error_to_string(open_res)
-            )
-            .into());
-        } else if open_res == sys::MODULE_HARDCODED_SKIP {
-            println!("Module {} is hardcoded to be skipped", self.name);
-            return Ok(());
+        if open_res == sys::MODULE_HARDCODED_SKIP {
+            return Ok((ModuleLoadStatus::Skipped, None));
+        } else if open_res != OBS_MODULE_SUCCESS || module.is_null() {
+            return Err((Some(open_res), module_error_to_string(open_res).to_string()));
         }
 
+        let before = ModuleTypes::registered();
         if unsafe { sys::obs_init_module(module) } {
-            Ok(())
+            let types = ModuleTypes::registered().added_since(&before);
+            Ok((ModuleLoadStatus::Loaded, Some(types)))
         } else {
-            Err(format!("Failed to init module '{}'", self.name).into())
+            Err((None, "Failed to initialize the module".to_string()))
         }
     }
 }
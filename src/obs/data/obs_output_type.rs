@@ -0,0 +1,137 @@
+use crate::obs::data::obs_properties::ObsProperties;
+use crate::obs::data::obs_settings::ObsSettings;
+use crate::obs::sys;
+use crate::obs::traits::from_raw::FromRaw;
+use crate::obs::util::c_string::to_string;
+use crate::obs::util::obs_error::ObsError;
+use crate::obs::util::obs_guard::ObsGuard;
+use crate::obs::util::types::ResultType;
+use std::ffi::{CStr, CString};
+use std::sync::Arc;
+
+/// The flags of an output type.
+#[cfg_attr(feature = "napi", napi(object))]
+pub struct OutputFlags {
+    /// Whether the output uses video.
+    pub video: bool,
+    /// Whether the output uses audio.
+    pub audio: bool,
+    /// Whether the output requires encoders.
+    pub encoded: bool,
+    /// Whether the output requires a service.
+    pub service: bool,
+    /// Whether the output supports multiple audio tracks.
+    pub multi_track: bool,
+    /// Whether the output can be paused.
+    pub can_pause: bool,
+}
+
+/// Information about a registered output type.
+/// No output is created to retrieve this information.
+///
+/// # Example
+/// ```ignore
+/// let flv = obs.get_output_type("flv_output")?;
+/// println!("{} {:?}", flv.get_flags()?.encoded, flv.get_supported_video_codecs()?);
+/// ```
+pub struct ObsOutputType {
+    id: String,
+    guard: Arc<ObsGuard>,
+}
+
+impl ObsOutputType {
+    pub(crate) fn new(id: String, guard: Arc<ObsGuard>) -> ResultType<Self> {
+        let c_id = CString::new(id.as_str())?;
+
+        // Unknown output ids don't have a display name
+        if unsafe { sys::obs_output_get_display_name(c_id.as_ptr()) }.is_null() {
+            Err(ObsError::UnknownType { kind: "output", id })
+        } else {
+            Ok(Self { id, guard })
+        }
+    }
+
+    /// The id of the output type.
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+
+    /// The flags of this output type.
+    pub fn get_flags(&self) -> ResultType<OutputFlags> {
+        let id = CString::new(self.id.as_str())?;
+        let flags = unsafe { sys::obs_get_output_flags(id.as_ptr()) };
+
+        Ok(OutputFlags {
+            video: flags & sys::OBS_OUTPUT_VIDEO != 0,
+            audio: flags & sys::OBS_OUTPUT_AUDIO != 0,
+            encoded: flags & sys::OBS_OUTPUT_ENCODED != 0,
+            service: flags & sys::OBS_OUTPUT_SERVICE != 0,
+            multi_track: flags & sys::OBS_OUTPUT_MULTI_TRACK != 0,
+            can_pause: flags & sys::OBS_OUTPUT_CAN_PAUSE != 0,
+        })
+    }
+
+    /// The video codecs supported by this output type.
+    /// Empty if the output type does not restrict the video codecs.
+    pub fn get_supported_video_codecs(&self) -> ResultType<Vec<String>> {
+        let id = CString::new(self.id.as_str())?;
+        Ok(split_codecs(unsafe {
+            sys::obs_get_output_supported_video_codecs(id.as_ptr())
+        }))
+    }
+
+    /// The audio codecs supported by this output type.
+    /// Empty if the output type does not restrict the audio codecs.
+    pub fn get_supported_audio_codecs(&self) -> ResultType<Vec<String>> {
+        let id = CString::new(self.id.as_str())?;
+        Ok(split_codecs(unsafe {
+            sys::obs_get_output_supported_audio_codecs(id.as_ptr())
+        }))
+    }
+
+    /// The localized display name of this output type.
+    pub fn get_display_name(&self) -> ResultType<Option<String>> {
+        let id = CString::new(self.id.as_str())?;
+        Ok(unsafe { to_string(sys::obs_output_get_display_name(id.as_ptr())) })
+    }
+
+    /// Get the default settings of this output type.
+    pub fn get_defaults(&self) -> ResultType<ObsSettings> {
+        let id = CString::new(self.id.as_str())?;
+        let settings = unsafe { sys::obs_output_defaults(id.as_ptr()) };
+
+        if settings.is_null() {
+            Err("Failed to get output defaults".into())
+        } else {
+            Ok(ObsSettings::from_raw(settings, Some(self.guard.clone())))
+        }
+    }
+
+    /// Get the properties of this output type.
+    pub fn get_properties(&self) -> ResultType<ObsProperties> {
+        let id = CString::new(self.id.as_str())?;
+        let properties = unsafe { sys::obs_get_output_properties(id.as_ptr()) };
+
+        if properties.is_null() {
+            Err("Failed to get output properties".into())
+        } else {
+            Ok(ObsProperties::from_raw(
+                properties,
+                Some(self.guard.clone()),
+            ))
+        }
+    }
+}
+
+fn split_codecs(codecs: *const std::os::raw::c_char) -> Vec<String> {
+    if codecs.is_null() {
+        return vec![];
+    }
+
+    unsafe { CStr::from_ptr(codecs) }
+        .to_string_lossy()
+        .split(';')
+        .filter(|c| !c.is_empty())
+        .map(|c| c.to_string())
+        .collect()
+}
@@ -1,22 +1,25 @@
 use crate::obs::data::obs_property::ObsProperty;
+use crate::obs::data::obs_settings::ObsSettings;
+use crate::obs::data::properties_schema::PropertiesSchema;
+use crate::obs::data::settings_validation::SettingsIssue;
 use crate::obs::sys;
 use crate::obs::traits::from_raw::{FromRaw, Guard};
+use crate::obs::traits::raw::Raw;
+use crate::obs::util::obs_error::ObsError;
 use crate::obs::util::obs_guard::ObsGuard;
+use crate::obs::util::types::ResultType;
 use std::ffi::CString;
 use std::sync::Arc;
 
 /// An obs properties object.
-#[napi]
 pub struct ObsProperties {
     properties: *mut sys::obs_properties_t,
     guard: Arc<ObsGuard>,
 }
 
-#[napi]
 impl ObsProperties {
     /// Get a property by its name.
-    #[napi]
-    pub fn get_property(&self, name: String) -> Option<ObsProperty> {
+    pub fn get_property(&self, name: &str) -> Option<ObsProperty> {
         let name = CString::new(name).ok()?;
         let property = unsafe { sys::obs_properties_get(self.properties, name.as_ptr()) };
 
@@ -28,7 +31,6 @@ impl ObsProperties {
     }
 
     /// Get a list of all properties stored in this object.
-    #[napi]
     pub fn list_properties(&self) -> Vec<ObsProperty> {
         let mut properties = Vec::new();
         let mut property = unsafe { sys::obs_properties_first(self.properties) };
@@ -40,6 +42,64 @@ impl ObsProperties {
 
         properties
     }
+
+    /// Generate a JSON schema describing the settings these properties edit.
+    /// The title defaults to `Settings`.
+    pub fn to_json_schema(
+        &self,
+        title: Option<&str>,
+        defaults: Option<&ObsSettings>,
+    ) -> ResultType<String> {
+        let schema = self.schema(defaults)?;
+        Ok(schema
+            .to_json_schema(title.unwrap_or("Settings"))
+            .to_string())
+    }
+
+    /// Generate a TypeScript interface describing the settings these properties edit.
+    pub fn to_type_definition(
+        &self,
+        name: &str,
+        defaults: Option<&ObsSettings>,
+    ) -> ResultType<String> {
+        Ok(self.schema(defaults)?.to_type_definition(name))
+    }
+
+    /// Validate settings against these properties.
+    /// Checks for unknown keys, type mismatches, numbers out of range
+    /// and values which are not part of a list property.
+    /// Returns an empty list if the settings are valid.
+    pub fn validate(&self, settings: &ObsSettings) -> ResultType<Vec<SettingsIssue>> {
+        Ok(unsafe { self.schema(None)?.validate(settings.raw()) })
+    }
+
+    /// Validate settings and return an error containing
+    /// all issues if the settings are not valid.
+    pub fn validate_strict(&self, settings: &ObsSettings) -> ResultType<()> {
+        let issues = self.validate(settings)?;
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(ObsError::InvalidSettings(issues))
+        }
+    }
+
+    pub(crate) fn schema(&self, defaults: Option<&ObsSettings>) -> ResultType<PropertiesSchema> {
+        unsafe {
+            PropertiesSchema::from_raw(
+                self.properties,
+                defaults.map(|d| d.raw()),
+                self.guard.clone(),
+            )
+        }
+    }
+}
+
+impl Raw<sys::obs_properties_t> for ObsProperties {
+    unsafe fn raw(&self) -> *mut sys::obs_properties_t {
+        self.properties
+    }
 }
 
 impl FromRaw<sys::obs_properties_t> for ObsProperties {
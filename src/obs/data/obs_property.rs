@@ -3,7 +3,7 @@ use crate::obs::sys;
 use crate::obs::traits::from_raw::{FromRaw, Guard};
 
 /// An obs property
-#[napi(object)]
+#[cfg_attr(feature = "napi", napi(object))]
 pub struct ObsProperty {
     /// The name of the property.
     pub name: Option<String>,
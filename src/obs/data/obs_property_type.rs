@@ -1,7 +1,7 @@
 use crate::obs::data::inner_obs_property::InnerObsProperty;
 use crate::obs::sys;
+use crate::obs::util::obs_error::ObsError;
 use crate::obs::util::types::ResultType;
-use std::error::Error;
 
 #[derive(Debug, Clone, PartialEq, AsRefStr)]
 pub enum ObsPropertyType {
@@ -23,7 +23,7 @@ pub enum ObsPropertyType {
 }
 
 impl TryFrom<&InnerObsProperty> for ObsPropertyType {
-    type Error = Box<dyn Error>;
+    type Error = ObsError;
 
     fn try_from(value: &InnerObsProperty) -> ResultType<Self> {
         Ok(match value.get_property_type() {
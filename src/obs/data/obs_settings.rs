@@ -2,93 +2,52 @@ use crate::obs::data::obs_data::ObsData;
 use crate::obs::sys;
 use crate::obs::traits::from_raw::{FromRaw, Guard};
 use crate::obs::traits::raw::Raw;
-use crate::obs::util::napi_error::{to_napi_error_str, to_napi_error_string};
-use crate::obs::util::node_util::is_integer;
+use crate::obs::util::obs_error::ObsError;
+use crate::obs::util::types::ResultType;
 use core::fmt::Display;
-use napi::{Env, JsObject, JsString, JsUnknown, ValueType};
 use std::ffi::{CStr, CString};
 
 /// A wrapper around obs settings.
 ///
-/// # Examples
-/// ```ts
-/// const settings = new ObsSettings({
-///     rate_control: 'CQP',
-///     cqp: 23,
-///     preset: 'medium',
-///     profile: 'high',
-/// });
+/// # Example
+/// ```ignore
+/// let mut settings = ObsSettings::new()?;
+/// settings
+///     .set_string("rate_control", "CQP")?
+///     .set_int("cqp", 23)?
+///     .set_string("preset", "medium")?;
 /// ```
-/// or
-/// ```ts
-/// const settings = new ObsSettings()
-///   .setString('rate_control', 'CQP')
-///   .setInt('cqp', 23)
-///   .setString('preset', 'medium')
-///   .setString('profile', 'high');
-/// ```
-#[napi]
+///
+/// Cloning the settings creates another reference to the same settings.
+#[derive(Clone)]
 pub struct ObsSettings(ObsData);
 
-#[napi]
 impl ObsSettings {
-    /// Create a new settings object.
-    /// You can either define the settings in the constructor or use the `set_*` methods.
-    ///
-    /// @param data - The settings to use.
-    #[napi(constructor)]
-    pub fn new(
-        env: Env,
-        #[napi(ts_arg_type = "Record<string, string | number | boolean> | null")] data: Option<
-            JsObject,
-        >,
-    ) -> napi::Result<Self> {
+    /// Create a new, empty settings object.
+    pub fn new() -> ResultType<Self> {
         let settings = unsafe { sys::obs_data_create() };
 
         if settings.is_null() {
-            Err(to_napi_error_str("Failed to create settings"))
+            Err(ObsError::CreateFailed("settings"))
         } else {
-            let mut res = Self(ObsData::from_raw(settings, None));
-            if let Some(data) = data {
-                let keys = data.get_property_names()?;
-                for i in 0..keys.get_array_length()? {
-                    let key: JsString = keys.get_element(i)?;
-                    let key = key.into_utf16()?.as_str()?;
-
-                    let value: JsUnknown = data.get_named_property(&key)?;
-                    match value.get_type()? {
-                        ValueType::String => {
-                            let value: JsString = value.coerce_to_string()?;
-                            let value = value.into_utf16()?.as_str()?;
-                            res.set_string(key, value)?;
-                        }
-                        ValueType::Number => {
-                            let value = value.coerce_to_number()?;
-
-                            if is_integer(&env, &value)? {
-                                res.set_int(key, value.try_into()?)?;
-                            } else {
-                                res.set_double(key, value.try_into()?)?;
-                            }
-                        }
-                        ValueType::Boolean => {
-                            let value = value.coerce_to_bool()?;
-                            res.set_bool(key, value.get_value()?)?;
-                        }
-                        _ => {
-                            return Err(to_napi_error_str("Unsupported value type"));
-                        }
-                    }
-                }
-            }
-
-            Ok(res)
+            Ok(Self(ObsData::from_raw(settings, None)))
+        }
+    }
+
+    /// Create a settings object from a JSON string.
+    pub fn from_json(json: &str) -> ResultType<Self> {
+        let json = CString::new(json)?;
+        let settings = unsafe { sys::obs_data_create_from_json(json.as_ptr()) };
+
+        if settings.is_null() {
+            Err("Failed to parse the settings".into())
+        } else {
+            Ok(Self(ObsData::from_raw(settings, None)))
         }
     }
 
     /// Set a string value.
-    #[napi]
-    pub fn set_string(&mut self, name: String, value: String) -> napi::Result<&Self> {
+    pub fn set_string(&mut self, name: &str, value: &str) -> ResultType<&mut Self> {
         let name = CString::new(name)?;
         let value = CString::new(value)?;
 
@@ -100,26 +59,21 @@ impl ObsSettings {
     }
 
     /// Get a string value.
-    #[napi]
-    pub fn get_string(&self, name: String) -> napi::Result<String> {
+    pub fn get_string(&self, name: &str) -> ResultType<String> {
         let name = CString::new(name)?;
 
         let value = unsafe { sys::obs_data_get_string(self.0.raw(), name.as_ptr()) };
 
         if value.is_null() {
-            Err(to_napi_error_str("Failed to get string"))
+            Err("Failed to get string".into())
         } else {
             let value = unsafe { CStr::from_ptr(value) };
-            let value = value
-                .to_str()
-                .map_err(|e| to_napi_error_string(e.to_string()))?;
-            Ok(value.to_string())
+            Ok(value.to_str()?.to_string())
         }
     }
 
     /// Set an integer value.
-    #[napi]
-    pub fn set_int(&mut self, name: String, value: i64) -> napi::Result<&Self> {
+    pub fn set_int(&mut self, name: &str, value: i64) -> ResultType<&mut Self> {
         let name = CString::new(name)?;
 
         unsafe {
@@ -130,18 +84,14 @@ impl ObsSettings {
     }
 
     /// Get an integer value.
-    #[napi]
-    pub fn get_int(&self, name: String) -> napi::Result<i64> {
+    pub fn get_int(&self, name: &str) -> ResultType<i64> {
         let name = CString::new(name)?;
 
-        let value = unsafe { sys::obs_data_get_int(self.0.raw(), name.as_ptr()) };
-
-        Ok(value)
+        Ok(unsafe { sys::obs_data_get_int(self.0.raw(), name.as_ptr()) })
     }
 
     /// Set a double value.
-    #[napi]
-    pub fn set_double(&mut self, name: String, value: f64) -> napi::Result<&Self> {
+    pub fn set_double(&mut self, name: &str, value: f64) -> ResultType<&mut Self> {
         let name = CString::new(name)?;
 
         unsafe {
@@ -152,18 +102,14 @@ impl ObsSettings {
     }
 
     /// Get a double value.
-    #[napi]
-    pub fn get_double(&self, name: String) -> napi::Result<f64> {
+    pub fn get_double(&self, name: &str) -> ResultType<f64> {
         let name = CString::new(name)?;
 
-        let value = unsafe { sys::obs_data_get_double(self.0.raw(), name.as_ptr()) };
-
-        Ok(value)
+        Ok(unsafe { sys::obs_data_get_double(self.0.raw(), name.as_ptr()) })
     }
 
     /// Set a boolean value.
-    #[napi]
-    pub fn set_bool(&mut self, name: String, value: bool) -> napi::Result<&Self> {
+    pub fn set_bool(&mut self, name: &str, value: bool) -> ResultType<&mut Self> {
         let name = CString::new(name)?;
 
         unsafe {
@@ -174,13 +120,15 @@ impl ObsSettings {
     }
 
     /// Get a boolean value.
-    #[napi]
-    pub fn get_bool(&self, name: String) -> napi::Result<bool> {
+    pub fn get_bool(&self, name: &str) -> ResultType<bool> {
         let name = CString::new(name)?;
 
-        let value = unsafe { sys::obs_data_get_bool(self.0.raw(), name.as_ptr()) };
+        Ok(unsafe { sys::obs_data_get_bool(self.0.raw(), name.as_ptr()) })
+    }
 
-        Ok(value)
+    /// Get the settings as a JSON string.
+    pub fn to_json(&self) -> ResultType<String> {
+        self.0.to_json_string()
     }
 }
 
@@ -0,0 +1,251 @@
+use crate::obs::sys;
+use crate::obs::util::types::ResultType;
+use serde::{Deserialize, Serialize};
+use std::ffi::{c_void, CStr, CString};
+
+/// An entry of a profiler snapshot.
+/// All times are in microseconds.
+#[cfg_attr(feature = "napi", napi(object))]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfilerEntry {
+    /// The name of the profiled section, e.g. `obs_video_thread(16.6667 ms)`.
+    pub name: String,
+    /// How often the section was entered.
+    pub call_count: i64,
+    /// The shortest time spent in the section.
+    pub min_time: i64,
+    /// The longest time spent in the section.
+    pub max_time: i64,
+    /// The average time spent in the section.
+    pub avg_time: f64,
+    /// The median time spent in the section.
+    pub median_time: i64,
+    /// The 90th percentile of the time spent in the section.
+    pub percentile90_time: i64,
+    /// The 99th percentile of the time spent in the section.
+    pub percentile99_time: i64,
+    /// The expected time between two calls, only set for sections
+    /// which are expected to run at a fixed interval, e.g. the video thread.
+    pub expected_interval: Option<i64>,
+    /// The shortest time between two calls, if an interval is expected.
+    pub min_interval: Option<i64>,
+    /// The longest time between two calls, if an interval is expected.
+    pub max_interval: Option<i64>,
+    /// The average time between two calls, if an interval is expected.
+    pub avg_interval: Option<f64>,
+    /// The sections entered from this section.
+    pub children: Vec<ProfilerEntry>,
+}
+
+/// Durations collected by the profiler with how often they were measured.
+struct TimeEntries(Vec<(u64, u64)>);
+
+impl TimeEntries {
+    unsafe fn from_raw(times: *mut sys::profiler_time_entries_t) -> Self {
+        if times.is_null() {
+            return Self(Vec::new());
+        }
+
+        let da = &(*times).da;
+        let entries = da.array as *const sys::profiler_time_entry;
+        let mut times = (0..da.num)
+            .map(|i| {
+                let entry = &*entries.add(i);
+                (entry.time_delta, entry.count)
+            })
+            .collect::<Vec<_>>();
+        times.sort_unstable_by_key(|(time, _)| *time);
+
+        Self(times)
+    }
+
+    fn count(&self) -> u64 {
+        self.0.iter().map(|(_, count)| count).sum()
+    }
+
+    fn average(&self) -> f64 {
+        let count = self.count();
+        if count == 0 {
+            return 0.0;
+        }
+
+        let total: f64 = self
+            .0
+            .iter()
+            .map(|(time, count)| *time as f64 * *count as f64)
+            .sum();
+        total / count as f64
+    }
+
+    /// Get the time below or at which `percentile` percent of the measurements lie.
+    fn percentile(&self, percentile: f64) -> u64 {
+        let target = (self.count() as f64 * percentile / 100.0).ceil() as u64;
+        let mut seen = 0;
+        for (time, count) in &self.0 {
+            seen += count;
+            if seen >= target {
+                return *time;
+            }
+        }
+
+        self.0.last().map(|(time, _)| *time).unwrap_or(0)
+    }
+}
+
+unsafe extern "C" fn collect_entry(
+    context: *mut c_void,
+    entry: *mut sys::profiler_snapshot_entry_t,
+) -> bool {
+    let entries = &mut *(context as *mut Vec<ProfilerEntry>);
+    entries.push(ProfilerEntry::from_raw(entry));
+    true
+}
+
+impl ProfilerEntry {
+    unsafe fn from_raw(entry: *mut sys::profiler_snapshot_entry_t) -> Self {
+        let name = sys::profiler_snapshot_entry_name(entry);
+        let times = TimeEntries::from_raw(sys::profiler_snapshot_entry_times(entry));
+
+        let expected_interval = sys::profiler_snapshot_entry_expected_time_between_calls(entry);
+        let intervals = (expected_interval > 0).then(|| {
+            TimeEntries::from_raw(sys::profiler_snapshot_entry_times_between_calls(entry))
+        });
+
+        let mut children = Vec::new();
+        sys::profiler_snapshot_enumerate_children(
+            entry,
+            Some(collect_entry),
+            &mut children as *mut Vec<ProfilerEntry> as *mut c_void,
+        );
+
+        Self {
+            name: if name.is_null() {
+                String::new()
+            } else {
+                CStr::from_ptr(name).to_string_lossy().into_owned()
+            },
+            call_count: sys::profiler_snapshot_entry_overall_count(entry) as _,
+            min_time: sys::profiler_snapshot_entry_min_time(entry) as _,
+            max_time: sys::profiler_snapshot_entry_max_time(entry) as _,
+            avg_time: times.average(),
+            median_time: times.percentile(50.0) as _,
+            percentile90_time: times.percentile(90.0) as _,
+            percentile99_time: times.percentile(99.0) as _,
+            expected_interval: intervals.as_ref().map(|_| expected_interval as _),
+            min_interval: intervals
+                .as_ref()
+                .map(|_| sys::profiler_snapshot_entry_min_time_between_calls(entry) as _),
+            max_interval: intervals
+                .as_ref()
+                .map(|_| sys::profiler_snapshot_entry_max_time_between_calls(entry) as _),
+            avg_interval: intervals.as_ref().map(TimeEntries::average),
+            children,
+        }
+    }
+}
+
+/// A snapshot of the data collected by the profiler.
+pub(crate) struct ProfilerSnapshot(*mut sys::profiler_snapshot_t);
+
+impl ProfilerSnapshot {
+    /// Take a snapshot. Requires obs to be started with the profiler enabled.
+    pub fn create() -> ResultType<Self> {
+        let snapshot = unsafe { sys::profile_snapshot_create() };
+        if snapshot.is_null() {
+            Err("Failed to create the profiler snapshot".into())
+        } else {
+            Ok(Self(snapshot))
+        }
+    }
+
+    /// Get the root entries of the snapshot, e.g. the video thread.
+    pub fn roots(&self) -> Vec<ProfilerEntry> {
+        let mut roots = Vec::new();
+        unsafe {
+            sys::profiler_snapshot_enumerate_roots(
+                self.0,
+                Some(collect_entry),
+                &mut roots as *mut Vec<ProfilerEntry> as *mut c_void,
+            );
+        }
+
+        roots
+    }
+
+    /// Write the snapshot to a CSV file, compressed using gzip if the path ends with `.gz`.
+    pub fn dump(&self, path: &str) -> ResultType<()> {
+        let gzip = path.ends_with(".gz");
+        let path = CString::new(path)?;
+        let written = unsafe {
+            if gzip {
+                sys::profiler_snapshot_dump_csv_gz(self.0, path.as_ptr())
+            } else {
+                sys::profiler_snapshot_dump_csv(self.0, path.as_ptr())
+            }
+        };
+
+        if written {
+            Ok(())
+        } else {
+            Err("Failed to write the profiler snapshot".into())
+        }
+    }
+}
+
+impl Drop for ProfilerSnapshot {
+    fn drop(&mut self) {
+        unsafe { sys::profile_snapshot_free(self.0) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn time_statistics() {
+        // Sorted by time, as returned by `from_raw`
+        let times = TimeEntries(vec![(10, 5), (20, 3), (100, 2)]);
+        assert_eq!(times.count(), 10);
+        assert_eq!(times.average(), 31.0);
+        assert_eq!(times.percentile(50.0), 10);
+        assert_eq!(times.percentile(80.0), 20);
+        assert_eq!(times.percentile(90.0), 100);
+        assert_eq!(times.percentile(100.0), 100);
+    }
+
+    #[test]
+    fn empty_times() {
+        let times = TimeEntries(Vec::new());
+        assert_eq!(times.count(), 0);
+        assert_eq!(times.average(), 0.0);
+        assert_eq!(times.percentile(99.0), 0);
+    }
+
+    #[test]
+    fn entry_field_names() {
+        let entry = ProfilerEntry {
+            name: "obs_video_thread(16.6667 ms)".to_string(),
+            call_count: 1,
+            min_time: 2,
+            max_time: 3,
+            avg_time: 2.5,
+            median_time: 2,
+            percentile90_time: 3,
+            percentile99_time: 3,
+            expected_interval: Some(16667),
+            min_interval: None,
+            max_interval: None,
+            avg_interval: None,
+            children: Vec::new(),
+        };
+
+        let value = serde_json::to_value(&entry).unwrap();
+        assert_eq!(value["callCount"], json!(1));
+        assert_eq!(value["percentile90Time"], json!(3));
+        assert_eq!(value["expectedInterval"], json!(16667));
+        assert_eq!(value["minInterval"], json!(null));
+    }
+}
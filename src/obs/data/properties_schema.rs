@@ -0,0 +1,428 @@
+use crate::obs::data::inner_obs_property::{InnerObsProperty, ListValue};
+use crate::obs::data::obs_property_type::ObsPropertyType;
+use crate::obs::sys;
+use crate::obs::traits::from_raw::FromRaw;
+use crate::obs::util::obs_guard::ObsGuard;
+use crate::obs::util::types::ResultType;
+use serde_json::{json, Map, Value};
+use std::ffi::{CStr, CString};
+use std::sync::Arc;
+
+/// The value kind of a single settings field.
+pub(crate) enum FieldKind {
+    Bool,
+    Int {
+        min: i64,
+        max: i64,
+        step: i64,
+    },
+    Float {
+        min: f64,
+        max: f64,
+    },
+    String,
+    List {
+        items: Vec<(String, ListValue)>,
+        editable: bool,
+    },
+    EditableList,
+    Object,
+}
+
+/// A single settings field derived from an obs property.
+pub(crate) struct SchemaField {
+    pub name: String,
+    pub description: Option<String>,
+    pub long_description: Option<String>,
+    pub kind: FieldKind,
+    pub default: Option<Value>,
+}
+
+/// The settings fields described by an obs properties object.
+/// Used to generate a JSON schema and a TypeScript interface
+/// for the settings of a source, encoder or output type.
+pub struct PropertiesSchema {
+    pub(crate) fields: Vec<SchemaField>,
+}
+
+impl PropertiesSchema {
+    /// Collect the settings fields from the given properties.
+    /// Default values are taken from `defaults`, if set.
+    pub unsafe fn from_raw(
+        properties: *mut sys::obs_properties_t,
+        defaults: Option<*mut sys::obs_data_t>,
+        guard: Arc<ObsGuard>,
+    ) -> ResultType<Self> {
+        let mut fields = Vec::new();
+        collect_fields(properties, defaults, &guard, &mut fields)?;
+
+        Ok(Self { fields })
+    }
+
+    /// Generate a draft-07 JSON schema for the settings.
+    pub fn to_json_schema(&self, title: &str) -> Value {
+        let mut properties = Map::new();
+        for field in &self.fields {
+            let mut schema = match &field.kind {
+                FieldKind::Bool => json!({ "type": "boolean" }),
+                FieldKind::Int { min, max, step } => json!({
+                    "type": "integer",
+                    "minimum": min,
+                    "maximum": max,
+                    // A step that doesn't line up with the minimum can't be
+                    // expressed as multipleOf, which is relative to zero
+                    "multipleOf": if *step > 1 && min % step == 0 { Some(step) } else { None },
+                }),
+                FieldKind::Float { min, max } => json!({
+                    "type": "number",
+                    "minimum": min,
+                    "maximum": max,
+                }),
+                FieldKind::String => json!({ "type": "string" }),
+                FieldKind::List {
+                    items,
+                    editable: true,
+                } => json!({
+                    "type": list_json_type(items),
+                    "examples": items
+                        .iter()
+                        .map(|(_, value)| list_value_to_json(value))
+                        .collect::<Vec<_>>(),
+                }),
+                FieldKind::List { items, .. } if items.is_empty() => json!({
+                    "type": list_json_type(items),
+                }),
+                FieldKind::List { items, .. } => json!({
+                    "type": list_json_type(items),
+                    "oneOf": items
+                        .iter()
+                        .map(|(name, value)| json!({
+                            "const": list_value_to_json(value),
+                            "title": name,
+                        }))
+                        .collect::<Vec<_>>(),
+                }),
+                FieldKind::EditableList => json!({
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": {
+                            "value": { "type": "string" },
+                            "selected": { "type": "boolean" },
+                            "hidden": { "type": "boolean" },
+                        },
+                        "required": ["value"],
+                    },
+                }),
+                FieldKind::Object => json!({ "type": "object" }),
+            };
+
+            let schema_obj = schema.as_object_mut().unwrap();
+            schema_obj.retain(|_, v| !v.is_null());
+            if let Some(description) = &field.description {
+                schema_obj.insert("title".to_string(), json!(description));
+            }
+            if let Some(long_description) = &field.long_description {
+                schema_obj.insert("description".to_string(), json!(long_description));
+            }
+            if let Some(default) = &field.default {
+                schema_obj.insert("default".to_string(), default.clone());
+            }
+
+            properties.insert(field.name.clone(), schema);
+        }
+
+        json!({
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "title": title,
+            "type": "object",
+            "properties": properties,
+            "additionalProperties": false,
+        })
+    }
+
+    /// Generate a TypeScript interface declaration for the settings.
+    pub fn to_type_definition(&self, name: &str) -> String {
+        let mut res = format!("export interface {} {{\n", name);
+        for field in &self.fields {
+            let mut doc = Vec::new();
+            if let Some(description) = &field.description {
+                doc.push(description.clone());
+            }
+            if let Some(long_description) = &field.long_description {
+                doc.push(long_description.clone());
+            }
+            match &field.kind {
+                FieldKind::Int { min, max, .. } => {
+                    doc.push(format!("@minimum {}", min));
+                    doc.push(format!("@maximum {}", max));
+                }
+                FieldKind::Float { min, max } => {
+                    doc.push(format!("@minimum {}", min));
+                    doc.push(format!("@maximum {}", max));
+                }
+                _ => {}
+            }
+            if let Some(default) = &field.default {
+                doc.push(format!("@default {}", default));
+            }
+
+            if !doc.is_empty() {
+                res.push_str("    /**\n");
+                for line in doc.iter().flat_map(|d| d.lines()) {
+                    res.push_str(&format!("     * {}\n", line.replace("*/", "*\\/")));
+                }
+                res.push_str("     */\n");
+            }
+
+            let ts_type = match &field.kind {
+                FieldKind::Bool => "boolean".to_string(),
+                FieldKind::Int { .. } | FieldKind::Float { .. } => "number".to_string(),
+                FieldKind::String => "string".to_string(),
+                FieldKind::List {
+                    items,
+                    editable: false,
+                } if !items.is_empty() => items
+                    .iter()
+                    .map(|(_, value)| list_value_to_json(value).to_string())
+                    .collect::<Vec<_>>()
+                    .join(" | "),
+                FieldKind::List { items, .. } => list_json_type(items).replace("integer", "number"),
+                FieldKind::EditableList => {
+                    "{ value: string; selected?: boolean; hidden?: boolean }[]".to_string()
+                }
+                FieldKind::Object => "Record<string, unknown>".to_string(),
+            };
+
+            res.push_str(&format!(
+                "    {}?: {};\n",
+                ts_property_name(&field.name),
+                ts_type
+            ));
+        }
+
+        res.push_str("}\n");
+        res
+    }
+}
+
+unsafe fn collect_fields(
+    properties: *mut sys::obs_properties_t,
+    defaults: Option<*mut sys::obs_data_t>,
+    guard: &Arc<ObsGuard>,
+    fields: &mut Vec<SchemaField>,
+) -> ResultType<()> {
+    if properties.is_null() {
+        return Ok(());
+    }
+
+    let mut property = sys::obs_properties_first(properties);
+    while !property.is_null() {
+        let inner = InnerObsProperty::from_raw(property, Some(guard.clone()));
+        let name = inner.name().ok_or("Failed to get the name of a property")?;
+
+        let kind = match inner.get_type()? {
+            ObsPropertyType::Bool => Some(FieldKind::Bool),
+            ObsPropertyType::Int => {
+                let (min, max, step) = inner.int_range();
+                Some(FieldKind::Int {
+                    min: min.into(),
+                    max: max.into(),
+                    step: step.into(),
+                })
+            }
+            ObsPropertyType::Color => Some(color_kind(false)),
+            ObsPropertyType::ColorAlpha => Some(color_kind(true)),
+            ObsPropertyType::Float => {
+                let (min, max, _) = inner.float_range();
+                Some(FieldKind::Float { min, max })
+            }
+            ObsPropertyType::Text | ObsPropertyType::Path => Some(FieldKind::String),
+            ObsPropertyType::List(_) => Some(FieldKind::List {
+                items: inner.get_list_values()?,
+                editable: inner.is_list_editable(),
+            }),
+            ObsPropertyType::EditableList => Some(FieldKind::EditableList),
+            ObsPropertyType::Font | ObsPropertyType::FrameRate => Some(FieldKind::Object),
+            ObsPropertyType::Group => {
+                // Checkable groups store their state as a bool,
+                // the group content is stored in the same settings object.
+                if inner.group_type() == sys::obs_group_type_OBS_GROUP_CHECKABLE {
+                    fields.push(new_field(&inner, name.clone(), FieldKind::Bool, defaults)?);
+                }
+
+                collect_fields(inner.group_content(), defaults, guard, fields)?;
+                None
+            }
+            _ => None,
+        };
+
+        if let Some(kind) = kind {
+            fields.push(new_field(&inner, name, kind, defaults)?);
+        }
+
+        if !sys::obs_property_next(&mut property) {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+/// Color properties have no range, the value is a packed (A)BGR integer.
+pub(crate) fn color_kind(alpha: bool) -> FieldKind {
+    FieldKind::Int {
+        min: 0,
+        max: if alpha { 0xFFFFFFFF } else { 0xFFFFFF },
+        step: 1,
+    }
+}
+
+unsafe fn new_field(
+    property: &InnerObsProperty,
+    name: String,
+    kind: FieldKind,
+    defaults: Option<*mut sys::obs_data_t>,
+) -> ResultType<SchemaField> {
+    let default = match defaults {
+        Some(defaults) => get_default(defaults, &name, &kind)?,
+        None => None,
+    };
+
+    Ok(SchemaField {
+        name,
+        description: property.description(),
+        long_description: property.long_description(),
+        kind,
+        default,
+    })
+}
+
+unsafe fn get_default(
+    defaults: *mut sys::obs_data_t,
+    name: &str,
+    kind: &FieldKind,
+) -> ResultType<Option<Value>> {
+    let c_name = CString::new(name)?;
+    if !sys::obs_data_has_default_value(defaults, c_name.as_ptr()) {
+        return Ok(None);
+    }
+
+    let get_string = || {
+        let value = sys::obs_data_get_default_string(defaults, c_name.as_ptr());
+        if value.is_null() {
+            Value::Null
+        } else {
+            json!(CStr::from_ptr(value).to_string_lossy())
+        }
+    };
+
+    Ok(match kind {
+        FieldKind::Bool => Some(json!(sys::obs_data_get_default_bool(
+            defaults,
+            c_name.as_ptr()
+        ))),
+        FieldKind::Int { .. } => Some(json!(sys::obs_data_get_default_int(
+            defaults,
+            c_name.as_ptr()
+        ))),
+        FieldKind::Float { .. } => Some(json!(sys::obs_data_get_default_double(
+            defaults,
+            c_name.as_ptr()
+        ))),
+        FieldKind::String => Some(get_string()),
+        FieldKind::List { items, .. } => Some(match items.first() {
+            Some((_, ListValue::Int(_))) => {
+                json!(sys::obs_data_get_default_int(defaults, c_name.as_ptr()))
+            }
+            Some((_, ListValue::Float(_))) => {
+                json!(sys::obs_data_get_default_double(defaults, c_name.as_ptr()))
+            }
+            _ => get_string(),
+        }),
+        FieldKind::EditableList | FieldKind::Object => None,
+    })
+}
+
+fn list_value_to_json(value: &ListValue) -> Value {
+    match value {
+        ListValue::Int(i) => json!(i),
+        ListValue::Float(f) => json!(f),
+        ListValue::String(s) => json!(s),
+    }
+}
+
+fn list_json_type(items: &[(String, ListValue)]) -> &'static str {
+    match items.first() {
+        Some((_, ListValue::Int(_))) => "integer",
+        Some((_, ListValue::Float(_))) => "number",
+        _ => "string",
+    }
+}
+
+fn ts_property_name(name: &str) -> String {
+    let is_identifier = name.chars().enumerate().all(|(i, c)| {
+        c == '_' || c == '$' || c.is_ascii_alphabetic() || (i > 0 && c.is_ascii_digit())
+    });
+
+    if is_identifier && !name.is_empty() {
+        name.to_string()
+    } else {
+        json!(name).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn int_field(name: &str, min: i64, max: i64, step: i64) -> SchemaField {
+        SchemaField {
+            name: name.to_string(),
+            description: None,
+            long_description: None,
+            kind: FieldKind::Int { min, max, step },
+            default: None,
+        }
+    }
+
+    #[test]
+    fn multiple_of_only_when_aligned_with_minimum() {
+        let schema = PropertiesSchema {
+            fields: vec![
+                int_field("aligned", 0, 100, 10),
+                int_field("unaligned", 5, 100, 10),
+                int_field("unit", 0, 100, 1),
+            ],
+        }
+        .to_json_schema("test");
+
+        let properties = &schema["properties"];
+        assert_eq!(properties["aligned"]["multipleOf"], json!(10));
+        assert!(properties["unaligned"].get("multipleOf").is_none());
+        assert!(properties["unit"].get("multipleOf").is_none());
+    }
+
+    #[test]
+    fn color_ranges() {
+        let schema = PropertiesSchema {
+            fields: vec![
+                SchemaField {
+                    kind: color_kind(false),
+                    ..int_field("color", 0, 0, 0)
+                },
+                SchemaField {
+                    kind: color_kind(true),
+                    ..int_field("color_alpha", 0, 0, 0)
+                },
+            ],
+        }
+        .to_json_schema("test");
+
+        let properties = &schema["properties"];
+        assert_eq!(properties["color"]["minimum"], json!(0));
+        assert_eq!(properties["color"]["maximum"], json!(0xFFFFFF));
+        assert_eq!(properties["color_alpha"]["maximum"], json!(0xFFFFFFFFu32));
+        assert!(properties["color_alpha"].get("multipleOf").is_none());
+    }
+}
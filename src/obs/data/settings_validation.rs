@@ -0,0 +1,346 @@
+use crate::obs::data::inner_obs_property::ListValue;
+use crate::obs::data::properties_schema::{FieldKind, PropertiesSchema, SchemaField};
+use crate::obs::sys;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::ffi::CStr;
+
+/// The kind of a settings validation issue.
+#[cfg_attr(feature = "napi", napi)]
+#[cfg_attr(not(feature = "napi"), derive(Clone, Copy))]
+#[derive(Debug, Serialize, Deserialize)]
+pub enum SettingsIssueKind {
+    /// The key does not belong to any property.
+    UnknownKey,
+    /// The value has a different type than the property expects.
+    TypeMismatch,
+    /// The number is outside of the range of the property.
+    OutOfRange,
+    /// The value is not one of the values of the list property.
+    InvalidListValue,
+}
+
+/// A problem found while validating settings against properties.
+#[cfg_attr(feature = "napi", napi(object))]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SettingsIssue {
+    /// The settings key the issue was found for.
+    pub key: String,
+    /// The kind of the issue.
+    pub kind: SettingsIssueKind,
+    /// A human readable description of the issue.
+    pub message: String,
+}
+
+/// A value stored in a settings object.
+enum SettingsValue {
+    Int(i64),
+    Double(f64),
+    String(String),
+    Bool,
+    Object,
+    Array,
+    Null,
+}
+
+impl SettingsValue {
+    fn type_name(&self) -> &'static str {
+        match self {
+            SettingsValue::Int(_) => "integer",
+            SettingsValue::Double(_) => "number",
+            SettingsValue::String(_) => "string",
+            SettingsValue::Bool => "boolean",
+            SettingsValue::Object => "object",
+            SettingsValue::Array => "array",
+            SettingsValue::Null => "null",
+        }
+    }
+
+    fn as_f64(&self) -> Option<f64> {
+        match self {
+            SettingsValue::Int(i) => Some(*i as f64),
+            SettingsValue::Double(d) => Some(*d),
+            _ => None,
+        }
+    }
+}
+
+impl PropertiesSchema {
+    /// Validate the user values of the given settings against these properties.
+    /// Returns an empty list if the settings are valid.
+    pub unsafe fn validate(&self, settings: *mut sys::obs_data_t) -> Vec<SettingsIssue> {
+        let fields: HashMap<&str, &SchemaField> =
+            self.fields.iter().map(|f| (f.name.as_str(), f)).collect();
+        let mut issues = Vec::new();
+
+        let mut item = sys::obs_data_first(settings);
+        while !item.is_null() {
+            if sys::obs_data_item_has_user_value(item) {
+                let name = sys::obs_data_item_get_name(item);
+                let name = if name.is_null() {
+                    String::new()
+                } else {
+                    CStr::from_ptr(name).to_string_lossy().into_owned()
+                };
+
+                match fields.get(name.as_str()) {
+                    Some(field) => {
+                        if let Some(issue) = validate_value(field, read_value(item)) {
+                            issues.push(issue);
+                        }
+                    }
+                    None => issues.push(SettingsIssue {
+                        message: format!("Unknown settings key '{}'", name),
+                        key: name,
+                        kind: SettingsIssueKind::UnknownKey,
+                    }),
+                }
+            }
+
+            // Releases the current item and moves to the next one
+            if !sys::obs_data_item_next(&mut item) {
+                break;
+            }
+        }
+
+        issues
+    }
+}
+
+/// Format a list of issues as a single error message.
+pub fn issues_to_string(issues: &[SettingsIssue]) -> String {
+    issues
+        .iter()
+        .map(|i| i.message.as_str())
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+unsafe fn read_value(item: *mut sys::obs_data_item_t) -> SettingsValue {
+    match sys::obs_data_item_gettype(item) {
+        sys::obs_data_type_OBS_DATA_STRING => {
+            let value = sys::obs_data_item_get_string(item);
+            if value.is_null() {
+                SettingsValue::Null
+            } else {
+                SettingsValue::String(CStr::from_ptr(value).to_string_lossy().into_owned())
+            }
+        }
+        sys::obs_data_type_OBS_DATA_NUMBER => {
+            if sys::obs_data_item_numtype(item) == sys::obs_data_number_type_OBS_DATA_NUM_DOUBLE {
+                SettingsValue::Double(sys::obs_data_item_get_double(item))
+            } else {
+                SettingsValue::Int(sys::obs_data_item_get_int(item))
+            }
+        }
+        sys::obs_data_type_OBS_DATA_BOOLEAN => SettingsValue::Bool,
+        sys::obs_data_type_OBS_DATA_OBJECT => SettingsValue::Object,
+        sys::obs_data_type_OBS_DATA_ARRAY => SettingsValue::Array,
+        _ => SettingsValue::Null,
+    }
+}
+
+fn validate_value(field: &SchemaField, value: SettingsValue) -> Option<SettingsIssue> {
+    let mismatch = |expected: &str| {
+        Some(SettingsIssue {
+            key: field.name.clone(),
+            kind: SettingsIssueKind::TypeMismatch,
+            message: format!(
+                "Expected '{}' to be of type {}, got {}",
+                field.name,
+                expected,
+                value.type_name()
+            ),
+        })
+    };
+
+    let out_of_range = |min: f64, max: f64, actual: f64| {
+        if actual < min || actual > max {
+            Some(SettingsIssue {
+                key: field.name.clone(),
+                kind: SettingsIssueKind::OutOfRange,
+                message: format!(
+                    "Expected '{}' to be in range [{}, {}], got {}",
+                    field.name, min, max, actual
+                ),
+            })
+        } else {
+            None
+        }
+    };
+
+    match &field.kind {
+        FieldKind::Bool => match value {
+            SettingsValue::Bool => None,
+            _ => mismatch("boolean"),
+        },
+        FieldKind::Int { min, max, .. } => match value {
+            SettingsValue::Int(i) => out_of_range(*min as f64, *max as f64, i as f64),
+            SettingsValue::Double(d) if d.fract() == 0.0 => {
+                out_of_range(*min as f64, *max as f64, d)
+            }
+            _ => mismatch("integer"),
+        },
+        FieldKind::Float { min, max } => match value.as_f64() {
+            Some(d) => out_of_range(*min, *max, d),
+            None => mismatch("number"),
+        },
+        FieldKind::String => match value {
+            SettingsValue::String(_) => None,
+            _ => mismatch("string"),
+        },
+        FieldKind::List { items, editable } => {
+            let contained = match (items.first(), &value) {
+                (Some((_, ListValue::String(_))), SettingsValue::String(s)) => items
+                    .iter()
+                    .any(|(_, v)| matches!(v, ListValue::String(v) if v == s)),
+                (Some((_, ListValue::Int(_))), SettingsValue::Int(i)) => items
+                    .iter()
+                    .any(|(_, v)| matches!(v, ListValue::Int(v) if v == i)),
+                (Some((_, ListValue::Float(_))), _) if value.as_f64().is_some() => {
+                    let d = value.as_f64().unwrap();
+                    items
+                        .iter()
+                        .any(|(_, v)| matches!(v, ListValue::Float(v) if *v == d))
+                }
+                (Some((_, ListValue::String(_))), _) => return mismatch("string"),
+                (Some(_), _) => return mismatch("number"),
+                // Lists without items are usually populated at runtime
+                (None, _) => true,
+            };
+
+            if contained || *editable {
+                None
+            } else {
+                Some(SettingsIssue {
+                    key: field.name.clone(),
+                    kind: SettingsIssueKind::InvalidListValue,
+                    message: format!(
+                        "The value of '{}' is not one of the list values",
+                        field.name
+                    ),
+                })
+            }
+        }
+        FieldKind::EditableList => match value {
+            SettingsValue::Array => None,
+            _ => mismatch("array"),
+        },
+        FieldKind::Object => match value {
+            SettingsValue::Object => None,
+            _ => mismatch("object"),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::obs::data::properties_schema::color_kind;
+
+    fn field(kind: FieldKind) -> SchemaField {
+        SchemaField {
+            name: "field".to_string(),
+            description: None,
+            long_description: None,
+            kind,
+            default: None,
+        }
+    }
+
+    fn issue_kind(field: &SchemaField, value: SettingsValue) -> Option<SettingsIssueKind> {
+        validate_value(field, value).map(|issue| issue.kind)
+    }
+
+    #[test]
+    fn color() {
+        let color = field(color_kind(false));
+        assert!(issue_kind(&color, SettingsValue::Int(0xFF8000)).is_none());
+        assert!(matches!(
+            issue_kind(&color, SettingsValue::Int(0x1000000)),
+            Some(SettingsIssueKind::OutOfRange)
+        ));
+
+        let color_alpha = field(color_kind(true));
+        assert!(issue_kind(&color_alpha, SettingsValue::Int(0xFFFFFFFF)).is_none());
+        assert!(matches!(
+            issue_kind(&color_alpha, SettingsValue::Int(-1)),
+            Some(SettingsIssueKind::OutOfRange)
+        ));
+    }
+
+    #[test]
+    fn int_range() {
+        let int = field(FieldKind::Int {
+            min: 0,
+            max: 10,
+            step: 1,
+        });
+        assert!(issue_kind(&int, SettingsValue::Int(10)).is_none());
+        assert!(issue_kind(&int, SettingsValue::Double(5.0)).is_none());
+        assert!(matches!(
+            issue_kind(&int, SettingsValue::Int(11)),
+            Some(SettingsIssueKind::OutOfRange)
+        ));
+        assert!(matches!(
+            issue_kind(&int, SettingsValue::Double(5.5)),
+            Some(SettingsIssueKind::TypeMismatch)
+        ));
+    }
+
+    #[test]
+    fn type_mismatch() {
+        let string = field(FieldKind::String);
+        assert!(issue_kind(&string, SettingsValue::String("a".to_string())).is_none());
+        assert!(matches!(
+            issue_kind(&string, SettingsValue::Bool),
+            Some(SettingsIssueKind::TypeMismatch)
+        ));
+    }
+
+    #[test]
+    fn list_values() {
+        let items = vec![
+            ("A".to_string(), ListValue::String("a".to_string())),
+            ("B".to_string(), ListValue::String("b".to_string())),
+        ];
+        let list = field(FieldKind::List {
+            items: items.clone(),
+            editable: false,
+        });
+        assert!(issue_kind(&list, SettingsValue::String("b".to_string())).is_none());
+        assert!(matches!(
+            issue_kind(&list, SettingsValue::String("c".to_string())),
+            Some(SettingsIssueKind::InvalidListValue)
+        ));
+
+        let editable = field(FieldKind::List {
+            items,
+            editable: true,
+        });
+        assert!(issue_kind(&editable, SettingsValue::String("c".to_string())).is_none());
+
+        let empty = field(FieldKind::List {
+            items: Vec::new(),
+            editable: false,
+        });
+        assert!(issue_kind(&empty, SettingsValue::String("c".to_string())).is_none());
+    }
+
+    #[test]
+    fn issues_joined() {
+        let issues = [
+            SettingsIssue {
+                key: "a".to_string(),
+                kind: SettingsIssueKind::UnknownKey,
+                message: "first".to_string(),
+            },
+            SettingsIssue {
+                key: "b".to_string(),
+                kind: SettingsIssueKind::OutOfRange,
+                message: "second".to_string(),
+            },
+        ];
+        assert_eq!(issues_to_string(&issues), "first; second");
+    }
+}
@@ -0,0 +1,408 @@
+//! Hosts libobs for a `RemoteObs` instance. Runs in the helper process.
+//!
+//! Requests are read from stdin, one JSON object per line:
+//! `{"id": 1, "method": "createOutput", "params": {...}}`.
+//! Responses and events are written to stdout, prefixed with `RESULT_PREFIX`:
+//! `{"id": 1, "result": ...}`, `{"id": 1, "error": "..."}` or
+//! `{"event": "outputState", "handle": 2, "state": "Active"}` and
+//! `{"event": "mediaEvent", "handle": 3, "mediaEvent": "Ended"}`.
+//! `startAsync` and `stopAsync` are answered once the output
+//! has actually started or stopped, other requests may be answered first.
+//! Once obs has been started, `{"ready": true}` is written,
+//! or `{"ready": false, "error": "..."}` if obs failed to start.
+//! Malformed requests are answered with an error if they have an id
+//! and skipped otherwise. The helper exits once stdin is closed.
+
+use crate::obs::data::obs_properties::ObsProperties;
+use crate::obs::data::obs_settings::ObsSettings;
+use crate::obs::helper::{get_arg, RESULT_PREFIX};
+use crate::obs::io::obs_encoder::ObsEncoder;
+use crate::obs::io::obs_output::ObsOutput;
+use crate::obs::io::obs_source::ObsSource;
+use crate::obs::objects::module_load_options::ModuleLoadOptions;
+use crate::obs::objects::reconfigure_options::ReconfigureOptions;
+use crate::obs::objects::startup_options::StartupOptions;
+use crate::obs::objects::type_schema::TypeSchema;
+use crate::obs::obs::Obs;
+use crate::obs::util::obs_error::ObsError;
+use crate::obs::util::types::ResultType;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::io::{self, BufRead, Write};
+
+#[derive(Deserialize)]
+struct Request {
+    id: u64,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+enum HostObject {
+    Encoder(ObsEncoder),
+    Source(ObsSource),
+    Output(ObsOutput),
+}
+
+struct Host {
+    /// The objects created by the client, by the handle chosen by the client.
+    objects: HashMap<u64, HostObject>,
+    obs: Obs,
+}
+
+/// Start obs and handle requests until stdin is closed.
+pub(crate) fn run(args: &[String]) -> Result<(), String> {
+    let options: StartupOptions = match get_arg(args, "--startup") {
+        Some(options) => {
+            serde_json::from_str(options).map_err(|e| format!("Invalid startup options: {}", e))?
+        }
+        None => StartupOptions::default(),
+    };
+
+    let obs = Obs::with_options(
+        get_arg(args, "--locale").unwrap_or("en-US"),
+        get_arg(args, "--libobs"),
+        &options,
+    );
+
+    let mut host = match obs {
+        Ok(obs) => {
+            send(&json!({ "ready": true }))?;
+            Host {
+                objects: HashMap::new(),
+                obs,
+            }
+        }
+        Err(e) => return send(&json!({ "ready": false, "error": e.to_string() })),
+    };
+
+    for line in io::stdin().lock().lines() {
+        let line = line.map_err(|e| e.to_string())?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        // A malformed request must not stop the helper
+        let request = match parse_request(&line) {
+            Ok(request) => request,
+            Err((Some(id), error)) => {
+                send(&json!({ "id": id, "error": error }))?;
+                continue;
+            }
+            Err((None, error)) => {
+                eprintln!("{}", error);
+                continue;
+            }
+        };
+        let response = match host.handle_deferred(request.id, &request.method, &request.params) {
+            Some(Ok(())) => continue,
+            Some(Err(e)) => Err(e),
+            None => host.handle(&request.method, &request.params),
+        };
+
+        send(&response_message(request.id, response))?;
+    }
+
+    Ok(())
+}
+
+/// Parse a request line.
+/// If the line is invalid, returns the error and the request id if it could be read.
+fn parse_request(line: &str) -> Result<Request, (Option<u64>, String)> {
+    serde_json::from_str(line).map_err(|e| {
+        let id = serde_json::from_str::<Value>(line)
+            .ok()
+            .and_then(|request| request["id"].as_u64());
+
+        (id, format!("Invalid request: {}", e))
+    })
+}
+
+/// Write a message to stdout.
+/// Messages may be sent from obs threads, locking stdout keeps lines intact.
+fn send(message: &Value) -> Result<(), String> {
+    let mut stdout = io::stdout().lock();
+    writeln!(stdout, "{}{}", RESULT_PREFIX, message)
+        .and_then(|_| stdout.flush())
+        .map_err(|e| e.to_string())
+}
+
+fn response_message(id: u64, response: ResultType<Value>) -> Value {
+    match response {
+        Ok(result) => json!({ "id": id, "result": result }),
+        Err(e) => json!({ "id": id, "error": e.to_string() }),
+    }
+}
+
+fn param<T: DeserializeOwned>(params: &Value, name: &str) -> ResultType<T> {
+    serde_json::from_value(params[name].clone())
+        .map_err(|e| ObsError::Failed(format!("Invalid parameter '{}': {}", name, e)))
+}
+
+fn settings_param(params: &Value, name: &str) -> ResultType<Option<ObsSettings>> {
+    match &params[name] {
+        Value::Null => Ok(None),
+        settings => ObsSettings::from_json(&settings.to_string()).map(Some),
+    }
+}
+
+fn settings_to_json(settings: ObsSettings) -> ResultType<Value> {
+    serde_json::from_str(&settings.to_json()?)
+        .map_err(|e| ObsError::Failed(format!("Failed to parse the settings: {}", e)))
+}
+
+fn schema(properties: ObsProperties, name: Option<String>) -> ResultType<Value> {
+    to_value(TypeSchema {
+        json_schema: properties.to_json_schema(None, None)?,
+        type_definition: properties
+            .to_type_definition(name.as_deref().unwrap_or("Settings"), None)?,
+    })
+}
+
+fn to_value<T: serde::Serialize>(value: T) -> ResultType<Value> {
+    serde_json::to_value(value).map_err(|e| ObsError::Failed(e.to_string()))
+}
+
+impl Host {
+    fn handle(&mut self, method: &str, params: &Value) -> ResultType<Value> {
+        match method {
+            "getAllModules" => {
+                let obs_path: Option<String> = param(params, "obsPath")?;
+                to_value(self.obs.get_all_modules(obs_path.as_deref())?)
+            }
+            "loadModules" => {
+                let options: Option<ModuleLoadOptions> = param(params, "options")?;
+                to_value(
+                    self.obs
+                        .load_modules(param(params, "modules")?, &options.unwrap_or_default())?,
+                )
+            }
+            "resetVideo" => to_value(self.obs.reset_video(&param(params, "data")?)?),
+            "resetAudio" => to_value(self.obs.reset_audio(&param(params, "data")?)?),
+            "reconfigure" => {
+                let options: Option<ReconfigureOptions> = param(params, "options")?;
+                to_value(
+                    self.obs
+                        .reconfigure(&param(params, "data")?, &options.unwrap_or_default())?,
+                )
+            }
+            "getVideoInfo" => to_value(self.obs.get_video_info()?),
+            "getAudioInfo" => to_value(self.obs.get_audio_info()?),
+            "videoActive" => to_value(self.obs.video_active()),
+            "getLocale" => to_value(self.obs.get_locale()?),
+            "setLocale" => to_value(self.obs.set_locale(&param::<String>(params, "locale")?)?),
+            "getProfilerSnapshot" => to_value(self.obs.get_profiler_snapshot()?),
+            "dumpProfilerSnapshot" => to_value(
+                self.obs
+                    .dump_profiler_snapshot(&param::<String>(params, "path")?)?,
+            ),
+            "version" => to_value(self.obs.version()),
+            "listSourceTypes" => to_value(self.obs.list_source_types()),
+            "listEncoderTypes" => to_value(self.obs.list_encoder_types()),
+            "listOutputTypes" => to_value(self.obs.list_output_types()),
+            "createVideoEncoder" | "createAudioEncoder" => {
+                let name: String = param(params, "name")?;
+                let id: String = param(params, "id")?;
+                let settings = settings_param(params, "settings")?;
+                let encoder = if method == "createVideoEncoder" {
+                    self.obs
+                        .create_video_encoder(&name, &id, settings.as_ref())?
+                } else {
+                    self.obs
+                        .create_audio_encoder(&name, &id, settings.as_ref())?
+                };
+
+                self.insert(params, HostObject::Encoder(encoder))
+            }
+            "createSource" => {
+                let source = self.obs.create_source(
+                    &param::<String>(params, "name")?,
+                    &param::<String>(params, "id")?,
+                    param(params, "channel")?,
+                    settings_param(params, "settings")?.as_ref(),
+                )?;
+
+                // Only media sources emit media events
+                let handle: u64 = param(params, "handle")?;
+                let _ = source.on_media_event(move |event| {
+                    let _ = send(&json!({
+                        "event": "mediaEvent",
+                        "handle": handle,
+                        "mediaEvent": event,
+                    }));
+                });
+
+                self.insert(params, HostObject::Source(source))
+            }
+            "createOutput" => {
+                let output = self.obs.create_output(
+                    &param::<String>(params, "name")?,
+                    &param::<String>(params, "id")?,
+                    settings_param(params, "settings")?.as_ref(),
+                )?;
+
+                let handle: u64 = param(params, "handle")?;
+                output.on_state_change(move |state| {
+                    let _ = send(&json!({
+                        "event": "outputState",
+                        "handle": handle,
+                        "state": state,
+                    }));
+                });
+
+                self.insert(params, HostObject::Output(output))
+            }
+            "release" => {
+                self.objects.remove(&param::<u64>(params, "handle")?);
+                Ok(Value::Null)
+            }
+            _ => self.handle_object(method, params),
+        }
+    }
+
+    fn insert(&mut self, params: &Value, object: HostObject) -> ResultType<Value> {
+        self.objects.insert(param(params, "handle")?, object);
+        Ok(Value::Null)
+    }
+
+    fn get(&self, params: &Value, name: &str) -> ResultType<Option<&HostObject>> {
+        match param::<Option<u64>>(params, name)? {
+            Some(handle) => self
+                .objects
+                .get(&handle)
+                .map(Some)
+                .ok_or_else(|| ObsError::Failed(format!("Unknown handle {}", handle))),
+            None => Ok(None),
+        }
+    }
+
+    fn get_encoder(&self, params: &Value, name: &str) -> ResultType<Option<&ObsEncoder>> {
+        match self.get(params, name)? {
+            Some(HostObject::Encoder(encoder)) => Ok(Some(encoder)),
+            Some(_) => Err(ObsError::Failed(format!("'{}' is not an encoder", name))),
+            None => Ok(None),
+        }
+    }
+
+    /// Handle a request which is answered once the call has completed,
+    /// returns `None` if the method is answered immediately.
+    /// If an error is returned, the request must be answered with it.
+    fn handle_deferred(&self, id: u64, method: &str, params: &Value) -> Option<ResultType<()>> {
+        match method {
+            "startAsync" | "stopAsync" => Some(self.start_or_stop(id, method, params)),
+            _ => None,
+        }
+    }
+
+    /// Start or stop an output, answering the request once it has started or stopped.
+    fn start_or_stop(&self, id: u64, method: &str, params: &Value) -> ResultType<()> {
+        let output = match self.get(params, "handle")?.ok_or("Missing handle")? {
+            HostObject::Output(output) => output,
+            _ => return Err(ObsError::Failed(format!("Unknown method '{}'", method))),
+        };
+        let respond = move |res: ResultType<()>| {
+            let _ = send(&response_message(id, res.map(|_| Value::Null)));
+        };
+
+        if method == "startAsync" {
+            output.start_with_callback(
+                self.get_encoder(params, "videoEncoder")?,
+                self.get_encoder(params, "audioEncoder")?,
+                respond,
+            )
+        } else {
+            output.stop_with_callback(respond)
+        }
+    }
+
+    fn handle_object(&self, method: &str, params: &Value) -> ResultType<Value> {
+        let object = self.get(params, "handle")?.ok_or("Missing handle")?;
+        let strict = || param::<Option<bool>>(params, "strict").map(Option::unwrap_or_default);
+
+        match (object, method) {
+            (HostObject::Encoder(encoder), "getSettings") => {
+                settings_to_json(encoder.get_settings()?)
+            }
+            (HostObject::Encoder(encoder), "updateSettings") => to_value(encoder.update_settings(
+                &settings_param(params, "settings")?.ok_or("Missing settings")?,
+                strict()?,
+            )?),
+            (HostObject::Encoder(encoder), "getSchema") => {
+                schema(encoder.get_properties()?, param(params, "name")?)
+            }
+            (HostObject::Encoder(encoder), "validate") => to_value(
+                encoder
+                    .validate(&settings_param(params, "settings")?.ok_or("Missing settings")?)?,
+            ),
+            (HostObject::Source(source), "getSettings") => settings_to_json(source.get_settings()?),
+            (HostObject::Source(source), "updateSettings") => to_value(source.update_settings(
+                &settings_param(params, "settings")?.ok_or("Missing settings")?,
+                strict()?,
+            )?),
+            (HostObject::Source(source), "getSchema") => {
+                schema(source.get_properties()?, param(params, "name")?)
+            }
+            (HostObject::Source(source), "validate") => to_value(
+                source.validate(&settings_param(params, "settings")?.ok_or("Missing settings")?)?,
+            ),
+            (HostObject::Source(source), "play") => to_value(source.play()?),
+            (HostObject::Source(source), "pause") => to_value(source.pause()?),
+            (HostObject::Source(source), "stop") => to_value(source.stop()?),
+            (HostObject::Source(source), "restart") => to_value(source.restart()?),
+            (HostObject::Source(source), "seek") => to_value(source.seek(param(params, "ms")?)?),
+            (HostObject::Source(source), "next") => to_value(source.next()?),
+            (HostObject::Source(source), "previous") => to_value(source.previous()?),
+            (HostObject::Source(source), "getTime") => to_value(source.get_time()?),
+            (HostObject::Source(source), "getDuration") => to_value(source.get_duration()?),
+            (HostObject::Source(source), "getState") => to_value(source.get_state()?),
+            (HostObject::Output(output), "getSettings") => settings_to_json(output.get_settings()?),
+            (HostObject::Output(output), "updateSettings") => to_value(output.update_settings(
+                &settings_param(params, "settings")?.ok_or("Missing settings")?,
+                strict()?,
+            )?),
+            (HostObject::Output(output), "getSchema") => {
+                schema(output.get_properties()?, param(params, "name")?)
+            }
+            (HostObject::Output(output), "validate") => to_value(
+                output.validate(&settings_param(params, "settings")?.ok_or("Missing settings")?)?,
+            ),
+            (HostObject::Output(output), "start") => to_value(output.start(
+                self.get_encoder(params, "videoEncoder")?,
+                self.get_encoder(params, "audioEncoder")?,
+            )?),
+            (HostObject::Output(output), "stop") => to_value(output.stop()?),
+            (HostObject::Output(output), "forceStop") => to_value(output.force_stop()?),
+            (HostObject::Output(output), "pause") => to_value(output.pause()?),
+            (HostObject::Output(output), "resume") => to_value(output.resume()?),
+            (HostObject::Output(output), "getState") => to_value(output.get_state()),
+            (HostObject::Output(output), "getActive") => to_value(output.get_active()),
+            (HostObject::Output(output), "getPaused") => to_value(output.get_paused()),
+            (HostObject::Output(output), "canPause") => to_value(output.can_pause()),
+            _ => Err(ObsError::Failed(format!("Unknown method '{}'", method))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_valid_request() {
+        let request = parse_request(r#"{"id": 1, "method": "version"}"#).unwrap();
+        assert_eq!(request.id, 1);
+        assert_eq!(request.method, "version");
+        assert_eq!(request.params, Value::Null);
+    }
+
+    #[test]
+    fn parse_invalid_request() {
+        let (id, _) = parse_request(r#"{"id": 2, "method": 3}"#).err().unwrap();
+        assert_eq!(id, Some(2));
+
+        let (id, _) = parse_request("not json").err().unwrap();
+        assert_eq!(id, None);
+    }
+}
@@ -0,0 +1,55 @@
+//! The helper process used to run libobs outside of the current process.
+//!
+//! The helper binary is built using
+//! `cargo build --release --no-default-features --features helper --bin obs-helper`.
+
+pub(crate) mod host;
+pub(crate) mod probe;
+pub(crate) mod remote;
+
+use std::env;
+use std::path::PathBuf;
+
+/// The environment variable containing the path to the helper binary.
+pub const HELPER_PATH_VAR: &str = "OBS_HELPER_PATH";
+/// The prefix of the lines the helper reports results on,
+/// as libobs may log to stdout as well.
+pub(crate) const RESULT_PREFIX: &str = "OBS_HELPER_RESULT ";
+
+/// Run the helper with the given command line arguments, excluding the program name.
+/// Returns the exit code of the helper.
+pub fn main(args: Vec<String>) -> i32 {
+    let res = match args.first().map(String::as_str) {
+        Some("probe") => probe::run(&args[1..]),
+        Some("host") => host::run(&args[1..]),
+        _ => Err("Usage: obs-helper <probe|host> [options]".to_string()),
+    };
+
+    match res {
+        Ok(()) => 0,
+        Err(e) => {
+            eprintln!("{}", e);
+            2
+        }
+    }
+}
+
+/// Get the path of the helper binary.
+/// Uses `path` if given, the `OBS_HELPER_PATH` environment variable if set
+/// and searches for `obs-helper` on the `PATH` otherwise.
+pub fn helper_path(path: Option<&str>) -> PathBuf {
+    match path {
+        Some(path) => PathBuf::from(path),
+        None => env::var_os(HELPER_PATH_VAR)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(format!("obs-helper{}", env::consts::EXE_SUFFIX))),
+    }
+}
+
+/// Get the value of a `--name value` option.
+pub(crate) fn get_arg<'a>(args: &'a [String], name: &str) -> Option<&'a str> {
+    args.iter()
+        .position(|arg| arg == name)
+        .and_then(|i| args.get(i + 1))
+        .map(String::as_str)
+}
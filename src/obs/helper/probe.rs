@@ -0,0 +1,227 @@
+use crate::obs::data::obs_module::ObsModule;
+use crate::obs::helper::{get_arg, helper_path, RESULT_PREFIX};
+use crate::obs::obs::Obs;
+use crate::obs::util::obs_error::ObsError;
+use crate::obs::util::types::ResultType;
+use serde_json::{json, Value};
+use std::io::{self, Read, Write};
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const DEFAULT_TIMEOUT_MS: u32 = 10000;
+
+/// The outcome of probing a module.
+#[cfg_attr(feature = "napi", napi)]
+#[cfg_attr(not(feature = "napi"), derive(Clone, Copy))]
+#[derive(PartialEq, Eq, Debug)]
+pub enum ModuleProbeStatus {
+    /// The module was loaded and initialized.
+    Loaded,
+    /// libobs skipped the module because of a hardcoded rule.
+    Skipped,
+    /// The module failed to load or initialize.
+    Failed,
+    /// The helper process crashed while loading or unloading the module.
+    Crashed,
+    /// The module did not finish loading within the timeout.
+    Hung,
+}
+
+/// Options for probing modules.
+#[cfg_attr(feature = "napi", napi(object))]
+#[derive(Clone, Debug, Default)]
+pub struct ProbeOptions {
+    /// The path of the `obs-helper` binary.
+    /// Defaults to the `OBS_HELPER_PATH` environment variable
+    /// or `obs-helper` on the `PATH`.
+    pub helper_path: Option<String>,
+    /// The path of libobs or the directory containing it.
+    /// libobs is searched for on the library search path if not set.
+    pub libobs_path: Option<String>,
+    /// The locale to start obs with. Defaults to `en-US`.
+    pub locale: Option<String>,
+    /// The time in milliseconds after which a module is considered hung.
+    /// Defaults to 10 seconds.
+    pub timeout: Option<u32>,
+}
+
+/// The result of probing a module.
+#[cfg_attr(feature = "napi", napi(object))]
+#[derive(Clone, Debug)]
+pub struct ModuleProbeResult {
+    /// The info of the module.
+    pub module: ObsModule,
+    /// The outcome of probing the module.
+    pub status: ModuleProbeStatus,
+    /// The error code returned by `obs_open_module`
+    /// or the exit code of the crashed helper.
+    pub code: Option<i32>,
+    /// The error message, if the module failed, crashed or hung.
+    pub error: Option<String>,
+}
+
+/// Load each module in its own helper process.
+/// Returns an error if the helper could not be started or could not start obs.
+pub fn probe_modules(
+    modules: &[ObsModule],
+    options: &ProbeOptions,
+) -> ResultType<Vec<ModuleProbeResult>> {
+    modules
+        .iter()
+        .map(|module| probe_module(module, options))
+        .collect()
+}
+
+fn probe_module(module: &ObsModule, options: &ProbeOptions) -> ResultType<ModuleProbeResult> {
+    let mut command = Command::new(helper_path(options.helper_path.as_deref()));
+    command
+        .arg("probe")
+        .args(["--locale", options.locale.as_deref().unwrap_or("en-US")])
+        .args(["--name", &module.name])
+        .args(["--bin", &module.bin_path])
+        .args(["--data", &module.data_path])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null());
+
+    if let Some(libobs_path) = &options.libobs_path {
+        command.args(["--libobs", libobs_path]);
+    }
+
+    let mut child = command
+        .spawn()
+        .map_err(|e| format!("Failed to start the helper: {}", e))?;
+
+    // Read the output while waiting, so the helper can't block on a full pipe
+    let mut stdout = child
+        .stdout
+        .take()
+        .ok_or("Failed to read the helper output")?;
+    let reader = thread::spawn(move || {
+        let mut output = String::new();
+        let _ = stdout.read_to_string(&mut output);
+        output
+    });
+
+    let timeout = options.timeout.unwrap_or(DEFAULT_TIMEOUT_MS);
+    let status = wait_timeout(&mut child, Duration::from_millis(timeout as _))
+        .map_err(|e| format!("Failed to wait for the helper: {}", e))?;
+    let output = reader.join().unwrap_or_default();
+    let result = output
+        .lines()
+        .rev()
+        .find_map(|line| line.strip_prefix(RESULT_PREFIX))
+        .and_then(|line| serde_json::from_str::<Value>(line).ok());
+
+    let probe_result =
+        |status: ModuleProbeStatus, code: Option<i32>, error: Option<String>| ModuleProbeResult {
+            module: module.clone(),
+            status,
+            code,
+            error,
+        };
+
+    let status = match status {
+        Some(status) => status,
+        None => {
+            return Ok(probe_result(
+                ModuleProbeStatus::Hung,
+                None,
+                Some(format!("The module did not load within {}ms", timeout)),
+            ))
+        }
+    };
+
+    let result = match result {
+        Some(result) => result,
+        None if status.success() => return Err("The helper did not report a result".into()),
+        None => {
+            return Ok(probe_result(
+                ModuleProbeStatus::Crashed,
+                status.code(),
+                Some(format!("The helper exited with {}", status)),
+            ))
+        }
+    };
+
+    let error = result["error"].as_str().map(str::to_string);
+    let code = result["code"].as_i64().map(|code| code as i32);
+    let module_status = match result["status"].as_str() {
+        Some("Loaded") => ModuleProbeStatus::Loaded,
+        Some("Skipped") => ModuleProbeStatus::Skipped,
+        Some("Failed") => ModuleProbeStatus::Failed,
+        _ => {
+            return Err(ObsError::Failed(format!(
+                "The helper failed: {}",
+                error.unwrap_or_else(|| "Unknown error".to_string())
+            )))
+        }
+    };
+
+    if status.success() {
+        Ok(probe_result(module_status, code, error))
+    } else {
+        Ok(probe_result(
+            ModuleProbeStatus::Crashed,
+            status.code(),
+            Some(format!(
+                "The helper exited with {} after loading the module",
+                status
+            )),
+        ))
+    }
+}
+
+/// Wait for `child` to exit, killing it after `timeout`.
+/// Returns `None` if the child was killed.
+fn wait_timeout(child: &mut Child, timeout: Duration) -> io::Result<Option<ExitStatus>> {
+    let start = Instant::now();
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(status));
+        }
+
+        if start.elapsed() >= timeout {
+            child.kill()?;
+            child.wait()?;
+            return Ok(None);
+        }
+
+        thread::sleep(Duration::from_millis(10));
+    }
+}
+
+/// Load a single module and report the result on stdout.
+/// Runs in the helper process.
+pub(crate) fn run(args: &[String]) -> Result<(), String> {
+    let module = ObsModule {
+        name: get_arg(args, "--name").ok_or("Missing --name")?.to_string(),
+        bin_path: get_arg(args, "--bin").ok_or("Missing --bin")?.to_string(),
+        data_path: get_arg(args, "--data").ok_or("Missing --data")?.to_string(),
+    };
+
+    let obs = Obs::new(
+        get_arg(args, "--locale").unwrap_or("en-US"),
+        get_arg(args, "--libobs"),
+    );
+    let result = match &obs {
+        Ok(_) => {
+            let result = module.load();
+            json!({
+                "status": format!("{:?}", result.status),
+                "code": result.code,
+                "error": result.error,
+            })
+        }
+        Err(e) => json!({ "status": "Error", "error": e.to_string() }),
+    };
+
+    // Report the result before obs is shut down, which may crash as well
+    let mut stdout = io::stdout();
+    writeln!(stdout, "{}{}", RESULT_PREFIX, result).map_err(|e| e.to_string())?;
+    stdout.flush().map_err(|e| e.to_string())?;
+
+    drop(obs);
+    Ok(())
+}
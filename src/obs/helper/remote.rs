@@ -0,0 +1,1268 @@
+use crate::obs::data::obs_module::ObsModule;
+use crate::obs::data::profiler_snapshot::ProfilerEntry;
+use crate::obs::data::settings_validation::SettingsIssue;
+use crate::obs::helper::{helper_path, RESULT_PREFIX};
+use crate::obs::io::obs_output::wait_for;
+use crate::obs::objects::media_state::{MediaEvent, MediaState};
+use crate::obs::objects::module_load_options::ModuleLoadOptions;
+use crate::obs::objects::module_load_result::ModuleLoadResult;
+use crate::obs::objects::obs_version::ObsVersion;
+use crate::obs::objects::output_state::OutputState;
+use crate::obs::objects::reconfigure_options::{ReconfigureData, ReconfigureOptions};
+use crate::obs::objects::reset_audio_data::ResetAudioData;
+use crate::obs::objects::reset_video_data::ResetVideoData;
+use crate::obs::objects::startup_options::StartupOptions;
+use crate::obs::objects::type_schema::TypeSchema;
+use crate::obs::util::obs_error::ObsError;
+use crate::obs::util::types::ResultType;
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use std::collections::{HashMap, VecDeque};
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+use std::sync::{mpsc, Arc, Mutex, Weak};
+use std::thread;
+use std::time::{Duration, Instant};
+
+type EventListener = Box<dyn Fn(&RemoteEvent) + Send + Sync>;
+type StateListener = Arc<dyn Fn(OutputState) + Send + Sync>;
+type MediaListener = Arc<dyn Fn(MediaEvent) + Send + Sync>;
+type Response = ResultType<Value>;
+type ResponseCallback = Box<dyn FnOnce(Response) + Send>;
+
+/// Options for starting a helper process hosting obs.
+#[cfg_attr(feature = "napi", napi(object))]
+#[derive(Clone, Debug, Default)]
+pub struct RemoteObsOptions {
+    /// The locale to start obs with. Defaults to `en-US`.
+    pub locale: Option<String>,
+    /// The path of libobs or the directory containing it.
+    /// libobs is searched for on the library search path if not set.
+    pub libobs_path: Option<String>,
+    /// The path of the `obs-helper` binary.
+    /// Defaults to the `OBS_HELPER_PATH` environment variable
+    /// or `obs-helper` on the `PATH`.
+    pub helper_path: Option<String>,
+    /// The options to start obs with.
+    pub startup: Option<StartupOptions>,
+    /// Whether to restart the helper process if it exits unexpectedly.
+    /// Defaults to `true`.
+    pub restart: Option<bool>,
+    /// The number of restarts allowed within `restart_window`.
+    /// Once reached, the next crash is not restarted and `RestartFailed`
+    /// is emitted instead. Defaults to 3.
+    pub max_restarts: Option<u32>,
+    /// The time window in milliseconds `max_restarts` applies to.
+    /// Defaults to 60 seconds.
+    pub restart_window: Option<u32>,
+}
+
+impl RemoteObsOptions {
+    fn restart_window(&self) -> Duration {
+        Duration::from_millis(self.restart_window.unwrap_or(60_000) as u64)
+    }
+}
+
+/// The kind of a helper process event.
+#[cfg_attr(feature = "napi", napi)]
+#[cfg_attr(not(feature = "napi"), derive(Clone, Copy))]
+#[derive(PartialEq, Eq, Debug)]
+pub enum RemoteEventKind {
+    /// The helper process exited unexpectedly.
+    /// All pending calls failed and all outputs are stopped.
+    Crashed,
+    /// The helper process was restarted after a crash.
+    /// Modules, video and audio settings, encoders, sources and outputs
+    /// have been restored, outputs must be started again.
+    Restarted,
+    /// The helper process could not be restarted after a crash
+    /// or crashed too often within the restart window.
+    /// The instance can't be used anymore.
+    RestartFailed,
+}
+
+/// An event of the helper process.
+#[cfg_attr(feature = "napi", napi(object))]
+#[derive(Clone, Debug)]
+pub struct RemoteEvent {
+    /// The kind of the event.
+    pub kind: RemoteEventKind,
+    /// The exit code of the crashed helper process, if it exited with one.
+    pub code: Option<i32>,
+    /// A message describing the crash or why the restart failed.
+    pub error: Option<String>,
+}
+
+/// A call which sets up the obs instance or creates an object.
+/// Calls are replayed in order when the helper process is restarted.
+#[derive(Debug, PartialEq)]
+struct JournalEntry {
+    /// The handle of the object the call created or modified.
+    handle: Option<u64>,
+    method: &'static str,
+    params: Value,
+}
+
+struct Inner {
+    options: RemoteObsOptions,
+    /// The stdin of the running helper process, `None` while it is not running.
+    stdin: Mutex<Option<ChildStdin>>,
+    /// The callbacks of the requests waiting for a response, by request id.
+    pending: Mutex<HashMap<u64, ResponseCallback>>,
+    next_id: AtomicU64,
+    next_handle: AtomicU64,
+    journal: Mutex<Vec<JournalEntry>>,
+    listeners: Mutex<Vec<EventListener>>,
+    output_states: Mutex<HashMap<u64, OutputState>>,
+    state_listeners: Mutex<HashMap<u64, Vec<StateListener>>>,
+    /// The media listeners of each source with their ids.
+    media_listeners: Mutex<HashMap<u64, Vec<(u32, MediaListener)>>>,
+    next_listener_id: AtomicU32,
+    /// The times of the restarts within the restart window.
+    restarts: Mutex<VecDeque<Instant>>,
+    /// Set once restarting failed, the instance can't be used anymore.
+    failed: AtomicBool,
+}
+
+/// An obs instance running in a helper process.
+/// A crash of libobs or a plugin only terminates the helper process,
+/// which is restarted and set up again unless disabled in the options.
+///
+/// Objects created using the instance are proxies for objects
+/// in the helper process, all calls block until the helper responded.
+/// Settings are passed as JSON objects, so this process doesn't need libobs.
+/// The helper process is stopped once the instance and all objects
+/// created using it are dropped.
+///
+/// # Example
+/// ```ignore
+/// let obs = RemoteObs::new(RemoteObsOptions::default())?;
+/// obs.on_event(|event| println!("{:?}", event.kind));
+///
+/// let modules = obs.get_all_modules(None)?;
+/// obs.load_modules(modules, &ModuleLoadOptions::default())?;
+/// obs.reset_audio(&audio_data)?;
+/// obs.reset_video(&video_data)?;
+///
+/// let video_encoder = obs.create_video_encoder("x264", "obs_x264", None)?;
+/// let output = obs.create_output("output", "flv_output", Some(&json!({
+///     "path": "/path/to/your/output.flv",
+/// })))?;
+/// output.start(Some(&video_encoder), None)?;
+/// ```
+#[derive(Clone)]
+pub struct RemoteObs {
+    inner: Arc<Inner>,
+}
+
+impl RemoteObs {
+    /// Start the helper process and obs in it.
+    /// Returns an error if the helper could not be started or could not start obs.
+    pub fn new(options: RemoteObsOptions) -> ResultType<Self> {
+        let inner = Arc::new(Inner {
+            options,
+            stdin: Mutex::new(None),
+            pending: Mutex::new(HashMap::new()),
+            next_id: AtomicU64::new(1),
+            next_handle: AtomicU64::new(1),
+            journal: Mutex::new(Vec::new()),
+            listeners: Mutex::new(Vec::new()),
+            output_states: Mutex::new(HashMap::new()),
+            state_listeners: Mutex::new(HashMap::new()),
+            media_listeners: Mutex::new(HashMap::new()),
+            next_listener_id: AtomicU32::new(1),
+            restarts: Mutex::new(VecDeque::new()),
+            failed: AtomicBool::new(false),
+        });
+
+        inner.spawn()?;
+        Ok(Self { inner })
+    }
+
+    /// Whether the helper process is running.
+    pub fn is_running(&self) -> bool {
+        self.inner.stdin.lock().unwrap().is_some()
+    }
+
+    /// Listen for events of the helper process.
+    /// The listener is called from the thread reading the helper output
+    /// and must not call into the instance.
+    pub fn on_event<F>(&self, callback: F)
+    where
+        F: Fn(&RemoteEvent) + Send + Sync + 'static,
+    {
+        self.inner
+            .listeners
+            .lock()
+            .unwrap()
+            .push(Box::new(callback));
+    }
+
+    /// Get the current locale of obs in the helper process.
+    pub fn get_locale(&self) -> ResultType<String> {
+        self.inner.call("getLocale", Value::Null)
+    }
+
+    /// Change the locale of obs and all loaded modules.
+    /// The locale is kept if the helper process is restarted.
+    pub fn set_locale(&self, locale: &str) -> ResultType<()> {
+        self.inner
+            .record(None, "setLocale", json!({ "locale": locale }))
+            .map(|_| ())
+    }
+
+    /// Take a snapshot of the data collected by the profiler in the helper process.
+    /// Requires obs to be started with the profiler enabled.
+    pub fn get_profiler_snapshot(&self) -> ResultType<Vec<ProfilerEntry>> {
+        self.inner.call("getProfilerSnapshot", Value::Null)
+    }
+
+    /// Write a snapshot of the data collected by the profiler in the helper process
+    /// to a CSV file, which is compressed using gzip if the path ends with `.gz`.
+    pub fn dump_profiler_snapshot(&self, path: &str) -> ResultType<()> {
+        self.inner
+            .call("dumpProfilerSnapshot", json!({ "path": path }))
+    }
+
+    /// Get all modules which may be loaded.
+    pub fn get_all_modules(&self, obs_path: Option<&str>) -> ResultType<Vec<ObsModule>> {
+        self.inner
+            .call("getAllModules", json!({ "obsPath": obs_path }))
+    }
+
+    /// Load modules in the helper process.
+    /// See `Obs::load_modules`.
+    pub fn load_modules(
+        &self,
+        modules: Vec<ObsModule>,
+        options: &ModuleLoadOptions,
+    ) -> ResultType<Vec<ModuleLoadResult>> {
+        let params = json!({ "modules": modules, "options": options });
+        from_value(self.inner.record(None, "loadModules", params)?)
+    }
+
+    /// Reset the video data.
+    pub fn reset_video(&self, data: &ResetVideoData) -> ResultType<()> {
+        self.inner
+            .record(None, "resetVideo", json!({ "data": data }))
+            .map(|_| ())
+    }
+
+    /// Reset the audio data.
+    pub fn reset_audio(&self, data: &ResetAudioData) -> ResultType<()> {
+        self.inner
+            .record(None, "resetAudio", json!({ "data": data }))
+            .map(|_| ())
+    }
+
+    /// Reset the video and audio in the helper process while outputs may be running.
+    /// See `Obs::reconfigure` for details.
+    pub fn reconfigure(
+        &self,
+        data: &ReconfigureData,
+        options: &ReconfigureOptions,
+    ) -> ResultType<()> {
+        let params = json!({ "data": data, "options": options });
+        self.inner.record(None, "reconfigure", params).map(|_| ())
+    }
+
+    /// Get the video data obs is currently running with in the helper process.
+    pub fn get_video_info(&self) -> ResultType<ResetVideoData> {
+        self.inner.call("getVideoInfo", Value::Null)
+    }
+
+    /// Get the audio data obs is currently running with in the helper process.
+    pub fn get_audio_info(&self) -> ResultType<ResetAudioData> {
+        self.inner.call("getAudioInfo", Value::Null)
+    }
+
+    /// Check if video is active in the helper process.
+    pub fn video_active(&self) -> ResultType<bool> {
+        self.inner.call("videoActive", Value::Null)
+    }
+
+    /// Get the version of libobs loaded by the helper process.
+    pub fn version(&self) -> ResultType<ObsVersion> {
+        self.inner.call("version", Value::Null)
+    }
+
+    /// List all source types.
+    pub fn list_source_types(&self) -> ResultType<Vec<String>> {
+        self.inner.call("listSourceTypes", Value::Null)
+    }
+
+    /// List all encoder types.
+    pub fn list_encoder_types(&self) -> ResultType<Vec<String>> {
+        self.inner.call("listEncoderTypes", Value::Null)
+    }
+
+    /// List all output types.
+    pub fn list_output_types(&self) -> ResultType<Vec<String>> {
+        self.inner.call("listOutputTypes", Value::Null)
+    }
+
+    /// Create a video encoder.
+    pub fn create_video_encoder(
+        &self,
+        name: &str,
+        id: &str,
+        settings: Option<&Value>,
+    ) -> ResultType<RemoteEncoder> {
+        self.create(
+            "createVideoEncoder",
+            json!({ "name": name, "id": id, "settings": settings }),
+        )
+        .map(RemoteEncoder)
+    }
+
+    /// Create an audio encoder.
+    pub fn create_audio_encoder(
+        &self,
+        name: &str,
+        id: &str,
+        settings: Option<&Value>,
+    ) -> ResultType<RemoteEncoder> {
+        self.create(
+            "createAudioEncoder",
+            json!({ "name": name, "id": id, "settings": settings }),
+        )
+        .map(RemoteEncoder)
+    }
+
+    /// Create a source and set it as the source of an output channel.
+    pub fn create_source(
+        &self,
+        name: &str,
+        id: &str,
+        channel: u32,
+        settings: Option<&Value>,
+    ) -> ResultType<RemoteSource> {
+        self.create(
+            "createSource",
+            json!({ "name": name, "id": id, "channel": channel, "settings": settings }),
+        )
+        .map(RemoteSource)
+    }
+
+    /// Create an output.
+    pub fn create_output(
+        &self,
+        name: &str,
+        id: &str,
+        settings: Option<&Value>,
+    ) -> ResultType<RemoteOutput> {
+        let object = self.create(
+            "createOutput",
+            json!({ "name": name, "id": id, "settings": settings }),
+        )?;
+        self.inner
+            .output_states
+            .lock()
+            .unwrap()
+            .insert(object.handle, OutputState::Stopped);
+
+        Ok(RemoteOutput(object))
+    }
+
+    fn create(&self, method: &'static str, mut params: Value) -> ResultType<RemoteObject> {
+        let handle = self.inner.next_handle.fetch_add(1, Ordering::SeqCst);
+        params["handle"] = json!(handle);
+        self.inner.record(Some(handle), method, params)?;
+
+        Ok(RemoteObject {
+            handle,
+            inner: self.inner.clone(),
+        })
+    }
+}
+
+impl Inner {
+    /// Start the helper process and wait until it has started obs.
+    fn spawn(self: &Arc<Self>) -> ResultType<()> {
+        let mut command = Command::new(helper_path(self.options.helper_path.as_deref()));
+        command
+            .arg("host")
+            .args([
+                "--locale",
+                self.options.locale.as_deref().unwrap_or("en-US"),
+            ])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null());
+
+        if let Some(libobs_path) = &self.options.libobs_path {
+            command.args(["--libobs", libobs_path]);
+        }
+
+        if let Some(startup) = &self.options.startup {
+            command.args(["--startup", &json!(startup).to_string()]);
+        }
+
+        let mut child = command
+            .spawn()
+            .map_err(|e| format!("Failed to start the helper: {}", e))?;
+        let stdin = child
+            .stdin
+            .take()
+            .ok_or("Failed to open the helper input")?;
+        let mut stdout = BufReader::new(
+            child
+                .stdout
+                .take()
+                .ok_or("Failed to read the helper output")?,
+        );
+
+        let ready = read_message(&mut stdout);
+        match ready.as_ref().map(|ready| &ready["ready"]) {
+            Some(Value::Bool(true)) => {}
+            _ => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(ObsError::Failed(format!(
+                    "The helper failed to start obs: {}",
+                    ready
+                        .as_ref()
+                        .and_then(|ready| ready["error"].as_str())
+                        .unwrap_or("The helper exited")
+                )));
+            }
+        }
+
+        *self.stdin.lock().unwrap() = Some(stdin);
+
+        let inner = Arc::downgrade(self);
+        thread::Builder::new()
+            .name("obs-helper".to_string())
+            .spawn(move || read_messages(inner, child, stdout))
+            .map_err(|e| format!("Failed to start the reader thread: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Send a request and wait for the response.
+    fn request(&self, method: &str, params: Value) -> Response {
+        let (sender, receiver) = mpsc::channel();
+        self.request_with_callback(method, params, move |response| {
+            let _ = sender.send(response);
+        })?;
+
+        receiver
+            .recv()
+            .unwrap_or_else(|_| Err("The helper process exited".into()))
+    }
+
+    /// Send a request and call `callback` with the response.
+    /// The callback is called from the thread reading the helper output,
+    /// or with an error if the helper process crashes first.
+    /// `callback` is not called if the request could not be sent.
+    fn request_with_callback<F>(&self, method: &str, params: Value, callback: F) -> ResultType<()>
+    where
+        F: FnOnce(Response) + Send + 'static,
+    {
+        let id = self.next_id.fetch_add(1, Ordering::SeqCst);
+        self.pending.lock().unwrap().insert(id, Box::new(callback));
+
+        let request = json!({ "id": id, "method": method, "params": params });
+        if let Err(e) = self.write(&request) {
+            self.pending.lock().unwrap().remove(&id);
+            return Err(e);
+        }
+
+        Ok(())
+    }
+
+    /// Send a request without waiting for the response.
+    fn notify(&self, method: &str, params: Value) {
+        let id = self.next_id.fetch_add(1, Ordering::SeqCst);
+        let _ = self.write(&json!({ "id": id, "method": method, "params": params }));
+    }
+
+    fn write(&self, message: &Value) -> ResultType<()> {
+        match self.stdin.lock().unwrap().as_mut() {
+            Some(stdin) => writeln!(stdin, "{}", message)
+                .and_then(|_| stdin.flush())
+                .map_err(|e| ObsError::Failed(format!("Failed to send the request: {}", e))),
+            None => Err("The helper process is not running".into()),
+        }
+    }
+
+    fn call<T: DeserializeOwned>(&self, method: &str, params: Value) -> ResultType<T> {
+        from_value(self.request(method, params)?)
+    }
+
+    /// Send a request and record it in the journal if it succeeded.
+    fn record(&self, handle: Option<u64>, method: &'static str, params: Value) -> Response {
+        let result = self.request(method, params.clone())?;
+        let mut journal = self.journal.lock().unwrap();
+        if method == "reconfigure" {
+            // Nothing is running while the journal is replayed,
+            // so reconfiguring is the same as resetting
+            let data = &params["data"];
+            if !data["video"].is_null() {
+                journal_add(
+                    &mut journal,
+                    None,
+                    "resetVideo",
+                    json!({ "data": data["video"] }),
+                );
+            }
+            if !data["audio"].is_null() {
+                journal_add(
+                    &mut journal,
+                    None,
+                    "resetAudio",
+                    json!({ "data": data["audio"] }),
+                );
+            }
+        } else {
+            journal_add(&mut journal, handle, method, params);
+        }
+
+        Ok(result)
+    }
+
+    fn handle_message(&self, message: Value) {
+        if message["event"] == "outputState" {
+            if let (Some(handle), Ok(state)) = (
+                message["handle"].as_u64(),
+                from_value::<OutputState>(message["state"].clone()),
+            ) {
+                self.set_output_state(handle, state);
+            }
+
+            return;
+        }
+
+        if message["event"] == "mediaEvent" {
+            if let (Some(handle), Ok(event)) = (
+                message["handle"].as_u64(),
+                from_value::<MediaEvent>(message["mediaEvent"].clone()),
+            ) {
+                self.emit_media_event(handle, event);
+            }
+
+            return;
+        }
+
+        let callback = match message["id"].as_u64() {
+            Some(id) => self.pending.lock().unwrap().remove(&id),
+            None => None,
+        };
+
+        if let Some(callback) = callback {
+            let response = match message["error"].as_str() {
+                Some(error) => Err(ObsError::Failed(error.to_string())),
+                None => Ok(message["result"].clone()),
+            };
+
+            callback(response);
+        }
+    }
+
+    fn set_output_state(&self, handle: u64, state: OutputState) {
+        let changed = match self.output_states.lock().unwrap().get_mut(&handle) {
+            Some(current) if *current != state => {
+                *current = state;
+                true
+            }
+            _ => false,
+        };
+
+        if changed {
+            let listeners = self
+                .state_listeners
+                .lock()
+                .unwrap()
+                .get(&handle)
+                .cloned()
+                .unwrap_or_default();
+
+            for listener in listeners {
+                listener(state);
+            }
+        }
+    }
+
+    fn emit_media_event(&self, handle: u64, event: MediaEvent) {
+        let listeners = self
+            .media_listeners
+            .lock()
+            .unwrap()
+            .get(&handle)
+            .cloned()
+            .unwrap_or_default();
+
+        for (_, listener) in listeners {
+            listener(event);
+        }
+    }
+
+    fn emit(&self, event: RemoteEvent) {
+        for listener in self.listeners.lock().unwrap().iter() {
+            listener(&event);
+        }
+    }
+
+    /// Called once the helper process exited unexpectedly.
+    fn crashed(&self, code: Option<i32>, error: String) {
+        self.stdin.lock().unwrap().take();
+        let pending: Vec<_> = self.pending.lock().unwrap().drain().collect();
+        for (_, callback) in pending {
+            callback(Err(ObsError::Failed(error.clone())));
+        }
+
+        let handles: Vec<u64> = self.output_states.lock().unwrap().keys().copied().collect();
+        for handle in handles {
+            self.set_output_state(handle, OutputState::Stopped);
+        }
+
+        self.emit(RemoteEvent {
+            kind: RemoteEventKind::Crashed,
+            code,
+            error: Some(error),
+        });
+    }
+
+    /// Restart the helper process and replay the journal.
+    /// Fails without restarting if the process has been restarted
+    /// `max_restarts` times within the restart window.
+    fn restart(self: &Arc<Self>) -> ResultType<()> {
+        let max_restarts = self.options.max_restarts.unwrap_or(3) as usize;
+        let window = self.options.restart_window();
+        {
+            let mut restarts = self.restarts.lock().unwrap();
+            let now = Instant::now();
+            while restarts
+                .front()
+                .is_some_and(|time| now.duration_since(*time) > window)
+            {
+                restarts.pop_front();
+            }
+
+            if restarts.len() >= max_restarts {
+                return Err(ObsError::Failed(format!(
+                    "The helper process crashed {} times within {} seconds",
+                    restarts.len() + 1,
+                    window.as_secs_f64()
+                )));
+            }
+
+            restarts.push_back(now);
+        }
+
+        self.spawn()?;
+
+        let journal = self
+            .journal
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|entry| (entry.method, entry.params.clone()))
+            .collect::<Vec<_>>();
+
+        for (method, params) in journal {
+            self.request(method, params)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for Inner {
+    fn drop(&mut self) {
+        // Closing stdin stops the helper process
+        self.stdin.lock().unwrap().take();
+    }
+}
+
+/// Add a call to the journal, keeping only the latest call which resets
+/// obs and merging settings updates of an object into a single update.
+/// A reset replaces the previous one at the end of the journal,
+/// so it is replayed after the objects created before it.
+/// Entries of released objects are removed once the object is dropped.
+fn journal_add(
+    journal: &mut Vec<JournalEntry>,
+    handle: Option<u64>,
+    method: &'static str,
+    params: Value,
+) {
+    let is_previous = |entry: &JournalEntry| entry.handle == handle && entry.method == method;
+    match method {
+        "setLocale" | "resetVideo" | "resetAudio" => journal.retain(|entry| !is_previous(entry)),
+        "updateSettings" => {
+            if let Some(entry) = journal.iter_mut().find(|entry| is_previous(entry)) {
+                let settings = params["settings"].as_object().cloned().unwrap_or_default();
+                match entry.params["settings"].as_object_mut() {
+                    Some(existing) => existing.extend(settings),
+                    None => entry.params["settings"] = Value::Object(settings),
+                }
+
+                entry.params["strict"] = params["strict"].clone();
+                return;
+            }
+        }
+        _ => {}
+    }
+
+    journal.push(JournalEntry {
+        handle,
+        method,
+        params,
+    });
+}
+
+fn from_value<T: DeserializeOwned>(value: Value) -> ResultType<T> {
+    serde_json::from_value(value)
+        .map_err(|e| ObsError::Failed(format!("Invalid response from the helper: {}", e)))
+}
+
+/// Read the next message from the helper output, skipping log lines.
+fn read_message(stdout: &mut BufReader<ChildStdout>) -> Option<Value> {
+    let mut line = String::new();
+    loop {
+        line.clear();
+        match stdout.read_line(&mut line) {
+            Ok(0) | Err(_) => return None,
+            Ok(_) => {
+                if let Some(message) = line.trim_end().strip_prefix(RESULT_PREFIX) {
+                    if let Ok(message) = serde_json::from_str(message) {
+                        return Some(message);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Route the messages of the helper process until it exits.
+/// Stops once the instance has been dropped.
+fn read_messages(inner: Weak<Inner>, mut child: Child, mut stdout: BufReader<ChildStdout>) {
+    while let Some(message) = read_message(&mut stdout) {
+        match inner.upgrade() {
+            Some(inner) => inner.handle_message(message),
+            None => break,
+        }
+    }
+
+    let status = child.wait();
+    let inner = match inner.upgrade() {
+        Some(inner) => inner,
+        None => return,
+    };
+
+    // The process of a failed restart exits once its stdin has been closed
+    if inner.failed.load(Ordering::SeqCst) {
+        return;
+    }
+
+    let (code, error) = match status {
+        Ok(status) => (
+            status.code(),
+            format!("The helper process exited with {}", status),
+        ),
+        Err(e) => (None, format!("The helper process exited: {}", e)),
+    };
+    inner.crashed(code, error);
+
+    if inner.options.restart.unwrap_or(true) {
+        let event = match inner.restart() {
+            Ok(()) => RemoteEvent {
+                kind: RemoteEventKind::Restarted,
+                code: None,
+                error: None,
+            },
+            Err(e) => {
+                inner.failed.store(true, Ordering::SeqCst);
+                inner.stdin.lock().unwrap().take();
+                RemoteEvent {
+                    kind: RemoteEventKind::RestartFailed,
+                    code: None,
+                    error: Some(e.to_string()),
+                }
+            }
+        };
+
+        inner.emit(event);
+    }
+}
+
+/// An object in the helper process.
+/// Released in the helper process once dropped.
+struct RemoteObject {
+    handle: u64,
+    inner: Arc<Inner>,
+}
+
+impl RemoteObject {
+    fn call<T: DeserializeOwned>(&self, method: &str, mut params: Value) -> ResultType<T> {
+        params["handle"] = json!(self.handle);
+        self.inner.call(method, params)
+    }
+
+    fn get_settings(&self) -> ResultType<Value> {
+        self.call("getSettings", json!({}))
+    }
+
+    fn update_settings(&self, settings: &Value, strict: bool) -> ResultType<()> {
+        let params = json!({ "handle": self.handle, "settings": settings, "strict": strict });
+        self.inner
+            .record(Some(self.handle), "updateSettings", params)
+            .map(|_| ())
+    }
+
+    fn get_schema(&self, name: Option<&str>) -> ResultType<TypeSchema> {
+        self.call("getSchema", json!({ "name": name }))
+    }
+
+    fn validate(&self, settings: &Value) -> ResultType<Vec<SettingsIssue>> {
+        self.call("validate", json!({ "settings": settings }))
+    }
+}
+
+impl Drop for RemoteObject {
+    fn drop(&mut self) {
+        self.inner
+            .journal
+            .lock()
+            .unwrap()
+            .retain(|entry| entry.handle != Some(self.handle));
+        self.inner
+            .output_states
+            .lock()
+            .unwrap()
+            .remove(&self.handle);
+        self.inner
+            .state_listeners
+            .lock()
+            .unwrap()
+            .remove(&self.handle);
+        self.inner
+            .media_listeners
+            .lock()
+            .unwrap()
+            .remove(&self.handle);
+
+        self.inner
+            .notify("release", json!({ "handle": self.handle }));
+    }
+}
+
+/// An encoder in the helper process.
+pub struct RemoteEncoder(RemoteObject);
+
+impl RemoteEncoder {
+    /// Get the encoder settings.
+    pub fn get_settings(&self) -> ResultType<Value> {
+        self.0.get_settings()
+    }
+
+    /// Update the encoder settings.
+    /// If `strict` is set, the settings are validated against the properties first.
+    pub fn update_settings(&self, settings: &Value, strict: bool) -> ResultType<()> {
+        self.0.update_settings(settings, strict)
+    }
+
+    /// Generate the settings schema of this encoder from its properties.
+    /// The name of the TypeScript interface defaults to `Settings`.
+    pub fn get_schema(&self, name: Option<&str>) -> ResultType<TypeSchema> {
+        self.0.get_schema(name)
+    }
+
+    /// Validate settings against the properties of this encoder.
+    /// Returns an empty list if the settings are valid.
+    pub fn validate(&self, settings: &Value) -> ResultType<Vec<SettingsIssue>> {
+        self.0.validate(settings)
+    }
+}
+
+/// A source in the helper process.
+pub struct RemoteSource(RemoteObject);
+
+impl RemoteSource {
+    /// Start playing the media.
+    /// Returns an error if the source is not a media source.
+    pub fn play(&self) -> ResultType<()> {
+        self.0.call("play", json!({}))
+    }
+
+    /// Pause the media.
+    /// Returns an error if the source is not a media source.
+    pub fn pause(&self) -> ResultType<()> {
+        self.0.call("pause", json!({}))
+    }
+
+    /// Stop the media.
+    /// Returns an error if the source is not a media source.
+    pub fn stop(&self) -> ResultType<()> {
+        self.0.call("stop", json!({}))
+    }
+
+    /// Restart the media from the beginning.
+    /// Returns an error if the source is not a media source.
+    pub fn restart(&self) -> ResultType<()> {
+        self.0.call("restart", json!({}))
+    }
+
+    /// Seek to a position in milliseconds.
+    /// Returns an error if the source is not a media source.
+    pub fn seek(&self, ms: i64) -> ResultType<()> {
+        self.0.call("seek", json!({ "ms": ms }))
+    }
+
+    /// Skip to the next item of a playlist.
+    /// Returns an error if the source is not a media source.
+    pub fn next(&self) -> ResultType<()> {
+        self.0.call("next", json!({}))
+    }
+
+    /// Go back to the previous item of a playlist.
+    /// Returns an error if the source is not a media source.
+    pub fn previous(&self) -> ResultType<()> {
+        self.0.call("previous", json!({}))
+    }
+
+    /// Get the current position of the media in milliseconds.
+    pub fn get_time(&self) -> ResultType<i64> {
+        self.0.call("getTime", json!({}))
+    }
+
+    /// Get the duration of the media in milliseconds.
+    pub fn get_duration(&self) -> ResultType<i64> {
+        self.0.call("getDuration", json!({}))
+    }
+
+    /// Get the playback state of the media.
+    pub fn get_state(&self) -> ResultType<MediaState> {
+        self.0.call("getState", json!({}))
+    }
+
+    /// Listen for media events of this source.
+    /// The listener is called from the thread reading the helper output
+    /// and must not call into the instance.
+    /// Returns the id of the listener, which can be passed
+    /// to `remove_media_listener` to stop listening.
+    pub fn on_media_event<F>(&self, callback: F) -> u32
+    where
+        F: Fn(MediaEvent) + Send + Sync + 'static,
+    {
+        let inner = &self.0.inner;
+        let id = inner.next_listener_id.fetch_add(1, Ordering::SeqCst);
+        inner
+            .media_listeners
+            .lock()
+            .unwrap()
+            .entry(self.0.handle)
+            .or_default()
+            .push((id, Arc::new(callback)));
+
+        id
+    }
+
+    /// Remove a listener added using `on_media_event`.
+    /// Returns `false` if no listener with the given id exists.
+    pub fn remove_media_listener(&self, id: u32) -> bool {
+        match self
+            .0
+            .inner
+            .media_listeners
+            .lock()
+            .unwrap()
+            .get_mut(&self.0.handle)
+        {
+            Some(listeners) => {
+                let count = listeners.len();
+                listeners.retain(|(listener, _)| *listener != id);
+                listeners.len() != count
+            }
+            None => false,
+        }
+    }
+
+    /// Get the source settings.
+    pub fn get_settings(&self) -> ResultType<Value> {
+        self.0.get_settings()
+    }
+
+    /// Update the source settings.
+    /// If `strict` is set, the settings are validated against the properties first.
+    pub fn update_settings(&self, settings: &Value, strict: bool) -> ResultType<()> {
+        self.0.update_settings(settings, strict)
+    }
+
+    /// Generate the settings schema of this source from its properties.
+    /// The name of the TypeScript interface defaults to `Settings`.
+    pub fn get_schema(&self, name: Option<&str>) -> ResultType<TypeSchema> {
+        self.0.get_schema(name)
+    }
+
+    /// Validate settings against the properties of this source.
+    /// Returns an empty list if the settings are valid.
+    pub fn validate(&self, settings: &Value) -> ResultType<Vec<SettingsIssue>> {
+        self.0.validate(settings)
+    }
+}
+
+/// An output in the helper process.
+/// The state is kept in sync with the output in the helper process
+/// and changes to `Stopped` if the helper process crashes.
+pub struct RemoteOutput(RemoteObject);
+
+impl RemoteOutput {
+    /// Start the output.
+    /// At least one encoder must be set before starting the output.
+    /// Returns an error if the output is not stopped.
+    pub fn start(
+        &self,
+        video_encoder: Option<&RemoteEncoder>,
+        audio_encoder: Option<&RemoteEncoder>,
+    ) -> ResultType<()> {
+        self.0.call(
+            "start",
+            json!({
+                "videoEncoder": video_encoder.map(|e| e.0.handle),
+                "audioEncoder": audio_encoder.map(|e| e.0.handle),
+            }),
+        )
+    }
+
+    /// Stop the output.
+    /// Returns an error if the output is not running.
+    pub fn stop(&self) -> ResultType<()> {
+        self.0.call("stop", json!({}))
+    }
+
+    /// Start the output and call `callback` once it has actually started,
+    /// or with an error if it stopped before it started.
+    /// The callback is called from the thread reading the helper output.
+    /// Returns an error without calling `callback` if the request could not be sent.
+    pub fn start_with_callback<F>(
+        &self,
+        video_encoder: Option<&RemoteEncoder>,
+        audio_encoder: Option<&RemoteEncoder>,
+        callback: F,
+    ) -> ResultType<()>
+    where
+        F: FnOnce(ResultType<()>) + Send + 'static,
+    {
+        let params = json!({
+            "handle": self.0.handle,
+            "videoEncoder": video_encoder.map(|e| e.0.handle),
+            "audioEncoder": audio_encoder.map(|e| e.0.handle),
+        });
+        self.0
+            .inner
+            .request_with_callback("startAsync", params, |res| callback(res.map(|_| ())))
+    }
+
+    /// Stop the output and call `callback` once it has actually stopped,
+    /// e.g. once a recording has been finalized.
+    /// The callback is called from the thread reading the helper output.
+    /// Returns an error without calling `callback` if the request could not be sent.
+    pub fn stop_with_callback<F>(&self, callback: F) -> ResultType<()>
+    where
+        F: FnOnce(ResultType<()>) + Send + 'static,
+    {
+        let params = json!({ "handle": self.0.handle });
+        self.0
+            .inner
+            .request_with_callback("stopAsync", params, |res| callback(res.map(|_| ())))
+    }
+
+    /// Start the output and block until it has actually started.
+    /// Returns an error if the output stopped before it started
+    /// or if it didn't start within `timeout`.
+    pub fn start_and_wait(
+        &self,
+        video_encoder: Option<&RemoteEncoder>,
+        audio_encoder: Option<&RemoteEncoder>,
+        timeout: Option<Duration>,
+    ) -> ResultType<()> {
+        let (sender, receiver) = mpsc::channel();
+        self.start_with_callback(video_encoder, audio_encoder, move |res| {
+            let _ = sender.send(res);
+        })?;
+
+        wait_for(
+            receiver,
+            timeout,
+            "Timed out waiting for the output to start",
+        )
+    }
+
+    /// Stop the output and block until it has actually stopped.
+    /// Returns an error if the output didn't stop within `timeout`.
+    pub fn stop_and_wait(&self, timeout: Option<Duration>) -> ResultType<()> {
+        let (sender, receiver) = mpsc::channel();
+        self.stop_with_callback(move |res| {
+            let _ = sender.send(res);
+        })?;
+
+        wait_for(
+            receiver,
+            timeout,
+            "Timed out waiting for the output to stop",
+        )
+    }
+
+    /// Force stop the output.
+    /// Returns an error if the output is not running.
+    pub fn force_stop(&self) -> ResultType<()> {
+        self.0.call("forceStop", json!({}))
+    }
+
+    /// Pause the output.
+    /// Returns an error if the output is not running.
+    pub fn pause(&self) -> ResultType<()> {
+        self.0.call("pause", json!({}))
+    }
+
+    /// Resume the output.
+    /// Returns an error if the output is not paused.
+    pub fn resume(&self) -> ResultType<()> {
+        self.0.call("resume", json!({}))
+    }
+
+    /// Check if the output is active in the helper process.
+    pub fn get_active(&self) -> ResultType<bool> {
+        self.0.call("getActive", json!({}))
+    }
+
+    /// Check if the output is paused in the helper process.
+    pub fn get_paused(&self) -> ResultType<bool> {
+        self.0.call("getPaused", json!({}))
+    }
+
+    /// Check if the output can be paused.
+    pub fn can_pause(&self) -> ResultType<bool> {
+        self.0.call("canPause", json!({}))
+    }
+
+    /// Get the state of the output.
+    pub fn get_state(&self) -> OutputState {
+        self.0
+            .inner
+            .output_states
+            .lock()
+            .unwrap()
+            .get(&self.0.handle)
+            .copied()
+            .unwrap_or(OutputState::Stopped)
+    }
+
+    /// Listen for state changes of this output.
+    /// The listener is called from the thread reading the helper output
+    /// and must not call into the instance.
+    pub fn on_state_change<F>(&self, callback: F)
+    where
+        F: Fn(OutputState) + Send + Sync + 'static,
+    {
+        self.0
+            .inner
+            .state_listeners
+            .lock()
+            .unwrap()
+            .entry(self.0.handle)
+            .or_default()
+            .push(Arc::new(callback));
+    }
+
+    /// Get the output settings.
+    pub fn get_settings(&self) -> ResultType<Value> {
+        self.0.get_settings()
+    }
+
+    /// Update the output settings.
+    /// If `strict` is set, the settings are validated against the properties first.
+    pub fn update_settings(&self, settings: &Value, strict: bool) -> ResultType<()> {
+        self.0.update_settings(settings, strict)
+    }
+
+    /// Generate the settings schema of this output from its properties.
+    /// The name of the TypeScript interface defaults to `Settings`.
+    pub fn get_schema(&self, name: Option<&str>) -> ResultType<TypeSchema> {
+        self.0.get_schema(name)
+    }
+
+    /// Validate settings against the properties of this output.
+    /// Returns an empty list if the settings are valid.
+    pub fn validate(&self, settings: &Value) -> ResultType<Vec<SettingsIssue>> {
+        self.0.validate(settings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(handle: Option<u64>, method: &'static str, params: Value) -> JournalEntry {
+        JournalEntry {
+            handle,
+            method,
+            params,
+        }
+    }
+
+    #[test]
+    fn journal_keeps_latest_reset() {
+        let mut journal = Vec::new();
+        journal_add(&mut journal, None, "resetVideo", json!({ "data": 1 }));
+        journal_add(
+            &mut journal,
+            Some(1),
+            "createOutput",
+            json!({ "handle": 1 }),
+        );
+        journal_add(&mut journal, None, "resetVideo", json!({ "data": 2 }));
+        journal_add(&mut journal, None, "resetAudio", json!({ "data": 3 }));
+        journal_add(&mut journal, None, "loadModules", json!({}));
+        journal_add(&mut journal, None, "loadModules", json!({}));
+
+        assert_eq!(
+            journal,
+            vec![
+                entry(Some(1), "createOutput", json!({ "handle": 1 })),
+                entry(None, "resetVideo", json!({ "data": 2 })),
+                entry(None, "resetAudio", json!({ "data": 3 })),
+                entry(None, "loadModules", json!({})),
+                entry(None, "loadModules", json!({})),
+            ]
+        );
+    }
+
+    #[test]
+    fn journal_merges_settings_per_handle() {
+        let mut journal = Vec::new();
+        let update = |settings: Value| json!({ "settings": settings, "strict": false });
+        journal_add(
+            &mut journal,
+            Some(1),
+            "updateSettings",
+            update(json!({ "a": 1, "b": 1 })),
+        );
+        journal_add(
+            &mut journal,
+            Some(2),
+            "updateSettings",
+            update(json!({ "a": 1 })),
+        );
+        journal_add(
+            &mut journal,
+            Some(1),
+            "updateSettings",
+            update(json!({ "b": 2, "c": 2 })),
+        );
+
+        assert_eq!(
+            journal,
+            vec![
+                entry(
+                    Some(1),
+                    "updateSettings",
+                    update(json!({ "a": 1, "b": 2, "c": 2 }))
+                ),
+                entry(Some(2), "updateSettings", update(json!({ "a": 1 }))),
+            ]
+        );
+    }
+}
@@ -1,19 +1,36 @@
+use crate::obs::data::obs_encoder_type::EncoderKind;
 use crate::obs::data::obs_properties::ObsProperties;
 use crate::obs::data::obs_settings::ObsSettings;
+use crate::obs::data::settings_validation::SettingsIssue;
 use crate::obs::sys;
 use crate::obs::traits::from_raw::{FromRaw, Guard};
 use crate::obs::traits::raw::Raw;
-use crate::obs::util::napi_error::MapToNapiError;
 use crate::obs::util::obs_guard::ObsGuard;
 use crate::obs::util::types::ResultType;
 use std::sync::Arc;
 
-struct ObsEncoder {
+/// An obs video or audio encoder.
+///
+/// # Example
+/// ```ignore
+/// let mut settings = ObsSettings::new()?;
+/// settings.set_string("rate_control", "CQP")?.set_int("cqp", 23)?;
+///
+/// let encoder = obs.create_video_encoder("nvenc", "jim_nvenc", Some(&settings))?;
+/// ```
+pub struct ObsEncoder {
     encoder: *mut sys::obs_encoder_t,
+    kind: EncoderKind,
     guard: Arc<ObsGuard>,
 }
 
 impl ObsEncoder {
+    /// Whether this is an audio or a video encoder.
+    pub fn kind(&self) -> EncoderKind {
+        self.kind
+    }
+
+    /// Get the settings of this encoder.
     pub fn get_settings(&self) -> ResultType<ObsSettings> {
         let data = unsafe { sys::obs_encoder_get_settings(self.encoder) };
 
@@ -24,12 +41,28 @@ impl ObsEncoder {
         }
     }
 
-    pub fn update(&self, settings: &ObsSettings) {
+    /// Update the settings of this encoder.
+    /// If `strict` is set, the settings are validated first
+    /// and an error is returned if they are invalid.
+    pub fn update_settings(&self, settings: &ObsSettings, strict: bool) -> ResultType<()> {
+        if strict {
+            self.get_properties()?.validate_strict(settings)?;
+        }
+
         unsafe {
             sys::obs_encoder_update(self.encoder, settings.raw());
         }
+
+        Ok(())
+    }
+
+    /// Validate settings against the properties of this encoder.
+    /// Returns an empty list if the settings are valid.
+    pub fn validate(&self, settings: &ObsSettings) -> ResultType<Vec<SettingsIssue>> {
+        self.get_properties()?.validate(settings)
     }
 
+    /// Get the properties of this encoder.
     pub fn get_properties(&self) -> ResultType<ObsProperties> {
         let data = unsafe { sys::obs_encoder_properties(self.encoder) };
 
@@ -41,106 +74,26 @@ impl ObsEncoder {
     }
 }
 
-/// An obs video encoder.
-///
-/// # Example
-/// ```ts
-/// const encoder = await obs.createVideoEncoder('nvenc', 'jim_nvenc', new ObsSettings({
-///    rate_control: 'CQP',
-///    cqp: 23,
-///    preset: 'medium',
-///    profile: 'high',
-/// }));
-#[napi]
-pub struct ObsVideoEncoder(ObsEncoder);
-
-#[napi]
-impl ObsVideoEncoder {
-    /// Get the settings of this encoder.
-    #[napi]
-    pub fn get_settings(&self) -> napi::Result<ObsSettings> {
-        self.0.get_settings().map_napi_err()
-    }
-
-    /// Update the settings of this encoder.
-    #[napi]
-    pub fn update_settings(&self, settings: &ObsSettings) {
-        self.0.update(settings)
-    }
-
-    /// Get the properties of this encoder.
-    #[napi]
-    pub fn get_properties(&self) -> napi::Result<ObsProperties> {
-        self.0.get_properties().map_napi_err()
-    }
-}
-
-impl FromRaw<sys::obs_encoder_t> for ObsVideoEncoder {
+impl FromRaw<sys::obs_encoder_t> for ObsEncoder {
     unsafe fn from_raw_unchecked(encoder: *mut sys::obs_encoder_t, guard: Guard) -> Self {
-        Self(ObsEncoder {
-            encoder,
-            guard: guard.unwrap(),
-        })
-    }
-}
-
-unsafe impl Send for ObsVideoEncoder {}
-
-/// An obs audio encoder.
-///
-/// # Example
-/// ```ts
-/// const audioEncoder = await obs.createAudioEncoder('aac', 'ffmpeg_aac', new ObsSettings({
-///    bitrate: 128,
-///    rate_control: 'CBR',
-/// }));
-/// ```
-#[napi]
-pub struct ObsAudioEncoder(ObsEncoder);
-
-#[napi]
-impl ObsAudioEncoder {
-    /// Get the settings of this encoder.
-    #[napi]
-    pub fn get_settings(&self) -> napi::Result<ObsSettings> {
-        self.0.get_settings().map_napi_err()
-    }
+        let kind = EncoderKind::from_value(sys::obs_encoder_get_type(encoder))
+            .unwrap_or(EncoderKind::Video);
 
-    /// Update the settings of this encoder.
-    #[napi]
-    pub fn update_settings(&self, settings: &ObsSettings) {
-        self.0.update(settings)
-    }
-
-    /// Get the properties of this encoder.
-    #[napi]
-    pub fn get_properties(&self) -> napi::Result<ObsProperties> {
-        self.0.get_properties().map_napi_err()
-    }
-}
-
-impl FromRaw<sys::obs_encoder_t> for ObsAudioEncoder {
-    unsafe fn from_raw_unchecked(encoder: *mut sys::obs_encoder_t, guard: Guard) -> Self {
-        Self(ObsEncoder {
+        Self {
             encoder,
+            kind,
             guard: guard.unwrap(),
-        })
+        }
     }
 }
 
-unsafe impl Send for ObsAudioEncoder {}
-
-impl Raw<sys::obs_encoder_t> for ObsVideoEncoder {
+impl Raw<sys::obs_encoder_t> for ObsEncoder {
     unsafe fn raw(&self) -> *mut sys::obs_encoder_t {
-        self.0.encoder
+        self.encoder
     }
 }
 
-impl Raw<sys::obs_encoder_t> for ObsAudioEncoder {
-    unsafe fn raw(&self) -> *mut sys::obs_encoder_t {
-        self.0.encoder
-    }
-}
+unsafe impl Send for ObsEncoder {}
 
 impl Drop for ObsEncoder {
     fn drop(&mut self) {
@@ -1,56 +1,202 @@
+use crate::obs::custom::encoded_packet::Packet;
+use crate::obs::custom::js_output::{is_js_output, remove_packet_listener, set_packet_listener};
+use crate::obs::custom::mpegts_output::{
+    is_mpegts_output, remove_data_listener, set_data_listener,
+};
+use crate::obs::data::obs_encoder_type::EncoderKind;
 use crate::obs::data::obs_properties::ObsProperties;
 use crate::obs::data::obs_settings::ObsSettings;
-use crate::obs::io::obs_encoder::{ObsAudioEncoder, ObsVideoEncoder};
+use crate::obs::data::settings_validation::SettingsIssue;
+use crate::obs::io::obs_encoder::ObsEncoder;
+use crate::obs::objects::output_state::OutputState;
 use crate::obs::sys;
 use crate::obs::traits::from_raw::{FromRaw, Guard};
 use crate::obs::traits::raw::Raw;
-use crate::obs::util::napi_error::{to_napi_error_str, to_napi_error_string};
+use crate::obs::util::obs_error::{ObsError, OBS_OUTPUT_SUCCESS};
 use crate::obs::util::obs_guard::ObsGuard;
+use crate::obs::util::signal_handler::{calldata_int, calldata_ptr, SignalConnection};
+use crate::obs::util::types::ResultType;
 use std::ffi::CStr;
 use std::mem;
-use std::sync::atomic::{AtomicPtr, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicPtr, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
-#[derive(PartialEq)]
-enum OutputState {
-    Stopped,
-    Running,
-    Paused,
+type StateListener = Arc<dyn Fn(OutputState) + Send + Sync>;
+type WaitCallback = Box<dyn FnOnce(ResultType<()>) + Send>;
+
+/// Callbacks waiting for the output to start or stop.
+#[derive(Default)]
+struct Waiters {
+    next_id: u64,
+    start: Vec<(u64, WaitCallback)>,
+    stop: Vec<(u64, WaitCallback)>,
+}
+
+impl Waiters {
+    fn next_id(&mut self) -> u64 {
+        self.next_id += 1;
+        self.next_id
+    }
+}
+
+/// The state of an output shared with its signal callbacks.
+struct OutputShared {
+    state: Mutex<OutputState>,
+    /// Set once the output has been started until it emitted the start or stop signal,
+    /// e.g. while it is still connecting.
+    pending_start: AtomicBool,
+    waiters: Mutex<Waiters>,
+    listeners: Mutex<Vec<StateListener>>,
+}
+
+impl OutputShared {
+    fn new() -> Self {
+        Self {
+            state: Mutex::new(OutputState::Stopped),
+            pending_start: AtomicBool::new(false),
+            waiters: Mutex::new(Waiters::default()),
+            listeners: Mutex::new(Vec::new()),
+        }
+    }
+
+    fn get_state(&self) -> OutputState {
+        *self.state.lock().unwrap()
+    }
+
+    /// Change the state if `check` accepts the current state.
+    fn begin<F>(&self, state: OutputState, check: F) -> ResultType<()>
+    where
+        F: FnOnce(OutputState) -> ResultType<()>,
+    {
+        let previous = {
+            let mut current = self.state.lock().unwrap();
+            check(*current)?;
+            mem::replace(&mut *current, state)
+        };
+
+        if previous != state {
+            self.notify(state);
+        }
+
+        Ok(())
+    }
+
+    fn set_state(&self, state: OutputState) {
+        let previous = mem::replace(&mut *self.state.lock().unwrap(), state);
+        if previous != state {
+            self.notify(state);
+        }
+    }
+
+    /// Call the listeners without holding the lock,
+    /// so they may change the state or add listeners.
+    fn notify(&self, state: OutputState) {
+        let listeners = self.listeners.lock().unwrap().clone();
+        for listener in listeners {
+            listener(state);
+        }
+    }
+
+    fn started(&self) {
+        self.pending_start.store(false, Ordering::SeqCst);
+        self.set_state(OutputState::Active);
+
+        let start = mem::take(&mut self.waiters.lock().unwrap().start);
+        for (_, callback) in start {
+            callback(Ok(()));
+        }
+    }
+
+    fn stopped(&self, code: i32, output: Option<*mut sys::obs_output_t>) {
+        self.pending_start.store(false, Ordering::SeqCst);
+        self.set_state(OutputState::Stopped);
+
+        let (start, stop) = {
+            let mut waiters = self.waiters.lock().unwrap();
+            (mem::take(&mut waiters.start), mem::take(&mut waiters.stop))
+        };
+
+        for (_, callback) in start {
+            callback(Err(ObsError::OutputStopped {
+                code,
+                message: output.and_then(|output| unsafe { last_error(output) }),
+            }));
+        }
+
+        for (_, callback) in stop {
+            callback(Ok(()));
+        }
+    }
 }
 
 /// An obs output.
-#[napi]
+///
+/// # Example
+/// ```ignore
+/// let mut settings = ObsSettings::new()?;
+/// settings.set_string("path", "/path/to/your/output.flv")?;
+///
+/// let output = obs.create_output("output", "flv_output", Some(&settings))?;
+/// output.start(Some(&video_encoder), Some(&audio_encoder))?;
+/// ```
 pub struct ObsOutput {
     output: AtomicPtr<sys::obs_output_t>,
-    state: Mutex<OutputState>,
+    shared: Arc<OutputShared>,
+    signals: Vec<SignalConnection>,
     guard: Arc<ObsGuard>,
 }
 
-#[napi]
 impl ObsOutput {
     /// Start the output.
     /// This will start the output and start sending data to the output.
     /// At least one encoder must be set before starting the output.
-    /// Throws an error if the output is already running.
-    #[napi]
+    /// Returns an error if the output is not stopped.
     pub fn start(
         &self,
-        video_encoder: Option<&ObsVideoEncoder>,
-        audio_encoder: Option<&ObsAudioEncoder>,
-    ) -> napi::Result<()> {
-        let mut state = self.state.lock().unwrap();
-        if *state == OutputState::Running {
-            return Err(to_napi_error_str("Output is already running"));
+        video_encoder: Option<&ObsEncoder>,
+        audio_encoder: Option<&ObsEncoder>,
+    ) -> ResultType<()> {
+        self.shared
+            .begin(OutputState::Starting, |state| match state {
+                OutputState::Stopped => Ok(()),
+                OutputState::Stopping => {
+                    Err(ObsError::InvalidState("The output is still stopping"))
+                }
+                _ => Err(ObsError::InvalidState("Output is already running")),
+            })?;
+
+        // The output may start before obs_output_start returns
+        self.shared.pending_start.store(true, Ordering::SeqCst);
+        let res = self.start_output(video_encoder, audio_encoder);
+        if res.is_err() {
+            self.shared.pending_start.store(false, Ordering::SeqCst);
+            self.shared.set_state(OutputState::Stopped);
         }
 
+        res
+    }
+
+    fn start_output(
+        &self,
+        video_encoder: Option<&ObsEncoder>,
+        audio_encoder: Option<&ObsEncoder>,
+    ) -> ResultType<()> {
         if video_encoder.is_none() && audio_encoder.is_none() {
-            return Err(to_napi_error_str("No encoder specified"));
+            return Err("No encoder specified".into());
         }
 
         if let Some(encoder) = video_encoder {
+            if !matches!(encoder.kind(), EncoderKind::Video) {
+                return Err(ObsError::Unsupported(
+                    "The video encoder is not a video encoder",
+                ));
+            }
+
             let video = unsafe { sys::obs_get_video() };
             if video.is_null() {
-                return Err(to_napi_error_str("Failed to get video"));
+                return Err("Failed to get video".into());
             }
 
             unsafe {
@@ -60,9 +206,15 @@ impl ObsOutput {
         }
 
         if let Some(encoder) = audio_encoder {
+            if !matches!(encoder.kind(), EncoderKind::Audio) {
+                return Err(ObsError::Unsupported(
+                    "The audio encoder is not an audio encoder",
+                ));
+            }
+
             let audio = unsafe { sys::obs_get_audio() };
             if audio.is_null() {
-                return Err(to_napi_error_str("Failed to get audio"));
+                return Err("Failed to get audio".into());
             }
 
             unsafe {
@@ -78,61 +230,178 @@ impl ObsOutput {
         let ok = unsafe { sys::obs_output_start(self.raw()) };
 
         if !ok {
-            let error = unsafe { sys::obs_output_get_last_error(self.raw()) };
-            let message: String = if error.is_null() {
-                "Unknown".to_string()
-            } else {
-                unsafe { CStr::from_ptr(error) }
-                    .to_string_lossy()
-                    .into_owned()
-            };
-
-            Err(to_napi_error_string(format!(
-                "Failed to start output. Error message: {}",
-                message
-            )))
+            let message = unsafe { last_error(self.raw()) };
+            Err(ObsError::OutputStart(
+                message.unwrap_or_else(|| "Unknown".to_string()),
+            ))
         } else {
-            let _ = mem::replace(&mut *state, OutputState::Running);
             Ok(())
         }
     }
 
     /// Stop the output.
     /// This will stop the output and stop sending data to the output.
-    /// Throws an error if the output is not running.
-    #[napi]
-    pub fn stop(&self) -> napi::Result<()> {
-        let mut state = self.state.lock().unwrap();
-        if *state == OutputState::Stopped {
-            return Err(to_napi_error_str("Output is already stopped"));
-        }
+    /// The output is in the `Stopping` state until it has actually stopped.
+    /// An output which is still starting, e.g. connecting, may still activate,
+    /// use `force_stop` to abort starting.
+    /// Returns an error if the output is not running.
+    pub fn stop(&self) -> ResultType<()> {
+        self.shared
+            .begin(OutputState::Stopping, |state| match state {
+                OutputState::Stopped => Err(ObsError::InvalidState("Output is already stopped")),
+                OutputState::Stopping => Err(ObsError::InvalidState("Output is already stopping")),
+                _ => Ok(()),
+            })?;
 
         unsafe { sys::obs_output_stop(self.raw()) };
-        let _ = mem::replace(&mut *state, OutputState::Stopped);
+        self.check_stopped();
         Ok(())
     }
 
     /// Force stop the output.
     /// This will force stop the output and stop sending data to the output.
-    /// Throws an error if the output is not running.
-    #[napi]
-    pub fn force_stop(&self) -> napi::Result<()> {
-        let mut state = self.state.lock().unwrap();
-        if *state == OutputState::Stopped {
-            return Err(to_napi_error_str("Output is already stopped"));
-        }
+    /// May be used to stop an output which is still stopping.
+    /// Returns an error if the output is not running.
+    pub fn force_stop(&self) -> ResultType<()> {
+        self.shared
+            .begin(OutputState::Stopping, |state| match state {
+                OutputState::Stopped => Err(ObsError::InvalidState("Output is already stopped")),
+                _ => Ok(()),
+            })?;
 
         unsafe { sys::obs_output_force_stop(self.raw()) };
-        let _ = mem::replace(&mut *state, OutputState::Stopped);
+        self.check_stopped();
         Ok(())
     }
 
+    /// libobs doesn't emit the stop signal for outputs which aren't active,
+    /// so they are stopped here. Outputs which are reconnecting or still starting,
+    /// e.g. connecting, may still emit signals and are left to them.
+    fn check_stopped(&self) {
+        let running = unsafe {
+            sys::obs_output_active(self.raw()) || sys::obs_output_reconnecting(self.raw())
+        };
+
+        if !running && !self.shared.pending_start.load(Ordering::SeqCst) {
+            self.shared.stopped(OBS_OUTPUT_SUCCESS, None);
+        }
+    }
+
+    /// Get the state of the output.
+    pub fn get_state(&self) -> OutputState {
+        self.shared.get_state()
+    }
+
+    /// Listen for state changes of this output.
+    /// The listener is called with the new state, from obs threads
+    /// or from the thread changing the state.
+    pub fn on_state_change<F>(&self, callback: F)
+    where
+        F: Fn(OutputState) + Send + Sync + 'static,
+    {
+        self.shared
+            .listeners
+            .lock()
+            .unwrap()
+            .push(Arc::new(callback));
+    }
+
+    /// Start the output and call `callback` once the output has actually started,
+    /// or with an error if the output stopped before it started.
+    /// `callback` is not called if an error is returned.
+    /// The callback is called from obs threads.
+    pub fn start_with_callback<F>(
+        &self,
+        video_encoder: Option<&ObsEncoder>,
+        audio_encoder: Option<&ObsEncoder>,
+        callback: F,
+    ) -> ResultType<()>
+    where
+        F: FnOnce(ResultType<()>) + Send + 'static,
+    {
+        // The output may start before obs_output_start returns,
+        // so the callback must be registered first
+        let id = {
+            let mut waiters = self.shared.waiters.lock().unwrap();
+            let id = waiters.next_id();
+            waiters.start.push((id, Box::new(callback)));
+            id
+        };
+
+        let res = self.start(video_encoder, audio_encoder);
+        if res.is_err() {
+            let mut waiters = self.shared.waiters.lock().unwrap();
+            waiters.start.retain(|(waiter, _)| *waiter != id);
+        }
+
+        res
+    }
+
+    /// Stop the output and call `callback` once the output has actually stopped,
+    /// e.g. once a recording has been finalized.
+    /// `callback` is not called if an error is returned.
+    /// The callback is called from obs threads.
+    pub fn stop_with_callback<F>(&self, callback: F) -> ResultType<()>
+    where
+        F: FnOnce(ResultType<()>) + Send + 'static,
+    {
+        let id = {
+            let mut waiters = self.shared.waiters.lock().unwrap();
+            let id = waiters.next_id();
+            waiters.stop.push((id, Box::new(callback)));
+            id
+        };
+
+        let res = self.stop();
+        if res.is_err() {
+            let mut waiters = self.shared.waiters.lock().unwrap();
+            waiters.stop.retain(|(waiter, _)| *waiter != id);
+        }
+
+        res
+    }
+
+    /// Start the output and block until it has actually started.
+    /// Returns an error if the output stopped before it started
+    /// or if it didn't start within `timeout`.
+    pub fn start_and_wait(
+        &self,
+        video_encoder: Option<&ObsEncoder>,
+        audio_encoder: Option<&ObsEncoder>,
+        timeout: Option<Duration>,
+    ) -> ResultType<()> {
+        let (sender, receiver) = mpsc::channel();
+        self.start_with_callback(video_encoder, audio_encoder, move |res| {
+            let _ = sender.send(res);
+        })?;
+
+        wait_for(
+            receiver,
+            timeout,
+            "Timed out waiting for the output to start",
+        )
+    }
+
+    /// Stop the output and block until it has actually stopped.
+    /// Returns an error if the output didn't stop within `timeout`.
+    pub fn stop_and_wait(&self, timeout: Option<Duration>) -> ResultType<()> {
+        let (sender, receiver) = mpsc::channel();
+        self.stop_with_callback(move |res| {
+            let _ = sender.send(res);
+        })?;
+
+        wait_for(
+            receiver,
+            timeout,
+            "Timed out waiting for the output to stop",
+        )
+    }
+
     /// Get the output properties.
-    #[napi]
-    pub fn get_properties(&self) -> napi::Result<ObsProperties> {
+    pub fn get_properties(&self) -> ResultType<ObsProperties> {
         let properties = unsafe { sys::obs_output_properties(self.raw()) };
         if properties.is_null() {
-            return Err(to_napi_error_str("Failed to get properties"));
+            return Err("Failed to get properties".into());
         }
 
         Ok(ObsProperties::from_raw(
@@ -142,26 +411,21 @@ impl ObsOutput {
     }
 
     /// Get if the output is paused.
-    #[napi(getter)]
     pub fn get_paused(&self) -> bool {
-        let _lock = self.state.lock().unwrap();
         unsafe { sys::obs_output_paused(self.raw()) }
     }
 
     /// Check if the output can be paused.
-    #[napi(getter)]
     pub fn can_pause(&self) -> bool {
         unsafe { sys::obs_output_can_pause(self.raw()) }
     }
 
     /// Check if the output is active.
-    #[napi(getter)]
     pub fn get_active(&self) -> bool {
         unsafe { sys::obs_output_active(self.raw()) }
     }
 
     /// Get the output name.
-    #[napi(getter)]
     pub fn get_name(&self) -> String {
         unsafe { CStr::from_ptr(sys::obs_output_get_name(self.raw())) }
             .to_string_lossy()
@@ -169,51 +433,100 @@ impl ObsOutput {
     }
 
     /// Pause the output.
-    /// Throws an error if the output is not running.
-    #[napi]
-    pub fn pause(&self) -> napi::Result<()> {
-        let mut state = self.state.lock().unwrap();
-        if *state == OutputState::Stopped || *state == OutputState::Paused {
-            return Err(to_napi_error_str("The output is not runnning"));
+    /// Returns an error if the output is not running.
+    pub fn pause(&self) -> ResultType<()> {
+        if self.get_state() != OutputState::Active {
+            return Err(ObsError::InvalidState("The output is not runnning"));
         }
 
         if !unsafe { sys::obs_output_pause(self.raw(), true) } {
-            Err(to_napi_error_str("Failed to pause output"))
+            Err("Failed to pause output".into())
         } else {
-            let _ = mem::replace(&mut *state, OutputState::Paused);
+            self.shared.set_state(OutputState::Paused);
             Ok(())
         }
     }
 
     /// Resume the output.
-    /// Throws an error if the output is not paused.
-    #[napi]
-    pub fn resume(&self) -> napi::Result<()> {
-        let mut state = self.state.lock().unwrap();
-        if *state != OutputState::Paused {
-            return Err(to_napi_error_str("The output is not paused"));
+    /// Returns an error if the output is not paused.
+    pub fn resume(&self) -> ResultType<()> {
+        if self.get_state() != OutputState::Paused {
+            return Err(ObsError::InvalidState("The output is not paused"));
         }
 
         if !unsafe { sys::obs_output_pause(self.raw(), false) } {
-            Err(to_napi_error_str("Failed to resume output"))
+            Err("Failed to resume output".into())
         } else {
-            let _ = mem::replace(&mut *state, OutputState::Running);
+            self.shared.set_state(OutputState::Active);
             Ok(())
         }
     }
 
     /// Set the output settings.
-    #[napi(setter)]
     pub fn set_settings(&self, settings: &ObsSettings) {
         unsafe { sys::obs_output_update(self.raw(), settings.raw()) };
     }
 
+    /// Update the output settings.
+    /// If `strict` is set, the settings are validated first
+    /// and an error is returned if they are invalid.
+    pub fn update_settings(&self, settings: &ObsSettings, strict: bool) -> ResultType<()> {
+        if strict {
+            self.get_properties()?.validate_strict(settings)?;
+        }
+
+        self.set_settings(settings);
+        Ok(())
+    }
+
+    /// Validate settings against the properties of this output.
+    /// Returns an empty list if the settings are valid.
+    pub fn validate(&self, settings: &ObsSettings) -> ResultType<Vec<SettingsIssue>> {
+        self.get_properties()?.validate(settings)
+    }
+
+    /// Receive the encoded packets of this output.
+    /// The output must be of a type registered using `Obs::register_output_type`.
+    /// Replaces any previously set packet listener.
+    /// The listener is called from the obs output thread.
+    pub fn on_packet<F>(&self, callback: F) -> ResultType<()>
+    where
+        F: Fn(Packet) + Send + Sync + 'static,
+    {
+        if !unsafe { is_js_output(self.raw()) } {
+            return Err(ObsError::Unsupported(
+                "The output type was not registered using registerOutputType",
+            ));
+        }
+
+        set_packet_listener(unsafe { self.raw() }, Box::new(callback));
+        Ok(())
+    }
+
+    /// Receive the muxed MPEG-TS data of this output.
+    /// The output must be of a type registered using `Obs::register_mpegts_output_type`.
+    /// The listener is called with `None` once the output has stopped.
+    /// Replaces any previously set data listener.
+    /// The listener is called from the obs output thread.
+    pub fn on_data<F>(&self, callback: F) -> ResultType<()>
+    where
+        F: Fn(Option<Vec<u8>>) + Send + Sync + 'static,
+    {
+        if !unsafe { is_mpegts_output(self.raw()) } {
+            return Err(ObsError::Unsupported(
+                "The output type was not registered using registerMpegTsOutputType",
+            ));
+        }
+
+        set_data_listener(unsafe { self.raw() }, Box::new(callback));
+        Ok(())
+    }
+
     /// Get the output settings.
-    #[napi(getter)]
-    pub fn get_settings(&self) -> napi::Result<ObsSettings> {
+    pub fn get_settings(&self) -> ResultType<ObsSettings> {
         let settings = unsafe { sys::obs_output_get_settings(self.raw()) };
         if settings.is_null() {
-            return Err(to_napi_error_str("Failed to get settings"));
+            Err("Failed to get settings".into())
         } else {
             Ok(ObsSettings::from_raw(settings, Some(self.guard.clone())))
         }
@@ -222,9 +535,13 @@ impl ObsOutput {
 
 impl FromRaw<sys::obs_output_t> for ObsOutput {
     unsafe fn from_raw_unchecked(raw: *mut sys::obs_output_t, guard: Guard) -> Self {
+        let shared = Arc::new(OutputShared::new());
+        let signals = connect_signals(raw, &shared);
+
         Self {
             output: AtomicPtr::new(raw),
-            state: Mutex::new(OutputState::Stopped),
+            shared,
+            signals,
             guard: guard.unwrap(),
         }
     }
@@ -240,8 +557,72 @@ unsafe impl Send for ObsOutput {}
 
 impl Drop for ObsOutput {
     fn drop(&mut self) {
+        // Disconnect all signals before the signal handler may be destroyed
+        self.signals.clear();
+
         unsafe {
+            remove_packet_listener(self.raw());
+            remove_data_listener(self.raw());
             sys::obs_output_release(self.raw());
         }
     }
 }
+
+/// Connect to the signals changing the state of an output.
+unsafe fn connect_signals(
+    output: *mut sys::obs_output_t,
+    shared: &Arc<OutputShared>,
+) -> Vec<SignalConnection> {
+    let handler = sys::obs_output_get_signal_handler(output);
+    let mut signals = Vec::new();
+
+    for (signal, state) in OutputState::signals() {
+        let shared = shared.clone();
+        signals.extend(
+            SignalConnection::connect(handler, signal, move |_| shared.set_state(state)).ok(),
+        );
+    }
+
+    let start = shared.clone();
+    signals.extend(SignalConnection::connect(handler, "start", move |_| start.started()).ok());
+
+    let stop = shared.clone();
+    signals.extend(
+        SignalConnection::connect(handler, "stop", move |calldata| {
+            let code = unsafe { calldata_int(calldata, "code") }.unwrap_or(0) as i32;
+            let output = unsafe { calldata_ptr::<sys::obs_output_t>(calldata, "output") };
+            stop.stopped(code, output);
+        })
+        .ok(),
+    );
+
+    signals
+}
+
+/// Get the last error reported by an output.
+unsafe fn last_error(output: *mut sys::obs_output_t) -> Option<String> {
+    let error = sys::obs_output_get_last_error(output);
+    if error.is_null() {
+        None
+    } else {
+        Some(CStr::from_ptr(error).to_string_lossy().into_owned())
+    }
+}
+
+pub(crate) fn wait_for(
+    receiver: mpsc::Receiver<ResultType<()>>,
+    timeout: Option<Duration>,
+    message: &'static str,
+) -> ResultType<()> {
+    let res = match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout).map_err(|e| match e {
+            RecvTimeoutError::Timeout => ObsError::Timeout(message),
+            RecvTimeoutError::Disconnected => "The output was destroyed".into(),
+        }),
+        None => receiver
+            .recv()
+            .map_err(|_| ObsError::from("The output was destroyed")),
+    };
+
+    res?
+}
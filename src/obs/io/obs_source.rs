@@ -1,53 +1,67 @@
+use crate::obs::custom::audio_data::{output_audio, reset_timestamp, AudioDataInfo};
+use crate::obs::custom::video_frame::{output_video, VideoFrameInfo};
 use crate::obs::data::obs_properties::ObsProperties;
 use crate::obs::data::obs_settings::ObsSettings;
+use crate::obs::data::settings_validation::SettingsIssue;
+use crate::obs::objects::media_state::{MediaEvent, MediaState};
 use crate::obs::sys;
 use crate::obs::traits::from_raw::{FromRaw, Guard};
 use crate::obs::traits::raw::Raw;
-use crate::obs::util::napi_error::to_napi_error_str;
+use crate::obs::util::obs_error::ObsError;
 use crate::obs::util::obs_guard::ObsGuard;
+use crate::obs::util::signal_handler::SignalConnection;
+use crate::obs::util::types::ResultType;
 use core::ffi::CStr;
 use std::ffi::CString;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
 
 /// An obs source.
 ///
 /// # Example
-/// ```ts
-/// const source = await obs.createSource('My Source', 'monitor_capture', new ObsSettings({
-///    monitor: 1,
-///    capture_cursor: false,
-///    method: 2,
-/// }));
+/// ```ignore
+/// let mut settings = ObsSettings::new()?;
+/// settings.set_string("local_file", "/path/to/clip.mp4")?;
+///
+/// let media = obs.create_source("clip", "ffmpeg_source", 0, Some(&settings))?;
+/// let listener = media.on_media_event(|event| {
+///     if matches!(event, MediaEvent::Ended) {
+///         println!("Clip ended");
+///     }
+/// })?;
+///
+/// media.play()?;
+/// media.seek(5000)?;
+/// media.remove_media_listener(listener);
 /// ```
-#[napi]
 pub struct ObsSource {
     source: *mut sys::obs_source_t,
     id: String,
+    /// The signal connections of the media listeners, by listener id.
+    signals: Mutex<Vec<(u32, SignalConnection)>>,
+    next_listener_id: AtomicU32,
     guard: Arc<ObsGuard>,
 }
 
-#[napi]
 impl ObsSource {
     /// Get the default settings for this source.
-    #[napi]
-    pub fn get_default_settings(&self) -> napi::Result<ObsSettings> {
+    pub fn get_default_settings(&self) -> ResultType<ObsSettings> {
         let id = CString::new(self.id.clone())?;
         let settings = unsafe { sys::obs_get_source_defaults(id.as_ptr()) };
 
         if settings.is_null() {
-            Err(to_napi_error_str("Failed to get default settings"))
+            Err("Failed to get default settings".into())
         } else {
             Ok(ObsSettings::from_raw(settings, Some(self.guard.clone())))
         }
     }
 
     /// Get the properties for this source.
-    #[napi]
-    pub fn get_properties(&self) -> napi::Result<ObsProperties> {
+    pub fn get_properties(&self) -> ResultType<ObsProperties> {
         let properties = unsafe { sys::obs_source_properties(self.source) };
 
         if properties.is_null() {
-            Err(to_napi_error_str("Failed to get properties"))
+            Err("Failed to get properties".into())
         } else {
             Ok(ObsProperties::from_raw(
                 properties,
@@ -57,26 +71,197 @@ impl ObsSource {
     }
 
     /// Update the settings of the source.
-    #[napi]
-    pub fn update_settings(&self, settings: &ObsSettings) {
+    /// If `strict` is set, the settings are validated first
+    /// and an error is returned if they are invalid.
+    pub fn update_settings(&self, settings: &ObsSettings, strict: bool) -> ResultType<()> {
+        if strict {
+            self.get_properties()?.validate_strict(settings)?;
+        }
+
         unsafe {
             sys::obs_source_update(self.source, settings.raw());
         }
+
+        Ok(())
+    }
+
+    /// Validate settings against the properties of this source.
+    /// Returns an empty list if the settings are valid.
+    pub fn validate(&self, settings: &ObsSettings) -> ResultType<Vec<SettingsIssue>> {
+        self.get_properties()?.validate(settings)
     }
 
     /// Get the settings of the source.
-    #[napi(getter)]
-    pub fn get_settings(&self) -> napi::Result<ObsSettings> {
+    pub fn get_settings(&self) -> ResultType<ObsSettings> {
         let settings = unsafe { sys::obs_source_get_settings(self.source) };
 
         if settings.is_null() {
-            Err(to_napi_error_str("Failed to get settings"))
+            Err("Failed to get settings".into())
         } else {
             Ok(ObsSettings::from_raw(settings, Some(self.guard.clone())))
         }
     }
 }
 
+impl ObsSource {
+    /// Play the media of this source.
+    /// Returns an error if this is not a media source.
+    pub fn play(&self) -> ResultType<()> {
+        self.check_media()?;
+        unsafe { sys::obs_source_media_play_pause(self.source, false) };
+        Ok(())
+    }
+
+    /// Pause the media of this source.
+    /// Returns an error if this is not a media source.
+    pub fn pause(&self) -> ResultType<()> {
+        self.check_media()?;
+        unsafe { sys::obs_source_media_play_pause(self.source, true) };
+        Ok(())
+    }
+
+    /// Stop the media of this source.
+    /// Returns an error if this is not a media source.
+    pub fn stop(&self) -> ResultType<()> {
+        self.check_media()?;
+        unsafe { sys::obs_source_media_stop(self.source) };
+        Ok(())
+    }
+
+    /// Restart the media of this source.
+    /// Returns an error if this is not a media source.
+    pub fn restart(&self) -> ResultType<()> {
+        self.check_media()?;
+        unsafe { sys::obs_source_media_restart(self.source) };
+        Ok(())
+    }
+
+    /// Seek to a position in the media of this source, in milliseconds.
+    /// Returns an error if this is not a media source.
+    pub fn seek(&self, ms: i64) -> ResultType<()> {
+        self.check_media()?;
+        unsafe { sys::obs_source_media_set_time(self.source, ms) };
+        Ok(())
+    }
+
+    /// Skip to the next item of the media of this source.
+    /// Returns an error if this is not a media source.
+    pub fn next(&self) -> ResultType<()> {
+        self.check_media()?;
+        unsafe { sys::obs_source_media_next(self.source) };
+        Ok(())
+    }
+
+    /// Skip to the previous item of the media of this source.
+    /// Returns an error if this is not a media source.
+    pub fn previous(&self) -> ResultType<()> {
+        self.check_media()?;
+        unsafe { sys::obs_source_media_previous(self.source) };
+        Ok(())
+    }
+
+    /// The current position of the media in milliseconds.
+    pub fn get_time(&self) -> ResultType<i64> {
+        self.check_media()?;
+        Ok(unsafe { sys::obs_source_media_get_time(self.source) })
+    }
+
+    /// The duration of the media in milliseconds.
+    pub fn get_duration(&self) -> ResultType<i64> {
+        self.check_media()?;
+        Ok(unsafe { sys::obs_source_media_get_duration(self.source) })
+    }
+
+    /// The playback state of the media.
+    pub fn get_state(&self) -> ResultType<MediaState> {
+        self.check_media()?;
+        Ok(MediaState::from_value(unsafe {
+            sys::obs_source_media_get_state(self.source)
+        }))
+    }
+
+    /// Listen for media events of this source,
+    /// e.g. when the media started, ended or was paused.
+    /// The listener is called from obs threads.
+    /// Returns the id of the listener, which can be passed
+    /// to `remove_media_listener` to stop listening.
+    pub fn on_media_event<F>(&self, callback: F) -> ResultType<u32>
+    where
+        F: Fn(MediaEvent) + Send + Sync + 'static,
+    {
+        self.check_media()?;
+        let callback = Arc::new(callback);
+        let id = self.next_listener_id.fetch_add(1, Ordering::Relaxed);
+
+        let handler = unsafe { sys::obs_source_get_signal_handler(self.source) };
+        let connections = MediaEvent::signals()
+            .into_iter()
+            .map(|(signal, event)| {
+                let callback = callback.clone();
+                unsafe {
+                    SignalConnection::connect(handler, signal, move |_| {
+                        callback(event);
+                    })
+                }
+                .map(|connection| (id, connection))
+            })
+            .collect::<ResultType<Vec<_>>>()?;
+
+        self.signals.lock().unwrap().extend(connections);
+        Ok(id)
+    }
+
+    /// Remove a listener added using `on_media_event`.
+    /// Returns `false` if no listener with the given id exists.
+    pub fn remove_media_listener(&self, id: u32) -> bool {
+        let mut signals = self.signals.lock().unwrap();
+        let count = signals.len();
+        signals.retain(|(listener, _)| *listener != id);
+
+        signals.len() != count
+    }
+}
+
+impl ObsSource {
+    /// Push a raw video frame to this source.
+    /// The source must be of a type registered using `Obs::register_video_source_type`
+    /// or any other async video source type.
+    /// The frame data must be tightly packed, planes following each other.
+    pub fn push_video_frame(&self, data: &[u8], info: &VideoFrameInfo) -> ResultType<()> {
+        let flags = unsafe { sys::obs_source_get_output_flags(self.source) };
+        if flags & sys::OBS_SOURCE_ASYNC_VIDEO == 0 {
+            return Err(ObsError::Unsupported(
+                "The source is not an async video source",
+            ));
+        }
+
+        unsafe { output_video(self.source, data, info) }
+    }
+
+    /// Push raw audio data to this source.
+    /// The source must be of a type registered using `Obs::register_audio_source_type`
+    /// or any other audio source type.
+    /// Planar data must contain the planes of all channels following each other.
+    pub fn push_audio(&self, data: &[u8], info: &AudioDataInfo) -> ResultType<()> {
+        let flags = unsafe { sys::obs_source_get_output_flags(self.source) };
+        if flags & sys::OBS_SOURCE_AUDIO == 0 {
+            return Err(ObsError::Unsupported("The source is not an audio source"));
+        }
+
+        unsafe { output_audio(self.source, data, info) }
+    }
+
+    fn check_media(&self) -> ResultType<()> {
+        let flags = unsafe { sys::obs_source_get_output_flags(self.source) };
+
+        if flags & sys::OBS_SOURCE_CONTROLLABLE_MEDIA != 0 {
+            Ok(())
+        } else {
+            Err(ObsError::Unsupported("The source is not a media source"))
+        }
+    }
+}
+
 impl FromRaw<sys::obs_source_t> for ObsSource {
     unsafe fn from_raw_unchecked(source: *mut sys::obs_source_t, guard: Guard) -> Self {
         let id = {
@@ -88,6 +273,8 @@ impl FromRaw<sys::obs_source_t> for ObsSource {
         Self {
             source,
             id,
+            signals: Mutex::new(Vec::new()),
+            next_listener_id: AtomicU32::new(1),
             guard: guard.unwrap(),
         }
     }
@@ -103,6 +290,10 @@ unsafe impl Send for ObsSource {}
 
 impl Drop for ObsSource {
     fn drop(&mut self) {
+        // Disconnect all signals before the signal handler may be destroyed
+        self.signals.lock().unwrap().clear();
+        reset_timestamp(self.source);
+
         unsafe {
             sys::obs_source_release(self.source);
         }
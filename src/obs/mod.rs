@@ -1,7 +1,49 @@
-mod data;
-mod io;
-mod objects;
-pub mod obs;
-mod sys;
-mod traits;
-pub mod util;
+pub(crate) mod custom;
+pub(crate) mod data;
+pub mod helper;
+pub(crate) mod io;
+pub(crate) mod objects;
+#[allow(clippy::module_inception)]
+pub(crate) mod obs;
+pub mod plugin;
+pub mod sys;
+pub(crate) mod traits;
+pub(crate) mod util;
+
+pub use custom::audio_data::{AudioDataInfo, AudioFormat};
+pub use custom::encoded_packet::Packet;
+pub use custom::video_frame::VideoFrameInfo;
+pub use data::loaded_obs_module::LoadedObsModule;
+pub use data::module_types::ModuleTypes;
+pub use data::obs_encoder_type::{EncoderCaps, EncoderKind, ObsEncoderType};
+pub use data::obs_module::ObsModule;
+pub use data::obs_output_type::{ObsOutputType, OutputFlags};
+pub use data::obs_properties::ObsProperties;
+pub use data::obs_property::ObsProperty;
+pub use data::obs_settings::ObsSettings;
+pub use data::profiler_snapshot::ProfilerEntry;
+pub use data::settings_validation::{SettingsIssue, SettingsIssueKind};
+pub use helper::probe::{ModuleProbeResult, ModuleProbeStatus, ProbeOptions};
+pub use helper::remote::{
+    RemoteEncoder, RemoteEvent, RemoteEventKind, RemoteObs, RemoteObsOptions, RemoteOutput,
+    RemoteSource,
+};
+pub use io::obs_encoder::ObsEncoder;
+pub use io::obs_output::ObsOutput;
+pub use io::obs_source::ObsSource;
+pub use objects::media_state::{MediaEvent, MediaState};
+pub use objects::module_load_options::ModuleLoadOptions;
+pub use objects::module_load_result::{ModuleLoadResult, ModuleLoadStatus};
+pub use objects::nix_platform::NixPlatform;
+pub use objects::obs_version::{Capability, ObsVersion};
+pub use objects::output_state::OutputState;
+pub use objects::reconfigure_options::{ReconfigureData, ReconfigureOptions};
+pub use objects::reset_audio_data::{ResetAudioData, SpeakerLayout};
+pub use objects::reset_video_data::{
+    GraphicsModule, ResetVideoData, ScaleType, VideoColorSpace, VideoFormat, VideoRange,
+};
+pub use objects::startup_options::StartupOptions;
+pub use objects::type_schema::{ObsObjectKind, TypeSchema};
+pub use obs::Obs;
+pub use util::obs_error::ObsError;
+pub use util::types::ResultType;
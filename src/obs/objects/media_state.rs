@@ -0,0 +1,63 @@
+use crate::obs::sys;
+use serde::{Deserialize, Serialize};
+
+/// The playback state of a media source.
+#[cfg_attr(feature = "napi", napi)]
+#[cfg_attr(not(feature = "napi"), derive(Clone, Copy))]
+#[derive(Serialize, Deserialize)]
+pub enum MediaState {
+    None,
+    Playing,
+    Opening,
+    Buffering,
+    Paused,
+    Stopped,
+    Ended,
+    Error,
+}
+
+impl MediaState {
+    pub fn from_value(value: sys::obs_media_state) -> Self {
+        match value {
+            sys::obs_media_state_OBS_MEDIA_STATE_PLAYING => MediaState::Playing,
+            sys::obs_media_state_OBS_MEDIA_STATE_OPENING => MediaState::Opening,
+            sys::obs_media_state_OBS_MEDIA_STATE_BUFFERING => MediaState::Buffering,
+            sys::obs_media_state_OBS_MEDIA_STATE_PAUSED => MediaState::Paused,
+            sys::obs_media_state_OBS_MEDIA_STATE_STOPPED => MediaState::Stopped,
+            sys::obs_media_state_OBS_MEDIA_STATE_ENDED => MediaState::Ended,
+            sys::obs_media_state_OBS_MEDIA_STATE_ERROR => MediaState::Error,
+            _ => MediaState::None,
+        }
+    }
+}
+
+/// A notification emitted by a media source.
+#[cfg_attr(feature = "napi", napi)]
+#[cfg_attr(not(feature = "napi"), derive(Clone, Copy))]
+#[derive(Serialize, Deserialize)]
+pub enum MediaEvent {
+    Started,
+    Ended,
+    Paused,
+    Played,
+    Restarted,
+    Stopped,
+    Next,
+    Previous,
+}
+
+impl MediaEvent {
+    /// All media events with the name of the signal emitting them.
+    pub fn signals() -> [(&'static str, MediaEvent); 8] {
+        [
+            ("media_started", MediaEvent::Started),
+            ("media_ended", MediaEvent::Ended),
+            ("media_pause", MediaEvent::Paused),
+            ("media_play", MediaEvent::Played),
+            ("media_restart", MediaEvent::Restarted),
+            ("media_stopped", MediaEvent::Stopped),
+            ("media_next", MediaEvent::Next),
+            ("media_previous", MediaEvent::Previous),
+        ]
+    }
+}
@@ -1,3 +1,11 @@
-pub(crate) mod failed_obs_module;
+pub(crate) mod media_state;
+pub(crate) mod module_load_options;
+pub(crate) mod module_load_result;
+pub(crate) mod nix_platform;
+pub(crate) mod obs_version;
+pub(crate) mod output_state;
+pub(crate) mod reconfigure_options;
 pub(crate) mod reset_audio_data;
 pub(crate) mod reset_video_data;
+pub(crate) mod startup_options;
+pub(crate) mod type_schema;
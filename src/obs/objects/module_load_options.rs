@@ -0,0 +1,131 @@
+use serde::{Deserialize, Serialize};
+
+/// Options for loading modules.
+///
+/// # Example
+/// ```ts
+/// const results = await obs.loadModules(modules, {
+///     deny: ['aja*', 'decklink*', 'frontend-tools'],
+///     disabled: ['obs-websocket'],
+/// });
+/// ```
+#[cfg_attr(feature = "napi", napi(object))]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleLoadOptions {
+    /// Glob patterns of module names which may be loaded.
+    /// All modules may be loaded if not set.
+    /// Supports `*` and `?` as wildcards.
+    pub allow: Option<Vec<String>>,
+    /// Glob patterns of module names which must not be loaded.
+    /// Takes precedence over `allow`.
+    /// Supports `*` and `?` as wildcards.
+    pub deny: Option<Vec<String>>,
+    /// Names of modules which are disabled.
+    /// The names are also passed to libobs if it supports disabling modules.
+    pub disabled: Option<Vec<String>>,
+    /// Names of the modules which are safe to load.
+    /// Only these modules are loaded if set.
+    /// The names are also passed to libobs if it supports safe modules.
+    pub safe: Option<Vec<String>>,
+    /// Whether to call `obs_post_load_modules` after loading the modules.
+    /// This only happens once per obs instance. Defaults to `true`.
+    pub post_load: Option<bool>,
+    /// Whether to return an error if a module fails to load. Defaults to `false`.
+    pub throw_on_load_failed: Option<bool>,
+}
+
+impl ModuleLoadOptions {
+    /// Check if a module is allowed by the `allow` and `deny` lists.
+    pub fn is_allowed(&self, name: &str) -> bool {
+        let allowed = match &self.allow {
+            Some(allow) => allow.iter().any(|pattern| glob_match(pattern, name)),
+            None => true,
+        };
+        let denied = match &self.deny {
+            Some(deny) => deny.iter().any(|pattern| glob_match(pattern, name)),
+            None => false,
+        };
+
+        allowed && !denied
+    }
+
+    /// Check if a module is disabled, either because it is
+    /// in the `disabled` list or because it is not in the `safe` list.
+    pub fn is_disabled(&self, name: &str) -> bool {
+        let disabled = match &self.disabled {
+            Some(disabled) => disabled.iter().any(|module| module == name),
+            None => false,
+        };
+        let safe = match &self.safe {
+            Some(safe) => safe.iter().any(|module| module == name),
+            None => true,
+        };
+
+        disabled || !safe
+    }
+}
+
+/// Match `name` against a glob pattern supporting `*` and `?`.
+pub fn glob_match(pattern: &str, name: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let name = name.chars().collect::<Vec<_>>();
+
+    let (mut p, mut n) = (0, 0);
+    // The position of the last `*` in the pattern and the name position it matched up to
+    let mut star: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, n));
+            p += 1;
+        } else if let Some((star_p, star_n)) = star {
+            p = star_p + 1;
+            n = star_n + 1;
+            star = Some((star_p, star_n + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::glob_match;
+
+    #[test]
+    fn exact() {
+        assert!(glob_match("obs-x264", "obs-x264"));
+        assert!(!glob_match("obs-x264", "obs-x265"));
+        assert!(!glob_match("obs-x264", "obs-x264-extra"));
+    }
+
+    #[test]
+    fn star() {
+        assert!(glob_match("*", ""));
+        assert!(glob_match("*", "obs-x264"));
+        assert!(glob_match("obs-*", "obs-"));
+        assert!(glob_match("obs-*", "obs-x264"));
+        assert!(!glob_match("obs-*", "ob"));
+        assert!(glob_match("*-x264", "obs-x264"));
+        assert!(glob_match("obs*x*4", "obs-x264"));
+        assert!(!glob_match("obs*x*5", "obs-x264"));
+    }
+
+    #[test]
+    fn question_mark() {
+        assert!(glob_match("obs-x26?", "obs-x264"));
+        assert!(!glob_match("obs-x26?", "obs-x26"));
+    }
+
+    #[test]
+    fn empty_pattern() {
+        assert!(glob_match("", ""));
+        assert!(!glob_match("", "obs-x264"));
+    }
+}
@@ -0,0 +1,60 @@
+use crate::obs::data::module_types::ModuleTypes;
+use crate::obs::data::obs_module::ObsModule;
+use serde::{Deserialize, Serialize};
+
+/// The outcome of loading a module.
+#[cfg_attr(feature = "napi", napi)]
+#[cfg_attr(not(feature = "napi"), derive(Clone, Copy))]
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum ModuleLoadStatus {
+    /// The module was loaded and initialized.
+    Loaded,
+    /// libobs skipped the module because of a hardcoded rule.
+    Skipped,
+    /// The module was not loaded because of the `allow` or `deny` lists.
+    Denied,
+    /// The module was not loaded because it is disabled
+    /// or not in the list of safe modules.
+    Disabled,
+    /// The module failed to load or initialize.
+    Failed,
+}
+
+/// The result of loading a module.
+#[cfg_attr(feature = "napi", napi(object))]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleLoadResult {
+    /// The info of the module.
+    pub module: ObsModule,
+    /// The outcome of loading the module.
+    pub status: ModuleLoadStatus,
+    /// The error code returned by `obs_open_module`, if the module failed to open.
+    pub code: Option<i32>,
+    /// The error message, if the module failed to load.
+    pub error: Option<String>,
+    /// The types registered by the module while it was initialized.
+    pub types: Option<ModuleTypes>,
+}
+
+impl ModuleLoadResult {
+    pub fn new(module: ObsModule, status: ModuleLoadStatus) -> Self {
+        Self {
+            module,
+            status,
+            code: None,
+            error: None,
+            types: None,
+        }
+    }
+
+    pub fn failed(module: ObsModule, code: Option<i32>, error: String) -> Self {
+        Self {
+            module,
+            status: ModuleLoadStatus::Failed,
+            code,
+            error: Some(error),
+            types: None,
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+use std::env;
+
+/// The windowing platform obs renders with on Linux and other unix systems.
+/// libobs only supports EGL, either on X11 or Wayland.
+/// Use `X11` with a virtual display like Xvfb on headless servers.
+#[cfg_attr(feature = "napi", napi)]
+#[cfg_attr(not(feature = "napi"), derive(Clone, Copy))]
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum NixPlatform {
+    /// EGL on an X11 display, e.g. a desktop session or Xvfb.
+    X11,
+    /// EGL on a Wayland display.
+    Wayland,
+}
+
+impl NixPlatform {
+    /// The value of `enum obs_nix_platform_type`.
+    pub(crate) fn value(&self) -> i32 {
+        match self {
+            NixPlatform::X11 => 1,
+            NixPlatform::Wayland => 2,
+        }
+    }
+
+    /// Detect the platform of the current session.
+    /// Returns `None` if neither a Wayland nor an X11 display is set.
+    pub fn detect() -> Option<Self> {
+        if env::var_os("WAYLAND_DISPLAY").is_some() {
+            Some(NixPlatform::Wayland)
+        } else if env::var_os("DISPLAY").is_some() {
+            Some(NixPlatform::X11)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use crate::obs::sys;
+use crate::obs::util::obs_error::ObsError;
+use crate::obs::util::types::ResultType;
+use serde::{Deserialize, Serialize};
+use std::ffi::CStr;
+
+/// The version of the loaded libobs.
+#[cfg_attr(feature = "napi", napi(object))]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ObsVersion {
+    /// The full version string, e.g. `29.1.3`.
+    pub full: String,
+    /// The api major version.
+    pub major: u32,
+    /// The api minor version.
+    pub minor: u32,
+    /// The api patch version.
+    pub patch: u32,
+}
+
+impl ObsVersion {
+    /// Get the version of the loaded libobs.
+    pub fn current() -> Self {
+        let version = unsafe { sys::obs_get_version() };
+        let full = match sys::functions().obs_get_version_string {
+            Some(get_version_string) => unsafe {
+                let version = get_version_string();
+                if version.is_null() {
+                    None
+                } else {
+                    Some(CStr::from_ptr(version).to_string_lossy().to_string())
+                }
+            },
+            None => None,
+        };
+
+        Self {
+            full: full.unwrap_or_else(|| sys::version_to_string(version)),
+            major: version >> 24,
+            minor: (version >> 16) & 0xff,
+            patch: version & 0xffff,
+        }
+    }
+
+    /// Check if this version is at least `major.minor`.
+    pub fn at_least(&self, (major, minor): (u32, u32)) -> bool {
+        (self.major, self.minor) >= (major, minor)
+    }
+}
+
+/// A libobs feature which is not available in every libobs version.
+#[cfg_attr(feature = "napi", napi)]
+#[cfg_attr(not(feature = "napi"), derive(Clone, Copy))]
+#[derive(Debug)]
+pub enum Capability {
+    /// Resetting the audio with fixed buffering using `obs_reset_audio2`.
+    ResetAudio2,
+    /// Finding modules including their names using `obs_find_modules2`.
+    FindModules2,
+    /// The HDR video formats `I010`, `P010`, `I210`, `I412` and `YA2L`
+    /// and the HDR color spaces.
+    HdrVideo,
+}
+
+impl Capability {
+    /// A readable name of the capability used in error messages.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Capability::ResetAudio2 => "Fixed audio buffering",
+            Capability::FindModules2 => "obs_find_modules2",
+            Capability::HdrVideo => "HDR video",
+        }
+    }
+
+    /// The libobs version this capability was introduced in.
+    pub fn since(&self) -> (u32, u32) {
+        match self {
+            Capability::ResetAudio2 => (29, 1),
+            Capability::FindModules2 => (27, 0),
+            Capability::HdrVideo => (28, 0),
+        }
+    }
+
+    /// Check if the loaded libobs supports this capability.
+    pub fn is_available(&self) -> bool {
+        let functions = sys::functions();
+        let exported = match self {
+            Capability::ResetAudio2 => functions.obs_reset_audio2.is_some(),
+            Capability::FindModules2 => functions.obs_find_modules2.is_some(),
+            Capability::HdrVideo => true,
+        };
+
+        exported && ObsVersion::current().at_least(self.since())
+    }
+
+    /// Return an error if the loaded libobs does not support this capability.
+    pub fn require(&self) -> ResultType<()> {
+        if self.is_available() {
+            Ok(())
+        } else {
+            Err(ObsError::RequiresVersion {
+                feature: self.name(),
+                version: self.since(),
+            })
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+/// The state of an output.
+/// The state is kept in sync with libobs using the signals of the output,
+/// so it also changes if libobs stops an output by itself, e.g. on a disconnect.
+#[cfg_attr(feature = "napi", napi)]
+#[cfg_attr(not(feature = "napi"), derive(Clone, Copy))]
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum OutputState {
+    /// The output is not running.
+    Stopped,
+    /// The output has been started but is not active yet,
+    /// e.g. while connecting to a server.
+    Starting,
+    /// The output is active.
+    Active,
+    /// The output is paused.
+    Paused,
+    /// The output lost its connection and is reconnecting.
+    Reconnecting,
+    /// The output has been stopped but is still finishing,
+    /// e.g. while a recording is finalized.
+    Stopping,
+}
+
+impl OutputState {
+    /// The signals changing the state of an output with the state they change to.
+    /// `start` and `stop` are not included as they also resolve waiting calls.
+    pub fn signals() -> [(&'static str, OutputState); 6] {
+        [
+            ("starting", OutputState::Starting),
+            ("pause", OutputState::Paused),
+            ("unpause", OutputState::Active),
+            ("reconnect", OutputState::Reconnecting),
+            ("reconnect_success", OutputState::Active),
+            ("stopping", OutputState::Stopping),
+        ]
+    }
+}
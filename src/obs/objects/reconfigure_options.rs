@@ -0,0 +1,38 @@
+use crate::obs::objects::reset_audio_data::ResetAudioData;
+use crate::obs::objects::reset_video_data::ResetVideoData;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// The video and audio data to reset obs with using `reconfigure`.
+/// Only the data which is set is reset.
+#[cfg_attr(feature = "napi", napi(object))]
+#[derive(Serialize, Deserialize)]
+pub struct ReconfigureData {
+    pub video: Option<ResetVideoData>,
+    pub audio: Option<ResetAudioData>,
+}
+
+/// Options for reconfiguring the video and audio while outputs are running.
+///
+/// # Example
+/// ```ts
+/// await obs.reconfigure({ video: videoData }, { restartOutputs: true });
+/// ```
+#[cfg_attr(feature = "napi", napi(object))]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReconfigureOptions {
+    /// Whether to start the outputs which were stopped again once
+    /// the video and audio have been reset. Defaults to `false`,
+    /// in which case the outputs stay stopped.
+    pub restart_outputs: Option<bool>,
+    /// The time in milliseconds to wait for the outputs to stop
+    /// before they are stopped forcefully. Defaults to 10 seconds.
+    pub stop_timeout: Option<u32>,
+}
+
+impl ReconfigureOptions {
+    pub fn stop_timeout(&self) -> Duration {
+        Duration::from_millis(self.stop_timeout.unwrap_or(10_000) as u64)
+    }
+}
@@ -1,9 +1,11 @@
 use crate::obs::sys;
 use crate::obs::traits::enum_value::EnumValue;
-use napi::bindgen_prelude::ToNapiValue;
+use serde::{Deserialize, Serialize};
 
 /// The data required to reset the audio.
-#[napi(object)]
+#[cfg_attr(feature = "napi", napi(object))]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ResetAudioData {
     pub samples_per_sec: u32,
     pub speakers: SpeakerLayout,
@@ -11,8 +13,22 @@ pub struct ResetAudioData {
     pub fixed_buffering: bool,
 }
 
+impl ResetAudioData {
+    /// Read the audio data from the `obs_audio_info2` obs was reset with.
+    pub(crate) fn from_info(info: &sys::obs_audio_info2) -> Self {
+        Self {
+            samples_per_sec: info.samples_per_sec,
+            speakers: SpeakerLayout::from_value(info.speakers),
+            max_buffering_ms: info.max_buffering_ms,
+            fixed_buffering: info.fixed_buffering,
+        }
+    }
+}
+
 /// OBS audio speaker layout.
-#[napi]
+#[cfg_attr(feature = "napi", napi)]
+#[cfg_attr(not(feature = "napi"), derive(Clone, Copy))]
+#[derive(Serialize, Deserialize)]
 pub enum SpeakerLayout {
     Unknown,
     Mono,
@@ -24,6 +40,36 @@ pub enum SpeakerLayout {
     Layout7point1,
 }
 
+impl SpeakerLayout {
+    /// The number of audio channels of this layout.
+    pub fn channels(&self) -> usize {
+        match self {
+            SpeakerLayout::Unknown => 0,
+            SpeakerLayout::Mono => 1,
+            SpeakerLayout::Stereo => 2,
+            SpeakerLayout::Layout2point1 => 3,
+            SpeakerLayout::Layout4point0 => 4,
+            SpeakerLayout::Layout4point1 => 5,
+            SpeakerLayout::Layout5point1 => 6,
+            SpeakerLayout::Layout7point1 => 8,
+        }
+    }
+
+    pub(crate) fn from_value(value: i32) -> Self {
+        match value {
+            sys::speaker_layout_SPEAKERS_UNKNOWN => SpeakerLayout::Unknown,
+            sys::speaker_layout_SPEAKERS_MONO => SpeakerLayout::Mono,
+            sys::speaker_layout_SPEAKERS_STEREO => SpeakerLayout::Stereo,
+            sys::speaker_layout_SPEAKERS_2POINT1 => SpeakerLayout::Layout2point1,
+            sys::speaker_layout_SPEAKERS_4POINT0 => SpeakerLayout::Layout4point0,
+            sys::speaker_layout_SPEAKERS_4POINT1 => SpeakerLayout::Layout4point1,
+            sys::speaker_layout_SPEAKERS_5POINT1 => SpeakerLayout::Layout5point1,
+            sys::speaker_layout_SPEAKERS_7POINT1 => SpeakerLayout::Layout7point1,
+            _ => SpeakerLayout::Unknown,
+        }
+    }
+}
+
 impl EnumValue for SpeakerLayout {
     fn value(&self) -> i32 {
         match self {
@@ -1,10 +1,13 @@
+use crate::obs::objects::obs_version::Capability;
 use crate::obs::sys;
 use crate::obs::traits::enum_value::EnumValue;
-use napi::bindgen_prelude::ToNapiValue;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
 
 /// Data required to reset the video.
-#[derive(Clone)]
-#[napi(object)]
+#[derive(Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "napi", napi(object))]
+#[serde(rename_all = "camelCase")]
 pub struct ResetVideoData {
     pub graphics_module: GraphicsModule,
     pub fps_num: u32,
@@ -21,26 +24,63 @@ pub struct ResetVideoData {
     pub scale_type: ScaleType,
 }
 
+impl ResetVideoData {
+    /// Read the video data from the `obs_video_info` obs was reset with.
+    /// The graphics module is not read from `info` as its name may not be valid anymore.
+    pub(crate) fn from_info(info: &sys::obs_video_info, graphics_module: GraphicsModule) -> Self {
+        Self {
+            graphics_module,
+            fps_num: info.fps_num,
+            fps_den: info.fps_den,
+            base_width: info.base_width,
+            base_height: info.base_height,
+            output_width: info.output_width,
+            output_height: info.output_height,
+            output_format: VideoFormat::from_value(info.output_format),
+            adapter: info.adapter,
+            gpu_conversion: info.gpu_conversion,
+            colorspace: VideoColorSpace::from_value(info.colorspace),
+            range: VideoRange::from_value(info.range),
+            scale_type: ScaleType::from_value(info.scale_type),
+        }
+    }
+}
+
 unsafe impl Send for ResetVideoData {}
 
 /// The graphics module to use.
-#[napi]
+#[cfg_attr(feature = "napi", napi)]
+#[cfg_attr(not(feature = "napi"), derive(Clone, Copy))]
+#[derive(Serialize, Deserialize)]
 pub enum GraphicsModule {
     OpenGL,
     D3D11,
 }
 
+impl Display for GraphicsModule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            GraphicsModule::OpenGL => "libobs-opengl",
+            GraphicsModule::D3D11 => "libobs-d3d11",
+        })
+    }
+}
+
 impl GraphicsModule {
-    pub fn to_string(&self) -> String {
-        match self {
-            GraphicsModule::OpenGL => "libobs-opengl".to_string(),
-            GraphicsModule::D3D11 => "libobs-d3d11".to_string(),
+    /// The graphics module obs uses by default on this platform.
+    pub fn platform_default() -> Self {
+        if cfg!(windows) {
+            GraphicsModule::D3D11
+        } else {
+            GraphicsModule::OpenGL
         }
     }
 }
 
 /// The video format.
-#[napi]
+#[cfg_attr(feature = "napi", napi)]
+#[cfg_attr(not(feature = "napi"), derive(Clone, Copy))]
+#[derive(Serialize, Deserialize)]
 pub enum VideoFormat {
     None,
     I420,
@@ -91,8 +131,50 @@ impl EnumValue for VideoFormat {
     }
 }
 
+impl VideoFormat {
+    pub(crate) fn from_value(value: i32) -> Self {
+        match value {
+            sys::video_format_VIDEO_FORMAT_NONE => VideoFormat::None,
+            sys::video_format_VIDEO_FORMAT_I420 => VideoFormat::I420,
+            sys::video_format_VIDEO_FORMAT_NV12 => VideoFormat::NV12,
+            sys::video_format_VIDEO_FORMAT_YVYU => VideoFormat::YVYU,
+            sys::video_format_VIDEO_FORMAT_YUY2 => VideoFormat::YUY2,
+            sys::video_format_VIDEO_FORMAT_UYVY => VideoFormat::UYVY,
+            sys::video_format_VIDEO_FORMAT_RGBA => VideoFormat::RGBA,
+            sys::video_format_VIDEO_FORMAT_BGRA => VideoFormat::BGRA,
+            sys::video_format_VIDEO_FORMAT_Y800 => VideoFormat::Y800,
+            sys::video_format_VIDEO_FORMAT_I444 => VideoFormat::I444,
+            sys::video_format_VIDEO_FORMAT_I42A => VideoFormat::I42A,
+            sys::video_format_VIDEO_FORMAT_YUVA => VideoFormat::YUVA,
+            sys::video_format_VIDEO_FORMAT_I40A => VideoFormat::I40A,
+            sys::video_format_VIDEO_FORMAT_BGR3 => VideoFormat::BGR3,
+            sys::video_format_VIDEO_FORMAT_AYUV => VideoFormat::AYUV,
+            sys::video_format_VIDEO_FORMAT_I010 => VideoFormat::I010,
+            sys::video_format_VIDEO_FORMAT_P010 => VideoFormat::P010,
+            sys::video_format_VIDEO_FORMAT_I210 => VideoFormat::I210,
+            sys::video_format_VIDEO_FORMAT_I412 => VideoFormat::I412,
+            sys::video_format_VIDEO_FORMAT_YA2L => VideoFormat::YA2L,
+            _ => VideoFormat::None,
+        }
+    }
+
+    /// The capability required to use this format, if any.
+    pub fn capability(&self) -> Option<Capability> {
+        match self {
+            VideoFormat::I010
+            | VideoFormat::P010
+            | VideoFormat::I210
+            | VideoFormat::I412
+            | VideoFormat::YA2L => Some(Capability::HdrVideo),
+            _ => None,
+        }
+    }
+}
+
 /// The video color space.
-#[napi]
+#[cfg_attr(feature = "napi", napi)]
+#[cfg_attr(not(feature = "napi"), derive(Clone, Copy))]
+#[derive(Serialize, Deserialize)]
 pub enum VideoColorSpace {
     Default,
     CS601,
@@ -115,8 +197,32 @@ impl EnumValue for VideoColorSpace {
     }
 }
 
+impl VideoColorSpace {
+    pub(crate) fn from_value(value: i32) -> Self {
+        match value {
+            sys::video_colorspace_VIDEO_CS_DEFAULT => VideoColorSpace::Default,
+            sys::video_colorspace_VIDEO_CS_601 => VideoColorSpace::CS601,
+            sys::video_colorspace_VIDEO_CS_709 => VideoColorSpace::CS709,
+            sys::video_colorspace_VIDEO_CS_SRGB => VideoColorSpace::SRGB,
+            sys::video_colorspace_VIDEO_CS_2100_PQ => VideoColorSpace::CS2100PQ,
+            sys::video_colorspace_VIDEO_CS_2100_HLG => VideoColorSpace::CS2100HLG,
+            _ => VideoColorSpace::Default,
+        }
+    }
+
+    /// The capability required to use this color space, if any.
+    pub fn capability(&self) -> Option<Capability> {
+        match self {
+            VideoColorSpace::CS2100PQ | VideoColorSpace::CS2100HLG => Some(Capability::HdrVideo),
+            _ => None,
+        }
+    }
+}
+
 /// The video range.
-#[napi]
+#[cfg_attr(feature = "napi", napi)]
+#[cfg_attr(not(feature = "napi"), derive(Clone, Copy))]
+#[derive(Serialize, Deserialize)]
 pub enum VideoRange {
     Default,
     Partial,
@@ -133,8 +239,21 @@ impl EnumValue for VideoRange {
     }
 }
 
+impl VideoRange {
+    pub(crate) fn from_value(value: i32) -> Self {
+        match value {
+            sys::video_range_type_VIDEO_RANGE_DEFAULT => VideoRange::Default,
+            sys::video_range_type_VIDEO_RANGE_PARTIAL => VideoRange::Partial,
+            sys::video_range_type_VIDEO_RANGE_FULL => VideoRange::Full,
+            _ => VideoRange::Default,
+        }
+    }
+}
+
 /// The scale type.
-#[napi]
+#[cfg_attr(feature = "napi", napi)]
+#[cfg_attr(not(feature = "napi"), derive(Clone, Copy))]
+#[derive(Serialize, Deserialize)]
 pub enum ScaleType {
     Disable,
     Point,
@@ -156,3 +275,17 @@ impl EnumValue for ScaleType {
         }
     }
 }
+
+impl ScaleType {
+    pub(crate) fn from_value(value: i32) -> Self {
+        match value {
+            sys::obs_scale_type_OBS_SCALE_DISABLE => ScaleType::Disable,
+            sys::obs_scale_type_OBS_SCALE_POINT => ScaleType::Point,
+            sys::obs_scale_type_OBS_SCALE_BICUBIC => ScaleType::Bicubic,
+            sys::obs_scale_type_OBS_SCALE_BILINEAR => ScaleType::Bilinear,
+            sys::obs_scale_type_OBS_SCALE_LANCZOS => ScaleType::Lanczos,
+            sys::obs_scale_type_OBS_SCALE_AREA => ScaleType::Area,
+            _ => ScaleType::Disable,
+        }
+    }
+}
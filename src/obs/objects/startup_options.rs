@@ -0,0 +1,44 @@
+use crate::obs::objects::nix_platform::NixPlatform;
+use serde::{Deserialize, Serialize};
+
+/// Options for starting obs.
+///
+/// # Example
+/// ```ts
+/// const obs = await Obs.newInstance('en-US', undefined, {
+///     moduleConfigPath: '/path/to/config/plugin_config',
+///     profiler: true,
+///     dataPaths: ['/path/to/obs/data/libobs/'],
+/// });
+/// ```
+#[cfg_attr(feature = "napi", napi(object))]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StartupOptions {
+    /// The directory modules store their configuration in.
+    /// Modules can't persist their configuration if not set.
+    pub module_config_path: Option<String>,
+    /// Whether to enable the profiler. Defaults to `false`.
+    pub profiler: Option<bool>,
+    /// The file the profiler snapshot is written to on shutdown, like OBS Studio does.
+    /// The file is compressed using gzip if the path ends with `.gz`.
+    /// Requires the profiler to be enabled.
+    pub profiler_dump_path: Option<String>,
+    /// Additional directories to search for libobs data files, e.g. effects.
+    /// Paths must end with a path separator.
+    pub data_paths: Option<Vec<String>>,
+    /// The windowing platform to render with on Linux and other unix systems.
+    /// Detected from the `WAYLAND_DISPLAY` and `DISPLAY` environment variables if not set.
+    /// Ignored on Windows and macOS.
+    pub nix_platform: Option<NixPlatform>,
+    /// The name of the X11 or Wayland display to connect to, e.g. `:99` for Xvfb.
+    /// The display of the current session is used if not set.
+    pub display: Option<String>,
+    /// Whether to render using Mesa's software rasterizer,
+    /// e.g. on servers without a GPU. Defaults to `false`.
+    /// Sets `LIBGL_ALWAYS_SOFTWARE=1` for the whole process when obs is started,
+    /// which only has an effect before OpenGL has been initialized.
+    /// Setting environment variables is not thread safe, so if other threads
+    /// may read the environment, set the variable at process start instead.
+    pub software_rendering: Option<bool>,
+}
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+/// The kind of an obs object type.
+#[cfg_attr(feature = "napi", napi)]
+#[cfg_attr(not(feature = "napi"), derive(Clone, Copy))]
+pub enum ObsObjectKind {
+    Source,
+    Encoder,
+    Output,
+}
+
+/// The generated settings schema of an obs object type.
+#[cfg_attr(feature = "napi", napi(object))]
+#[derive(Serialize, Deserialize)]
+pub struct TypeSchema {
+    /// The JSON schema of the settings.
+    pub json_schema: String,
+    /// A TypeScript interface declaration of the settings.
+    pub type_definition: String,
+}
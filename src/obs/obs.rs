@@ -1,190 +1,317 @@
+use crate::obs::custom::js_output::register_js_output;
+use crate::obs::custom::js_source::{JsAudioSource, JsVideoSource};
+use crate::obs::custom::mpegts_output::register_mpegts_output;
 use crate::obs::data::loaded_obs_module::LoadedObsModule;
+use crate::obs::data::module_types::{enum_types, ModuleTypes};
+use crate::obs::data::obs_encoder_type::ObsEncoderType;
 use crate::obs::data::obs_module::ObsModule;
+use crate::obs::data::obs_output_type::ObsOutputType;
+use crate::obs::data::obs_properties::ObsProperties;
 use crate::obs::data::obs_settings::ObsSettings;
-use crate::obs::io::obs_encoder::{ObsAudioEncoder, ObsVideoEncoder};
+use crate::obs::data::profiler_snapshot::{ProfilerEntry, ProfilerSnapshot};
+use crate::obs::helper::probe::{probe_modules, ModuleProbeResult, ProbeOptions};
+use crate::obs::io::obs_encoder::ObsEncoder;
 use crate::obs::io::obs_output::ObsOutput;
 use crate::obs::io::obs_source::ObsSource;
-use crate::obs::objects::failed_obs_module::FailedObsModule;
+use crate::obs::objects::module_load_options::ModuleLoadOptions;
+use crate::obs::objects::module_load_result::{ModuleLoadResult, ModuleLoadStatus};
+use crate::obs::objects::nix_platform::NixPlatform;
+use crate::obs::objects::obs_version::{Capability, ObsVersion};
+use crate::obs::objects::reconfigure_options::{ReconfigureData, ReconfigureOptions};
 use crate::obs::objects::reset_audio_data::ResetAudioData;
-use crate::obs::objects::reset_video_data::ResetVideoData;
+use crate::obs::objects::reset_video_data::{GraphicsModule, ResetVideoData};
+use crate::obs::objects::startup_options::StartupOptions;
+use crate::obs::objects::type_schema::{ObsObjectKind, TypeSchema};
+use crate::obs::plugin::register_source;
 use crate::obs::sys;
 use crate::obs::traits::enum_value::EnumValue;
 use crate::obs::traits::from_raw::FromRaw;
 use crate::obs::traits::raw::Raw;
-use crate::obs::util::napi_error::{to_napi_error_str, to_napi_error_string, MapToNapiError};
-use crate::obs::util::obs_error::{obs_error_to_string, OBS_VIDEO_SUCCESS};
+use crate::obs::util::active_outputs::ActiveOutputs;
+use crate::obs::util::nix_display::NixDisplay;
+use crate::obs::util::obs_error::{ObsError, OBS_VIDEO_SUCCESS};
 use crate::obs::util::obs_guard::ObsGuard;
-use futures::future;
+use crate::obs::util::types::ResultType;
+use std::cell::Cell;
+use std::collections::HashMap;
 use std::ffi::{CStr, CString};
+use std::path::Path;
 use std::sync::Arc;
-use std::{env, ptr};
+use std::{env, mem, ptr};
 
-/// The main obs class.
-/// You can only have one instance of this class active at a time.
+/// The main obs struct.
+/// You can only have one instance of this struct active at a time,
+/// obs is shut down once it and all objects created using it are dropped.
 ///
 /// # Example
-/// ```ts
-/// const obs = await Obs.newInstance('en-US');
+/// ```ignore
+/// let mut obs = Obs::new("en-US", None)?;
 ///
-/// // Get all modules which may be loaded
-/// const modules = await obs.getAllModules('/path/to/your/obs/installation');
-/// // Load the modules
-/// await obs.loadModules(modules);
+/// // Load all modules which may be loaded
+/// let modules = obs.get_all_modules(Some("/path/to/your/obs/installation"))?;
+/// obs.load_modules(modules, &ModuleLoadOptions::default())?;
 ///
-/// // Reset the audio and video
-/// await obs.resetAudio({
-///    fixedBuffering: false,
-///    speakers: obs.SpeakerLayout.Stereo,
-///    maxBufferingMs: 1000,
-///    samplesPerSec: 48000,
-/// });
+/// obs.reset_audio(&ResetAudioData {
+///     samples_per_sec: 48000,
+///     speakers: SpeakerLayout::Stereo,
+///     max_buffering_ms: 1000,
+///     fixed_buffering: false,
+/// })?;
+/// obs.reset_video(&video_data)?;
 ///
-/// await obs.resetVideo({
-///    adapter: 0,
-///    baseHeight: 1440,
-///    baseWidth: 2560,
-///    outputHeight: 1440,
-///    outputWidth: 2560,
-///    scaleType: obs.ScaleType.Bicubic,
-///    colorspace: obs.VideoColorSpace.CS709,
-///    fpsDen: 1,
-///    fpsNum: 60,
-///    gpuConversion: true,
-///    range: obs.VideoRange.Partial,
-///    graphicsModule: obs.GraphicsModule.D3D11,
-///    outputFormat: obs.VideoFormat.NV12,
-/// });
+/// let video_encoder = obs.create_video_encoder("x264", "obs_x264", None)?;
+/// let audio_encoder = obs.create_audio_encoder("aac", "ffmpeg_aac", None)?;
 ///
-/// // Create a video encoder
-/// const videoEncoder = await obs.createVideoEncoder('nvenc', 'jim_nvenc', new ObsSettings({
-///     rate_control: 'CQP',
-///     cqp: 23,
-///     preset: 'medium',
-///     profile: 'high',
-/// ));
+/// let mut settings = ObsSettings::new()?;
+/// settings.set_string("path", "/path/to/your/output.flv")?;
 ///
-/// // Create an audio encoder
-/// const audioEncoder = await obs.createAudioEncoder('aac', 'jim_aac', new ObsSettings({
-///    bitrate: 128,
-///    rate_control: 'CBR',
-/// }));
-///
-/// // Create a new video source
-/// const videoSource = await obs.createSource('screen_capture', 'monitor_capture', 0, new ObsSettings({
-///    capture_cursor: true,
-///    monitor: 0,
-///    method: 2,
-/// }));
-///
-/// // Create a new audio source
-/// await obs.createSource('audio_capture', 'wasapi_output_capture', 1);
-///
-/// // Create a new flv output
-/// const out = await obs.createOutput('flv_output', 'output', new ObsSettings({
-///    path: '/path/to/your/output.flv',
-/// }));
-///
-/// // Start the output
-/// out.start(videoEncoder, audioEncoder);
-///
-/// // Wait for 10 seconds
-/// await new Promise(resolve => setTimeout(resolve, 10000));
-///
-/// // Stop the output
-/// out.stop();
+/// let output = obs.create_output("output", "flv_output", Some(&settings))?;
+/// output.start(Some(&video_encoder), Some(&audio_encoder))?;
 /// ```
-#[napi]
 pub struct Obs {
     guard: Arc<ObsGuard>,
-    failed_modules: Vec<FailedObsModule>,
+    module_results: Vec<ModuleLoadResult>,
+    /// The types registered by the loaded modules, by module file name.
+    module_types: HashMap<String, ModuleTypes>,
+    post_loaded: bool,
+    /// The graphics module video was last reset with.
+    graphics_module: Cell<Option<GraphicsModule>>,
 }
 
-#[napi]
 impl Obs {
-    /// Create a new OBS instance.
-    #[napi(constructor)]
-    pub fn new(locale: String) -> napi::Result<Self> {
+    /// Start obs using the given locale, e.g. `en-US`.
+    /// libobs is loaded from `libobs_path` first, which may either be the path
+    /// to the library or to the directory containing it.
+    /// The library is searched for on the library search path if no path is given.
+    pub fn new(locale: &str, libobs_path: Option<&str>) -> ResultType<Self> {
+        Self::with_options(locale, libobs_path, &StartupOptions::default())
+    }
+
+    /// Start obs using the given locale and startup options.
+    /// See `new`.
+    pub fn with_options(
+        locale: &str,
+        libobs_path: Option<&str>,
+        options: &StartupOptions,
+    ) -> ResultType<Self> {
+        // Mesa reads this once the OpenGL library is loaded, so it has to be set
+        // before libobs and its graphics module are. Setting environment variables
+        // races with other threads reading them, see `StartupOptions::software_rendering`.
+        if options.software_rendering.unwrap_or(false) {
+            env::set_var("LIBGL_ALWAYS_SOFTWARE", "1");
+        }
+
+        sys::load(libobs_path)?;
         let locale = CString::new(locale)?;
+        let module_config_path = options
+            .module_config_path
+            .as_deref()
+            .map(CString::new)
+            .transpose()?;
+
+        for path in options.data_paths.iter().flatten() {
+            let path = CString::new(path.as_str())?;
+            unsafe { sys::obs_add_data_path(path.as_ptr()) };
+        }
 
-        let initialized: bool =
-            unsafe { sys::obs_startup(locale.as_ptr(), ptr::null_mut(), ptr::null_mut()) };
+        let display = Self::open_display(options)?;
+
+        let profiler_names = if options.profiler.unwrap_or(false) {
+            unsafe {
+                sys::profiler_start();
+                sys::profiler_name_store_create()
+            }
+        } else {
+            ptr::null_mut()
+        };
+
+        let initialized: bool = unsafe {
+            sys::obs_startup(
+                locale.as_ptr(),
+                module_config_path
+                    .as_ref()
+                    .map(|path| path.as_ptr())
+                    .unwrap_or(ptr::null()),
+                profiler_names,
+            )
+        };
 
         if initialized {
             Ok(Self {
-                guard: Arc::new(ObsGuard::new()),
-                failed_modules: Vec::new(),
+                guard: Arc::new(ObsGuard::new(
+                    profiler_names,
+                    options.profiler_dump_path.clone(),
+                    display,
+                )),
+                module_results: Vec::new(),
+                module_types: HashMap::new(),
+                post_loaded: false,
+                graphics_module: Cell::new(None),
             })
         } else {
-            Err(to_napi_error_str("Failed to initialize OBS"))
+            if !profiler_names.is_null() {
+                unsafe {
+                    sys::profiler_stop();
+                    sys::profiler_free();
+                    sys::profiler_name_store_free(profiler_names);
+                }
+            }
+
+            Err(ObsError::Startup)
         }
     }
 
-    /// Create a new OBS instance.
-    /// Async version.
-    #[napi(js_name = "newInstance")]
-    pub async fn new_obs_instance(locale: String) -> napi::Result<Obs> {
-        future::lazy(move |_| Self::new(locale)).await
+    /// Connect to the display obs renders on, on Linux and other unix systems.
+    /// The platform of the current session is used if none is set. Failing to
+    /// connect to it is not an error then, as obs may be used without video.
+    fn open_display(options: &StartupOptions) -> ResultType<Option<NixDisplay>> {
+        // There's nothing to render on with the simulated libobs
+        if !cfg!(unix) || cfg!(target_os = "macos") || cfg!(feature = "mock") {
+            return Ok(None);
+        }
+
+        let display = options.display.as_deref();
+        match options.nix_platform {
+            Some(platform) => NixDisplay::open(platform, display).map(Some),
+            None => {
+                Ok(NixPlatform::detect()
+                    .and_then(|platform| NixDisplay::open(platform, display).ok()))
+            }
+        }
     }
 
-    /// Get all modules which may be loaded.
-    /// This may include 'modules' which are not in fact modules.
-    #[napi]
-    pub fn get_all_modules_sync(&self, obs_path: Option<String>) -> napi::Result<Vec<ObsModule>> {
-        ObsModule::get_all_modules(
-            obs_path
-                .or_else(|| Self::find_obs_sync(Some(false)).ok())
-                .ok_or(to_napi_error_str("Failed to find OBS"))?,
-        )
+    /// Get the current locale, e.g. `en-US`.
+    pub fn get_locale(&self) -> ResultType<String> {
+        let locale = unsafe { sys::obs_get_locale() };
+        if locale.is_null() {
+            Err("Failed to get the locale".into())
+        } else {
+            Ok(unsafe { CStr::from_ptr(locale) }.to_str()?.to_string())
+        }
+    }
+
+    /// Change the locale of obs and all loaded modules.
+    /// Properties and display names retrieved after the change use the new locale,
+    /// properties retrieved before keep their descriptions.
+    pub fn set_locale(&self, locale: &str) -> ResultType<()> {
+        let locale = CString::new(locale)?;
+        unsafe { sys::obs_set_locale(locale.as_ptr()) };
+
+        Ok(())
+    }
+
+    /// Take a snapshot of the data collected by the profiler.
+    /// Returns the root entries, e.g. the video and graphics threads.
+    /// Requires obs to be started with the profiler enabled.
+    pub fn get_profiler_snapshot(&self) -> ResultType<Vec<ProfilerEntry>> {
+        self.profiler_snapshot().map(|snapshot| snapshot.roots())
+    }
+
+    /// Write a snapshot of the data collected by the profiler to a CSV file,
+    /// which is compressed using gzip if the path ends with `.gz`.
+    /// Requires obs to be started with the profiler enabled.
+    pub fn dump_profiler_snapshot(&self, path: &str) -> ResultType<()> {
+        self.profiler_snapshot()?.dump(path)
+    }
+
+    fn profiler_snapshot(&self) -> ResultType<ProfilerSnapshot> {
+        if !self.guard.profiler_enabled() {
+            return Err(ObsError::InvalidState("The profiler is not enabled"));
+        }
+
+        ProfilerSnapshot::create()
     }
 
     /// Get all modules which may be loaded.
-    /// Async version of `getAllModulesSync`.
-    #[napi]
-    pub async fn get_all_modules(&self, obs_path: Option<String>) -> napi::Result<Vec<ObsModule>> {
-        future::lazy(move |_| self.get_all_modules_sync(obs_path)).await
+    /// This may include 'modules' which are not in fact modules.
+    /// Searches for obs on the `PATH` if no path is given.
+    pub fn get_all_modules(&self, obs_path: Option<&str>) -> ResultType<Vec<ObsModule>> {
+        let obs_path = match obs_path {
+            Some(path) => path.to_string(),
+            None => Self::find_obs(false).map_err(|_| "Failed to find OBS")?,
+        };
+
+        ObsModule::get_all_modules(&obs_path)
     }
 
     /// Load modules.
-    ///
-    /// @param modules - the modules to load
-    /// @param throwOnLoadFailed - whether to throw an error if a module fails to load. Defaults to `false`.
-    #[napi]
-    pub fn load_modules_sync(
+    /// Returns the result of loading each module, which are also
+    /// added to `module_results`. Unless `throw_on_load_failed` is set
+    /// in the options, modules which fail to load are skipped.
+    pub fn load_modules(
         &mut self,
         modules: Vec<ObsModule>,
-        throw_on_load_failed: Option<bool>,
-    ) -> napi::Result<()> {
+        options: &ModuleLoadOptions,
+    ) -> ResultType<Vec<ModuleLoadResult>> {
+        let functions = sys::functions();
+        for (names, add) in [
+            (&options.disabled, functions.obs_add_disabled_module),
+            (&options.safe, functions.obs_add_safe_module),
+        ] {
+            if let (Some(names), Some(add)) = (names, add) {
+                for name in names {
+                    let name = CString::new(name.as_str())?;
+                    unsafe { add(name.as_ptr()) };
+                }
+            }
+        }
+
+        let mut results = Vec::with_capacity(modules.len());
         for module in modules {
-            let res = module.load();
+            let result = if !options.is_allowed(&module.name) {
+                ModuleLoadResult::new(module, ModuleLoadStatus::Denied)
+            } else if options.is_disabled(&module.name) {
+                ModuleLoadResult::new(module, ModuleLoadStatus::Disabled)
+            } else {
+                module.load()
+            };
+
+            if let Some(types) = &result.types {
+                if let Some(file_name) = Path::new(&result.module.bin_path).file_name() {
+                    self.module_types
+                        .insert(file_name.to_string_lossy().to_string(), types.clone());
+                }
+            }
 
-            if throw_on_load_failed.unwrap_or(false) {
-                res.map_napi_err()?;
-            } else if res.is_err() {
-                self.failed_modules
-                    .push(FailedObsModule::new(module, res.err().unwrap().to_string()));
+            let failed = result.status == ModuleLoadStatus::Failed;
+            results.push(result.clone());
+
+            if failed && options.throw_on_load_failed.unwrap_or(false) {
+                // Keep the results of the modules which have already been loaded
+                self.module_results.extend(results);
+                return Err(ObsError::Module {
+                    name: result.module.name,
+                    reason: result.error.unwrap_or_default(),
+                });
             }
         }
 
-        Ok(())
+        if options.post_load.unwrap_or(true) && !self.post_loaded {
+            if let Some(post_load) = functions.obs_post_load_modules {
+                unsafe { post_load() };
+            }
+
+            self.post_loaded = true;
+        }
+
+        self.module_results.extend(results.iter().cloned());
+        Ok(results)
     }
 
-    /// Load modules.
-    /// Async version of `loadModulesSync`.
-    #[napi]
-    pub async fn load_modules(
-        &mut self,
-        modules: Vec<ObsModule>,
-        throw_on_load_failed: Option<bool>,
-    ) -> napi::Result<()> {
-        future::lazy(move |_| self.load_modules_sync(modules, throw_on_load_failed)).await
+    /// Test-load modules, each in its own short-lived helper process,
+    /// to find modules which crash, hang or fail to load
+    /// before loading them into this process.
+    /// Requires the `obs-helper` binary, see `ProbeOptions::helper_path`.
+    pub fn probe_modules(
+        modules: &[ObsModule],
+        options: &ProbeOptions,
+    ) -> ResultType<Vec<ModuleProbeResult>> {
+        probe_modules(modules, options)
     }
 
-    #[napi]
-    pub async fn init_audio_monitoring(
-        &self,
-        device_name: String,
-        device_id: String,
-    ) -> napi::Result<bool> {
+    /// Set the audio monitoring device.
+    /// Returns `false` if audio monitoring is not available.
+    pub fn init_audio_monitoring(&self, device_name: &str, device_id: &str) -> ResultType<bool> {
         if unsafe { sys::obs_audio_monitoring_available() } {
             let device_name = CString::new(device_name)?;
             let device_id = CString::new(device_id)?;
@@ -196,7 +323,7 @@ impl Obs {
             if ok {
                 Ok(true)
             } else {
-                Err(to_napi_error_str("Failed to initialize audio monitoring"))
+                Err("Failed to initialize audio monitoring".into())
             }
         } else {
             Ok(false)
@@ -204,8 +331,18 @@ impl Obs {
     }
 
     /// Reset the video data.
-    #[napi]
-    pub fn reset_video_sync(&self, data: ResetVideoData) -> napi::Result<()> {
+    /// Returns an error if the loaded libobs does not support the format or color space.
+    pub fn reset_video(&self, data: &ResetVideoData) -> ResultType<()> {
+        for capability in [
+            data.output_format.capability(),
+            data.colorspace.capability(),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            capability.require()?;
+        }
+
         let graphics_module = CString::new(data.graphics_module.to_string())?;
         let mut info = sys::obs_video_info {
             graphics_module: graphics_module.as_ptr(),
@@ -226,26 +363,18 @@ impl Obs {
         let res = unsafe { sys::obs_reset_video(&mut info as *mut _) };
 
         if res == OBS_VIDEO_SUCCESS {
+            self.graphics_module.set(Some(data.graphics_module));
             Ok(())
         } else {
-            Err(to_napi_error_string(format!(
-                "Failed to set video. Error: {}",
-                obs_error_to_string(res)
-            )))
+            Err(ObsError::ResetVideo(res))
         }
     }
 
-    /// Reset the video data.
-    /// Async version of `resetVideoSync`.
-    #[napi]
-    pub async fn reset_video(&'static self, data: ResetVideoData) -> napi::Result<()> {
-        future::lazy(move |_| self.reset_video_sync(data)).await
-    }
-
     /// Reset the audio data.
-    #[napi]
-    pub fn reset_audio_sync(&self, data: ResetAudioData) -> napi::Result<()> {
-        let res = unsafe {
+    /// Falls back to `obs_reset_audio` on libobs versions without `obs_reset_audio2`,
+    /// ignoring `max_buffering_ms`. Fixed buffering requires `obs_reset_audio2`.
+    pub fn reset_audio(&self, data: &ResetAudioData) -> ResultType<()> {
+        let res = if Capability::ResetAudio2.is_available() {
             let mut info = sys::obs_audio_info2 {
                 samples_per_sec: data.samples_per_sec,
                 speakers: data.speakers.value(),
@@ -253,168 +382,250 @@ impl Obs {
                 fixed_buffering: data.fixed_buffering,
             };
 
-            sys::obs_reset_audio2(&mut info as *mut _)
+            unsafe { sys::obs_reset_audio2(&mut info as *mut _) }
+        } else if data.fixed_buffering {
+            return Capability::ResetAudio2.require();
+        } else {
+            let mut info = sys::obs_audio_info {
+                samples_per_sec: data.samples_per_sec,
+                speakers: data.speakers.value(),
+            };
+
+            unsafe { sys::obs_reset_audio(&mut info as *mut _) }
         };
 
         if res {
             Ok(())
         } else {
-            Err(to_napi_error_str("Failed to reset audio"))
+            Err(ObsError::ResetAudio)
         }
     }
 
-    /// Reset the audio data.
-    /// Async version of `resetAudioSync`.
-    #[napi]
-    pub async fn reset_audio(&'static self, data: ResetAudioData) -> napi::Result<()> {
-        future::lazy(move |_| self.reset_audio_sync(data)).await
-    }
+    /// Get the video data obs is currently running with.
+    /// Returns an error if the video has not been reset yet.
+    pub fn get_video_info(&self) -> ResultType<ResetVideoData> {
+        let mut info: sys::obs_video_info = unsafe { mem::zeroed() };
+        if !unsafe { sys::obs_get_video_info(&mut info) } {
+            return Err(ObsError::InvalidState("The video has not been reset"));
+        }
 
-    /// Get a list of modules which failed to load.
-    #[napi(getter)]
-    pub fn failed_modules(&self) -> napi::Result<Vec<FailedObsModule>> {
-        Ok(self.failed_modules.clone())
+        let graphics_module = self
+            .graphics_module
+            .get()
+            .unwrap_or_else(GraphicsModule::platform_default);
+        Ok(ResetVideoData::from_info(&info, graphics_module))
     }
 
-    #[napi(getter)]
-    pub fn get_loaded_modules(&self) -> napi::Result<Vec<LoadedObsModule>> {
-        LoadedObsModule::list_loaded_modules()
+    /// Get the audio data obs is currently running with.
+    /// Falls back to `obs_get_audio_info` on libobs versions without `obs_get_audio_info2`,
+    /// in which case `max_buffering_ms` is `0` and `fixed_buffering` is `false`.
+    /// Returns an error if the audio has not been reset yet.
+    pub fn get_audio_info(&self) -> ResultType<ResetAudioData> {
+        let mut info = sys::obs_audio_info2 {
+            samples_per_sec: 0,
+            speakers: 0,
+            max_buffering_ms: 0,
+            fixed_buffering: false,
+        };
+
+        let res = match sys::functions().obs_get_audio_info2 {
+            Some(get_audio_info2) => unsafe { get_audio_info2(&mut info) },
+            None => unsafe {
+                let mut legacy: sys::obs_audio_info = mem::zeroed();
+                let res = sys::obs_get_audio_info(&mut legacy);
+                info.samples_per_sec = legacy.samples_per_sec;
+                info.speakers = legacy.speakers;
+                res
+            },
+        };
+
+        if res {
+            Ok(ResetAudioData::from_info(&info))
+        } else {
+            Err(ObsError::InvalidState("The audio has not been reset"))
+        }
     }
 
-    /// List all encoder types.
-    /// This list includes video and audio encoders.
-    #[napi]
-    pub fn list_encoder_types_sync(&self) -> napi::Result<Vec<String>> {
-        let mut ok = true;
-        let mut i: u64 = 0;
-        let mut res = vec![];
+    /// Check if video is active, i.e. if any output is using it.
+    /// The video can't be reset while it is active.
+    pub fn video_active(&self) -> bool {
+        unsafe { sys::obs_video_active() }
+    }
 
-        unsafe {
-            while ok {
-                let mut ptr: *mut std::os::raw::c_char = ptr::null_mut();
-                ok = sys::obs_enum_encoder_types(
-                    i,
-                    &mut ptr as *mut *mut std::os::raw::c_char as *mut *const std::os::raw::c_char,
-                );
-                i += 1;
-
-                if ok && !ptr.is_null() {
-                    let cstr = CStr::from_ptr(ptr);
-                    res.push(cstr.to_string_lossy().to_string());
-                }
-            }
+    /// Reset the video and audio while outputs may be running.
+    /// All active outputs are stopped gracefully first, then the video and audio are reset.
+    /// If `restart_outputs` is set, the encoders of the stopped outputs are bound
+    /// to the new video and audio and the outputs are started again,
+    /// even if resetting failed, in which case they keep the previous configuration.
+    pub fn reconfigure(
+        &self,
+        data: &ReconfigureData,
+        options: &ReconfigureOptions,
+    ) -> ResultType<()> {
+        let outputs = ActiveOutputs::collect();
+        outputs.stop(options.stop_timeout());
+
+        let res = data
+            .video
+            .as_ref()
+            .map_or(Ok(()), |video| self.reset_video(video))
+            .and_then(|_| {
+                data.audio
+                    .as_ref()
+                    .map_or(Ok(()), |audio| self.reset_audio(audio))
+            });
+
+        if options.restart_outputs.unwrap_or(false) {
+            let restarted = outputs.restart();
+            res.and(restarted)
+        } else {
+            res
         }
+    }
 
-        Ok(res)
+    /// Get the version of the loaded libobs.
+    pub fn version(&self) -> ObsVersion {
+        ObsVersion::current()
     }
 
-    /// List all encoder types.
-    /// Async version of `listEncoderTypesSync`.
-    #[napi]
-    pub async fn list_encoder_types(&self) -> napi::Result<Vec<String>> {
-        future::lazy(move |_| self.list_encoder_types_sync()).await
+    /// Check if the loaded libobs supports a capability.
+    pub fn supports(&self, capability: Capability) -> bool {
+        capability.is_available()
     }
 
-    /// List all output types.
-    #[napi]
-    pub fn list_output_types_sync(&self) -> napi::Result<Vec<String>> {
-        let mut ok = true;
-        let mut i: u64 = 0;
-        let mut res = vec![];
+    /// Get the results of all modules loaded using `load_modules`.
+    pub fn module_results(&self) -> &[ModuleLoadResult] {
+        &self.module_results
+    }
 
-        unsafe {
-            while ok {
-                let mut ptr: *mut std::os::raw::c_char = ptr::null_mut();
-                ok = sys::obs_enum_output_types(
-                    i,
-                    &mut ptr as *mut *mut std::os::raw::c_char as *mut *const std::os::raw::c_char,
-                );
-                i += 1;
-
-                if ok && !ptr.is_null() {
-                    let cstr = CStr::from_ptr(ptr);
-                    res.push(cstr.to_string_lossy().to_string());
-                }
-            }
+    /// Get a list of all loaded modules.
+    /// The registered types are only known for modules loaded using `load_modules`.
+    pub fn get_loaded_modules(&self) -> ResultType<Vec<LoadedObsModule>> {
+        let mut modules = LoadedObsModule::list_loaded_modules()?;
+        for module in &mut modules {
+            module.types = self.module_types.get(&module.file_name).cloned();
         }
 
-        Ok(res)
+        Ok(modules)
     }
 
-    /// List all output types.
-    /// Async version of `listOutputTypesSync`.
-    #[napi]
-    pub async fn list_output_types(&self) -> napi::Result<Vec<String>> {
-        future::lazy(|_| self.list_output_types_sync()).await
+    /// Find the loaded module which registered the type with the given id,
+    /// e.g. `jim_nvenc`. Only modules loaded using `load_modules` are considered.
+    pub fn find_type_module(&self, id: &str) -> ResultType<Option<LoadedObsModule>> {
+        Ok(self.get_loaded_modules()?.into_iter().find(|module| {
+            module
+                .types
+                .as_ref()
+                .map(|types| types.contains(id))
+                .unwrap_or(false)
+        }))
     }
 
-    /*#[napi]
-    pub fn list_service_types_sync(&self) -> napi::Result<Vec<String>> {
-        let mut ok = true;
-        let mut i: u64 = 0;
-        let mut res = vec![];
+    /// List all encoder types.
+    /// This list includes video and audio encoders.
+    pub fn list_encoder_types(&self) -> Vec<String> {
+        unsafe { enum_types(|i, id| sys::obs_enum_encoder_types(i, id)) }
+    }
 
-        unsafe {
-            while ok {
-                let mut ptr: *mut std::os::raw::c_char = ptr::null_mut();
-                ok = sys::obs_enum_service_types(
-                    i,
-                    &mut ptr as *mut *mut std::os::raw::c_char as *mut *const std::os::raw::c_char,
-                );
-                i += 1;
-
-                if ok && !ptr.is_null() {
-                    let cstr = CStr::from_ptr(ptr);
-                    res.push(cstr.to_string_lossy().to_string());
-                }
-            }
-        }
+    /// Get information about an encoder type without creating an encoder.
+    /// Returns an error if the encoder type does not exist.
+    pub fn get_encoder_type(&self, id: &str) -> ResultType<ObsEncoderType> {
+        ObsEncoderType::new(id.to_string(), self.guard.clone())
+    }
+
+    /// List information about all encoder types.
+    /// This list includes video and audio encoders.
+    pub fn list_encoder_type_details(&self) -> ResultType<Vec<ObsEncoderType>> {
+        self.list_encoder_types()
+            .iter()
+            .map(|id| self.get_encoder_type(id))
+            .collect()
+    }
+
+    /// List all output types.
+    pub fn list_output_types(&self) -> Vec<String> {
+        unsafe { enum_types(|i, id| sys::obs_enum_output_types(i, id)) }
+    }
 
-        Ok(res)
+    /// Get information about an output type without creating an output.
+    /// Returns an error if the output type does not exist.
+    pub fn get_output_type(&self, id: &str) -> ResultType<ObsOutputType> {
+        ObsOutputType::new(id.to_string(), self.guard.clone())
     }
 
-    #[napi]
-    pub async fn list_service_types(&self) -> napi::Result<Vec<String>> {
-        future::lazy(move |_| self.list_service_types_sync()).await
-    }*/
+    /// List information about all output types.
+    pub fn list_output_type_details(&self) -> ResultType<Vec<ObsOutputType>> {
+        self.list_output_types()
+            .iter()
+            .map(|id| self.get_output_type(id))
+            .collect()
+    }
 
-    #[napi]
-    pub fn list_source_types_sync(&self) -> napi::Result<Vec<String>> {
-        let mut ok = true;
-        let mut i: u64 = 0;
-        let mut res = vec![];
+    /// List all source types.
+    pub fn list_source_types(&self) -> Vec<String> {
+        unsafe { enum_types(|i, id| sys::obs_enum_source_types(i, id)) }
+    }
 
-        unsafe {
-            while ok {
-                let mut ptr: *mut std::os::raw::c_char = ptr::null_mut();
-                ok = sys::obs_enum_source_types(
-                    i,
-                    &mut ptr as *mut *mut std::os::raw::c_char as *mut *const std::os::raw::c_char,
-                );
-                i += 1;
-
-                if ok && !ptr.is_null() {
-                    let cstr = CStr::from_ptr(ptr);
-                    res.push(cstr.to_string_lossy().to_string());
-                }
+    /// Generate a JSON schema and a TypeScript interface
+    /// for the settings of a source, encoder or output type.
+    /// The name of the interface defaults to the id in PascalCase.
+    pub fn get_type_schema(
+        &self,
+        kind: ObsObjectKind,
+        id: &str,
+        name: Option<&str>,
+    ) -> ResultType<TypeSchema> {
+        let c_id = CString::new(id)?;
+        let (properties, defaults) = unsafe {
+            match kind {
+                ObsObjectKind::Source => (
+                    sys::obs_get_source_properties(c_id.as_ptr()),
+                    sys::obs_get_source_defaults(c_id.as_ptr()),
+                ),
+                ObsObjectKind::Encoder => (
+                    sys::obs_get_encoder_properties(c_id.as_ptr()),
+                    sys::obs_encoder_defaults(c_id.as_ptr()),
+                ),
+                ObsObjectKind::Output => (
+                    sys::obs_get_output_properties(c_id.as_ptr()),
+                    sys::obs_output_defaults(c_id.as_ptr()),
+                ),
             }
-        }
+        };
 
-        Ok(res)
-    }
+        let defaults = if defaults.is_null() {
+            None
+        } else {
+            Some(ObsSettings::from_raw(defaults, Some(self.guard.clone())))
+        };
 
-    #[napi]
-    pub async fn list_source_types(&self) -> napi::Result<Vec<String>> {
-        future::lazy(|_| self.list_source_types_sync()).await
+        if properties.is_null() {
+            return Err(format!("The type '{}' does not exist or has no properties", id).into());
+        }
+
+        let properties = ObsProperties::from_raw(properties, Some(self.guard.clone()));
+        let schema = properties.schema(defaults.as_ref())?;
+        let name = name.map(|n| n.to_string()).unwrap_or_else(|| {
+            id.split(|c: char| !c.is_ascii_alphanumeric())
+                .filter(|p| !p.is_empty())
+                .map(|p| p[..1].to_uppercase() + &p[1..])
+                .collect::<String>()
+                + "Settings"
+        });
+
+        Ok(TypeSchema {
+            json_schema: schema.to_json_schema(id).to_string(),
+            type_definition: schema.to_type_definition(&name),
+        })
     }
 
-    #[napi]
-    pub fn create_video_encoder_sync(
+    /// Create a video encoder.
+    pub fn create_video_encoder(
         &self,
-        name: String,
-        id: String,
+        name: &str,
+        id: &str,
         settings: Option<&ObsSettings>,
-    ) -> napi::Result<ObsVideoEncoder> {
+    ) -> ResultType<ObsEncoder> {
         let id = CString::new(id)?;
         let name = CString::new(name)?;
 
@@ -428,29 +639,19 @@ impl Obs {
         };
 
         if encoder.is_null() {
-            Err(to_napi_error_str("Failed to get encoder"))
+            Err(ObsError::CreateFailed("encoder"))
         } else {
-            Ok(ObsVideoEncoder::from_raw(encoder, Some(self.guard.clone())))
+            Ok(ObsEncoder::from_raw(encoder, Some(self.guard.clone())))
         }
     }
 
-    #[napi]
-    pub async fn create_video_encoder(
-        &'static self,
-        name: String,
-        id: String,
-        settings: Option<&'static ObsSettings>,
-    ) -> napi::Result<ObsVideoEncoder> {
-        future::lazy(move |_| self.create_video_encoder_sync(name, id, settings)).await
-    }
-
-    #[napi]
-    pub fn create_audio_encoder_sync(
+    /// Create an audio encoder.
+    pub fn create_audio_encoder(
         &self,
-        name: String,
-        id: String,
+        name: &str,
+        id: &str,
         settings: Option<&ObsSettings>,
-    ) -> napi::Result<ObsAudioEncoder> {
+    ) -> ResultType<ObsEncoder> {
         let id = CString::new(id)?;
         let name = CString::new(name)?;
         let encoder = unsafe {
@@ -464,49 +665,19 @@ impl Obs {
         };
 
         if encoder.is_null() {
-            Err(to_napi_error_str("Failed to get encoder"))
+            Err(ObsError::CreateFailed("encoder"))
         } else {
-            Ok(ObsAudioEncoder::from_raw(encoder, Some(self.guard.clone())))
+            Ok(ObsEncoder::from_raw(encoder, Some(self.guard.clone())))
         }
     }
 
-    #[napi]
-    pub async fn create_audio_encoder(
-        &'static self,
-        name: String,
-        id: String,
-        settings: Option<&'static ObsSettings>,
-    ) -> napi::Result<ObsAudioEncoder> {
-        future::lazy(move |_| self.create_audio_encoder_sync(name, id, settings)).await
-    }
-
-    /*pub fn get_audio_monitoring_device(&self) -> napi::Result<AudioDevice> {
-        let mut name: *const std::os::raw::c_char = ptr::null();
-        let mut id: *const std::os::raw::c_char = ptr::null();
-        unsafe {
-            sys::obs_get_audio_monitoring_device(&mut name as _, &mut id as _);
-        }
-
-        if name.is_null() || id.is_null() {
-            Err("Failed to get audio monitoring device".into())
-        } else {
-            let name = unsafe { CStr::from_ptr(name) };
-            let id = unsafe { CStr::from_ptr(id) };
-
-            Ok(AudioDevice {
-                name: name.to_string_lossy().to_string(),
-                id: id.to_string_lossy().to_string(),
-            })
-        }
-    }*/
-
-    #[napi]
-    pub fn create_output_sync(
+    /// Create an output.
+    pub fn create_output(
         &self,
-        name: String,
-        id: String,
+        name: &str,
+        id: &str,
         settings: Option<&ObsSettings>,
-    ) -> napi::Result<ObsOutput> {
+    ) -> ResultType<ObsOutput> {
         let id = CString::new(id)?;
         let name = CString::new(name)?;
         let output = unsafe {
@@ -519,30 +690,20 @@ impl Obs {
         };
 
         if output.is_null() {
-            Err(to_napi_error_str("Failed to get encoder"))
+            Err(ObsError::CreateFailed("output"))
         } else {
             Ok(ObsOutput::from_raw(output, Some(self.guard.clone())))
         }
     }
 
-    #[napi]
-    pub async fn create_output(
-        &'static self,
-        name: String,
-        id: String,
-        settings: Option<&'static ObsSettings>,
-    ) -> napi::Result<ObsOutput> {
-        future::lazy(|_| self.create_output_sync(id, name, settings)).await
-    }
-
-    #[napi]
-    pub fn create_source_sync(
+    /// Create a source and set it as the source of an output channel.
+    pub fn create_source(
         &self,
-        name: String,
-        id: String,
+        name: &str,
+        id: &str,
         channel: u32,
         settings: Option<&ObsSettings>,
-    ) -> napi::Result<ObsSource> {
+    ) -> ResultType<ObsSource> {
         let source = unsafe {
             let name = CString::new(name)?;
             let id = CString::new(id)?;
@@ -560,44 +721,553 @@ impl Obs {
         }
 
         if source.is_null() {
-            Err(to_napi_error_str("Failed to create source"))
+            Err(ObsError::CreateFailed("source"))
         } else {
             Ok(ObsSource::from_raw(source, Some(self.guard.clone())))
         }
     }
 
-    #[napi]
-    pub async fn create_source(
-        &'static self,
-        name: String,
-        id: String,
-        channel: u32,
-        settings: Option<&'static ObsSettings>,
-    ) -> napi::Result<ObsSource> {
-        future::lazy(|_| self.create_source_sync(name, id, channel, settings)).await
+    /// Register a video source type whose frames are pushed
+    /// using `ObsSource::push_video_frame`.
+    pub fn register_video_source_type(&self, id: &str, display_name: &str) -> ResultType<()> {
+        register_source::<JsVideoSource>(id, display_name)
+    }
+
+    /// Register an audio source type whose samples are pushed
+    /// using `ObsSource::push_audio`.
+    pub fn register_audio_source_type(&self, id: &str, display_name: &str) -> ResultType<()> {
+        register_source::<JsAudioSource>(id, display_name)
+    }
+
+    /// Register an output type whose encoded packets are passed
+    /// to the listener set using `ObsOutput::on_packet`.
+    pub fn register_output_type(&self, id: &str, display_name: &str) -> ResultType<()> {
+        register_js_output(id.to_string(), display_name.to_string())
+    }
+
+    /// Register an output type muxing h264/hevc video and aac audio into
+    /// an MPEG transport stream, which is passed to the listener
+    /// set using `ObsOutput::on_data`.
+    pub fn register_mpegts_output_type(&self, id: &str, display_name: &str) -> ResultType<()> {
+        register_mpegts_output(id.to_string(), display_name.to_string())
     }
 
-    #[napi]
-    pub fn find_obs_sync(bin_path: Option<bool>) -> napi::Result<String> {
-        let path =
-            env::var_os("PATH").ok_or(to_napi_error_str("Failed to get PATH env variable"))?;
+    /// Find the obs installation using the `PATH`.
+    /// Returns the path of the directory containing `obs64.exe` if `bin_path`
+    /// is set, the root directory of the installation otherwise.
+    pub fn find_obs(bin_path: bool) -> ResultType<String> {
+        let path = env::var_os("PATH").ok_or("Failed to get PATH env variable")?;
         let p = env::split_paths(&path)
             .find(|p| p.join("obs64.exe").exists())
-            .ok_or(to_napi_error_str("Failed to find obs64.exe"))?;
+            .ok_or("Failed to find obs64.exe")?;
 
-        if bin_path.unwrap_or(false) {
+        if bin_path {
             Ok(p.to_string_lossy().to_string())
         } else {
             p.parent()
                 .and_then(|p| p.parent().map(|p| p.to_string_lossy().to_string()))
-                .ok_or(to_napi_error_str("Failed to find the OBS directory"))
+                .ok_or_else(|| "Failed to find the OBS directory".into())
         }
     }
-
-    #[napi]
-    pub async fn find_obs(bin_path: Option<bool>) -> napi::Result<String> {
-        future::lazy(|_| Self::find_obs_sync(bin_path)).await
-    }
 }
 
 unsafe impl Send for Obs {}
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use super::*;
+    use crate::obs::custom::audio_data::{AudioDataInfo, AudioFormat};
+    use crate::obs::objects::media_state::MediaEvent;
+    use crate::obs::objects::output_state::OutputState;
+    use crate::obs::objects::reset_audio_data::SpeakerLayout;
+    use crate::obs::objects::reset_video_data::{
+        ScaleType, VideoColorSpace, VideoFormat, VideoRange,
+    };
+    use crate::obs::plugin::{
+        register_output, OutputContext, OutputImpl, OutputTypeFlags, PacketRef, Settings,
+    };
+    use std::sync::{Mutex, MutexGuard};
+
+    /// libobs is global, so only one test may run obs at a time.
+    static OBS_LOCK: Mutex<()> = Mutex::new(());
+
+    fn start() -> (MutexGuard<'static, ()>, Obs) {
+        let lock = OBS_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let obs = Obs::new("en-US", None).unwrap();
+
+        (lock, obs)
+    }
+
+    fn video_data(width: u32, height: u32) -> ResetVideoData {
+        ResetVideoData {
+            graphics_module: GraphicsModule::OpenGL,
+            fps_num: 30,
+            fps_den: 1,
+            base_width: width,
+            base_height: height,
+            output_width: width,
+            output_height: height,
+            output_format: VideoFormat::NV12,
+            adapter: 0,
+            gpu_conversion: true,
+            colorspace: VideoColorSpace::CS709,
+            range: VideoRange::Partial,
+            scale_type: ScaleType::Bicubic,
+        }
+    }
+
+    fn audio_data() -> ResetAudioData {
+        ResetAudioData {
+            samples_per_sec: 48000,
+            speakers: SpeakerLayout::Stereo,
+            max_buffering_ms: 0,
+            fixed_buffering: false,
+        }
+    }
+
+    /// Reset video and audio and start an output with a video and an audio encoder.
+    fn start_output(obs: &Obs) -> (ObsOutput, ObsEncoder, ObsEncoder) {
+        obs.reset_video(&video_data(1920, 1080)).unwrap();
+        obs.reset_audio(&audio_data()).unwrap();
+
+        let video_encoder = obs
+            .create_video_encoder("video", "mock_video_encoder", None)
+            .unwrap();
+        let audio_encoder = obs
+            .create_audio_encoder("audio", "mock_audio_encoder", None)
+            .unwrap();
+        let output = obs.create_output("output", "mock_output", None).unwrap();
+        output
+            .start(Some(&video_encoder), Some(&audio_encoder))
+            .unwrap();
+
+        (output, video_encoder, audio_encoder)
+    }
+
+    #[test]
+    fn with_options() {
+        let _lock = OBS_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let options = StartupOptions {
+            module_config_path: Some("config".to_string()),
+            data_paths: Some(vec!["data/".to_string()]),
+            ..Default::default()
+        };
+
+        let obs = Obs::with_options("de-DE", None, &options).unwrap();
+        assert_eq!(obs.get_locale().unwrap(), "de-DE");
+
+        obs.set_locale("en-US").unwrap();
+        assert_eq!(obs.get_locale().unwrap(), "en-US");
+    }
+
+    #[test]
+    fn video_info_requires_reset() {
+        let (_lock, obs) = start();
+        assert!(obs.get_video_info().is_err());
+    }
+
+    #[test]
+    fn reset_video() {
+        let (_lock, obs) = start();
+        obs.reset_video(&video_data(1280, 720)).unwrap();
+
+        let info = obs.get_video_info().unwrap();
+        assert_eq!((info.base_width, info.base_height), (1280, 720));
+        assert_eq!((info.output_width, info.output_height), (1280, 720));
+        assert_eq!((info.fps_num, info.fps_den), (30, 1));
+        assert!(matches!(info.graphics_module, GraphicsModule::OpenGL));
+        assert!(matches!(info.output_format, VideoFormat::NV12));
+        assert!(!obs.video_active());
+    }
+
+    #[test]
+    fn start_and_stop_output() {
+        let (_lock, obs) = start();
+        let (output, _video, _audio) = start_output(&obs);
+
+        assert_eq!(output.get_state(), OutputState::Active);
+        assert!(output.get_active());
+        assert!(obs.video_active());
+        assert!(output.start(None, None).is_err());
+
+        output.stop().unwrap();
+        assert_eq!(output.get_state(), OutputState::Stopped);
+        assert!(!output.get_active());
+        assert!(!obs.video_active());
+        assert!(output.stop().is_err());
+    }
+
+    #[test]
+    fn output_start_error() {
+        let (_lock, obs) = start();
+        obs.reset_video(&video_data(1920, 1080)).unwrap();
+
+        let mut settings = ObsSettings::new().unwrap();
+        settings.set_string("start_error", "No space left").unwrap();
+        let encoder = obs
+            .create_video_encoder("video", "mock_video_encoder", None)
+            .unwrap();
+        let output = obs
+            .create_output("output", "mock_output", Some(&settings))
+            .unwrap();
+
+        let err = output.start(Some(&encoder), None).unwrap_err();
+        assert!(err.to_string().contains("No space left"));
+        assert_eq!(output.get_state(), OutputState::Stopped);
+    }
+
+    fn connecting_output(obs: &Obs) -> (ObsOutput, ObsEncoder) {
+        obs.reset_video(&video_data(1920, 1080)).unwrap();
+
+        let mut settings = ObsSettings::new().unwrap();
+        settings.set_bool("connecting", true).unwrap();
+        let encoder = obs
+            .create_video_encoder("video", "mock_video_encoder", None)
+            .unwrap();
+        let output = obs
+            .create_output("output", "mock_output", Some(&settings))
+            .unwrap();
+
+        (output, encoder)
+    }
+
+    #[test]
+    fn stop_while_connecting() {
+        let (_lock, obs) = start();
+        let (output, encoder) = connecting_output(&obs);
+
+        let started = Arc::new(Mutex::new(None));
+        let result = started.clone();
+        output
+            .start_with_callback(Some(&encoder), None, move |res| {
+                *result.lock().unwrap() = Some(res.is_ok());
+            })
+            .unwrap();
+        assert_eq!(output.get_state(), OutputState::Starting);
+
+        // libobs only stops connecting outputs by force, the output may still activate
+        output.stop().unwrap();
+        assert_eq!(output.get_state(), OutputState::Stopping);
+        assert_eq!(*started.lock().unwrap(), None);
+
+        unsafe { sys::mock::connect(output.raw()) };
+        assert_eq!(output.get_state(), OutputState::Active);
+        assert_eq!(*started.lock().unwrap(), Some(true));
+
+        output.stop().unwrap();
+        assert_eq!(output.get_state(), OutputState::Stopped);
+    }
+
+    #[test]
+    fn force_stop_while_connecting() {
+        let (_lock, obs) = start();
+        let (output, encoder) = connecting_output(&obs);
+
+        let started = Arc::new(Mutex::new(None));
+        let result = started.clone();
+        output
+            .start_with_callback(Some(&encoder), None, move |res| {
+                *result.lock().unwrap() = Some(res.is_ok());
+            })
+            .unwrap();
+
+        output.force_stop().unwrap();
+        assert_eq!(output.get_state(), OutputState::Stopped);
+        assert_eq!(*started.lock().unwrap(), Some(false));
+    }
+
+    #[test]
+    fn state_listeners_may_change_the_state() {
+        let (_lock, obs) = start();
+        let (output, _video_encoder, _audio_encoder) = start_output(&obs);
+        let output = Arc::new(output);
+
+        // Stop the output from its own listener once it is paused
+        let weak = Arc::downgrade(&output);
+        output.on_state_change(move |state| {
+            if let (OutputState::Paused, Some(output)) = (state, weak.upgrade()) {
+                output.stop().unwrap();
+            }
+        });
+
+        output.pause().unwrap();
+        assert_eq!(output.get_state(), OutputState::Stopped);
+    }
+
+    #[test]
+    fn reset_video_while_active() {
+        let (_lock, obs) = start();
+        let (output, _video, _audio) = start_output(&obs);
+
+        assert!(obs.reset_video(&video_data(1280, 720)).is_err());
+        output.stop().unwrap();
+        obs.reset_video(&video_data(1280, 720)).unwrap();
+    }
+
+    #[test]
+    fn reconfigure_restarts_outputs() {
+        let (_lock, obs) = start();
+        let (output, _video, _audio) = start_output(&obs);
+
+        let data = ReconfigureData {
+            video: Some(video_data(1280, 720)),
+            audio: None,
+        };
+        let options = ReconfigureOptions {
+            restart_outputs: Some(true),
+            stop_timeout: Some(1000),
+        };
+        obs.reconfigure(&data, &options).unwrap();
+
+        let info = obs.get_video_info().unwrap();
+        assert_eq!((info.output_width, info.output_height), (1280, 720));
+        assert_eq!(output.get_state(), OutputState::Active);
+        assert!(obs.video_active());
+    }
+
+    #[test]
+    fn reconfigure_keeps_outputs_stopped() {
+        let (_lock, obs) = start();
+        let (output, _video, _audio) = start_output(&obs);
+
+        let data = ReconfigureData {
+            video: Some(video_data(640, 480)),
+            audio: None,
+        };
+        obs.reconfigure(&data, &ReconfigureOptions::default())
+            .unwrap();
+
+        let info = obs.get_video_info().unwrap();
+        assert_eq!((info.base_width, info.base_height), (640, 480));
+        assert_eq!(output.get_state(), OutputState::Stopped);
+        assert!(!obs.video_active());
+    }
+
+    #[test]
+    fn registered_output_types() {
+        let (lock, obs) = start();
+        obs.register_mpegts_output_type("test_mpegts", "MPEG-TS")
+            .unwrap();
+        obs.register_output_type("test_packets", "Packets").unwrap();
+        assert!(obs.register_output_type("test_mpegts", "Packets").is_err());
+
+        let mpegts = obs.create_output("mpegts", "test_mpegts", None).unwrap();
+        assert!(mpegts.on_data(|_| {}).is_ok());
+        assert!(mpegts.on_packet(|_| {}).is_err());
+
+        let packets = obs.create_output("packets", "test_packets", None).unwrap();
+        assert!(packets.on_packet(|_| {}).is_ok());
+        assert!(packets.on_data(|_| {}).is_err());
+
+        drop((mpegts, packets, obs));
+
+        // Types are unregistered once obs has been shut down
+        let obs = Obs::new("en-US", None).unwrap();
+        obs.register_mpegts_output_type("test_mpegts", "MPEG-TS")
+            .unwrap();
+        drop((obs, lock));
+    }
+
+    /// An output implemented in rust, which is neither a packet nor an MPEG-TS output.
+    struct DiscardOutput;
+
+    impl OutputImpl for DiscardOutput {
+        const FLAGS: OutputTypeFlags = OutputTypeFlags::AV;
+
+        fn create(_settings: &Settings, _ctx: OutputContext) -> ResultType<Self> {
+            Ok(Self)
+        }
+
+        fn encoded_packet(&mut self, _packet: PacketRef) {}
+    }
+
+    #[test]
+    fn listeners_require_their_output_type() {
+        let (_lock, obs) = start();
+        register_output::<DiscardOutput>("test_discard", "Discard").unwrap();
+
+        // The type is registered using the plugin api, but doesn't pass on packets or data
+        let output = obs.create_output("discard", "test_discard", None).unwrap();
+        assert!(output.on_packet(|_| {}).is_err());
+        assert!(output.on_data(|_| {}).is_err());
+    }
+
+    #[test]
+    fn registered_types_must_be_new() {
+        let (_lock, obs) = start();
+
+        // Types provided by libobs or its modules can't be replaced
+        assert!(obs.register_output_type("mock_output", "Packets").is_err());
+        assert!(obs
+            .register_mpegts_output_type("mock_output", "MPEG-TS")
+            .is_err());
+        assert!(obs
+            .register_video_source_type("mock_video_source", "Video")
+            .is_err());
+        assert!(obs
+            .register_audio_source_type("mock_audio_source", "Audio")
+            .is_err());
+
+        // The existing types are kept
+        let output = obs.create_output("output", "mock_output", None).unwrap();
+        assert!(output.on_packet(|_| {}).is_err());
+    }
+
+    #[test]
+    fn remove_media_listener() {
+        let (_lock, obs) = start();
+        let media = obs
+            .create_source("media", "mock_media_source", 0, None)
+            .unwrap();
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let listener = {
+            let events = events.clone();
+            media
+                .on_media_event(move |event| events.lock().unwrap().push(event))
+                .unwrap()
+        };
+
+        media.play().unwrap();
+        assert!(matches!(
+            events.lock().unwrap()[..],
+            [MediaEvent::Started, MediaEvent::Played]
+        ));
+
+        assert!(media.remove_media_listener(listener));
+        assert!(!media.remove_media_listener(listener));
+
+        media.pause().unwrap();
+        assert_eq!(events.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn continuous_audio_timestamps() {
+        let (_lock, obs) = start();
+        obs.register_audio_source_type("test_audio", "Audio")
+            .unwrap();
+        let source = obs.create_source("audio", "test_audio", 0, None).unwrap();
+
+        // 480 stereo float frames, 10ms at 48kHz
+        let data = [0u8; 480 * 8];
+        let mut info = AudioDataInfo {
+            format: AudioFormat::Float,
+            speakers: SpeakerLayout::Stereo,
+            sample_rate: 48000,
+            timestamp: None,
+        };
+        source.push_audio(&data, &info).unwrap();
+        source.push_audio(&data, &info).unwrap();
+
+        info.timestamp = Some(1_000_000_000);
+        source.push_audio(&data, &info).unwrap();
+        info.timestamp = None;
+        source.push_audio(&data, &info).unwrap();
+
+        info.timestamp = Some(-1);
+        assert!(source.push_audio(&data, &info).is_err());
+
+        let timestamps = unsafe { sys::mock::audio_timestamps(source.raw()) };
+        assert_eq!(timestamps[1] - timestamps[0], 10_000_000);
+        assert_eq!(timestamps[2..], [1_000_000_000, 1_010_000_000]);
+    }
+
+    /// Simulate an older libobs until dropped.
+    struct SimulatedVersion;
+
+    impl SimulatedVersion {
+        fn new(major: u32, minor: u32) -> Self {
+            sys::mock::set_version(major, minor);
+            Self
+        }
+    }
+
+    impl Drop for SimulatedVersion {
+        fn drop(&mut self) {
+            sys::mock::set_version(sys::LIBOBS_API_MAJOR_VER, sys::LIBOBS_API_MINOR_VER);
+        }
+    }
+
+    #[test]
+    fn legacy_audio() {
+        let (_lock, obs) = start();
+        let _version = SimulatedVersion::new(29, 0);
+        assert!(!obs.supports(Capability::ResetAudio2));
+
+        let data = ResetAudioData {
+            max_buffering_ms: 500,
+            ..audio_data()
+        };
+        obs.reset_audio(&data).unwrap();
+
+        // Only the sample rate and speakers are known to older versions
+        let info = obs.get_audio_info().unwrap();
+        assert_eq!(info.samples_per_sec, 48000);
+        assert!(matches!(info.speakers, SpeakerLayout::Stereo));
+        assert_eq!(info.max_buffering_ms, 0);
+
+        let fixed = ResetAudioData {
+            fixed_buffering: true,
+            ..audio_data()
+        };
+        assert!(matches!(
+            obs.reset_audio(&fixed),
+            Err(ObsError::RequiresVersion {
+                version: (29, 1),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn hdr_requires_version() {
+        let (_lock, obs) = start();
+        let _version = SimulatedVersion::new(27, 0);
+        assert!(!obs.supports(Capability::HdrVideo));
+
+        let hdr = ResetVideoData {
+            output_format: VideoFormat::P010,
+            ..video_data(1920, 1080)
+        };
+        assert!(matches!(
+            obs.reset_video(&hdr),
+            Err(ObsError::RequiresVersion {
+                version: (28, 0),
+                ..
+            })
+        ));
+        obs.reset_video(&video_data(1920, 1080)).unwrap();
+    }
+
+    #[test]
+    fn legacy_module_discovery() {
+        let obs_path = env::temp_dir().join(format!("libobs-mock-{}", std::process::id()));
+        let bin_path = obs_path.join("obs-plugins").join("64bit");
+        std::fs::create_dir_all(&bin_path).unwrap();
+        std::fs::write(
+            bin_path.join(format!("mock-plugin.{}", env::consts::DLL_EXTENSION)),
+            [],
+        )
+        .unwrap();
+
+        let find = |major, minor| {
+            let (_lock, obs) = start();
+            let _version = SimulatedVersion::new(major, minor);
+            assert_eq!(
+                obs.supports(Capability::FindModules2),
+                (major, minor) >= (27, 0)
+            );
+            obs.get_all_modules(obs_path.to_str()).unwrap()
+        };
+        let modules = find(30, 2);
+        let legacy = find(26, 0);
+        std::fs::remove_dir_all(&obs_path).unwrap();
+
+        // The legacy api doesn't report names, they are derived from the binary
+        assert_eq!(legacy.len(), 1);
+        assert_eq!(legacy[0].name, "mock-plugin");
+        assert_eq!(legacy[0].name, modules[0].name);
+        assert_eq!(legacy[0].bin_path, modules[0].bin_path);
+        assert_eq!(legacy[0].data_path, modules[0].data_path);
+    }
+}
@@ -0,0 +1,128 @@
+//! A safe layer for implementing obs source, filter and output types in rust.
+//!
+//! Types are implemented using the [`SourceImpl`], [`FilterImpl`] and
+//! [`OutputImpl`] traits and registered with obs using
+//! [`register_source`], [`register_filter`] and [`register_output`].
+//! The trait methods are called with the instance locked, so they
+//! may mutate the instance without further synchronization.
+//!
+//! # Example
+//! ```ignore
+//! struct ColorSource {
+//!     ctx: SourceContext,
+//!     color: u32,
+//! }
+//!
+//! impl SourceImpl for ColorSource {
+//!     const FLAGS: SourceFlags = SourceFlags::ASYNC_VIDEO;
+//!
+//!     fn create(settings: &Settings, ctx: SourceContext) -> ResultType<Self> {
+//!         Ok(Self { ctx, color: settings.get_int("color") as u32 })
+//!     }
+//!
+//!     fn properties(properties: &mut PropertiesBuilder) {
+//!         properties.add_color("color", "Color");
+//!     }
+//!
+//!     fn update(&mut self, settings: &Settings) {
+//!         self.color = settings.get_int("color") as u32;
+//!     }
+//! }
+//!
+//! register_source::<ColorSource>("rust_color_source", "Color")?;
+//! ```
+// Not all of the api is used by the types implemented in this crate
+#![allow(dead_code)]
+
+mod output;
+mod properties;
+mod settings;
+mod source;
+
+pub use output::{register_output, OutputContext, OutputImpl, OutputTypeFlags, PacketRef};
+pub use properties::{PathKind, PropertiesBuilder, TextKind};
+pub use settings::Settings;
+pub use source::{
+    register_filter, register_source, FilterAudio, FilterFrame, FilterImpl, SourceContext,
+    SourceFlags, SourceImpl,
+};
+
+use crate::obs::sys;
+use crate::obs::util::types::ResultType;
+use std::any::TypeId;
+use std::ffi::CString;
+use std::os::raw::c_char;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Mutex, MutexGuard};
+
+/// The ids of all types registered using this module since obs was started,
+/// with the rust type implementing them.
+static REGISTERED_TYPES: Mutex<Vec<(String, TypeId)>> = Mutex::new(Vec::new());
+
+/// Reserve a type id implemented by `T` and create the id and name strings passed to obs.
+/// `get_display_name` looks up existing types of the same kind,
+/// ids which are already known to obs, e.g. from a module, can't be claimed.
+/// obs keeps the pointers to the id and the name,
+/// so these are leaked and live as long as the process.
+fn claim_type<T: 'static>(
+    id: &str,
+    display_name: &str,
+    get_display_name: unsafe fn(*const c_char) -> *const c_char,
+) -> ResultType<(*const c_char, *mut c_char)> {
+    let c_id = CString::new(id)?;
+    let c_name = CString::new(display_name)?;
+
+    let mut registered = REGISTERED_TYPES.lock().unwrap();
+    if registered.iter().any(|(r, _)| r == id)
+        || !unsafe { get_display_name(c_id.as_ptr()) }.is_null()
+    {
+        return Err(format!("The type '{}' is already registered", id).into());
+    }
+
+    registered.push((id.to_string(), TypeId::of::<T>()));
+    Ok((c_id.into_raw(), c_name.into_raw()))
+}
+
+/// Check whether the type `id` was registered using this module and is implemented by `T`.
+pub(crate) fn is_registered_as<T: 'static>(id: &str) -> bool {
+    REGISTERED_TYPES
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|(r, type_id)| r == id && *type_id == TypeId::of::<T>())
+}
+
+/// Forget all registered types. Called once obs has been shut down,
+/// which unregisters all types, so they can be registered again.
+pub(crate) fn clear_registered_types() {
+    REGISTERED_TYPES.lock().unwrap().clear();
+}
+
+/// Run a callback called by obs, returning `default` if it panics.
+/// Panics must not unwind into obs.
+fn catch_panic<R>(default: R, f: impl FnOnce() -> R) -> R {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or(default)
+}
+
+/// Lock an instance, ignoring whether a previous callback panicked.
+fn lock<T>(instance: &Mutex<T>) -> MutexGuard<'_, T> {
+    instance.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Log an error using the obs logger.
+pub(crate) fn log_error(message: String) {
+    let blog = match sys::functions().blog {
+        Some(blog) => blog,
+        None => return,
+    };
+
+    if let Ok(message) = CString::new(message) {
+        unsafe {
+            blog(sys::LOG_ERROR as _, c"%s".as_ptr(), message.as_ptr());
+        }
+    }
+}
+
+unsafe extern "C" fn get_name(type_data: *mut std::ffi::c_void) -> *const c_char {
+    type_data as *const c_char
+}
@@ -0,0 +1,332 @@
+use crate::obs::custom::encoded_packet::Packet;
+use crate::obs::data::obs_encoder_type::EncoderKind;
+use crate::obs::plugin::properties::PropertiesBuilder;
+use crate::obs::plugin::settings::Settings;
+use crate::obs::plugin::{catch_panic, claim_type, get_name, lock, log_error};
+use crate::obs::sys;
+use crate::obs::util::types::ResultType;
+use std::ffi::{c_void, CStr, CString};
+use std::mem;
+use std::ops::BitOr;
+use std::os::raw::c_char;
+use std::ptr;
+use std::slice;
+use std::sync::Mutex;
+
+/// The flags of an output type.
+/// All outputs implemented in rust receive encoded packets.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct OutputTypeFlags(u32);
+
+impl OutputTypeFlags {
+    pub const VIDEO: Self = Self(sys::OBS_OUTPUT_VIDEO);
+    pub const AUDIO: Self = Self(sys::OBS_OUTPUT_AUDIO);
+    pub const AV: Self = Self(sys::OBS_OUTPUT_AV);
+    /// The output receives the packets of multiple audio tracks.
+    pub const MULTI_TRACK: Self = Self(sys::OBS_OUTPUT_MULTI_TRACK);
+
+    pub const fn bits(&self) -> u32 {
+        self.0
+    }
+
+    /// Combine flags, usable in constants.
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl BitOr for OutputTypeFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(rhs)
+    }
+}
+
+/// The obs output an instance belongs to.
+#[derive(Clone, Copy)]
+pub struct OutputContext {
+    output: *mut sys::obs_output_t,
+}
+
+// obs outputs are thread safe
+unsafe impl Send for OutputContext {}
+unsafe impl Sync for OutputContext {}
+
+unsafe fn encoder_codec(encoder: *mut sys::obs_encoder_t) -> Option<String> {
+    if encoder.is_null() {
+        return None;
+    }
+
+    let codec = sys::obs_encoder_get_codec(encoder);
+    if codec.is_null() {
+        None
+    } else {
+        Some(CStr::from_ptr(codec).to_string_lossy().into_owned())
+    }
+}
+
+impl OutputContext {
+    /// The codec of the video encoder, e.g. `h264`.
+    pub fn video_codec(&self) -> Option<String> {
+        unsafe { encoder_codec(sys::obs_output_get_video_encoder(self.output)) }
+    }
+
+    /// The codec of the audio encoder of a track, e.g. `aac`.
+    pub fn audio_codec(&self, track: usize) -> Option<String> {
+        unsafe { encoder_codec(sys::obs_output_get_audio_encoder(self.output, track as _)) }
+    }
+
+    /// Stop the output with an error code, e.g. `OBS_OUTPUT_DISCONNECTED`.
+    pub fn signal_stop(&self, code: i32) {
+        unsafe { sys::obs_output_signal_stop(self.output, code as _) }
+    }
+
+    /// # Safety
+    /// The output must not be released using the returned pointer.
+    pub unsafe fn raw(&self) -> *mut sys::obs_output_t {
+        self.output
+    }
+}
+
+/// An encoded packet borrowed from obs.
+pub struct PacketRef<'a> {
+    packet: &'a sys::encoder_packet,
+}
+
+impl PacketRef<'_> {
+    pub fn data(&self) -> &[u8] {
+        if self.packet.data.is_null() {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(self.packet.data, self.packet.size) }
+        }
+    }
+
+    pub fn pts(&self) -> i64 {
+        self.packet.pts
+    }
+
+    pub fn dts(&self) -> i64 {
+        self.packet.dts
+    }
+
+    /// The timebase of the timestamps as `(numerator, denominator)`.
+    pub fn timebase(&self) -> (i32, i32) {
+        (self.packet.timebase_num, self.packet.timebase_den)
+    }
+
+    pub fn kind(&self) -> EncoderKind {
+        if self.packet.type_ == sys::obs_encoder_type_OBS_ENCODER_AUDIO {
+            EncoderKind::Audio
+        } else {
+            EncoderKind::Video
+        }
+    }
+
+    pub fn keyframe(&self) -> bool {
+        self.packet.keyframe
+    }
+
+    pub fn track_idx(&self) -> usize {
+        self.packet.track_idx
+    }
+
+    /// Copy the packet, including its codec and extra data.
+    pub fn to_packet(&self) -> Packet {
+        unsafe { Packet::from_raw(self.packet) }
+    }
+}
+
+/// An output type implemented in rust, receiving encoded packets.
+///
+/// Starting an output initializes its encoders, calls `start` and begins
+/// the data capture. Stopping an output ends the data capture before
+/// calling `stop`, so no packets are received after `stop` was called.
+pub trait OutputImpl: Send + Sized + 'static {
+    const FLAGS: OutputTypeFlags;
+    /// The supported video codecs, separated by semicolons.
+    const VIDEO_CODECS: Option<&'static str> = None;
+    /// The supported audio codecs, separated by semicolons.
+    const AUDIO_CODECS: Option<&'static str> = None;
+
+    /// Create a new instance.
+    /// Failing to create an instance fails the creation of the output.
+    fn create(settings: &Settings, ctx: OutputContext) -> ResultType<Self>;
+
+    /// Called when the settings were updated.
+    fn update(&mut self, _settings: &Settings) {}
+
+    /// Set the default settings.
+    fn defaults(_settings: &mut Settings) {}
+
+    /// Add the properties shown to users when editing the settings.
+    fn properties(_properties: &mut PropertiesBuilder) {}
+
+    /// Called when the output is started.
+    /// Returning an error fails the start with the error as last error.
+    fn start(&mut self) -> ResultType<()> {
+        Ok(())
+    }
+
+    /// Called when the output has stopped.
+    fn stop(&mut self) {}
+
+    /// Called for every encoded packet.
+    fn encoded_packet(&mut self, packet: PacketRef);
+
+    /// The number of bytes written by this output.
+    fn total_bytes(&self) -> u64 {
+        0
+    }
+}
+
+struct Instance<T> {
+    ctx: OutputContext,
+    inner: Mutex<T>,
+}
+
+unsafe fn instance<'a, T>(data: *mut c_void) -> &'a Instance<T> {
+    &*(data as *const Instance<T>)
+}
+
+unsafe extern "C" fn create<T: OutputImpl>(
+    settings: *mut sys::obs_data_t,
+    output: *mut sys::obs_output_t,
+) -> *mut c_void {
+    catch_panic(ptr::null_mut(), || {
+        let ctx = OutputContext { output };
+        match T::create(&Settings::from_raw(settings), ctx) {
+            Ok(inner) => Box::into_raw(Box::new(Instance {
+                ctx,
+                inner: Mutex::new(inner),
+            })) as *mut c_void,
+            Err(e) => {
+                log_error(format!("Failed to create output: {}", e));
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
+unsafe extern "C" fn destroy<T: OutputImpl>(data: *mut c_void) {
+    catch_panic((), || drop(Box::from_raw(data as *mut Instance<T>)))
+}
+
+unsafe extern "C" fn update<T: OutputImpl>(data: *mut c_void, settings: *mut sys::obs_data_t) {
+    catch_panic((), || {
+        lock(&instance::<T>(data).inner).update(&Settings::from_raw(settings))
+    })
+}
+
+unsafe extern "C" fn get_defaults<T: OutputImpl>(settings: *mut sys::obs_data_t) {
+    catch_panic((), || T::defaults(&mut Settings::from_raw(settings)))
+}
+
+unsafe extern "C" fn get_properties<T: OutputImpl>(
+    _data: *mut c_void,
+) -> *mut sys::obs_properties_t {
+    catch_panic(ptr::null_mut(), || {
+        let mut properties = PropertiesBuilder::new();
+        T::properties(&mut properties);
+        properties.into_raw()
+    })
+}
+
+unsafe extern "C" fn start<T: OutputImpl>(data: *mut c_void) -> bool {
+    let instance = instance::<T>(data);
+    let output = instance.ctx.output;
+
+    if !sys::obs_output_can_begin_data_capture(output, 0)
+        || !sys::obs_output_initialize_encoders(output, 0)
+    {
+        return false;
+    }
+
+    let res = catch_panic(Err("The output panicked".into()), || {
+        lock(&instance.inner).start()
+    });
+
+    if let Err(e) = res {
+        if let Ok(message) = CString::new(e.to_string()) {
+            sys::obs_output_set_last_error(output, message.as_ptr());
+        }
+
+        return false;
+    }
+
+    if sys::obs_output_begin_data_capture(output, 0) {
+        true
+    } else {
+        catch_panic((), || lock(&instance.inner).stop());
+        false
+    }
+}
+
+unsafe extern "C" fn stop<T: OutputImpl>(data: *mut c_void, _ts: u64) {
+    let instance = instance::<T>(data);
+
+    // The encoders may wait for a packet callback to return,
+    // so the data capture must be ended without holding the lock
+    sys::obs_output_end_data_capture(instance.ctx.output);
+    catch_panic((), || lock(&instance.inner).stop())
+}
+
+unsafe extern "C" fn encoded_packet<T: OutputImpl>(
+    data: *mut c_void,
+    packet: *mut sys::encoder_packet,
+) {
+    let instance = instance::<T>(data);
+
+    // A null packet signals an encoder error
+    if packet.is_null() {
+        instance.ctx.signal_stop(sys::OBS_OUTPUT_ENCODE_ERROR as _);
+        return;
+    }
+
+    catch_panic((), || {
+        lock(&instance.inner).encoded_packet(PacketRef { packet: &*packet })
+    })
+}
+
+unsafe extern "C" fn get_total_bytes<T: OutputImpl>(data: *mut c_void) -> u64 {
+    catch_panic(0, || lock(&instance::<T>(data).inner).total_bytes())
+}
+
+fn codecs(codecs: Option<&str>) -> ResultType<*const c_char> {
+    Ok(match codecs {
+        Some(codecs) => CString::new(codecs)?.into_raw(),
+        None => ptr::null(),
+    })
+}
+
+/// Register an output type implemented in rust.
+/// The type will be available until obs is shut down.
+pub fn register_output<T: OutputImpl>(id: &str, display_name: &str) -> ResultType<()> {
+    let video_codecs = codecs(T::VIDEO_CODECS)?;
+    let audio_codecs = codecs(T::AUDIO_CODECS)?;
+    let (c_id, c_name) = claim_type::<T>(id, display_name, sys::obs_output_get_display_name)?;
+
+    unsafe {
+        let mut info: sys::obs_output_info = mem::zeroed();
+        info.id = c_id;
+        info.flags = T::FLAGS.bits() | sys::OBS_OUTPUT_ENCODED;
+        info.encoded_video_codecs = video_codecs;
+        info.encoded_audio_codecs = audio_codecs;
+        info.get_name = Some(get_name);
+        info.create = Some(create::<T>);
+        info.destroy = Some(destroy::<T>);
+        info.update = Some(update::<T>);
+        info.get_defaults = Some(get_defaults::<T>);
+        info.get_properties = Some(get_properties::<T>);
+        info.start = Some(start::<T>);
+        info.stop = Some(stop::<T>);
+        info.encoded_packet = Some(encoded_packet::<T>);
+        info.get_total_bytes = Some(get_total_bytes::<T>);
+        info.type_data = c_name as *mut c_void;
+
+        sys::obs_register_output_s(&info, mem::size_of::<sys::obs_output_info>() as _);
+    }
+
+    Ok(())
+}
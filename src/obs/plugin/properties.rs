@@ -0,0 +1,288 @@
+use crate::obs::sys;
+use std::ffi::CString;
+use std::mem;
+
+/// The kind of a text property.
+pub enum TextKind {
+    Default,
+    Password,
+    Multiline,
+}
+
+/// The kind of a path property.
+pub enum PathKind {
+    File,
+    FileSave,
+    Directory,
+}
+
+/// A builder for the properties shown to users when editing settings.
+/// obs copies all names and descriptions, so they may be temporaries.
+pub struct PropertiesBuilder {
+    properties: *mut sys::obs_properties_t,
+}
+
+fn c_str(value: &str) -> CString {
+    CString::new(value).unwrap_or_default()
+}
+
+impl PropertiesBuilder {
+    pub fn new() -> Self {
+        Self {
+            properties: unsafe { sys::obs_properties_create() },
+        }
+    }
+
+    pub fn add_bool(&mut self, name: &str, description: &str) -> &mut Self {
+        let (name, description) = (c_str(name), c_str(description));
+        unsafe {
+            sys::obs_properties_add_bool(self.properties, name.as_ptr(), description.as_ptr());
+        }
+
+        self
+    }
+
+    pub fn add_int(
+        &mut self,
+        name: &str,
+        description: &str,
+        min: i32,
+        max: i32,
+        step: i32,
+    ) -> &mut Self {
+        let (name, description) = (c_str(name), c_str(description));
+        unsafe {
+            sys::obs_properties_add_int(
+                self.properties,
+                name.as_ptr(),
+                description.as_ptr(),
+                min,
+                max,
+                step,
+            );
+        }
+
+        self
+    }
+
+    pub fn add_int_slider(
+        &mut self,
+        name: &str,
+        description: &str,
+        min: i32,
+        max: i32,
+        step: i32,
+    ) -> &mut Self {
+        let (name, description) = (c_str(name), c_str(description));
+        unsafe {
+            sys::obs_properties_add_int_slider(
+                self.properties,
+                name.as_ptr(),
+                description.as_ptr(),
+                min,
+                max,
+                step,
+            );
+        }
+
+        self
+    }
+
+    pub fn add_float(
+        &mut self,
+        name: &str,
+        description: &str,
+        min: f64,
+        max: f64,
+        step: f64,
+    ) -> &mut Self {
+        let (name, description) = (c_str(name), c_str(description));
+        unsafe {
+            sys::obs_properties_add_float(
+                self.properties,
+                name.as_ptr(),
+                description.as_ptr(),
+                min,
+                max,
+                step,
+            );
+        }
+
+        self
+    }
+
+    pub fn add_text(&mut self, name: &str, description: &str, kind: TextKind) -> &mut Self {
+        let (name, description) = (c_str(name), c_str(description));
+        let kind = match kind {
+            TextKind::Default => sys::obs_text_type_OBS_TEXT_DEFAULT,
+            TextKind::Password => sys::obs_text_type_OBS_TEXT_PASSWORD,
+            TextKind::Multiline => sys::obs_text_type_OBS_TEXT_MULTILINE,
+        };
+
+        unsafe {
+            sys::obs_properties_add_text(
+                self.properties,
+                name.as_ptr(),
+                description.as_ptr(),
+                kind,
+            );
+        }
+
+        self
+    }
+
+    /// Add a path property.
+    /// `filter` is a file dialog filter like `Images (*.png *.jpg)`.
+    pub fn add_path(
+        &mut self,
+        name: &str,
+        description: &str,
+        kind: PathKind,
+        filter: Option<&str>,
+    ) -> &mut Self {
+        let (name, description) = (c_str(name), c_str(description));
+        let filter = filter.map(c_str);
+        let kind = match kind {
+            PathKind::File => sys::obs_path_type_OBS_PATH_FILE,
+            PathKind::FileSave => sys::obs_path_type_OBS_PATH_FILE_SAVE,
+            PathKind::Directory => sys::obs_path_type_OBS_PATH_DIRECTORY,
+        };
+
+        unsafe {
+            sys::obs_properties_add_path(
+                self.properties,
+                name.as_ptr(),
+                description.as_ptr(),
+                kind,
+                filter.as_ref().map_or(std::ptr::null(), |f| f.as_ptr()),
+                std::ptr::null(),
+            );
+        }
+
+        self
+    }
+
+    pub fn add_color(&mut self, name: &str, description: &str) -> &mut Self {
+        let (name, description) = (c_str(name), c_str(description));
+        unsafe {
+            sys::obs_properties_add_color(self.properties, name.as_ptr(), description.as_ptr());
+        }
+
+        self
+    }
+
+    /// Add a list property with string values.
+    /// `items` are pairs of the name shown to users and the value.
+    pub fn add_string_list(
+        &mut self,
+        name: &str,
+        description: &str,
+        items: &[(&str, &str)],
+    ) -> &mut Self {
+        let list = self.add_list(
+            name,
+            description,
+            sys::obs_combo_format_OBS_COMBO_FORMAT_STRING,
+        );
+        for (item_name, value) in items {
+            let (item_name, value) = (c_str(item_name), c_str(value));
+            unsafe {
+                sys::obs_property_list_add_string(list, item_name.as_ptr(), value.as_ptr());
+            }
+        }
+
+        self
+    }
+
+    /// Add a list property with integer values.
+    /// `items` are pairs of the name shown to users and the value.
+    pub fn add_int_list(
+        &mut self,
+        name: &str,
+        description: &str,
+        items: &[(&str, i64)],
+    ) -> &mut Self {
+        let list = self.add_list(
+            name,
+            description,
+            sys::obs_combo_format_OBS_COMBO_FORMAT_INT,
+        );
+        for (item_name, value) in items {
+            let item_name = c_str(item_name);
+            unsafe {
+                sys::obs_property_list_add_int(list, item_name.as_ptr(), *value);
+            }
+        }
+
+        self
+    }
+
+    /// Add a group containing the properties added to `group`.
+    /// A checkable group stores its state as a bool named `name`.
+    pub fn add_group(
+        &mut self,
+        name: &str,
+        description: &str,
+        checkable: bool,
+        group: PropertiesBuilder,
+    ) -> &mut Self {
+        let (name, description) = (c_str(name), c_str(description));
+        let kind = if checkable {
+            sys::obs_group_type_OBS_GROUP_CHECKABLE
+        } else {
+            sys::obs_group_type_OBS_GROUP_NORMAL
+        };
+
+        unsafe {
+            // The group takes ownership of the properties
+            sys::obs_properties_add_group(
+                self.properties,
+                name.as_ptr(),
+                description.as_ptr(),
+                kind,
+                group.into_raw(),
+            );
+        }
+
+        self
+    }
+
+    fn add_list(
+        &mut self,
+        name: &str,
+        description: &str,
+        format: sys::obs_combo_format,
+    ) -> *mut sys::obs_property_t {
+        let (name, description) = (c_str(name), c_str(description));
+        unsafe {
+            sys::obs_properties_add_list(
+                self.properties,
+                name.as_ptr(),
+                description.as_ptr(),
+                sys::obs_combo_type_OBS_COMBO_TYPE_LIST,
+                format,
+            )
+        }
+    }
+
+    /// Pass the ownership of the properties to obs.
+    pub(crate) fn into_raw(self) -> *mut sys::obs_properties_t {
+        let properties = self.properties;
+        mem::forget(self);
+        properties
+    }
+}
+
+impl Default for PropertiesBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for PropertiesBuilder {
+    fn drop(&mut self) {
+        unsafe {
+            sys::obs_properties_destroy(self.properties);
+        }
+    }
+}
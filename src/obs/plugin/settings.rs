@@ -0,0 +1,98 @@
+use crate::obs::sys;
+use std::ffi::{CStr, CString};
+use std::marker::PhantomData;
+
+/// The settings of a source, filter or output, borrowed from obs.
+pub struct Settings<'a> {
+    data: *mut sys::obs_data_t,
+    _marker: PhantomData<&'a mut sys::obs_data_t>,
+}
+
+fn c_name(name: &str) -> CString {
+    CString::new(name).unwrap_or_default()
+}
+
+impl<'a> Settings<'a> {
+    pub(crate) unsafe fn from_raw(data: *mut sys::obs_data_t) -> Self {
+        Self {
+            data,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Get a string value. Returns `None` if the value is not set.
+    pub fn get_string(&self, name: &str) -> Option<String> {
+        let name = c_name(name);
+        unsafe {
+            let value = sys::obs_data_get_string(self.data, name.as_ptr());
+            if value.is_null() {
+                None
+            } else {
+                Some(CStr::from_ptr(value).to_string_lossy().into_owned())
+            }
+        }
+    }
+
+    pub fn get_int(&self, name: &str) -> i64 {
+        let name = c_name(name);
+        unsafe { sys::obs_data_get_int(self.data, name.as_ptr()) }
+    }
+
+    pub fn get_double(&self, name: &str) -> f64 {
+        let name = c_name(name);
+        unsafe { sys::obs_data_get_double(self.data, name.as_ptr()) }
+    }
+
+    pub fn get_bool(&self, name: &str) -> bool {
+        let name = c_name(name);
+        unsafe { sys::obs_data_get_bool(self.data, name.as_ptr()) }
+    }
+
+    /// Check whether the value was set by the user, not just by default.
+    pub fn has_user_value(&self, name: &str) -> bool {
+        let name = c_name(name);
+        unsafe { sys::obs_data_has_user_value(self.data, name.as_ptr()) }
+    }
+
+    pub fn set_string(&mut self, name: &str, value: &str) {
+        let name = c_name(name);
+        let value = c_name(value);
+        unsafe { sys::obs_data_set_string(self.data, name.as_ptr(), value.as_ptr()) }
+    }
+
+    pub fn set_int(&mut self, name: &str, value: i64) {
+        let name = c_name(name);
+        unsafe { sys::obs_data_set_int(self.data, name.as_ptr(), value) }
+    }
+
+    pub fn set_double(&mut self, name: &str, value: f64) {
+        let name = c_name(name);
+        unsafe { sys::obs_data_set_double(self.data, name.as_ptr(), value) }
+    }
+
+    pub fn set_bool(&mut self, name: &str, value: bool) {
+        let name = c_name(name);
+        unsafe { sys::obs_data_set_bool(self.data, name.as_ptr(), value) }
+    }
+
+    pub fn set_default_string(&mut self, name: &str, value: &str) {
+        let name = c_name(name);
+        let value = c_name(value);
+        unsafe { sys::obs_data_set_default_string(self.data, name.as_ptr(), value.as_ptr()) }
+    }
+
+    pub fn set_default_int(&mut self, name: &str, value: i64) {
+        let name = c_name(name);
+        unsafe { sys::obs_data_set_default_int(self.data, name.as_ptr(), value) }
+    }
+
+    pub fn set_default_double(&mut self, name: &str, value: f64) {
+        let name = c_name(name);
+        unsafe { sys::obs_data_set_default_double(self.data, name.as_ptr(), value) }
+    }
+
+    pub fn set_default_bool(&mut self, name: &str, value: bool) {
+        let name = c_name(name);
+        unsafe { sys::obs_data_set_default_bool(self.data, name.as_ptr(), value) }
+    }
+}
@@ -0,0 +1,515 @@
+use crate::obs::custom::audio_data::{output_audio, reset_timestamp, AudioDataInfo};
+use crate::obs::custom::video_frame::{output_video, VideoFrameInfo};
+use crate::obs::plugin::properties::PropertiesBuilder;
+use crate::obs::plugin::settings::Settings;
+use crate::obs::plugin::{catch_panic, claim_type, get_name, lock, log_error};
+use crate::obs::sys;
+use crate::obs::util::types::ResultType;
+use std::ffi::c_void;
+use std::marker::PhantomData;
+use std::mem;
+use std::ops::BitOr;
+use std::ptr;
+use std::slice;
+use std::sync::Mutex;
+
+/// The output flags of a source or filter type.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SourceFlags(u32);
+
+impl SourceFlags {
+    /// The source renders video using the graphics api.
+    pub const VIDEO: Self = Self(sys::OBS_SOURCE_VIDEO);
+    /// The source outputs video frames using `SourceContext::output_video`.
+    pub const ASYNC_VIDEO: Self = Self(sys::OBS_SOURCE_ASYNC_VIDEO);
+    /// The source outputs audio.
+    pub const AUDIO: Self = Self(sys::OBS_SOURCE_AUDIO);
+    /// The source draws without the default effect.
+    pub const CUSTOM_DRAW: Self = Self(sys::OBS_SOURCE_CUSTOM_DRAW);
+
+    pub const fn bits(&self) -> u32 {
+        self.0
+    }
+
+    /// Combine flags, usable in constants.
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for SourceFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(rhs)
+    }
+}
+
+/// The obs source an instance belongs to.
+#[derive(Clone, Copy)]
+pub struct SourceContext {
+    source: *mut sys::obs_source_t,
+}
+
+// obs sources are thread safe
+unsafe impl Send for SourceContext {}
+unsafe impl Sync for SourceContext {}
+
+impl SourceContext {
+    /// Output a tightly packed video frame.
+    /// The source type must have the `ASYNC_VIDEO` flag.
+    pub fn output_video(&self, data: &[u8], info: &VideoFrameInfo) -> ResultType<()> {
+        unsafe { output_video(self.source, data, info) }
+    }
+
+    /// Output audio data.
+    /// The source type must have the `AUDIO` flag.
+    pub fn output_audio(&self, data: &[u8], info: &AudioDataInfo) -> ResultType<()> {
+        unsafe { output_audio(self.source, data, info) }
+    }
+
+    /// Render the next filter or source without applying this filter.
+    pub fn skip_video_filter(&self) {
+        unsafe { sys::obs_source_skip_video_filter(self.source) }
+    }
+
+    /// # Safety
+    /// The source must not be released using the returned pointer.
+    pub unsafe fn raw(&self) -> *mut sys::obs_source_t {
+        self.source
+    }
+}
+
+/// A source type implemented in rust.
+pub trait SourceImpl: Send + Sized + 'static {
+    const FLAGS: SourceFlags;
+
+    /// Create a new instance.
+    /// Failing to create an instance fails the creation of the source.
+    fn create(settings: &Settings, ctx: SourceContext) -> ResultType<Self>;
+
+    /// Called when the settings were updated.
+    fn update(&mut self, _settings: &Settings) {}
+
+    /// Set the default settings.
+    fn defaults(_settings: &mut Settings) {}
+
+    /// Add the properties shown to users when editing the settings.
+    fn properties(_properties: &mut PropertiesBuilder) {}
+
+    fn width(&self) -> u32 {
+        0
+    }
+
+    fn height(&self) -> u32 {
+        0
+    }
+
+    /// Called once per video frame.
+    fn video_tick(&mut self, _seconds: f32) {}
+
+    /// Render the source using the graphics api.
+    /// Only called for sources with the `VIDEO` flag, but without `ASYNC_VIDEO`.
+    fn video_render(&mut self) {}
+
+    /// Called when the source is shown on the program output.
+    fn activate(&mut self) {}
+
+    /// Called when the source is removed from the program output.
+    fn deactivate(&mut self) {}
+
+    /// Called when the source is shown anywhere.
+    fn show(&mut self) {}
+
+    /// Called when the source is not shown anywhere anymore.
+    fn hide(&mut self) {}
+}
+
+/// A video frame passed through an async video filter.
+pub struct FilterFrame<'a> {
+    frame: &'a mut sys::obs_source_frame,
+}
+
+impl FilterFrame<'_> {
+    pub fn width(&self) -> u32 {
+        self.frame.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.frame.height
+    }
+
+    pub fn format(&self) -> sys::video_format {
+        self.frame.format
+    }
+
+    /// The timestamp of the frame in nanoseconds.
+    pub fn timestamp(&self) -> u64 {
+        self.frame.timestamp
+    }
+
+    /// The number of bytes per line of a plane.
+    pub fn linesize(&self, plane: usize) -> usize {
+        self.frame.linesize.get(plane).copied().unwrap_or(0) as usize
+    }
+
+    /// Get the data of a plane. Returns `None` if the frame has no such plane.
+    pub fn plane_mut(&mut self, plane: usize) -> Option<&mut [u8]> {
+        let data = *self.frame.data.get(plane)?;
+        if data.is_null() {
+            return None;
+        }
+
+        // Chroma planes of 4:2:0 formats have half the lines
+        let half = matches!(
+            self.frame.format,
+            sys::video_format_VIDEO_FORMAT_I420
+                | sys::video_format_VIDEO_FORMAT_NV12
+                | sys::video_format_VIDEO_FORMAT_I010
+                | sys::video_format_VIDEO_FORMAT_P010
+                | sys::video_format_VIDEO_FORMAT_I40A
+        ) && (plane == 1 || plane == 2);
+
+        let height = self.frame.height as usize;
+        let lines = if half { height.div_ceil(2) } else { height };
+        Some(unsafe { slice::from_raw_parts_mut(data, self.linesize(plane) * lines) })
+    }
+}
+
+/// Audio data passed through an audio filter.
+/// Filters always receive planar float audio.
+pub struct FilterAudio<'a> {
+    audio: &'a mut sys::obs_audio_data,
+    channels: usize,
+}
+
+impl FilterAudio<'_> {
+    /// The number of samples per channel.
+    pub fn frames(&self) -> usize {
+        self.audio.frames as usize
+    }
+
+    pub fn channels(&self) -> usize {
+        self.channels
+    }
+
+    /// The timestamp of the audio data in nanoseconds.
+    pub fn timestamp(&self) -> u64 {
+        self.audio.timestamp
+    }
+
+    /// Get the samples of a channel. Returns `None` if there is no such channel.
+    pub fn channel_mut(&mut self, channel: usize) -> Option<&mut [f32]> {
+        if channel >= self.channels {
+            return None;
+        }
+
+        let data = *self.audio.data.get(channel)?;
+        if data.is_null() {
+            None
+        } else {
+            Some(unsafe { slice::from_raw_parts_mut(data as *mut f32, self.frames()) })
+        }
+    }
+}
+
+/// A filter type implemented in rust.
+pub trait FilterImpl: Send + Sized + 'static {
+    /// `VIDEO` for effect filters, `ASYNC_VIDEO` for filters
+    /// modifying the frames of async sources and `AUDIO` for audio filters.
+    const FLAGS: SourceFlags;
+
+    /// Create a new instance.
+    /// Failing to create an instance fails the creation of the filter.
+    fn create(settings: &Settings, ctx: SourceContext) -> ResultType<Self>;
+
+    /// Called when the settings were updated.
+    fn update(&mut self, _settings: &Settings) {}
+
+    /// Set the default settings.
+    fn defaults(_settings: &mut Settings) {}
+
+    /// Add the properties shown to users when editing the settings.
+    fn properties(_properties: &mut PropertiesBuilder) {}
+
+    /// Render the filtered source using the graphics api.
+    /// Only called for filters with the `VIDEO` flag, but without `ASYNC_VIDEO`.
+    fn video_render(&mut self, ctx: &SourceContext) {
+        ctx.skip_video_filter();
+    }
+
+    /// Modify a frame of an async video source.
+    fn filter_video(&mut self, _frame: &mut FilterFrame) {}
+
+    /// Modify audio data.
+    fn filter_audio(&mut self, _audio: &mut FilterAudio) {}
+}
+
+/// The callbacks shared by sources and filters.
+trait Lifecycle {
+    type Inner: Send + 'static;
+
+    fn create(settings: &Settings, ctx: SourceContext) -> ResultType<Self::Inner>;
+    fn update(inner: &mut Self::Inner, settings: &Settings);
+    fn defaults(settings: &mut Settings);
+    fn properties(properties: &mut PropertiesBuilder);
+}
+
+struct AsSource<T>(PhantomData<T>);
+struct AsFilter<T>(PhantomData<T>);
+
+impl<T: SourceImpl> Lifecycle for AsSource<T> {
+    type Inner = T;
+
+    fn create(settings: &Settings, ctx: SourceContext) -> ResultType<T> {
+        T::create(settings, ctx)
+    }
+
+    fn update(inner: &mut T, settings: &Settings) {
+        inner.update(settings)
+    }
+
+    fn defaults(settings: &mut Settings) {
+        T::defaults(settings)
+    }
+
+    fn properties(properties: &mut PropertiesBuilder) {
+        T::properties(properties)
+    }
+}
+
+impl<T: FilterImpl> Lifecycle for AsFilter<T> {
+    type Inner = T;
+
+    fn create(settings: &Settings, ctx: SourceContext) -> ResultType<T> {
+        T::create(settings, ctx)
+    }
+
+    fn update(inner: &mut T, settings: &Settings) {
+        inner.update(settings)
+    }
+
+    fn defaults(settings: &mut Settings) {
+        T::defaults(settings)
+    }
+
+    fn properties(properties: &mut PropertiesBuilder) {
+        T::properties(properties)
+    }
+}
+
+struct Instance<T> {
+    ctx: SourceContext,
+    inner: Mutex<T>,
+}
+
+unsafe fn instance<'a, T>(data: *mut c_void) -> &'a Instance<T> {
+    &*(data as *const Instance<T>)
+}
+
+unsafe extern "C" fn create<L: Lifecycle>(
+    settings: *mut sys::obs_data_t,
+    source: *mut sys::obs_source_t,
+) -> *mut c_void {
+    catch_panic(ptr::null_mut(), || {
+        let ctx = SourceContext { source };
+        match L::create(&Settings::from_raw(settings), ctx) {
+            Ok(inner) => Box::into_raw(Box::new(Instance {
+                ctx,
+                inner: Mutex::new(inner),
+            })) as *mut c_void,
+            Err(e) => {
+                log_error(format!("Failed to create source: {}", e));
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
+unsafe extern "C" fn destroy<L: Lifecycle>(data: *mut c_void) {
+    catch_panic((), || {
+        let instance = Box::from_raw(data as *mut Instance<L::Inner>);
+        reset_timestamp(instance.ctx.source);
+    })
+}
+
+unsafe extern "C" fn update<L: Lifecycle>(data: *mut c_void, settings: *mut sys::obs_data_t) {
+    catch_panic((), || {
+        let instance = instance::<L::Inner>(data);
+        L::update(&mut lock(&instance.inner), &Settings::from_raw(settings))
+    })
+}
+
+unsafe extern "C" fn get_defaults<L: Lifecycle>(settings: *mut sys::obs_data_t) {
+    catch_panic((), || L::defaults(&mut Settings::from_raw(settings)))
+}
+
+unsafe extern "C" fn get_properties<L: Lifecycle>(
+    _data: *mut c_void,
+) -> *mut sys::obs_properties_t {
+    catch_panic(ptr::null_mut(), || {
+        let mut properties = PropertiesBuilder::new();
+        L::properties(&mut properties);
+        properties.into_raw()
+    })
+}
+
+unsafe extern "C" fn get_width<T: SourceImpl>(data: *mut c_void) -> u32 {
+    catch_panic(0, || lock(&instance::<T>(data).inner).width())
+}
+
+unsafe extern "C" fn get_height<T: SourceImpl>(data: *mut c_void) -> u32 {
+    catch_panic(0, || lock(&instance::<T>(data).inner).height())
+}
+
+unsafe extern "C" fn video_tick<T: SourceImpl>(data: *mut c_void, seconds: f32) {
+    catch_panic((), || lock(&instance::<T>(data).inner).video_tick(seconds))
+}
+
+unsafe extern "C" fn video_render<T: SourceImpl>(
+    data: *mut c_void,
+    _effect: *mut sys::gs_effect_t,
+) {
+    catch_panic((), || lock(&instance::<T>(data).inner).video_render())
+}
+
+unsafe extern "C" fn activate<T: SourceImpl>(data: *mut c_void) {
+    catch_panic((), || lock(&instance::<T>(data).inner).activate())
+}
+
+unsafe extern "C" fn deactivate<T: SourceImpl>(data: *mut c_void) {
+    catch_panic((), || lock(&instance::<T>(data).inner).deactivate())
+}
+
+unsafe extern "C" fn show<T: SourceImpl>(data: *mut c_void) {
+    catch_panic((), || lock(&instance::<T>(data).inner).show())
+}
+
+unsafe extern "C" fn hide<T: SourceImpl>(data: *mut c_void) {
+    catch_panic((), || lock(&instance::<T>(data).inner).hide())
+}
+
+unsafe extern "C" fn filter_render<T: FilterImpl>(
+    data: *mut c_void,
+    _effect: *mut sys::gs_effect_t,
+) {
+    let instance = instance::<T>(data);
+    catch_panic((), || lock(&instance.inner).video_render(&instance.ctx))
+}
+
+unsafe extern "C" fn filter_video<T: FilterImpl>(
+    data: *mut c_void,
+    frame: *mut sys::obs_source_frame,
+) -> *mut sys::obs_source_frame {
+    if frame.is_null() {
+        return frame;
+    }
+
+    catch_panic((), || {
+        let mut frame = FilterFrame { frame: &mut *frame };
+        lock(&instance::<T>(data).inner).filter_video(&mut frame)
+    });
+
+    frame
+}
+
+unsafe extern "C" fn filter_audio<T: FilterImpl>(
+    data: *mut c_void,
+    audio: *mut sys::obs_audio_data,
+) -> *mut sys::obs_audio_data {
+    if audio.is_null() {
+        return audio;
+    }
+
+    catch_panic((), || {
+        let mut audio = FilterAudio {
+            audio: &mut *audio,
+            channels: sys::audio_output_get_channels(sys::obs_get_audio()),
+        };
+        lock(&instance::<T>(data).inner).filter_audio(&mut audio)
+    });
+
+    audio
+}
+
+unsafe fn source_info<L: Lifecycle>(
+    id: &str,
+    display_name: &str,
+    type_: sys::obs_source_type,
+    flags: SourceFlags,
+) -> ResultType<sys::obs_source_info> {
+    let (c_id, c_name) =
+        claim_type::<L::Inner>(id, display_name, sys::obs_source_get_display_name)?;
+
+    let mut info: sys::obs_source_info = mem::zeroed();
+    info.id = c_id;
+    info.type_ = type_;
+    info.output_flags = flags.bits();
+    info.get_name = Some(get_name);
+    info.create = Some(create::<L>);
+    info.destroy = Some(destroy::<L>);
+    info.update = Some(update::<L>);
+    info.get_defaults = Some(get_defaults::<L>);
+    info.get_properties = Some(get_properties::<L>);
+    info.type_data = c_name as *mut c_void;
+
+    Ok(info)
+}
+
+/// Register a source type implemented in rust.
+/// The type will be available until obs is shut down.
+pub fn register_source<T: SourceImpl>(id: &str, display_name: &str) -> ResultType<()> {
+    unsafe {
+        let mut info = source_info::<AsSource<T>>(
+            id,
+            display_name,
+            sys::obs_source_type_OBS_SOURCE_TYPE_INPUT,
+            T::FLAGS,
+        )?;
+
+        info.get_width = Some(get_width::<T>);
+        info.get_height = Some(get_height::<T>);
+        info.video_tick = Some(video_tick::<T>);
+        info.activate = Some(activate::<T>);
+        info.deactivate = Some(deactivate::<T>);
+        info.show = Some(show::<T>);
+        info.hide = Some(hide::<T>);
+        if !T::FLAGS.contains(SourceFlags::ASYNC_VIDEO) && T::FLAGS.contains(SourceFlags::VIDEO) {
+            info.video_render = Some(video_render::<T>);
+        }
+
+        sys::obs_register_source_s(&info, mem::size_of::<sys::obs_source_info>() as _);
+    }
+
+    Ok(())
+}
+
+/// Register a filter type implemented in rust.
+/// The type will be available until obs is shut down.
+pub fn register_filter<T: FilterImpl>(id: &str, display_name: &str) -> ResultType<()> {
+    unsafe {
+        let mut info = source_info::<AsFilter<T>>(
+            id,
+            display_name,
+            sys::obs_source_type_OBS_SOURCE_TYPE_FILTER,
+            T::FLAGS,
+        )?;
+
+        if T::FLAGS.contains(SourceFlags::ASYNC_VIDEO) {
+            info.filter_video = Some(filter_video::<T>);
+        } else if T::FLAGS.contains(SourceFlags::VIDEO) {
+            info.video_render = Some(filter_render::<T>);
+        }
+        if T::FLAGS.contains(SourceFlags::AUDIO) {
+            info.filter_audio = Some(filter_audio::<T>);
+        }
+
+        sys::obs_register_source_s(&info, mem::size_of::<sys::obs_source_info>() as _);
+    }
+
+    Ok(())
+}
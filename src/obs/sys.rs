@@ -1,6 +1,189 @@
+//! The raw libobs bindings.
+//!
+//! libobs is loaded at runtime using `load`, every function
+//! panics if it is called before libobs has been loaded.
+//! With the `mock` feature, `load` uses a simulated libobs instead, see `mock`.
 #![allow(non_upper_case_globals)]
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
 #![allow(dead_code)]
 #![allow(improper_ctypes)]
+#![allow(clippy::all)]
+
+use crate::obs::util::obs_error::ObsError;
+use crate::obs::util::types::ResultType;
+use libloading::Library;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
 include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+
+#[cfg(feature = "mock")]
+pub(crate) mod mock;
+
+struct LoadedLibrary {
+    functions: Functions,
+    /// The loaded library, `None` if libobs is simulated.
+    library: Option<Library>,
+}
+
+static LIBRARY: OnceLock<LoadedLibrary> = OnceLock::new();
+static LOAD_LOCK: Mutex<()> = Mutex::new(());
+
+/// Load libobs.
+/// `path` may either be the path to the library or to the directory containing it.
+/// The library is searched for on the library search path if no path is given.
+/// Does nothing if libobs has already been loaded.
+/// `path` is ignored if the `mock` feature is enabled.
+pub fn load(path: Option<&str>) -> ResultType<()> {
+    let _lock = LOAD_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    if LIBRARY.get().is_some() {
+        return Ok(());
+    }
+
+    let _ = LIBRARY.set(load_library(path)?);
+    Ok(())
+}
+
+#[cfg(feature = "mock")]
+fn load_library(_path: Option<&str>) -> ResultType<LoadedLibrary> {
+    Ok(LoadedLibrary {
+        functions: mock::functions(),
+        library: None,
+    })
+}
+
+#[cfg(not(feature = "mock"))]
+fn load_library(path: Option<&str>) -> ResultType<LoadedLibrary> {
+    let candidates = library_candidates(path);
+    let mut last_error = None;
+    for candidate in &candidates {
+        match unsafe { Library::new(candidate) } {
+            Ok(library) => {
+                let functions = unsafe { Functions::load(&library) };
+                check_version(&functions)?;
+
+                return Ok(LoadedLibrary {
+                    functions,
+                    library: Some(library),
+                });
+            }
+            Err(e) => last_error = Some(e),
+        }
+    }
+
+    Err(ObsError::Library(match last_error {
+        Some(e) => e.to_string(),
+        None => "No library to load".to_string(),
+    }))
+}
+
+/// Check if libobs has been loaded.
+pub fn is_loaded() -> bool {
+    LIBRARY.get().is_some()
+}
+
+/// Get the functions exported by the loaded libobs.
+/// Panics if libobs has not been loaded.
+#[cfg(not(feature = "mock"))]
+pub fn functions() -> &'static Functions {
+    &LIBRARY.get().expect("libobs has not been loaded").functions
+}
+
+/// Get the functions exported by the simulated libobs.
+/// Panics if libobs has not been loaded.
+/// These depend on the simulated version, which may change at runtime.
+#[cfg(feature = "mock")]
+pub fn functions() -> &'static Functions {
+    LIBRARY.get().expect("libobs has not been loaded");
+    mock::exported()
+}
+
+/// Look up a symbol of the loaded libobs which is not part of the bindings,
+/// e.g. functions declared in headers which are not included by `obs.h`.
+/// Returns `None` if libobs has not been loaded, is simulated or does not export the symbol.
+///
+/// # Safety
+/// `T` must match the type of the symbol, `name` must be null-terminated.
+pub unsafe fn symbol<T: Copy>(name: &[u8]) -> Option<T> {
+    let library = LIBRARY.get()?.library.as_ref()?;
+    library.get::<T>(name).ok().map(|symbol| *symbol)
+}
+
+/// Format a version returned by `obs_get_version`.
+pub fn version_to_string(version: u32) -> String {
+    format!(
+        "{}.{}.{}",
+        version >> 24,
+        (version >> 16) & 0xff,
+        version & 0xffff
+    )
+}
+
+/// The oldest libobs version which can be loaded. Functions added in later
+/// versions are optional in `Functions`, features depending on them are
+/// gated using `Capability`.
+pub const MIN_VERSION: (u32, u32) = (26, 0);
+
+fn check_version(functions: &Functions) -> ResultType<()> {
+    let get_version = functions.obs_get_version.ok_or_else(|| {
+        ObsError::Library("The library does not export obs_get_version".to_string())
+    })?;
+
+    check_version_supported(unsafe { get_version() })
+}
+
+/// Return an error if `version`, as returned by `obs_get_version`, is older than `MIN_VERSION`.
+fn check_version_supported(version: u32) -> ResultType<()> {
+    if (version >> 24, (version >> 16) & 0xff) < MIN_VERSION {
+        return Err(ObsError::Library(format!(
+            "libobs {} is not supported, at least libobs {}.{} is required",
+            version_to_string(version),
+            MIN_VERSION.0,
+            MIN_VERSION.1
+        )));
+    }
+
+    Ok(())
+}
+
+fn library_candidates(path: Option<&str>) -> Vec<PathBuf> {
+    let mut names = vec![PathBuf::from(libloading::library_filename("obs"))];
+    if cfg!(target_os = "linux") {
+        names.push(PathBuf::from("libobs.so.0"));
+    } else if cfg!(target_os = "macos") {
+        names.push(PathBuf::from("libobs.framework/libobs"));
+    }
+
+    match path.map(Path::new) {
+        Some(path) if path.is_dir() => names.into_iter().map(|name| path.join(name)).collect(),
+        Some(path) => vec![path.to_path_buf()],
+        None => names,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check_version_supported, version_to_string};
+
+    fn version(major: u32, minor: u32) -> u32 {
+        (major << 24) | (minor << 16)
+    }
+
+    #[test]
+    fn supported_versions() {
+        assert!(check_version_supported(version(25, 0)).is_err());
+        for (major, minor) in [(26, 0), (27, 2), (29, 1), (30, 2), (31, 0)] {
+            assert!(check_version_supported(version(major, minor)).is_ok());
+        }
+    }
+
+    #[test]
+    fn version_string() {
+        assert_eq!(version_to_string(0), "0.0.0");
+        assert_eq!(version_to_string((30 << 24) | (2 << 16) | 3), "30.2.3");
+        // The patch version takes the lower 16 bits
+        assert_eq!(version_to_string((27 << 24) | 0x1234), "27.0.4660");
+        assert_eq!(version_to_string(u32::MAX), "255.255.65535");
+    }
+}
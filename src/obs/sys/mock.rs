@@ -0,0 +1,1888 @@
+//! A simulated libobs used in place of the real library if the `mock` feature is enabled.
+//!
+//! The simulation keeps track of settings, sources, encoders and outputs and emits
+//! the signals libobs would emit, synchronously on the calling thread. Nothing is
+//! actually rendered, encoded or written. Functions which are not simulated are
+//! left unset and panic like functions which are not exported by libobs.
+//!
+//! The following types are available:
+//! * `mock_video_source`, `mock_audio_source` and `mock_media_source`, a media source
+//!   whose playback is controlled using the media functions. Its duration is set
+//!   using the `duration` setting in milliseconds and defaults to 10 seconds.
+//!   The playback time only changes when seeking, seeking to the end ends the media.
+//! * `mock_video_encoder` (h264) and `mock_audio_encoder` (aac).
+//! * `mock_output`, which fails to start with the `start_error` setting as error if it is set.
+//!
+//! Source and output types registered by the crate are available as well,
+//! but their callbacks are never called.
+//!
+//! Modules are found in the module paths like libobs does, by looking for
+//! libraries in the binary path, but can't be opened.
+//! An older libobs version can be simulated using `set_version`.
+use super::*;
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::env;
+use std::ffi::{c_char, c_int, c_void, CStr, CString};
+use std::fs;
+use std::mem;
+use std::ptr;
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::Instant;
+
+const MAX_CHANNELS: usize = 64;
+const DEFAULT_DURATION: i64 = 10_000;
+
+/// The address returned by `obs_get_video` and `obs_get_audio` once they have been reset.
+static MEDIA_MARKER: u8 = 0;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum TypeKind {
+    Source,
+    VideoEncoder,
+    AudioEncoder,
+    Output,
+}
+
+struct MockType {
+    kind: TypeKind,
+    id: CString,
+    display_name: CString,
+    /// The output flags of sources or the flags of outputs.
+    flags: u32,
+    /// The codec of encoders or the supported codecs of outputs.
+    codecs: Option<(CString, CString)>,
+    /// Whether the type was registered by a plugin, these are removed on shutdown.
+    registered: bool,
+}
+
+impl MockType {
+    fn new(kind: TypeKind, id: &str, display_name: &str, flags: u32) -> Self {
+        Self {
+            kind,
+            id: CString::new(id).unwrap(),
+            display_name: CString::new(display_name).unwrap(),
+            flags,
+            codecs: None,
+            registered: false,
+        }
+    }
+
+    fn with_codecs(mut self, video: &str, audio: &str) -> Self {
+        self.codecs = Some((CString::new(video).unwrap(), CString::new(audio).unwrap()));
+        self
+    }
+}
+
+struct VideoInfo {
+    info: obs_video_info,
+    /// Keeps the graphics module name of `info` alive.
+    _graphics_module: CString,
+}
+
+struct MockState {
+    /// All available types.
+    types: Vec<MockType>,
+    /// Registered types removed on shutdown, which are kept
+    /// so pointers to their names stay valid.
+    removed_types: Vec<MockType>,
+    locale: CString,
+    video: Option<VideoInfo>,
+    audio: Option<obs_audio_info2>,
+    channels: [usize; MAX_CHANNELS],
+    active_outputs: usize,
+    /// All outputs which have not been destroyed yet, in creation order.
+    outputs: Vec<usize>,
+    frame_time: u64,
+    /// The binary and data paths added using `obs_add_module_path`.
+    module_paths: Vec<(String, String)>,
+}
+
+unsafe impl Send for MockState {}
+
+static STATE: Mutex<Option<MockState>> = Mutex::new(None);
+
+fn with_state<T, F: FnOnce(&mut MockState) -> T>(f: F) -> T {
+    let mut state = STATE.lock().unwrap_or_else(|e| e.into_inner());
+    f(state.get_or_insert_with(MockState::new))
+}
+
+impl MockState {
+    fn new() -> Self {
+        let types = vec![
+            MockType::new(
+                TypeKind::Source,
+                "mock_video_source",
+                "Mock Video Source",
+                OBS_SOURCE_VIDEO,
+            ),
+            MockType::new(
+                TypeKind::Source,
+                "mock_audio_source",
+                "Mock Audio Source",
+                OBS_SOURCE_AUDIO,
+            ),
+            MockType::new(
+                TypeKind::Source,
+                "mock_media_source",
+                "Mock Media Source",
+                OBS_SOURCE_ASYNC_VIDEO | OBS_SOURCE_AUDIO | OBS_SOURCE_CONTROLLABLE_MEDIA,
+            ),
+            MockType::new(
+                TypeKind::VideoEncoder,
+                "mock_video_encoder",
+                "Mock Video Encoder",
+                0,
+            )
+            .with_codecs("h264", ""),
+            MockType::new(
+                TypeKind::AudioEncoder,
+                "mock_audio_encoder",
+                "Mock Audio Encoder",
+                0,
+            )
+            .with_codecs("", "aac"),
+            MockType::new(
+                TypeKind::Output,
+                "mock_output",
+                "Mock Output",
+                OBS_OUTPUT_AV | OBS_OUTPUT_ENCODED | OBS_OUTPUT_CAN_PAUSE,
+            )
+            .with_codecs("h264;hevc", "aac;opus"),
+        ];
+
+        Self {
+            types,
+            removed_types: Vec::new(),
+            locale: CString::default(),
+            video: None,
+            audio: None,
+            channels: [0; MAX_CHANNELS],
+            active_outputs: 0,
+            outputs: Vec::new(),
+            frame_time: 0,
+            module_paths: Vec::new(),
+        }
+    }
+
+    fn find_type(&self, id: *const c_char, kinds: &[TypeKind]) -> Option<&MockType> {
+        if id.is_null() {
+            return None;
+        }
+
+        let id = unsafe { CStr::from_ptr(id) };
+        self.types
+            .iter()
+            .find(|t| kinds.contains(&t.kind) && t.id.as_c_str() == id)
+    }
+
+    fn type_at(&self, kinds: &[TypeKind], idx: usize) -> Option<&MockType> {
+        self.types
+            .iter()
+            .filter(|t| kinds.contains(&t.kind))
+            .nth(idx)
+    }
+
+    fn register(&mut self, kind: TypeKind, id: *const c_char, flags: u32) {
+        if id.is_null() || self.find_type(id, &[kind]).is_some() {
+            return;
+        }
+
+        let id = unsafe { CStr::from_ptr(id) }.to_string_lossy();
+        let mut registered = MockType::new(kind, &id, &id, flags);
+        registered.registered = true;
+        self.types.push(registered);
+    }
+}
+
+const ENCODERS: &[TypeKind] = &[TypeKind::VideoEncoder, TypeKind::AudioEncoder];
+
+fn type_flags(id: *const c_char, kinds: &[TypeKind]) -> u32 {
+    with_state(|state| state.find_type(id, kinds).map(|t| t.flags).unwrap_or(0))
+}
+
+fn type_display_name(id: *const c_char, kinds: &[TypeKind]) -> *const c_char {
+    with_state(|state| {
+        state
+            .find_type(id, kinds)
+            .map(|t| t.display_name.as_ptr())
+            .unwrap_or(ptr::null())
+    })
+}
+
+fn type_codecs(id: *const c_char, kinds: &[TypeKind], video: bool) -> *const c_char {
+    with_state(|state| {
+        state
+            .find_type(id, kinds)
+            .and_then(|t| t.codecs.as_ref())
+            .map(|(v, a)| if video { v.as_ptr() } else { a.as_ptr() })
+            .filter(|codecs| unsafe { **codecs } != 0)
+            .unwrap_or(ptr::null())
+    })
+}
+
+// Settings
+
+#[derive(Clone)]
+enum DataValue {
+    String(CString),
+    Int(i64),
+    Double(f64),
+    Bool(bool),
+    /// Objects and arrays, which are only kept to be returned as json.
+    Json(Value),
+}
+
+impl DataValue {
+    fn from_json(value: Value) -> Option<Self> {
+        match value {
+            Value::String(s) => CString::new(s).ok().map(DataValue::String),
+            Value::Bool(b) => Some(DataValue::Bool(b)),
+            Value::Number(n) => match n.as_i64() {
+                Some(i) => Some(DataValue::Int(i)),
+                None => n.as_f64().map(DataValue::Double),
+            },
+            Value::Null => None,
+            value => Some(DataValue::Json(value)),
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        match self {
+            DataValue::String(s) => Value::from(s.to_string_lossy().into_owned()),
+            DataValue::Int(i) => Value::from(*i),
+            DataValue::Double(d) => Value::from(*d),
+            DataValue::Bool(b) => Value::from(*b),
+            DataValue::Json(value) => value.clone(),
+        }
+    }
+}
+
+#[derive(Default)]
+struct MockData {
+    refs: AtomicUsize,
+    values: Mutex<BTreeMap<String, DataValue>>,
+    /// The last json returned by `obs_data_get_json`.
+    json: Mutex<CString>,
+}
+
+impl MockData {
+    fn create() -> *mut obs_data_t {
+        let data = MockData::default();
+        data.refs.store(1, Ordering::Relaxed);
+        Box::into_raw(Box::new(data)) as *mut obs_data_t
+    }
+
+    unsafe fn get<'a>(data: *mut obs_data_t) -> Option<&'a MockData> {
+        (data as *const MockData).as_ref()
+    }
+
+    fn set(&self, name: *const c_char, value: DataValue) {
+        if name.is_null() {
+            return;
+        }
+
+        let name = unsafe { CStr::from_ptr(name) }
+            .to_string_lossy()
+            .into_owned();
+        self.values.lock().unwrap().insert(name, value);
+    }
+
+    fn read<T, F: FnOnce(Option<&DataValue>) -> T>(&self, name: *const c_char, f: F) -> T {
+        let values = self.values.lock().unwrap();
+        if name.is_null() {
+            return f(None);
+        }
+
+        let name = unsafe { CStr::from_ptr(name) }.to_string_lossy();
+        f(values.get(name.as_ref()))
+    }
+
+    fn int(&self, name: &str) -> Option<i64> {
+        match self.values.lock().unwrap().get(name) {
+            Some(DataValue::Int(i)) => Some(*i),
+            Some(DataValue::Double(d)) => Some(*d as i64),
+            _ => None,
+        }
+    }
+
+    fn bool(&self, name: &str) -> Option<bool> {
+        match self.values.lock().unwrap().get(name) {
+            Some(DataValue::Bool(b)) => Some(*b),
+            _ => None,
+        }
+    }
+
+    fn string(&self, name: &str) -> Option<CString> {
+        match self.values.lock().unwrap().get(name) {
+            Some(DataValue::String(s)) => Some(s.clone()),
+            _ => None,
+        }
+    }
+
+    /// Copy all values of `other` into this.
+    fn apply(&self, other: *mut obs_data_t) {
+        let other = match unsafe { MockData::get(other) } {
+            Some(other) if !ptr::eq(other, self) => other,
+            _ => return,
+        };
+
+        let source = other.values.lock().unwrap();
+        let mut values = self.values.lock().unwrap();
+        for (name, value) in source.iter() {
+            values.insert(name.clone(), value.clone());
+        }
+    }
+}
+
+unsafe extern "C" fn obs_data_create_mock() -> *mut obs_data_t {
+    MockData::create()
+}
+
+unsafe extern "C" fn obs_data_create_from_json_mock(json: *const c_char) -> *mut obs_data_t {
+    if json.is_null() {
+        return ptr::null_mut();
+    }
+
+    let object = match serde_json::from_slice::<Value>(CStr::from_ptr(json).to_bytes()) {
+        Ok(Value::Object(object)) => object,
+        _ => return ptr::null_mut(),
+    };
+
+    let data = MockData::create();
+    let mut values = MockData::get(data).unwrap().values.lock().unwrap();
+    for (name, value) in object {
+        if let Some(value) = DataValue::from_json(value) {
+            values.insert(name, value);
+        }
+    }
+
+    data
+}
+
+unsafe extern "C" fn obs_data_addref_mock(data: *mut obs_data_t) {
+    if let Some(data) = MockData::get(data) {
+        data.refs.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+unsafe extern "C" fn obs_data_release_mock(data: *mut obs_data_t) {
+    if let Some(mock) = MockData::get(data) {
+        if mock.refs.fetch_sub(1, Ordering::AcqRel) == 1 {
+            drop(Box::from_raw(data as *mut MockData));
+        }
+    }
+}
+
+unsafe extern "C" fn obs_data_get_json_mock(data: *mut obs_data_t) -> *const c_char {
+    let data = match MockData::get(data) {
+        Some(data) => data,
+        None => return ptr::null(),
+    };
+
+    let object = data
+        .values
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|(name, value)| (name.clone(), value.to_json()))
+        .collect::<Map<_, _>>();
+
+    let mut json = data.json.lock().unwrap();
+    *json = CString::new(Value::Object(object).to_string()).unwrap_or_default();
+    json.as_ptr()
+}
+
+unsafe extern "C" fn obs_data_set_string_mock(
+    data: *mut obs_data_t,
+    name: *const c_char,
+    val: *const c_char,
+) {
+    if let Some(data) = MockData::get(data) {
+        let value = if val.is_null() {
+            CString::default()
+        } else {
+            CStr::from_ptr(val).to_owned()
+        };
+
+        data.set(name, DataValue::String(value));
+    }
+}
+
+unsafe extern "C" fn obs_data_set_int_mock(data: *mut obs_data_t, name: *const c_char, val: i64) {
+    if let Some(data) = MockData::get(data) {
+        data.set(name, DataValue::Int(val));
+    }
+}
+
+unsafe extern "C" fn obs_data_set_double_mock(
+    data: *mut obs_data_t,
+    name: *const c_char,
+    val: f64,
+) {
+    if let Some(data) = MockData::get(data) {
+        data.set(name, DataValue::Double(val));
+    }
+}
+
+unsafe extern "C" fn obs_data_set_bool_mock(data: *mut obs_data_t, name: *const c_char, val: bool) {
+    if let Some(data) = MockData::get(data) {
+        data.set(name, DataValue::Bool(val));
+    }
+}
+
+unsafe extern "C" fn obs_data_get_string_mock(
+    data: *mut obs_data_t,
+    name: *const c_char,
+) -> *const c_char {
+    let empty = b"\0".as_ptr() as *const c_char;
+    match MockData::get(data) {
+        // The pointer stays valid until the value is changed, like in libobs
+        Some(data) => data.read(name, |value| match value {
+            Some(DataValue::String(s)) => s.as_ptr(),
+            _ => empty,
+        }),
+        None => empty,
+    }
+}
+
+unsafe extern "C" fn obs_data_get_int_mock(data: *mut obs_data_t, name: *const c_char) -> i64 {
+    MockData::get(data)
+        .map(|data| {
+            data.read(name, |value| match value {
+                Some(DataValue::Int(i)) => *i,
+                Some(DataValue::Double(d)) => *d as i64,
+                _ => 0,
+            })
+        })
+        .unwrap_or(0)
+}
+
+unsafe extern "C" fn obs_data_get_double_mock(data: *mut obs_data_t, name: *const c_char) -> f64 {
+    MockData::get(data)
+        .map(|data| {
+            data.read(name, |value| match value {
+                Some(DataValue::Int(i)) => *i as f64,
+                Some(DataValue::Double(d)) => *d,
+                _ => 0.0,
+            })
+        })
+        .unwrap_or(0.0)
+}
+
+unsafe extern "C" fn obs_data_get_bool_mock(data: *mut obs_data_t, name: *const c_char) -> bool {
+    MockData::get(data)
+        .map(|data| data.read(name, |value| matches!(value, Some(DataValue::Bool(true)))))
+        .unwrap_or(false)
+}
+
+unsafe extern "C" fn obs_data_has_user_value_mock(
+    data: *mut obs_data_t,
+    name: *const c_char,
+) -> bool {
+    MockData::get(data)
+        .map(|data| data.read(name, |value| value.is_some()))
+        .unwrap_or(false)
+}
+
+// Signals
+
+enum Param {
+    Int(i64),
+    Ptr(*mut c_void),
+}
+
+type Params = Vec<(&'static str, Param)>;
+type Callback = unsafe extern "C" fn(*mut c_void, *mut calldata_t);
+
+#[derive(Default)]
+struct MockSignals {
+    connections: Mutex<Vec<(CString, Callback, usize)>>,
+}
+
+impl MockSignals {
+    /// Call all callbacks connected to `signal`.
+    /// The parameters are stored in the stack of the calldata.
+    fn emit(&self, signal: &str, mut params: Params) {
+        let callbacks = self
+            .connections
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(name, _, _)| name.as_bytes() == signal.as_bytes())
+            .map(|(_, callback, data)| (*callback, *data))
+            .collect::<Vec<_>>();
+
+        for (callback, data) in callbacks {
+            unsafe {
+                let mut calldata: calldata_t = mem::zeroed();
+                calldata.stack = &mut params as *mut Params as *mut _;
+                callback(data as *mut c_void, &mut calldata);
+            }
+        }
+    }
+}
+
+unsafe extern "C" fn signal_handler_connect_mock(
+    handler: *mut signal_handler_t,
+    signal: *const c_char,
+    callback: signal_callback_t,
+    data: *mut c_void,
+) {
+    let handler = match (handler as *const MockSignals).as_ref() {
+        Some(handler) => handler,
+        None => return,
+    };
+
+    if let (Some(callback), false) = (callback, signal.is_null()) {
+        handler.connections.lock().unwrap().push((
+            CStr::from_ptr(signal).to_owned(),
+            callback,
+            data as usize,
+        ));
+    }
+}
+
+unsafe extern "C" fn signal_handler_disconnect_mock(
+    handler: *mut signal_handler_t,
+    signal: *const c_char,
+    callback: signal_callback_t,
+    data: *mut c_void,
+) {
+    let handler = match (handler as *const MockSignals).as_ref() {
+        Some(handler) => handler,
+        None => return,
+    };
+
+    if signal.is_null() {
+        return;
+    }
+
+    let signal = CStr::from_ptr(signal);
+    let mut connections = handler.connections.lock().unwrap();
+    if let Some(i) = connections.iter().position(|(name, cb, d)| {
+        name.as_c_str() == signal
+            && Some(*cb as usize) == callback.map(|c| c as usize)
+            && *d == data as usize
+    }) {
+        connections.remove(i);
+    }
+}
+
+unsafe extern "C" fn calldata_get_data_mock(
+    data: *const calldata_t,
+    name: *const c_char,
+    out: *mut c_void,
+    size: usize,
+) -> bool {
+    if data.is_null() || name.is_null() || out.is_null() {
+        return false;
+    }
+
+    let params = match ((*data).stack as *const Params).as_ref() {
+        Some(params) => params,
+        None => return false,
+    };
+
+    let name = CStr::from_ptr(name).to_string_lossy();
+    match params.iter().find(|(n, _)| *n == name) {
+        Some((_, Param::Int(value))) if size == mem::size_of::<i64>() => {
+            *(out as *mut i64) = *value;
+            true
+        }
+        Some((_, Param::Ptr(value))) if size == mem::size_of::<*mut c_void>() => {
+            *(out as *mut *mut c_void) = *value;
+            true
+        }
+        _ => false,
+    }
+}
+
+unsafe extern "C" fn calldata_get_string_mock(
+    _data: *const calldata_t,
+    _name: *const c_char,
+    _str: *mut *const c_char,
+) -> bool {
+    // No simulated signal has string parameters
+    false
+}
+
+// Sources, encoders and outputs
+
+#[derive(Default)]
+struct ObjectState {
+    media_state: obs_media_state,
+    media_time: i64,
+    active: bool,
+    /// Whether the output has been started with the `connecting` setting
+    /// and is waiting for `connect` to be called.
+    connecting: bool,
+    paused: bool,
+    started: Option<Instant>,
+    last_error: Option<CString>,
+    video_encoder: usize,
+    audio_encoder: usize,
+    audio_timestamps: Vec<u64>,
+}
+
+struct MockObject {
+    refs: AtomicUsize,
+    kind: TypeKind,
+    id: CString,
+    name: CString,
+    flags: u32,
+    codec: Option<CString>,
+    settings: *mut obs_data_t,
+    signals: MockSignals,
+    state: Mutex<ObjectState>,
+}
+
+impl MockObject {
+    /// Create an object of a known type, returns null if the type does not exist.
+    unsafe fn create<T>(
+        kinds: &[TypeKind],
+        id: *const c_char,
+        name: *const c_char,
+        settings: *mut obs_data_t,
+    ) -> *mut T {
+        let (kind, flags, codec) = match with_state(|state| {
+            state.find_type(id, kinds).map(|t| {
+                let codec = t.codecs.as_ref().map(|(video, audio)| match t.kind {
+                    TypeKind::AudioEncoder => audio.clone(),
+                    _ => video.clone(),
+                });
+
+                (t.kind, t.flags, codec)
+            })
+        }) {
+            Some(found) => found,
+            None => return ptr::null_mut(),
+        };
+
+        let object_settings = MockData::create();
+        MockData::get(object_settings).unwrap().apply(settings);
+
+        let object = MockObject {
+            refs: AtomicUsize::new(1),
+            kind,
+            id: CStr::from_ptr(id).to_owned(),
+            name: if name.is_null() {
+                CString::default()
+            } else {
+                CStr::from_ptr(name).to_owned()
+            },
+            flags,
+            codec,
+            settings: object_settings,
+            signals: MockSignals::default(),
+            state: Mutex::new(ObjectState {
+                media_state: obs_media_state_OBS_MEDIA_STATE_STOPPED,
+                ..Default::default()
+            }),
+        };
+
+        Box::into_raw(Box::new(object)) as *mut T
+    }
+
+    unsafe fn get<'a, T>(object: *const T) -> Option<&'a MockObject> {
+        (object as *const MockObject).as_ref()
+    }
+
+    unsafe fn addref<T>(object: *mut T) {
+        if let Some(object) = MockObject::get(object) {
+            object.refs.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    unsafe fn release<T>(object: *mut T) {
+        if let Some(mock) = MockObject::get(object) {
+            if mock.refs.fetch_sub(1, Ordering::AcqRel) == 1 {
+                let object = Box::from_raw(object as *mut MockObject);
+                obs_data_release_mock(object.settings);
+            }
+        }
+    }
+
+    fn settings(&self) -> &MockData {
+        unsafe { MockData::get(self.settings) }.unwrap()
+    }
+
+    fn get_settings(&self) -> *mut obs_data_t {
+        unsafe { obs_data_addref_mock(self.settings) };
+        self.settings
+    }
+
+    fn signal_handler(&self) -> *mut signal_handler_t {
+        &self.signals as *const MockSignals as *mut signal_handler_t
+    }
+
+    fn duration(&self) -> i64 {
+        self.settings()
+            .int("duration")
+            .unwrap_or(DEFAULT_DURATION)
+            .max(0)
+    }
+
+    fn is_media(&self) -> bool {
+        self.flags & OBS_SOURCE_CONTROLLABLE_MEDIA != 0
+    }
+}
+
+fn id_or_null(object: Option<&MockObject>) -> *const c_char {
+    object.map(|o| o.id.as_ptr()).unwrap_or(ptr::null())
+}
+
+unsafe extern "C" fn obs_source_create_mock(
+    id: *const c_char,
+    name: *const c_char,
+    settings: *mut obs_data_t,
+    _hotkey_data: *mut obs_data_t,
+) -> *mut obs_source_t {
+    MockObject::create(&[TypeKind::Source], id, name, settings)
+}
+
+unsafe extern "C" fn obs_source_release_mock(source: *mut obs_source_t) {
+    MockObject::release(source);
+}
+
+unsafe extern "C" fn obs_source_get_id_mock(source: *const obs_source_t) -> *const c_char {
+    id_or_null(MockObject::get(source))
+}
+
+unsafe extern "C" fn obs_source_get_display_name_mock(id: *const c_char) -> *const c_char {
+    type_display_name(id, &[TypeKind::Source])
+}
+
+unsafe extern "C" fn obs_source_get_output_flags_mock(source: *const obs_source_t) -> u32 {
+    MockObject::get(source).map(|s| s.flags).unwrap_or(0)
+}
+
+unsafe extern "C" fn obs_source_get_settings_mock(source: *const obs_source_t) -> *mut obs_data_t {
+    MockObject::get(source)
+        .map(MockObject::get_settings)
+        .unwrap_or(ptr::null_mut())
+}
+
+unsafe extern "C" fn obs_source_update_mock(source: *mut obs_source_t, settings: *mut obs_data_t) {
+    if let Some(source) = MockObject::get(source) {
+        source.settings().apply(settings);
+    }
+}
+
+unsafe extern "C" fn obs_source_get_signal_handler_mock(
+    source: *const obs_source_t,
+) -> *mut signal_handler_t {
+    MockObject::get(source)
+        .map(MockObject::signal_handler)
+        .unwrap_or(ptr::null_mut())
+}
+
+unsafe extern "C" fn obs_get_source_defaults_mock(id: *const c_char) -> *mut obs_data_t {
+    if type_flags(id, &[TypeKind::Source]) == 0 {
+        ptr::null_mut()
+    } else {
+        MockData::create()
+    }
+}
+
+unsafe extern "C" fn obs_set_output_source_mock(channel: u32, source: *mut obs_source_t) {
+    if channel as usize >= MAX_CHANNELS {
+        return;
+    }
+
+    MockObject::addref(source);
+    let previous =
+        with_state(|state| mem::replace(&mut state.channels[channel as usize], source as usize));
+    MockObject::release(previous as *mut obs_source_t);
+}
+
+/// Change the media state of a source and emit the given signals.
+unsafe fn media_action<F>(source: *mut obs_source_t, action: F)
+where
+    F: FnOnce(&MockObject, &mut ObjectState) -> Vec<&'static str>,
+{
+    let source = match MockObject::get(source) {
+        Some(source) if source.is_media() => source,
+        _ => return,
+    };
+
+    let signals = {
+        let mut state = source.state.lock().unwrap();
+        action(source, &mut state)
+    };
+
+    for signal in signals {
+        source.signals.emit(signal, vec![]);
+    }
+}
+
+fn media_stopped(state: obs_media_state) -> bool {
+    state == obs_media_state_OBS_MEDIA_STATE_STOPPED
+        || state == obs_media_state_OBS_MEDIA_STATE_ENDED
+        || state == obs_media_state_OBS_MEDIA_STATE_NONE
+}
+
+unsafe extern "C" fn obs_source_media_play_pause_mock(source: *mut obs_source_t, pause: bool) {
+    media_action(source, |_, state| {
+        if pause {
+            state.media_state = obs_media_state_OBS_MEDIA_STATE_PAUSED;
+            vec!["media_pause"]
+        } else if media_stopped(state.media_state) {
+            state.media_state = obs_media_state_OBS_MEDIA_STATE_PLAYING;
+            state.media_time = 0;
+            vec!["media_started", "media_play"]
+        } else {
+            state.media_state = obs_media_state_OBS_MEDIA_STATE_PLAYING;
+            vec!["media_play"]
+        }
+    });
+}
+
+unsafe extern "C" fn obs_source_media_restart_mock(source: *mut obs_source_t) {
+    media_action(source, |_, state| {
+        state.media_state = obs_media_state_OBS_MEDIA_STATE_PLAYING;
+        state.media_time = 0;
+        vec!["media_restart", "media_started"]
+    });
+}
+
+unsafe extern "C" fn obs_source_media_stop_mock(source: *mut obs_source_t) {
+    media_action(source, |_, state| {
+        state.media_state = obs_media_state_OBS_MEDIA_STATE_STOPPED;
+        state.media_time = 0;
+        vec!["media_stopped"]
+    });
+}
+
+unsafe extern "C" fn obs_source_media_next_mock(source: *mut obs_source_t) {
+    media_action(source, |_, _| vec!["media_next"]);
+}
+
+unsafe extern "C" fn obs_source_media_previous_mock(source: *mut obs_source_t) {
+    media_action(source, |_, _| vec!["media_previous"]);
+}
+
+unsafe extern "C" fn obs_source_media_set_time_mock(source: *mut obs_source_t, ms: i64) {
+    media_action(source, |source, state| {
+        let duration = source.duration();
+        state.media_time = ms.clamp(0, duration);
+
+        let playing = state.media_state == obs_media_state_OBS_MEDIA_STATE_PLAYING;
+        if state.media_time == duration && playing {
+            state.media_state = obs_media_state_OBS_MEDIA_STATE_ENDED;
+            vec!["media_ended"]
+        } else {
+            vec![]
+        }
+    });
+}
+
+unsafe extern "C" fn obs_source_media_get_time_mock(source: *mut obs_source_t) -> i64 {
+    MockObject::get(source)
+        .map(|s| s.state.lock().unwrap().media_time)
+        .unwrap_or(0)
+}
+
+unsafe extern "C" fn obs_source_media_get_duration_mock(source: *mut obs_source_t) -> i64 {
+    MockObject::get(source)
+        .filter(|s| s.is_media())
+        .map(MockObject::duration)
+        .unwrap_or(0)
+}
+
+unsafe extern "C" fn obs_source_media_get_state_mock(source: *mut obs_source_t) -> obs_media_state {
+    MockObject::get(source)
+        .map(|s| s.state.lock().unwrap().media_state)
+        .unwrap_or(obs_media_state_OBS_MEDIA_STATE_NONE)
+}
+
+unsafe extern "C" fn obs_source_output_video_mock(
+    _source: *mut obs_source_t,
+    _frame: *const obs_source_frame,
+) {
+}
+
+unsafe extern "C" fn obs_source_output_audio_mock(
+    source: *mut obs_source_t,
+    audio: *const obs_source_audio,
+) {
+    if let (Some(source), Some(audio)) = (MockObject::get(source), audio.as_ref()) {
+        let mut state = source.state.lock().unwrap();
+        state.audio_timestamps.push(audio.timestamp);
+    }
+}
+
+/// Get the timestamps of the audio data output to a source.
+pub(crate) unsafe fn audio_timestamps(source: *mut obs_source_t) -> Vec<u64> {
+    MockObject::get(source)
+        .map(|s| s.state.lock().unwrap().audio_timestamps.clone())
+        .unwrap_or_default()
+}
+
+unsafe extern "C" fn obs_get_video_frame_time_mock() -> u64 {
+    with_state(|state| {
+        state.frame_time += 1;
+        state.frame_time
+    })
+}
+
+unsafe extern "C" fn video_format_get_parameters_mock(
+    _color_space: video_colorspace,
+    _range: video_range_type,
+    matrix: *mut f32,
+    min_range: *mut f32,
+    max_range: *mut f32,
+) -> bool {
+    for i in 0..16 {
+        *matrix.add(i) = if i % 5 == 0 { 1.0 } else { 0.0 };
+    }
+
+    for i in 0..3 {
+        *min_range.add(i) = 0.0;
+        *max_range.add(i) = 1.0;
+    }
+
+    true
+}
+
+unsafe extern "C" fn obs_register_source_s_mock(info: *const obs_source_info, _size: usize) {
+    if let Some(info) = info.as_ref() {
+        with_state(|state| state.register(TypeKind::Source, info.id, info.output_flags));
+    }
+}
+
+unsafe extern "C" fn obs_video_encoder_create_mock(
+    id: *const c_char,
+    name: *const c_char,
+    settings: *mut obs_data_t,
+    _hotkey_data: *mut obs_data_t,
+) -> *mut obs_encoder_t {
+    MockObject::create(&[TypeKind::VideoEncoder], id, name, settings)
+}
+
+unsafe extern "C" fn obs_audio_encoder_create_mock(
+    id: *const c_char,
+    name: *const c_char,
+    settings: *mut obs_data_t,
+    _mixer_idx: usize,
+    _hotkey_data: *mut obs_data_t,
+) -> *mut obs_encoder_t {
+    MockObject::create(&[TypeKind::AudioEncoder], id, name, settings)
+}
+
+unsafe extern "C" fn obs_encoder_release_mock(encoder: *mut obs_encoder_t) {
+    MockObject::release(encoder);
+}
+
+fn encoder_type(kind: Option<TypeKind>) -> obs_encoder_type {
+    match kind {
+        Some(TypeKind::AudioEncoder) => obs_encoder_type_OBS_ENCODER_AUDIO,
+        _ => obs_encoder_type_OBS_ENCODER_VIDEO,
+    }
+}
+
+unsafe extern "C" fn obs_encoder_get_type_mock(encoder: *const obs_encoder_t) -> obs_encoder_type {
+    encoder_type(MockObject::get(encoder).map(|e| e.kind))
+}
+
+unsafe extern "C" fn obs_encoder_get_codec_mock(encoder: *const obs_encoder_t) -> *const c_char {
+    MockObject::get(encoder)
+        .and_then(|e| e.codec.as_ref())
+        .map(|codec| codec.as_ptr())
+        .unwrap_or(ptr::null())
+}
+
+unsafe extern "C" fn obs_encoder_get_settings_mock(
+    encoder: *const obs_encoder_t,
+) -> *mut obs_data_t {
+    MockObject::get(encoder)
+        .map(MockObject::get_settings)
+        .unwrap_or(ptr::null_mut())
+}
+
+unsafe extern "C" fn obs_encoder_update_mock(
+    encoder: *mut obs_encoder_t,
+    settings: *mut obs_data_t,
+) {
+    if let Some(encoder) = MockObject::get(encoder) {
+        encoder.settings().apply(settings);
+    }
+}
+
+unsafe extern "C" fn obs_encoder_set_video_mock(
+    _encoder: *mut obs_encoder_t,
+    _video: *mut video_t,
+) {
+}
+
+unsafe extern "C" fn obs_encoder_set_audio_mock(
+    _encoder: *mut obs_encoder_t,
+    _audio: *mut audio_t,
+) {
+}
+
+unsafe extern "C" fn obs_get_encoder_type_mock(id: *const c_char) -> obs_encoder_type {
+    encoder_type(with_state(|state| {
+        state.find_type(id, ENCODERS).map(|t| t.kind)
+    }))
+}
+
+unsafe extern "C" fn obs_get_encoder_codec_mock(id: *const c_char) -> *const c_char {
+    let kind = with_state(|state| state.find_type(id, ENCODERS).map(|t| t.kind));
+    type_codecs(id, ENCODERS, kind != Some(TypeKind::AudioEncoder))
+}
+
+unsafe extern "C" fn obs_get_encoder_caps_mock(_id: *const c_char) -> u32 {
+    0
+}
+
+unsafe extern "C" fn obs_encoder_get_display_name_mock(id: *const c_char) -> *const c_char {
+    type_display_name(id, ENCODERS)
+}
+
+unsafe extern "C" fn obs_encoder_defaults_mock(id: *const c_char) -> *mut obs_data_t {
+    if type_display_name(id, ENCODERS).is_null() {
+        ptr::null_mut()
+    } else {
+        MockData::create()
+    }
+}
+
+unsafe extern "C" fn obs_output_create_mock(
+    id: *const c_char,
+    name: *const c_char,
+    settings: *mut obs_data_t,
+    _hotkey_data: *mut obs_data_t,
+) -> *mut obs_output_t {
+    let output: *mut obs_output_t = MockObject::create(&[TypeKind::Output], id, name, settings);
+    if !output.is_null() {
+        with_state(|state| state.outputs.push(output as usize));
+    }
+
+    output
+}
+
+unsafe extern "C" fn obs_output_get_ref_mock(output: *mut obs_output_t) -> *mut obs_output_t {
+    if MockObject::get(output).is_none() {
+        return ptr::null_mut();
+    }
+
+    MockObject::addref(output);
+    output
+}
+
+unsafe extern "C" fn obs_output_release_mock(output: *mut obs_output_t) {
+    if let Some(mock) = MockObject::get(output) {
+        if mock.refs.load(Ordering::Acquire) == 1 {
+            if mock.state.lock().unwrap().active {
+                obs_output_force_stop_mock(output);
+            }
+
+            with_state(|state| state.outputs.retain(|o| *o != output as usize));
+        }
+    }
+
+    MockObject::release(output);
+}
+
+unsafe extern "C" fn obs_enum_outputs_mock(
+    enum_proc: Option<unsafe extern "C" fn(*mut c_void, *mut obs_output_t) -> bool>,
+    param: *mut c_void,
+) {
+    let enum_proc = match enum_proc {
+        Some(enum_proc) => enum_proc,
+        None => return,
+    };
+
+    // The callback may start or stop outputs, so it must not run while the state is locked
+    let outputs = with_state(|state| state.outputs.clone());
+    for output in outputs {
+        if !enum_proc(param, output as *mut obs_output_t) {
+            break;
+        }
+    }
+}
+
+unsafe extern "C" fn obs_output_start_mock(output: *mut obs_output_t) -> bool {
+    let mock = match MockObject::get(output) {
+        Some(mock) => mock,
+        None => return false,
+    };
+
+    if let Some(error) = mock.settings().string("start_error") {
+        mock.state.lock().unwrap().last_error = Some(error);
+        return false;
+    }
+
+    {
+        let mut state = mock.state.lock().unwrap();
+        if state.active || state.connecting {
+            return false;
+        }
+
+        state.last_error = None;
+    }
+
+    mock.signals.emit(
+        "starting",
+        vec![("output", Param::Ptr(output as *mut c_void))],
+    );
+    if mock.settings().bool("connecting") == Some(true) {
+        mock.state.lock().unwrap().connecting = true;
+    } else {
+        activate_output(output, mock);
+    }
+
+    true
+}
+
+unsafe fn activate_output(output: *mut obs_output_t, mock: &MockObject) {
+    {
+        let mut state = mock.state.lock().unwrap();
+        state.active = true;
+        state.connecting = false;
+        state.paused = false;
+        state.started = Some(Instant::now());
+    }
+
+    with_state(|state| state.active_outputs += 1);
+    for signal in ["activate", "start"] {
+        mock.signals
+            .emit(signal, vec![("output", Param::Ptr(output as *mut c_void))]);
+    }
+}
+
+/// Finish connecting an output started with the `connecting` setting.
+pub(crate) unsafe fn connect(output: *mut obs_output_t) {
+    if let Some(mock) = MockObject::get(output) {
+        if mock.state.lock().unwrap().connecting {
+            activate_output(output, mock);
+        }
+    }
+}
+
+unsafe fn stop_output(output: *mut obs_output_t) {
+    let mock = match MockObject::get(output) {
+        Some(mock) => mock,
+        None => return,
+    };
+
+    let params = || vec![("output", Param::Ptr(output as *mut c_void))];
+    {
+        let mut state = mock.state.lock().unwrap();
+        if !state.active {
+            return;
+        }
+
+        state.active = false;
+        state.paused = false;
+    }
+
+    with_state(|state| state.active_outputs = state.active_outputs.saturating_sub(1));
+    mock.signals.emit("stopping", params());
+    mock.signals.emit("deactivate", params());
+
+    let mut params = params();
+    params.push(("code", Param::Int(OBS_OUTPUT_SUCCESS as i64)));
+    mock.signals.emit("stop", params);
+}
+
+unsafe extern "C" fn obs_output_stop_mock(output: *mut obs_output_t) {
+    stop_output(output);
+}
+
+/// Like libobs, outputs which are still connecting are only stopped by force.
+unsafe extern "C" fn obs_output_force_stop_mock(output: *mut obs_output_t) {
+    let connecting = MockObject::get(output)
+        .map(|mock| mem::take(&mut mock.state.lock().unwrap().connecting))
+        .unwrap_or(false);
+
+    if !connecting {
+        return stop_output(output);
+    }
+
+    let params = || vec![("output", Param::Ptr(output as *mut c_void))];
+    if let Some(mock) = MockObject::get(output) {
+        mock.signals.emit("stopping", params());
+
+        let mut params = params();
+        params.push(("code", Param::Int(OBS_OUTPUT_SUCCESS as i64)));
+        mock.signals.emit("stop", params);
+    }
+}
+
+unsafe extern "C" fn obs_output_reconnecting_mock(_output: *const obs_output_t) -> bool {
+    false
+}
+
+unsafe extern "C" fn obs_output_active_mock(output: *const obs_output_t) -> bool {
+    MockObject::get(output)
+        .map(|o| o.state.lock().unwrap().active)
+        .unwrap_or(false)
+}
+
+unsafe extern "C" fn obs_output_paused_mock(output: *const obs_output_t) -> bool {
+    MockObject::get(output)
+        .map(|o| o.state.lock().unwrap().paused)
+        .unwrap_or(false)
+}
+
+unsafe extern "C" fn obs_output_can_pause_mock(output: *const obs_output_t) -> bool {
+    MockObject::get(output)
+        .map(|o| o.flags & OBS_OUTPUT_CAN_PAUSE != 0)
+        .unwrap_or(false)
+}
+
+unsafe extern "C" fn obs_output_pause_mock(output: *mut obs_output_t, pause: bool) -> bool {
+    let mock = match MockObject::get(output) {
+        Some(mock) if mock.flags & OBS_OUTPUT_CAN_PAUSE != 0 => mock,
+        _ => return false,
+    };
+
+    {
+        let mut state = mock.state.lock().unwrap();
+        if !state.active || state.paused == pause {
+            return false;
+        }
+
+        state.paused = pause;
+    }
+
+    let signal = if pause { "pause" } else { "unpause" };
+    mock.signals
+        .emit(signal, vec![("output", Param::Ptr(output as *mut c_void))]);
+    true
+}
+
+unsafe extern "C" fn obs_output_get_name_mock(output: *const obs_output_t) -> *const c_char {
+    MockObject::get(output)
+        .map(|o| o.name.as_ptr())
+        .unwrap_or(ptr::null())
+}
+
+unsafe extern "C" fn obs_output_get_id_mock(output: *const obs_output_t) -> *const c_char {
+    id_or_null(MockObject::get(output))
+}
+
+unsafe extern "C" fn obs_output_get_settings_mock(output: *const obs_output_t) -> *mut obs_data_t {
+    MockObject::get(output)
+        .map(MockObject::get_settings)
+        .unwrap_or(ptr::null_mut())
+}
+
+unsafe extern "C" fn obs_output_update_mock(output: *mut obs_output_t, settings: *mut obs_data_t) {
+    if let Some(output) = MockObject::get(output) {
+        output.settings().apply(settings);
+    }
+}
+
+unsafe extern "C" fn obs_output_get_signal_handler_mock(
+    output: *const obs_output_t,
+) -> *mut signal_handler_t {
+    MockObject::get(output)
+        .map(MockObject::signal_handler)
+        .unwrap_or(ptr::null_mut())
+}
+
+unsafe extern "C" fn obs_output_get_last_error_mock(output: *mut obs_output_t) -> *const c_char {
+    MockObject::get(output)
+        .and_then(|o| {
+            o.state
+                .lock()
+                .unwrap()
+                .last_error
+                .as_ref()
+                .map(|e| e.as_ptr())
+        })
+        .unwrap_or(ptr::null())
+}
+
+unsafe extern "C" fn obs_output_set_last_error_mock(
+    output: *mut obs_output_t,
+    message: *const c_char,
+) {
+    if let Some(output) = MockObject::get(output) {
+        output.state.lock().unwrap().last_error =
+            (!message.is_null()).then(|| CStr::from_ptr(message).to_owned());
+    }
+}
+
+unsafe extern "C" fn obs_output_set_media_mock(
+    _output: *mut obs_output_t,
+    _video: *mut video_t,
+    _audio: *mut audio_t,
+) {
+}
+
+unsafe extern "C" fn obs_output_set_video_encoder_mock(
+    output: *mut obs_output_t,
+    encoder: *mut obs_encoder_t,
+) {
+    if let Some(output) = MockObject::get(output) {
+        output.state.lock().unwrap().video_encoder = encoder as usize;
+    }
+}
+
+unsafe extern "C" fn obs_output_set_audio_encoder_mock(
+    output: *mut obs_output_t,
+    encoder: *mut obs_encoder_t,
+    _idx: usize,
+) {
+    if let Some(output) = MockObject::get(output) {
+        output.state.lock().unwrap().audio_encoder = encoder as usize;
+    }
+}
+
+unsafe extern "C" fn obs_output_get_video_encoder_mock(
+    output: *const obs_output_t,
+) -> *mut obs_encoder_t {
+    MockObject::get(output)
+        .map(|o| o.state.lock().unwrap().video_encoder as *mut obs_encoder_t)
+        .unwrap_or(ptr::null_mut())
+}
+
+unsafe extern "C" fn obs_output_get_audio_encoder_mock(
+    output: *const obs_output_t,
+    _idx: usize,
+) -> *mut obs_encoder_t {
+    MockObject::get(output)
+        .map(|o| o.state.lock().unwrap().audio_encoder as *mut obs_encoder_t)
+        .unwrap_or(ptr::null_mut())
+}
+
+/// The number of frames an output would have written since it was started,
+/// at the frame rate video was reset with.
+fn output_frames(output: *const obs_output_t) -> u64 {
+    let started = unsafe { MockObject::get(output) }.and_then(|o| {
+        let state = o.state.lock().unwrap();
+        state.active.then_some(state.started).flatten()
+    });
+
+    let fps = with_state(|state| {
+        state
+            .video
+            .as_ref()
+            .map(|v| v.info.fps_num as f64 / v.info.fps_den.max(1) as f64)
+            .unwrap_or(0.0)
+    });
+
+    started
+        .map(|started| (started.elapsed().as_secs_f64() * fps) as u64)
+        .unwrap_or(0)
+}
+
+unsafe extern "C" fn obs_output_get_total_frames_mock(output: *const obs_output_t) -> c_int {
+    output_frames(output) as c_int
+}
+
+unsafe extern "C" fn obs_output_get_frames_dropped_mock(_output: *const obs_output_t) -> c_int {
+    0
+}
+
+unsafe extern "C" fn obs_output_get_total_bytes_mock(output: *const obs_output_t) -> u64 {
+    // About 6 Mbit/s at 30 fps
+    output_frames(output) * 25_000
+}
+
+unsafe extern "C" fn obs_output_get_display_name_mock(id: *const c_char) -> *const c_char {
+    type_display_name(id, &[TypeKind::Output])
+}
+
+unsafe extern "C" fn obs_get_output_flags_mock(id: *const c_char) -> u32 {
+    type_flags(id, &[TypeKind::Output])
+}
+
+unsafe extern "C" fn obs_get_output_supported_video_codecs_mock(
+    id: *const c_char,
+) -> *const c_char {
+    type_codecs(id, &[TypeKind::Output], true)
+}
+
+unsafe extern "C" fn obs_get_output_supported_audio_codecs_mock(
+    id: *const c_char,
+) -> *const c_char {
+    type_codecs(id, &[TypeKind::Output], false)
+}
+
+unsafe extern "C" fn obs_output_defaults_mock(id: *const c_char) -> *mut obs_data_t {
+    if type_display_name(id, &[TypeKind::Output]).is_null() {
+        ptr::null_mut()
+    } else {
+        MockData::create()
+    }
+}
+
+unsafe extern "C" fn obs_register_output_s_mock(info: *const obs_output_info, _size: usize) {
+    if let Some(info) = info.as_ref() {
+        with_state(|state| state.register(TypeKind::Output, info.id, info.flags));
+    }
+}
+
+// Startup and global settings
+
+unsafe extern "C" fn obs_startup_mock(
+    locale: *const c_char,
+    _module_config_path: *const c_char,
+    _store: *mut profiler_name_store_t,
+) -> bool {
+    obs_set_locale_mock(locale);
+    true
+}
+
+unsafe extern "C" fn obs_shutdown_mock() {
+    let channels = with_state(|state| {
+        state.video = None;
+        state.audio = None;
+        state.active_outputs = 0;
+        state.module_paths.clear();
+
+        let (registered, builtin) = mem::take(&mut state.types)
+            .into_iter()
+            .partition(|t| t.registered);
+        state.types = builtin;
+        state.removed_types.extend(registered);
+
+        mem::replace(&mut state.channels, [0; MAX_CHANNELS])
+    });
+
+    for source in channels {
+        MockObject::release(source as *mut obs_source_t);
+    }
+}
+
+/// The simulated libobs version, as returned by `obs_get_version`.
+static VERSION: AtomicU32 =
+    AtomicU32::new((LIBOBS_API_MAJOR_VER << 24) | (LIBOBS_API_MINOR_VER << 16));
+
+/// Simulate libobs `major.minor`, functions added in later versions are not exported.
+/// Defaults to the version of the bindings.
+pub(crate) fn set_version(major: u32, minor: u32) {
+    VERSION.store((major << 24) | (minor << 16), Ordering::SeqCst);
+}
+
+unsafe extern "C" fn obs_get_version_mock() -> u32 {
+    VERSION.load(Ordering::SeqCst)
+}
+
+unsafe extern "C" fn obs_get_locale_mock() -> *const c_char {
+    with_state(|state| state.locale.as_ptr())
+}
+
+unsafe extern "C" fn obs_set_locale_mock(locale: *const c_char) {
+    if !locale.is_null() {
+        with_state(|state| state.locale = CStr::from_ptr(locale).to_owned());
+    }
+}
+
+unsafe extern "C" fn obs_add_data_path_mock(_path: *const c_char) {}
+
+unsafe extern "C" fn obs_reset_video_mock(ovi: *mut obs_video_info) -> c_int {
+    let ovi = match ovi.as_ref() {
+        Some(ovi) => ovi,
+        None => return OBS_VIDEO_INVALID_PARAM,
+    };
+
+    if ovi.fps_num == 0
+        || ovi.fps_den == 0
+        || ovi.base_width == 0
+        || ovi.base_height == 0
+        || ovi.output_width == 0
+        || ovi.output_height == 0
+    {
+        return OBS_VIDEO_INVALID_PARAM;
+    }
+
+    let graphics_module = if ovi.graphics_module.is_null() {
+        CString::default()
+    } else {
+        CStr::from_ptr(ovi.graphics_module).to_owned()
+    };
+
+    with_state(|state| {
+        if state.active_outputs > 0 {
+            return OBS_VIDEO_CURRENTLY_ACTIVE;
+        }
+
+        let mut info = *ovi;
+        info.graphics_module = graphics_module.as_ptr();
+        state.video = Some(VideoInfo {
+            info,
+            _graphics_module: graphics_module,
+        });
+
+        OBS_VIDEO_SUCCESS as c_int
+    })
+}
+
+unsafe extern "C" fn obs_reset_audio2_mock(oai: *const obs_audio_info2) -> bool {
+    match oai.as_ref() {
+        Some(oai) if oai.samples_per_sec > 0 => with_state(|state| {
+            if state.active_outputs > 0 {
+                return false;
+            }
+
+            state.audio = Some(*oai);
+            true
+        }),
+        _ => false,
+    }
+}
+
+unsafe extern "C" fn obs_reset_audio_mock(oai: *const obs_audio_info) -> bool {
+    match oai.as_ref() {
+        Some(oai) => obs_reset_audio2_mock(&obs_audio_info2 {
+            samples_per_sec: oai.samples_per_sec,
+            speakers: oai.speakers,
+            max_buffering_ms: 0,
+            fixed_buffering: false,
+        }),
+        None => false,
+    }
+}
+
+unsafe extern "C" fn obs_get_video_info_mock(ovi: *mut obs_video_info) -> bool {
+    match (
+        ovi.as_mut(),
+        with_state(|state| state.video.as_ref().map(|v| v.info)),
+    ) {
+        (Some(ovi), Some(info)) => {
+            *ovi = info;
+            true
+        }
+        _ => false,
+    }
+}
+
+unsafe extern "C" fn obs_get_audio_info2_mock(oai: *mut obs_audio_info2) -> bool {
+    match (oai.as_mut(), with_state(|state| state.audio)) {
+        (Some(oai), Some(info)) => {
+            *oai = info;
+            true
+        }
+        _ => false,
+    }
+}
+
+unsafe extern "C" fn obs_get_audio_info_mock(oai: *mut obs_audio_info) -> bool {
+    match (oai.as_mut(), with_state(|state| state.audio)) {
+        (Some(oai), Some(info)) => {
+            oai.samples_per_sec = info.samples_per_sec;
+            oai.speakers = info.speakers;
+            true
+        }
+        _ => false,
+    }
+}
+
+unsafe extern "C" fn obs_video_active_mock() -> bool {
+    with_state(|state| state.active_outputs > 0)
+}
+
+unsafe extern "C" fn obs_get_video_mock() -> *mut video_t {
+    if with_state(|state| state.video.is_some()) {
+        &MEDIA_MARKER as *const u8 as *mut video_t
+    } else {
+        ptr::null_mut()
+    }
+}
+
+unsafe extern "C" fn obs_get_audio_mock() -> *mut audio_t {
+    if with_state(|state| state.audio.is_some()) {
+        &MEDIA_MARKER as *const u8 as *mut audio_t
+    } else {
+        ptr::null_mut()
+    }
+}
+
+unsafe extern "C" fn obs_audio_monitoring_available_mock() -> bool {
+    false
+}
+
+fn enum_types(kinds: &[TypeKind], idx: usize, id: *mut *const c_char) -> bool {
+    with_state(|state| match state.type_at(kinds, idx) {
+        Some(t) => {
+            unsafe { *id = t.id.as_ptr() };
+            true
+        }
+        None => false,
+    })
+}
+
+unsafe extern "C" fn obs_enum_source_types_mock(idx: usize, id: *mut *const c_char) -> bool {
+    enum_types(&[TypeKind::Source], idx, id)
+}
+
+unsafe extern "C" fn obs_enum_encoder_types_mock(idx: usize, id: *mut *const c_char) -> bool {
+    enum_types(ENCODERS, idx, id)
+}
+
+unsafe extern "C" fn obs_enum_output_types_mock(idx: usize, id: *mut *const c_char) -> bool {
+    enum_types(&[TypeKind::Output], idx, id)
+}
+
+unsafe extern "C" fn obs_enum_service_types_mock(_idx: usize, _id: *mut *const c_char) -> bool {
+    false
+}
+
+// Modules, which are found but can't be opened
+
+unsafe extern "C" fn obs_add_module_path_mock(bin: *const c_char, data: *const c_char) {
+    if bin.is_null() || data.is_null() {
+        return;
+    }
+
+    let bin = CStr::from_ptr(bin).to_string_lossy().into_owned();
+    let data = CStr::from_ptr(data).to_string_lossy().into_owned();
+    with_state(|state| state.module_paths.push((bin, data)));
+}
+
+/// The name, binary path and data path of all libraries in the module paths.
+fn find_modules() -> Vec<(CString, CString, CString)> {
+    let paths = with_state(|state| state.module_paths.clone());
+    let mut modules = Vec::new();
+    for (bin, data) in paths {
+        let Ok(entries) = fs::read_dir(&bin) else {
+            continue;
+        };
+
+        for path in entries.flatten().map(|entry| entry.path()) {
+            if path.extension().and_then(|e| e.to_str()) != Some(env::consts::DLL_EXTENSION) {
+                continue;
+            }
+
+            let Some(name) = path.file_stem().map(|n| n.to_string_lossy().into_owned()) else {
+                continue;
+            };
+            let data_path = format!("{}/{}", data, name);
+            modules.push((
+                CString::new(name).unwrap(),
+                CString::new(path.to_string_lossy().into_owned()).unwrap(),
+                CString::new(data_path).unwrap(),
+            ));
+        }
+    }
+
+    modules
+}
+
+unsafe extern "C" fn obs_find_modules_mock(
+    callback: obs_find_module_callback_t,
+    param: *mut c_void,
+) {
+    let Some(callback) = callback else {
+        return;
+    };
+
+    for (_, bin_path, data_path) in find_modules() {
+        let info = obs_module_info {
+            bin_path: bin_path.as_ptr(),
+            data_path: data_path.as_ptr(),
+        };
+        callback(param, &info);
+    }
+}
+
+unsafe extern "C" fn obs_find_modules2_mock(
+    callback: obs_find_module_callback2_t,
+    param: *mut c_void,
+) {
+    let Some(callback) = callback else {
+        return;
+    };
+
+    for (name, bin_path, data_path) in find_modules() {
+        let info = obs_module_info2 {
+            bin_path: bin_path.as_ptr(),
+            data_path: data_path.as_ptr(),
+            name: name.as_ptr(),
+        };
+        callback(param, &info);
+    }
+}
+
+unsafe extern "C" fn obs_enum_modules_mock(
+    _callback: obs_enum_module_callback_t,
+    _param: *mut c_void,
+) {
+}
+
+unsafe extern "C" fn obs_open_module_mock(
+    _module: *mut *mut obs_module_t,
+    _path: *const c_char,
+    _data_path: *const c_char,
+) -> c_int {
+    MODULE_FILE_NOT_FOUND
+}
+
+// The profiler is not simulated, obs is started as if it was disabled
+
+unsafe extern "C" fn profiler_start_mock() {}
+
+unsafe extern "C" fn profiler_stop_mock() {}
+
+unsafe extern "C" fn profiler_free_mock() {}
+
+unsafe extern "C" fn profiler_name_store_create_mock() -> *mut profiler_name_store_t {
+    ptr::null_mut()
+}
+
+/// The functions exported by each simulated version, leaked so they can be returned by reference.
+static EXPORTED: Mutex<Vec<(u32, &'static Functions)>> = Mutex::new(Vec::new());
+
+/// Get the functions exported by the simulated libobs version.
+pub(super) fn exported() -> &'static Functions {
+    let version = VERSION.load(Ordering::SeqCst);
+    let mut exported = EXPORTED.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some((_, functions)) = exported.iter().find(|(v, _)| *v == version) {
+        return functions;
+    }
+
+    let mut f = functions();
+    let since = |major: u32, minor: u32| version >= (major << 24) | (minor << 16);
+    if !since(27, 0) {
+        f.obs_find_modules2 = None;
+    }
+    if !since(29, 1) {
+        f.obs_reset_audio2 = None;
+        f.obs_get_audio_info2 = None;
+    }
+
+    let functions: &'static Functions = Box::leak(Box::new(f));
+    exported.push((version, functions));
+    functions
+}
+
+/// Get the simulated functions.
+pub(super) fn functions() -> Functions {
+    // All functions are unset, i.e. not exported, by default
+    let mut f: Functions = unsafe { mem::zeroed() };
+
+    f.obs_startup = Some(obs_startup_mock);
+    f.obs_shutdown = Some(obs_shutdown_mock);
+    f.obs_get_version = Some(obs_get_version_mock);
+    f.obs_get_locale = Some(obs_get_locale_mock);
+    f.obs_set_locale = Some(obs_set_locale_mock);
+    f.obs_add_data_path = Some(obs_add_data_path_mock);
+    f.obs_reset_video = Some(obs_reset_video_mock);
+    f.obs_reset_audio = Some(obs_reset_audio_mock);
+    f.obs_reset_audio2 = Some(obs_reset_audio2_mock);
+    f.obs_get_video_info = Some(obs_get_video_info_mock);
+    f.obs_get_audio_info = Some(obs_get_audio_info_mock);
+    f.obs_get_audio_info2 = Some(obs_get_audio_info2_mock);
+    f.obs_video_active = Some(obs_video_active_mock);
+    f.obs_get_video = Some(obs_get_video_mock);
+    f.obs_get_audio = Some(obs_get_audio_mock);
+    f.obs_audio_monitoring_available = Some(obs_audio_monitoring_available_mock);
+    f.obs_set_output_source = Some(obs_set_output_source_mock);
+    f.obs_enum_source_types = Some(obs_enum_source_types_mock);
+    f.obs_enum_encoder_types = Some(obs_enum_encoder_types_mock);
+    f.obs_enum_output_types = Some(obs_enum_output_types_mock);
+    f.obs_enum_service_types = Some(obs_enum_service_types_mock);
+
+    f.obs_add_module_path = Some(obs_add_module_path_mock);
+    f.obs_find_modules = Some(obs_find_modules_mock);
+    f.obs_find_modules2 = Some(obs_find_modules2_mock);
+    f.obs_enum_modules = Some(obs_enum_modules_mock);
+    f.obs_open_module = Some(obs_open_module_mock);
+
+    f.profiler_start = Some(profiler_start_mock);
+    f.profiler_stop = Some(profiler_stop_mock);
+    f.profiler_free = Some(profiler_free_mock);
+    f.profiler_name_store_create = Some(profiler_name_store_create_mock);
+
+    f.obs_data_create = Some(obs_data_create_mock);
+    f.obs_data_create_from_json = Some(obs_data_create_from_json_mock);
+    f.obs_data_addref = Some(obs_data_addref_mock);
+    f.obs_data_release = Some(obs_data_release_mock);
+    f.obs_data_get_json = Some(obs_data_get_json_mock);
+    f.obs_data_set_string = Some(obs_data_set_string_mock);
+    f.obs_data_set_int = Some(obs_data_set_int_mock);
+    f.obs_data_set_double = Some(obs_data_set_double_mock);
+    f.obs_data_set_bool = Some(obs_data_set_bool_mock);
+    f.obs_data_get_string = Some(obs_data_get_string_mock);
+    f.obs_data_get_int = Some(obs_data_get_int_mock);
+    f.obs_data_get_double = Some(obs_data_get_double_mock);
+    f.obs_data_get_bool = Some(obs_data_get_bool_mock);
+    f.obs_data_has_user_value = Some(obs_data_has_user_value_mock);
+
+    f.signal_handler_connect = Some(signal_handler_connect_mock);
+    f.signal_handler_disconnect = Some(signal_handler_disconnect_mock);
+    f.calldata_get_data = Some(calldata_get_data_mock);
+    f.calldata_get_string = Some(calldata_get_string_mock);
+
+    f.obs_source_create = Some(obs_source_create_mock);
+    f.obs_source_release = Some(obs_source_release_mock);
+    f.obs_source_get_id = Some(obs_source_get_id_mock);
+    f.obs_source_get_display_name = Some(obs_source_get_display_name_mock);
+    f.obs_source_get_output_flags = Some(obs_source_get_output_flags_mock);
+    f.obs_source_get_settings = Some(obs_source_get_settings_mock);
+    f.obs_source_update = Some(obs_source_update_mock);
+    f.obs_source_get_signal_handler = Some(obs_source_get_signal_handler_mock);
+    f.obs_get_source_defaults = Some(obs_get_source_defaults_mock);
+    f.obs_source_media_play_pause = Some(obs_source_media_play_pause_mock);
+    f.obs_source_media_restart = Some(obs_source_media_restart_mock);
+    f.obs_source_media_stop = Some(obs_source_media_stop_mock);
+    f.obs_source_media_next = Some(obs_source_media_next_mock);
+    f.obs_source_media_previous = Some(obs_source_media_previous_mock);
+    f.obs_source_media_set_time = Some(obs_source_media_set_time_mock);
+    f.obs_source_media_get_time = Some(obs_source_media_get_time_mock);
+    f.obs_source_media_get_duration = Some(obs_source_media_get_duration_mock);
+    f.obs_source_media_get_state = Some(obs_source_media_get_state_mock);
+    f.obs_source_output_video = Some(obs_source_output_video_mock);
+    f.obs_source_output_audio = Some(obs_source_output_audio_mock);
+    f.obs_get_video_frame_time = Some(obs_get_video_frame_time_mock);
+    f.video_format_get_parameters = Some(video_format_get_parameters_mock);
+    f.obs_register_source_s = Some(obs_register_source_s_mock);
+
+    f.obs_video_encoder_create = Some(obs_video_encoder_create_mock);
+    f.obs_audio_encoder_create = Some(obs_audio_encoder_create_mock);
+    f.obs_encoder_release = Some(obs_encoder_release_mock);
+    f.obs_encoder_get_type = Some(obs_encoder_get_type_mock);
+    f.obs_encoder_get_codec = Some(obs_encoder_get_codec_mock);
+    f.obs_encoder_get_settings = Some(obs_encoder_get_settings_mock);
+    f.obs_encoder_update = Some(obs_encoder_update_mock);
+    f.obs_encoder_set_video = Some(obs_encoder_set_video_mock);
+    f.obs_encoder_set_audio = Some(obs_encoder_set_audio_mock);
+    f.obs_get_encoder_type = Some(obs_get_encoder_type_mock);
+    f.obs_get_encoder_codec = Some(obs_get_encoder_codec_mock);
+    f.obs_get_encoder_caps = Some(obs_get_encoder_caps_mock);
+    f.obs_encoder_get_display_name = Some(obs_encoder_get_display_name_mock);
+    f.obs_encoder_defaults = Some(obs_encoder_defaults_mock);
+
+    f.obs_output_create = Some(obs_output_create_mock);
+    f.obs_output_get_ref = Some(obs_output_get_ref_mock);
+    f.obs_enum_outputs = Some(obs_enum_outputs_mock);
+    f.obs_output_release = Some(obs_output_release_mock);
+    f.obs_output_start = Some(obs_output_start_mock);
+    f.obs_output_stop = Some(obs_output_stop_mock);
+    f.obs_output_force_stop = Some(obs_output_force_stop_mock);
+    f.obs_output_active = Some(obs_output_active_mock);
+    f.obs_output_reconnecting = Some(obs_output_reconnecting_mock);
+    f.obs_output_paused = Some(obs_output_paused_mock);
+    f.obs_output_can_pause = Some(obs_output_can_pause_mock);
+    f.obs_output_pause = Some(obs_output_pause_mock);
+    f.obs_output_get_name = Some(obs_output_get_name_mock);
+    f.obs_output_get_id = Some(obs_output_get_id_mock);
+    f.obs_output_get_settings = Some(obs_output_get_settings_mock);
+    f.obs_output_update = Some(obs_output_update_mock);
+    f.obs_output_get_signal_handler = Some(obs_output_get_signal_handler_mock);
+    f.obs_output_get_last_error = Some(obs_output_get_last_error_mock);
+    f.obs_output_set_last_error = Some(obs_output_set_last_error_mock);
+    f.obs_output_set_media = Some(obs_output_set_media_mock);
+    f.obs_output_set_video_encoder = Some(obs_output_set_video_encoder_mock);
+    f.obs_output_set_audio_encoder = Some(obs_output_set_audio_encoder_mock);
+    f.obs_output_get_video_encoder = Some(obs_output_get_video_encoder_mock);
+    f.obs_output_get_audio_encoder = Some(obs_output_get_audio_encoder_mock);
+    f.obs_output_get_total_frames = Some(obs_output_get_total_frames_mock);
+    f.obs_output_get_frames_dropped = Some(obs_output_get_frames_dropped_mock);
+    f.obs_output_get_total_bytes = Some(obs_output_get_total_bytes_mock);
+    f.obs_output_get_display_name = Some(obs_output_get_display_name_mock);
+    f.obs_get_output_flags = Some(obs_get_output_flags_mock);
+    f.obs_get_output_supported_video_codecs = Some(obs_get_output_supported_video_codecs_mock);
+    f.obs_get_output_supported_audio_codecs = Some(obs_get_output_supported_audio_codecs_mock);
+    f.obs_output_defaults = Some(obs_output_defaults_mock);
+    f.obs_register_output_s = Some(obs_register_output_s_mock);
+
+    f
+}
@@ -0,0 +1,152 @@
+use crate::obs::sys;
+use crate::obs::util::c_string::to_string;
+use crate::obs::util::signal_handler::SignalConnection;
+use crate::obs::util::types::ResultType;
+use std::ffi::c_void;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// The time to wait for the video to become inactive after outputs were stopped forcefully.
+const FORCE_STOP_TIMEOUT: Duration = Duration::from_secs(2);
+
+unsafe extern "C" fn collect_output(param: *mut c_void, output: *mut sys::obs_output_t) -> bool {
+    let outputs = &mut *(param as *mut Vec<*mut sys::obs_output_t>);
+    if sys::obs_output_active(output) {
+        let output = sys::obs_output_get_ref(output);
+        if !output.is_null() {
+            outputs.push(output);
+        }
+    }
+
+    true
+}
+
+/// References to all outputs which were active when this was created,
+/// including outputs which were not created by this crate.
+/// The state of `ObsOutput`s follows their signals, so they
+/// reflect outputs being stopped and started here.
+pub struct ActiveOutputs(Vec<*mut sys::obs_output_t>);
+
+impl ActiveOutputs {
+    pub fn collect() -> Self {
+        let mut outputs: Vec<*mut sys::obs_output_t> = Vec::new();
+        unsafe {
+            sys::obs_enum_outputs(
+                Some(collect_output),
+                &mut outputs as *mut Vec<*mut sys::obs_output_t> as *mut c_void,
+            );
+        }
+
+        Self(outputs)
+    }
+
+    /// Stop all outputs and wait until they and the video have become inactive.
+    /// Outputs which didn't stop within `timeout` are stopped forcefully.
+    pub fn stop(&self, timeout: Duration) {
+        let (sender, receiver) = mpsc::channel();
+        let _connections = self
+            .0
+            .iter()
+            .filter_map(|output| {
+                let sender = sender.clone();
+                unsafe {
+                    SignalConnection::connect(
+                        sys::obs_output_get_signal_handler(*output),
+                        "stop",
+                        move |_| {
+                            let _ = sender.send(());
+                        },
+                    )
+                }
+                .ok()
+            })
+            .collect::<Vec<_>>();
+
+        for output in &self.0 {
+            unsafe { sys::obs_output_stop(*output) };
+        }
+
+        // The stop signal only wakes this up early,
+        // the outputs are polled in case they stopped without it
+        let deadline = Instant::now() + timeout;
+        while self.any_active() && Instant::now() < deadline {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            let _ = receiver.recv_timeout(remaining.min(Duration::from_millis(50)));
+        }
+
+        for output in &self.0 {
+            if unsafe { sys::obs_output_active(*output) } {
+                unsafe { sys::obs_output_force_stop(*output) };
+            }
+        }
+
+        // Encoders may still be shutting down once the outputs are inactive
+        let deadline = Instant::now() + FORCE_STOP_TIMEOUT;
+        while unsafe { sys::obs_video_active() } && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    /// Bind the encoders of all outputs to the current video and audio and start them.
+    /// Returns an error listing the outputs which failed to start.
+    pub fn restart(&self) -> ResultType<()> {
+        let mut failed = Vec::new();
+        for output in &self.0 {
+            if let Err(e) = unsafe { restart_output(*output) } {
+                failed.push(e);
+            }
+        }
+
+        if failed.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("Failed to restart outputs: {}", failed.join(", ")).into())
+        }
+    }
+
+    fn any_active(&self) -> bool {
+        self.0
+            .iter()
+            .any(|output| unsafe { sys::obs_output_active(*output) })
+    }
+}
+
+unsafe fn restart_output(output: *mut sys::obs_output_t) -> Result<(), String> {
+    let video = sys::obs_get_video();
+    let audio = sys::obs_get_audio();
+
+    let video_encoder = sys::obs_output_get_video_encoder(output);
+    if !video_encoder.is_null() {
+        sys::obs_encoder_set_video(video_encoder, video);
+    }
+
+    // Multitrack outputs have an audio encoder per mix
+    for idx in 0..sys::MAX_AUDIO_MIXES as usize {
+        let audio_encoder = sys::obs_output_get_audio_encoder(output, idx);
+        if !audio_encoder.is_null() {
+            sys::obs_encoder_set_audio(audio_encoder, audio);
+        }
+    }
+
+    sys::obs_output_set_media(output, video, audio);
+    if sys::obs_output_start(output) {
+        return Ok(());
+    }
+
+    let name = to_string(sys::obs_output_get_name(output)).unwrap_or_default();
+    let error = to_string(sys::obs_output_get_last_error(output)).unwrap_or_default();
+    Err(if error.is_empty() {
+        name
+    } else {
+        format!("{} ({})", name, error)
+    })
+}
+
+impl Drop for ActiveOutputs {
+    fn drop(&mut self) {
+        for output in &self.0 {
+            unsafe { sys::obs_output_release(*output) };
+        }
+    }
+}
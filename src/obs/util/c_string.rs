@@ -0,0 +1,13 @@
+use std::ffi::{c_char, CStr};
+
+/// Copy a string owned by libobs, `None` if the pointer is null.
+///
+/// # Safety
+/// The pointer must be null or point to a nul terminated string.
+pub(crate) unsafe fn to_string(ptr: *const c_char) -> Option<String> {
+    if ptr.is_null() {
+        None
+    } else {
+        Some(CStr::from_ptr(ptr).to_string_lossy().into_owned())
+    }
+}
@@ -1,5 +1,7 @@
-pub(crate) mod napi_error;
-pub(crate) mod node_util;
+pub(crate) mod active_outputs;
+pub(crate) mod c_string;
+pub(crate) mod nix_display;
 pub(crate) mod obs_error;
 pub(crate) mod obs_guard;
+pub(crate) mod signal_handler;
 pub(crate) mod types;
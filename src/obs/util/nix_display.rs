@@ -0,0 +1,119 @@
+use crate::obs::objects::nix_platform::NixPlatform;
+use crate::obs::sys;
+use crate::obs::util::obs_error::ObsError;
+use crate::obs::util::types::ResultType;
+use libloading::Library;
+use std::ffi::{c_char, c_int, c_void, CString};
+use std::ptr;
+
+type SetPlatform = unsafe extern "C" fn(c_int);
+type SetPlatformDisplay = unsafe extern "C" fn(*mut c_void);
+type OpenDisplay = unsafe extern "C" fn(*const c_char) -> *mut c_void;
+
+/// A connection to the X11 or Wayland display obs renders on.
+/// The display is closed once dropped, which must happen after obs has been shut down.
+pub struct NixDisplay {
+    platform: NixPlatform,
+    display: *mut c_void,
+    library: Library,
+}
+
+impl NixDisplay {
+    /// Connect to the display with the given name, or the display of the
+    /// current session if no name is given, and pass it to obs.
+    /// Must be called before obs is started.
+    pub fn open(platform: NixPlatform, name: Option<&str>) -> ResultType<Self> {
+        if !cfg!(unix) || cfg!(target_os = "macos") {
+            return Err(ObsError::Unsupported(
+                "The platform can only be set on Linux and other unix systems",
+            ));
+        }
+
+        let (set_platform, set_display) = unsafe {
+            (
+                sys::symbol::<SetPlatform>(b"obs_set_nix_platform\0"),
+                sys::symbol::<SetPlatformDisplay>(b"obs_set_nix_platform_display\0"),
+            )
+        };
+        let (set_platform, set_display) = set_platform.zip(set_display).ok_or(
+            ObsError::Unsupported("libobs does not support setting the platform"),
+        )?;
+
+        let (libraries, open_symbol): (&[&str], &[u8]) = match platform {
+            NixPlatform::X11 => (&["libX11.so.6", "libX11.so"], b"XOpenDisplay\0"),
+            NixPlatform::Wayland => (
+                &["libwayland-client.so.0", "libwayland-client.so"],
+                b"wl_display_connect\0",
+            ),
+        };
+
+        let library = libraries
+            .iter()
+            .find_map(|name| unsafe { Library::new(name) }.ok())
+            .ok_or_else(|| {
+                ObsError::Library(format!(
+                    "Failed to load {} for {:?}",
+                    libraries[0], platform
+                ))
+            })?;
+
+        let name = name.map(CString::new).transpose()?;
+        let display = unsafe {
+            let open = library
+                .get::<OpenDisplay>(open_symbol)
+                .map_err(|e| ObsError::Library(e.to_string()))?;
+            open(
+                name.as_ref()
+                    .map(|name| name.as_ptr())
+                    .unwrap_or(ptr::null()),
+            )
+        };
+
+        if display.is_null() {
+            return Err(ObsError::Failed(format!(
+                "Failed to connect to the {:?} display {}",
+                platform,
+                name.as_ref()
+                    .map(|name| name.to_string_lossy().into_owned())
+                    .unwrap_or_else(|| "of the current session".to_string())
+            )));
+        }
+
+        unsafe {
+            set_platform(platform.value());
+            set_display(display);
+        }
+
+        Ok(Self {
+            platform,
+            display,
+            library,
+        })
+    }
+}
+
+impl Drop for NixDisplay {
+    fn drop(&mut self) {
+        unsafe {
+            match self.platform {
+                NixPlatform::X11 => {
+                    type CloseDisplay = unsafe extern "C" fn(*mut c_void) -> c_int;
+                    if let Ok(close) = self.library.get::<CloseDisplay>(b"XCloseDisplay\0") {
+                        close(self.display);
+                    }
+                }
+                NixPlatform::Wayland => {
+                    type Disconnect = unsafe extern "C" fn(*mut c_void);
+                    if let Ok(disconnect) =
+                        self.library.get::<Disconnect>(b"wl_display_disconnect\0")
+                    {
+                        disconnect(self.display);
+                    }
+                }
+            }
+        }
+    }
+}
+
+unsafe impl Send for NixDisplay {}
+unsafe impl Sync for NixDisplay {}
@@ -1,7 +1,13 @@
+use crate::obs::data::settings_validation::{issues_to_string, SettingsIssue};
 use crate::obs::sys;
+use std::error::Error;
+use std::ffi::NulError;
+use std::fmt::{self, Display};
+use std::str::Utf8Error;
 
 pub const OBS_VIDEO_SUCCESS: i32 = sys::OBS_VIDEO_SUCCESS as _;
 pub const OBS_MODULE_SUCCESS: i32 = sys::MODULE_SUCCESS as _;
+pub const OBS_OUTPUT_SUCCESS: i32 = sys::OBS_OUTPUT_SUCCESS as _;
 
 pub fn obs_error_to_string(code: i32) -> &'static str {
     match code {
@@ -26,3 +32,151 @@ pub fn module_error_to_string(code: i32) -> &'static str {
         _ => "unknown error",
     }
 }
+
+pub fn output_error_to_string(code: i32) -> &'static str {
+    match code {
+        OBS_OUTPUT_SUCCESS => "success",
+        sys::OBS_OUTPUT_BAD_PATH => "bad path",
+        sys::OBS_OUTPUT_CONNECT_FAILED => "connection failed",
+        sys::OBS_OUTPUT_INVALID_STREAM => "invalid stream",
+        sys::OBS_OUTPUT_ERROR => "generic error",
+        sys::OBS_OUTPUT_DISCONNECTED => "disconnected",
+        sys::OBS_OUTPUT_UNSUPPORTED => "unsupported",
+        sys::OBS_OUTPUT_NO_SPACE => "no space left",
+        sys::OBS_OUTPUT_ENCODE_ERROR => "encode error",
+        _ => "unknown error",
+    }
+}
+
+/// An error returned by the obs api.
+#[derive(Debug)]
+pub enum ObsError {
+    /// libobs could not be loaded or is not supported.
+    Library(String),
+    /// The loaded libobs is too old to support a feature.
+    RequiresVersion {
+        feature: &'static str,
+        version: (u32, u32),
+    },
+    /// obs could not be started.
+    Startup,
+    /// The video could not be reset.
+    /// Contains the obs error code, see `obs_error_to_string`.
+    ResetVideo(i32),
+    /// The audio could not be reset.
+    ResetAudio,
+    /// No type of the given kind with the given id is registered.
+    UnknownType { kind: &'static str, id: String },
+    /// obs failed to create a source, encoder, output or settings object.
+    CreateFailed(&'static str),
+    /// A module could not be loaded or initialized.
+    Module { name: String, reason: String },
+    /// Settings did not pass the validation against the properties.
+    InvalidSettings(Vec<SettingsIssue>),
+    /// The object does not support the operation,
+    /// e.g. media controls on a source which is not a media source.
+    Unsupported(&'static str),
+    /// The object is not in a state which allows the operation,
+    /// e.g. stopping an output which is not running.
+    InvalidState(&'static str),
+    /// An output failed to start.
+    /// Contains the last error reported by the output.
+    OutputStart(String),
+    /// An output stopped before it started.
+    /// Contains the stop code, see `output_error_to_string`,
+    /// and the last error reported by the output.
+    OutputStopped { code: i32, message: Option<String> },
+    /// Waiting for an operation to finish timed out.
+    Timeout(&'static str),
+    /// A string passed to obs contained a nul byte.
+    Nul(NulError),
+    /// A string returned by obs was not valid utf-8.
+    Utf8(Utf8Error),
+    /// Any other error.
+    Failed(String),
+}
+
+impl Display for ObsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ObsError::Library(message) => write!(f, "Failed to load libobs: {}", message),
+            ObsError::RequiresVersion { feature, version } => write!(
+                f,
+                "{} requires libobs >= {}.{}",
+                feature, version.0, version.1
+            ),
+            ObsError::Startup => write!(f, "Failed to initialize OBS"),
+            ObsError::ResetVideo(code) => {
+                write!(
+                    f,
+                    "Failed to set video. Error: {}",
+                    obs_error_to_string(*code)
+                )
+            }
+            ObsError::ResetAudio => write!(f, "Failed to reset audio"),
+            ObsError::UnknownType { kind, id } => write!(f, "Unknown {} type '{}'", kind, id),
+            ObsError::CreateFailed(kind) => write!(f, "Failed to create {}", kind),
+            ObsError::Module { name, reason } => {
+                write!(f, "Failed to load module '{}': {}", name, reason)
+            }
+            ObsError::InvalidSettings(issues) => {
+                write!(f, "Invalid settings: {}", issues_to_string(issues))
+            }
+            ObsError::Unsupported(message) | ObsError::InvalidState(message) => {
+                write!(f, "{}", message)
+            }
+            ObsError::OutputStart(message) => {
+                write!(f, "Failed to start output. Error message: {}", message)
+            }
+            ObsError::OutputStopped { code, message } => {
+                write!(
+                    f,
+                    "The output stopped. Error: {}",
+                    output_error_to_string(*code)
+                )?;
+                match message {
+                    Some(message) => write!(f, ". Error message: {}", message),
+                    None => Ok(()),
+                }
+            }
+            ObsError::Timeout(message) => write!(f, "{}", message),
+            ObsError::Nul(err) => write!(f, "{}", err),
+            ObsError::Utf8(err) => write!(f, "{}", err),
+            ObsError::Failed(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl Error for ObsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ObsError::Nul(err) => Some(err),
+            ObsError::Utf8(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<NulError> for ObsError {
+    fn from(err: NulError) -> Self {
+        ObsError::Nul(err)
+    }
+}
+
+impl From<Utf8Error> for ObsError {
+    fn from(err: Utf8Error) -> Self {
+        ObsError::Utf8(err)
+    }
+}
+
+impl From<String> for ObsError {
+    fn from(message: String) -> Self {
+        ObsError::Failed(message)
+    }
+}
+
+impl From<&str> for ObsError {
+    fn from(message: &str) -> Self {
+        ObsError::Failed(message.to_string())
+    }
+}
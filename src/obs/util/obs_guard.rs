@@ -1,10 +1,34 @@
+use crate::obs::data::profiler_snapshot::ProfilerSnapshot;
+use crate::obs::plugin::{clear_registered_types, log_error};
 use crate::obs::sys;
+use crate::obs::util::nix_display::NixDisplay;
+use std::sync::atomic::{AtomicPtr, Ordering};
 
-pub struct ObsGuard {}
+pub struct ObsGuard {
+    /// The name store of the profiler, null if the profiler is disabled.
+    profiler_names: AtomicPtr<sys::profiler_name_store_t>,
+    /// The file the profiler snapshot is written to on shutdown.
+    profiler_dump_path: Option<String>,
+    /// The display obs renders on, closed after obs has been shut down.
+    _display: Option<NixDisplay>,
+}
 
 impl ObsGuard {
-    pub fn new() -> Self {
-        Self {}
+    pub fn new(
+        profiler_names: *mut sys::profiler_name_store_t,
+        profiler_dump_path: Option<String>,
+        display: Option<NixDisplay>,
+    ) -> Self {
+        Self {
+            profiler_names: AtomicPtr::new(profiler_names),
+            profiler_dump_path,
+            _display: display,
+        }
+    }
+
+    /// Check if obs was started with the profiler enabled.
+    pub fn profiler_enabled(&self) -> bool {
+        !self.profiler_names.load(Ordering::Relaxed).is_null()
     }
 }
 
@@ -13,6 +37,20 @@ impl Drop for ObsGuard {
         unsafe {
             println!("Dropping obs");
             sys::obs_shutdown();
+            clear_registered_types();
+
+            let profiler_names = self.profiler_names.load(Ordering::Relaxed);
+            if !profiler_names.is_null() {
+                sys::profiler_stop();
+                if let Some(path) = &self.profiler_dump_path {
+                    if let Err(e) = ProfilerSnapshot::create().and_then(|s| s.dump(path)) {
+                        log_error(format!("Failed to dump the profiler snapshot: {}", e));
+                    }
+                }
+
+                sys::profiler_free();
+                sys::profiler_name_store_free(profiler_names);
+            }
         }
     }
 }
@@ -0,0 +1,103 @@
+use crate::obs::sys;
+use crate::obs::util::types::ResultType;
+use std::ffi::{c_void, CString};
+use std::mem;
+use std::ptr;
+
+type SignalCallback = Box<dyn Fn(*mut sys::calldata_t) + Send + Sync>;
+
+unsafe extern "C" fn signal_callback(data: *mut c_void, calldata: *mut sys::calldata_t) {
+    let callback = &*(data as *const SignalCallback);
+    callback(calldata);
+}
+
+/// A connection to a signal of an obs signal handler.
+/// The callback is disconnected once this is dropped,
+/// so this must be dropped before the owner of the signal handler is released.
+pub struct SignalConnection {
+    handler: *mut sys::signal_handler_t,
+    signal: CString,
+    callback: *mut SignalCallback,
+}
+
+impl SignalConnection {
+    /// Connect `callback` to the signal `signal` of `handler`.
+    pub unsafe fn connect<F>(
+        handler: *mut sys::signal_handler_t,
+        signal: &str,
+        callback: F,
+    ) -> ResultType<Self>
+    where
+        F: Fn(*mut sys::calldata_t) + Send + Sync + 'static,
+    {
+        if handler.is_null() {
+            return Err("The signal handler is null".into());
+        }
+
+        let signal = CString::new(signal)?;
+        let callback: *mut SignalCallback = Box::into_raw(Box::new(Box::new(callback)));
+        sys::signal_handler_connect(
+            handler,
+            signal.as_ptr(),
+            Some(signal_callback),
+            callback as *mut c_void,
+        );
+
+        Ok(Self {
+            handler,
+            signal,
+            callback,
+        })
+    }
+}
+
+impl Drop for SignalConnection {
+    fn drop(&mut self) {
+        unsafe {
+            sys::signal_handler_disconnect(
+                self.handler,
+                self.signal.as_ptr(),
+                Some(signal_callback),
+                self.callback as *mut c_void,
+            );
+            drop(Box::from_raw(self.callback));
+        }
+    }
+}
+
+unsafe impl Send for SignalConnection {}
+unsafe impl Sync for SignalConnection {}
+
+/// Get an integer parameter of a signal.
+pub unsafe fn calldata_int(calldata: *mut sys::calldata_t, name: &str) -> Option<i64> {
+    let name = CString::new(name).ok()?;
+    let mut value: i64 = 0;
+
+    if sys::calldata_get_data(
+        calldata,
+        name.as_ptr(),
+        &mut value as *mut i64 as *mut c_void,
+        mem::size_of::<i64>() as _,
+    ) {
+        Some(value)
+    } else {
+        None
+    }
+}
+
+/// Get a pointer parameter of a signal.
+pub unsafe fn calldata_ptr<T>(calldata: *mut sys::calldata_t, name: &str) -> Option<*mut T> {
+    let name = CString::new(name).ok()?;
+    let mut value: *mut T = ptr::null_mut();
+
+    if sys::calldata_get_data(
+        calldata,
+        name.as_ptr(),
+        &mut value as *mut *mut T as *mut c_void,
+        mem::size_of::<*mut T>() as _,
+    ) {
+        Some(value)
+    } else {
+        None
+    }
+}
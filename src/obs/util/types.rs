@@ -1,3 +1,3 @@
-use std::error::Error;
+use crate::obs::util::obs_error::ObsError;
 
-pub type ResultType<T> = Result<T, Box<dyn Error>>;
+pub type ResultType<T> = Result<T, ObsError>;
@@ -0,0 +1,15 @@
+import { Readable, ReadableOptions } from 'stream';
+import { ObsOutput } from './index';
+
+/**
+ * Create a readable stream of the MPEG-TS data of an output.
+ * The output must be of a type registered using `Obs.registerMpegTsOutputType`.
+ * The stream ends once the output is stopped.
+ *
+ * obs does not wait for the consumer, data is buffered
+ * in the stream if it is read slower than it is produced.
+ *
+ * @param output the output to read from
+ * @param options the stream options
+ */
+export function createOutputStream(output: ObsOutput, options?: ReadableOptions): Readable;
@@ -0,0 +1,32 @@
+const { Readable } = require('stream');
+
+/**
+ * Create a readable stream of the MPEG-TS data of an output.
+ * The output must be of a type registered using `Obs.registerMpegTsOutputType`.
+ * The stream ends once the output is stopped.
+ *
+ * obs does not wait for the consumer, data is buffered
+ * in the stream if it is read slower than it is produced.
+ *
+ * @param {import('./index').ObsOutput} output the output to read from
+ * @param {import('stream').ReadableOptions} [options] the stream options
+ * @returns {Readable} the stream
+ */
+function createOutputStream(output, options) {
+    const stream = new Readable({
+        ...options,
+        read() {},
+    });
+
+    // Keep the output alive as long as the stream is in use
+    stream.output = output;
+    output.onData((chunk) => {
+        if (!stream.destroyed) {
+            stream.push(chunk);
+        }
+    });
+
+    return stream;
+}
+
+module.exports = { createOutputStream };
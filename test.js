@@ -1,33 +1,14 @@
 const obs = require('./index');
 
-const skip = [
-    'frontend-tools',
-    'obs-websocket',
-    //'win-wasapi',
-    'win-mf'
-];
-
-const modulesToSkip = [
-    'aja-output-ui',
-    'aja',
-    'chrome_elf',
-    'decklink',
-    'enc-amf',
-    'libcef',
-    'libEGL',
-    'libGLESv2',
-    'win-decklink',
-    //'win-dshow',
-    //'win-capture',
-    ...skip
-];
-
 async function main() {
     process.chdir(await obs.Obs.findObs(true));
     const instance = await obs.Obs.newInstance('en-US');
     const modules = await instance.getAllModules();
-    instance.loadModulesSync(modules.filter(m => !modulesToSkip.includes(m.name)), false);
-    console.log('Failed modules:', instance.failedModules);
+    const results = instance.loadModulesSync(modules, {
+        deny: ['aja*', 'chrome_elf', '*decklink', 'enc-amf', 'libcef', 'libEGL', 'libGLESv2', 'win-mf'],
+        disabled: ['frontend-tools', 'obs-websocket'],
+    });
+    console.log('Failed modules:', results.filter(r => r.status === obs.ModuleLoadStatus.Failed));
     console.log('Loaded modules:', instance.loadedModules);
 
     await instance.resetAudio({
@@ -71,7 +52,7 @@ async function main() {
     await instance.createSource('audio_capture', 'wasapi_output_capture', 1);
     //console.log(audio.getProperties().getProperties())
 
-    const out = await instance.createOutput('flv_output', 'output', new obs.ObsSettings()
+    const out = await instance.createOutput('output', 'flv_output', new obs.ObsSettings()
         .setString('path', "C:/Users/marku/Desktop/test.flv"));
     console.log(await instance.listOutputTypes());
 